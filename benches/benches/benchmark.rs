@@ -1,6 +1,6 @@
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 
-use juniper::InputValue;
+use juniper::{InputValue, parser::parse_document_source};
 use juniper_benchmarks as j;
 
 fn bench_sync_vs_async_users_flat_instant(c: &mut Criterion) {
@@ -76,5 +76,74 @@ fn bench_sync_vs_async_users_flat_instant(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_sync_vs_async_users_flat_instant);
+fn bench_validation_overlapping_fields(c: &mut Criterion) {
+    /// Query spreading `count` sibling fragments selecting the same fields.
+    fn sibling_fragments(count: usize) -> String {
+        let spreads = (0..count)
+            .map(|i| format!("...UserFields{i}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let fragments = (0..count)
+            .map(|i| format!("fragment UserFields{i} on User {{ id kind username email }}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!("query {{ usersSyncInstant(ids: [1]) {{ {spreads} }} }}\n{fragments}")
+    }
+
+    /// Query spreading a chain of `count` fragments, each one spreading the next.
+    fn nested_fragments(count: usize) -> String {
+        let fragments = (0..count)
+            .map(|i| {
+                let next = if i + 1 < count {
+                    format!("...UserFields{}", i + 1)
+                } else {
+                    String::new()
+                };
+                format!("fragment UserFields{i} on User {{ id username {next} }}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            "query {{ usersSyncInstant(ids: [1]) {{ id ...UserFields0 }} \
+             userSyncInstant(id: 1) {{ ...UserFields0 }} }}\n{fragments}",
+        )
+    }
+
+    /// Query selecting the same field `count` times, each one with a sub-selection.
+    fn repeated_fields(count: usize) -> String {
+        let fields = (0..count)
+            .map(|_| "userSyncInstant(id: 1) { id username email }")
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!("query {{ {fields} }}")
+    }
+
+    let schema = j::new_schema();
+
+    let mut group = c.benchmark_group("Validation - Overlapping Fields");
+    for count in [10, 100, 500] {
+        for (name, query) in [
+            ("Sibling Fragments", sibling_fragments(count)),
+            ("Nested Fragments", nested_fragments(count)),
+            ("Repeated Fields", repeated_fields(count)),
+        ] {
+            let document = parse_document_source(&query, &schema.schema).unwrap();
+            assert!(
+                j::validate(&schema, &document).is_empty(),
+                "{name} query should be valid",
+            );
+
+            group.bench_function(BenchmarkId::new(name, count), |b| {
+                b.iter(|| j::validate(&schema, &document))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_sync_vs_async_users_flat_instant,
+    bench_validation_overlapping_fields,
+);
 criterion_main!(benches);
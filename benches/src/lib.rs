@@ -1,8 +1,9 @@
 #![cfg_attr(test, expect(unused_crate_dependencies, reason = "benches"))]
 
 use juniper::{
-    DefaultScalarValue, EmptyMutation, EmptySubscription, ExecutionError, FieldError, GraphQLEnum,
-    GraphQLObject, RootNode, Value, Variables, graphql_object,
+    DefaultScalarValue, Document, EmptyMutation, EmptySubscription, ExecutionError, FieldError,
+    GraphQLEnum, GraphQLObject, RootNode, RuleError, Value, Variables, graphql_object,
+    validation::{ValidatorContext, visit_all_rules},
 };
 
 pub type QueryResult = Result<
@@ -108,3 +109,12 @@ pub async fn execute(query: &str, vars: Variables) -> QueryResult {
         .await
         .map_err(|e| format!("{e:?}"))
 }
+
+pub fn validate<'a>(
+    root: &'a RootNode<Query, EmptyMutation<Context>, EmptySubscription<Context>>,
+    document: &'a Document<'a, DefaultScalarValue>,
+) -> Vec<RuleError> {
+    let mut ctx = ValidatorContext::new(&root.schema, document);
+    visit_all_rules(&mut ctx, document);
+    ctx.into_errors()
+}
@@ -0,0 +1,212 @@
+//! Typed execution of GraphQL operations, described by a [`GraphQLQuery`].
+//!
+//! A [`GraphQLQuery`] is usually generated from an executable GraphQL document and a stored SDL
+//! of the schema via `#[derive(GraphQLQuery)]` macro (requires `schema-language` feature), along
+//! with strongly typed `Variables` and `ResponseData` of the operation.
+
+use crate::{
+    DefaultScalarValue, ExecutionError, FieldError, FromInputValue, GraphQLError, GraphQLType,
+    GraphQLTypeAsync, RootNode, ScalarValue, ToInputValue, Value, Variables,
+};
+
+/// Typed GraphQL operation.
+pub trait GraphQLQuery<S = DefaultScalarValue> {
+    /// Type of the variables of this operation.
+    type Variables: ToInputValue<S>;
+
+    /// Type of the data returned by this operation.
+    type ResponseData: FromInputValue<S, Error = FieldError<S>>;
+
+    /// Name of this operation.
+    const OPERATION_NAME: &'static str;
+
+    /// Source of the GraphQL document containing this operation.
+    const DOCUMENT: &'static str;
+
+    /// Converts the provided typed `variables` of this operation into [`Variables`].
+    ///
+    /// Omitted (absent) variables are not included.
+    #[must_use]
+    fn variables(variables: &Self::Variables) -> Variables<S>
+    where
+        S: Clone,
+    {
+        variables
+            .to_input_value()
+            .to_object_value()
+            .map(|o| o.into_iter().map(|(k, v)| (k.into(), v.clone())).collect())
+            .unwrap_or_default()
+    }
+
+    /// Decodes the typed response data of this operation from the provided [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// If the provided [`Value`] doesn't match the shape of [`GraphQLQuery::ResponseData`].
+    fn response_data(value: &Value<S>) -> Result<Self::ResponseData, FieldError<S>>
+    where
+        S: Clone,
+    {
+        Self::ResponseData::from_input_value(&value.to_input_value())
+    }
+}
+
+/// Typed response of a [`GraphQLQuery`] execution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response<D, S = DefaultScalarValue> {
+    /// Decoded data of the response.
+    ///
+    /// Errors, if the data cannot be decoded, which happens when a non-`null`able field fails to
+    /// resolve, for example.
+    pub data: Result<D, FieldError<S>>,
+
+    /// Errors occurred during the execution.
+    pub errors: Vec<ExecutionError<S>>,
+}
+
+impl<D, S: Clone> Response<D, S> {
+    /// Creates a new typed [`Response`] from the provided execution result of the `Q` operation.
+    fn new<Q>((value, errors): (Value<S>, Vec<ExecutionError<S>>)) -> Self
+    where
+        Q: GraphQLQuery<S, ResponseData = D> + ?Sized,
+    {
+        Self {
+            data: Q::response_data(&value),
+            errors,
+        }
+    }
+}
+
+/// Executes the provided [`GraphQLQuery`] with the given `variables` synchronously.
+///
+/// # Errors
+///
+/// If the [`GraphQLQuery::DOCUMENT`] cannot be parsed, fails validation or cannot be executed.
+pub fn execute_sync<Q, QueryT, MutationT, SubscriptionT, S>(
+    _: Q,
+    variables: &Q::Variables,
+    root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+    context: &QueryT::Context,
+) -> Result<Response<Q::ResponseData, S>, GraphQLError>
+where
+    Q: GraphQLQuery<S>,
+    S: ScalarValue,
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S, Context = QueryT::Context>,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
+{
+    crate::execute_sync(
+        Q::DOCUMENT,
+        Some(Q::OPERATION_NAME),
+        root_node,
+        &Q::variables(variables),
+        context,
+    )
+    .map(Response::new::<Q>)
+}
+
+/// Executes the provided [`GraphQLQuery`] with the given `variables`.
+///
+/// # Errors
+///
+/// If the [`GraphQLQuery::DOCUMENT`] cannot be parsed, fails validation or cannot be executed.
+pub async fn execute<Q, QueryT, MutationT, SubscriptionT, S>(
+    _: Q,
+    variables: &Q::Variables,
+    root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+    context: &QueryT::Context,
+) -> Result<Response<Q::ResponseData, S>, GraphQLError>
+where
+    Q: GraphQLQuery<S>,
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    crate::execute(
+        Q::DOCUMENT,
+        Some(Q::OPERATION_NAME),
+        root_node,
+        &Q::variables(variables),
+        context,
+    )
+    .await
+    .map(Response::new::<Q>)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        EmptyMutation, EmptySubscription, FieldError, FromInputValue, InputValue, RootNode,
+        ToInputValue, graphql_object, macros::helper::client as helper,
+    };
+
+    use super::{GraphQLQuery, Response, execute_sync};
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn greeting(name: String) -> String {
+            format!("Hello, {name}!")
+        }
+    }
+
+    struct Greeting;
+
+    struct Variables {
+        name: String,
+    }
+
+    impl ToInputValue for Variables {
+        fn to_input_value(&self) -> InputValue {
+            graphql_input_value!({"name": (self.name.clone())})
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct ResponseData {
+        greeting: String,
+    }
+
+    impl FromInputValue for ResponseData {
+        type Error = FieldError;
+
+        fn from_input_value(v: &InputValue) -> Result<Self, Self::Error> {
+            let fields = helper::object(v, "Query")?;
+            Ok(Self {
+                greeting: helper::field(&fields, "greeting")?,
+            })
+        }
+    }
+
+    impl GraphQLQuery for Greeting {
+        type Variables = Variables;
+        type ResponseData = ResponseData;
+
+        const OPERATION_NAME: &'static str = "Greeting";
+        const DOCUMENT: &'static str = "query Greeting($name: String!) { greeting(name: $name) }";
+    }
+
+    #[test]
+    fn executes_typed_query() {
+        let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+        let variables = Variables {
+            name: "Luke".into(),
+        };
+
+        assert_eq!(
+            execute_sync(Greeting, &variables, &schema, &()),
+            Ok(Response {
+                data: Ok(ResponseData {
+                    greeting: "Hello, Luke!".into(),
+                }),
+                errors: vec![],
+            }),
+        );
+    }
+}
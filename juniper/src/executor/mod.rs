@@ -22,12 +22,14 @@ use crate::{
     },
     parser::{SourcePosition, Spanning},
     schema::{
+        SchemaError,
         meta::{
             Argument, DeprecationStatus, EnumMeta, EnumValue, Field, InputObjectMeta,
             InterfaceMeta, ListMeta, MetaType, NullableMeta, ObjectMeta, PlaceholderMeta,
             ScalarMeta, UnionMeta,
         },
         model::{RootNode, SchemaType, TypeType},
+        visibility::Visibility,
     },
     types::{
         async_await::{GraphQLTypeAsync, GraphQLValueAsync},
@@ -74,6 +76,7 @@ where
     context: &'a CtxT,
     errors: &'r RwLock<Vec<ExecutionError<S>>>,
     field_path: Arc<FieldPath<'a>>,
+    visibility: &'r Visibility,
 }
 
 /// Error type for errors that occur during query execution
@@ -558,6 +561,7 @@ where
             context: ctx,
             errors: self.errors,
             field_path: self.field_path.clone(),
+            visibility: self.visibility,
         }
     }
 
@@ -590,6 +594,7 @@ where
                 location,
                 Arc::clone(&self.field_path),
             )),
+            visibility: self.visibility,
         }
     }
 
@@ -612,6 +617,7 @@ where
             context: self.context,
             errors: self.errors,
             field_path: self.field_path.clone(),
+            visibility: self.visibility,
         }
     }
 
@@ -633,6 +639,11 @@ where
         self.schema
     }
 
+    /// The schema elements visible to the current request
+    pub(crate) fn visibility(&self) -> &'r Visibility {
+        self.visibility
+    }
+
     #[doc(hidden)]
     pub fn current_type(&self) -> &TypeType<'a, S> {
         &self.current_type
@@ -754,6 +765,7 @@ where
             context: self.context,
             errors: RwLock::new(vec![]),
             field_path: Arc::clone(&self.field_path),
+            visibility: self.visibility.clone(),
         }
     }
 }
@@ -882,6 +894,7 @@ where
             context,
             errors: &errors,
             field_path: Arc::new(FieldPath::Root(operation.span.start)),
+            visibility: &root_node.visibility(context)?,
         };
 
         value = match operation.item.operation_type {
@@ -983,6 +996,7 @@ where
             context,
             errors: &errors,
             field_path: Arc::new(FieldPath::Root(operation.span.start)),
+            visibility: &root_node.visibility(context)?,
         };
 
         value = match operation.item.operation_type {
@@ -1131,6 +1145,7 @@ where
             context,
             errors: &errors,
             field_path: Arc::new(FieldPath::Root(operation.span.start)),
+            visibility: &root_node.visibility(context)?,
         };
 
         value = match operation.item.operation_type {
@@ -1157,12 +1172,89 @@ where
 pub struct Registry<S = DefaultScalarValue> {
     /// Currently registered types
     pub types: FnvHashMap<Name, MetaType<S>>,
+
+    /// Names of the Rust types the registered [`types`] originate from.
+    ///
+    /// [`types`]: Registry::types
+    rust_types: FnvHashMap<Name, &'static str>,
+
+    /// Registered [`types`] whose names are claimed by several different Rust types, along with
+    /// the names of those Rust types.
+    ///
+    /// [`types`]: Registry::types
+    collisions: Vec<(Name, &'static str, &'static str)>,
+
+    /// [`SchemaError`]s detected while building the [`types`].
+    ///
+    /// [`types`]: Registry::types
+    errors: Vec<SchemaError>,
+
+    /// Names of the types registered via [`Registry::get_type()`], in the order of their
+    /// registration.
+    definition_order: Vec<Name>,
 }
 
 impl<S> Registry<S> {
     /// Constructs a new [`Registry`] out of the given `types`.
     pub fn new(types: FnvHashMap<Name, MetaType<S>>) -> Self {
-        Self { types }
+        Self {
+            types,
+            rust_types: FnvHashMap::default(),
+            collisions: vec![],
+            errors: vec![],
+            definition_order: vec![],
+        }
+    }
+
+    /// Returns the names of the types registered via [`Registry::get_type()`], in the order of
+    /// their registration.
+    pub(crate) fn definition_order(&self) -> &[Name] {
+        &self.definition_order
+    }
+
+    /// Returns the GraphQL type names claimed by several different Rust types, along with the
+    /// names of those Rust types.
+    ///
+    /// Built-in and scalar types are never reported, as many Rust types are allowed to represent
+    /// the same scalar (like [`String`] and [`str`] both representing a GraphQL `String`).
+    pub(crate) fn collisions(&self) -> &[(Name, &'static str, &'static str)] {
+        &self.collisions
+    }
+
+    /// Returns the [`SchemaError`]s detected while building the registered types.
+    pub(crate) fn errors(&self) -> &[SchemaError] {
+        &self.errors
+    }
+
+    /// Records the provided [`SchemaError`] detected while building [`GraphQLType::meta`] of a
+    /// type, to be reported once the schema is constructed.
+    pub fn add_error(&mut self, error: SchemaError) {
+        self.errors.push(error);
+    }
+
+    /// Remembers the Rust type `T` as the origin of the registered type with the provided `name`,
+    /// recording a collision if it has been registered by another Rust type already.
+    fn track_rust_type<T: ?Sized>(&mut self, name: &Name) {
+        let rust_type = rust_type_path(std::any::type_name::<T>());
+        match self.rust_types.get(name) {
+            None => {
+                self.rust_types.insert(name.clone(), rust_type);
+            }
+            Some(&origin) if origin != rust_type => {
+                let ignored = self
+                    .types
+                    .get(name)
+                    .is_some_and(|t| t.is_builtin() || matches!(t, MetaType::Scalar(..)));
+                let reported = self
+                    .collisions
+                    .iter()
+                    .any(|(n, _, r)| n == name && *r == rust_type);
+                if !ignored && !reported {
+                    self.collisions.push((name.clone(), origin, rust_type));
+                }
+            }
+            Some(_) => {}
+        }
     }
 
     /// Returns a [`Type`] instance for the given [`GraphQLType`], registered in this [`Registry`].
@@ -1181,8 +1273,12 @@ impl<S> Registry<S> {
                     validated_name.clone(),
                     Type::nullable(name.clone()).wrap_non_null(),
                 );
+                self.track_rust_type::<T>(&validated_name);
+                self.definition_order.push(validated_name.clone());
                 let meta = T::meta(info, self);
                 self.types.insert(validated_name, meta);
+            } else {
+                self.track_rust_type::<T>(&validated_name);
             }
             self.types[&name].as_type()
         } else {
@@ -1202,6 +1298,8 @@ impl<S> Registry<S> {
             arguments: None,
             field_type: self.get_type::<T>(info),
             deprecation_status: DeprecationStatus::Current,
+            directives: vec![],
+            visible: None,
         }
     }
 
@@ -1221,6 +1319,8 @@ impl<S> Registry<S> {
             arguments: None,
             field_type: self.get_type::<I>(info),
             deprecation_status: DeprecationStatus::Current,
+            directives: vec![],
+            visible: None,
         }
     }
 
@@ -1362,3 +1462,29 @@ impl<S> Registry<S> {
         InputObjectMeta::new::<T>(name, args)
     }
 }
+
+/// Returns the provided [`std::any::type_name()`] with its outer [`GraphQLType`]-delegating
+/// pointers (like `&`, [`Box`] or [`Arc`]) stripped.
+///
+/// Generic arguments are preserved, so differently parameterized generic Rust types (like
+/// `Connection<User>` and `Connection<Post>`) claiming the same GraphQL type name are detected as
+/// a collision.
+///
+/// [`Arc`]: std::sync::Arc
+fn rust_type_path(mut type_name: &'static str) -> &'static str {
+    loop {
+        if let Some(rest) = type_name
+            .strip_prefix("&mut ")
+            .or_else(|| type_name.strip_prefix('&'))
+        {
+            type_name = rest;
+        } else if let Some(inner) = ["alloc::boxed::Box<", "alloc::sync::Arc<"]
+            .into_iter()
+            .find_map(|p| type_name.strip_prefix(p)?.strip_suffix('>'))
+        {
+            type_name = inner;
+        } else {
+            return type_name;
+        }
+    }
+}
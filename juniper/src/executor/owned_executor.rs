@@ -8,7 +8,10 @@ use crate::{
     ast::Fragment,
     executor::FieldPath,
     parser::SourcePosition,
-    schema::model::{SchemaType, TypeType},
+    schema::{
+        model::{SchemaType, TypeType},
+        visibility::Visibility,
+    },
 };
 
 /// [`Executor`] owning all its variables. Can be used after [`Executor`] was
@@ -23,6 +26,7 @@ pub struct OwnedExecutor<'a, CtxT, S> {
     pub(super) context: &'a CtxT,
     pub(super) errors: RwLock<Vec<ExecutionError<S>>>,
     pub(super) field_path: Arc<FieldPath<'a>>,
+    pub(super) visibility: Visibility,
 }
 
 impl<CtxT, S> Clone for OwnedExecutor<'_, CtxT, S>
@@ -40,6 +44,7 @@ where
             context: self.context,
             errors: RwLock::new(vec![]),
             field_path: self.field_path.clone(),
+            visibility: self.visibility.clone(),
         }
     }
 }
@@ -68,6 +73,7 @@ where
             context: self.context,
             errors: RwLock::new(vec![]),
             field_path: self.field_path.clone(),
+            visibility: self.visibility.clone(),
         }
     }
 
@@ -106,6 +112,7 @@ where
                 location,
                 Arc::clone(&self.field_path),
             )),
+            visibility: self.visibility.clone(),
         }
     }
 
@@ -121,6 +128,7 @@ where
             context: self.context,
             errors: &self.errors,
             field_path: Arc::clone(&self.field_path),
+            visibility: &self.visibility,
         }
     }
 }
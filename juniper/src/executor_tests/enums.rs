@@ -5,7 +5,7 @@ use crate::{
     parser::SourcePosition,
     schema::model::RootNode,
     types::scalars::{EmptyMutation, EmptySubscription},
-    validation::RuleError,
+    validation::{RuleError, RuleErrorExtensions},
     value::{DefaultScalarValue, Object},
 };
 
@@ -139,9 +139,15 @@ async fn does_not_accept_incorrect_enum_name_in_variables() {
     assert_eq!(
         error,
         RuleError::new(
-            r#"Variable "$color" got invalid value. Invalid value for enum "Color"."#,
+            r#"Variable "$color" got invalid value. Invalid value for enum "Color", found string `"BLURPLE"`."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$color")
+                .with_expected_type("Color")
+                .with_received("string")
+        )
         .into(),
     );
 }
@@ -164,9 +170,15 @@ async fn does_not_accept_incorrect_type_in_variables() {
     assert_eq!(
         error,
         RuleError::new(
-            r#"Variable "$color" got invalid value. Expected "Color", found not a string or enum."#,
+            r#"Variable "$color" got invalid value. Expected "Color", found int."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$color")
+                .with_expected_type("Color")
+                .with_received("int")
+        )
         .into(),
     );
 }
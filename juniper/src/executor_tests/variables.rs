@@ -5,7 +5,7 @@ use crate::{
     parser::SourcePosition,
     schema::model::RootNode,
     types::scalars::{EmptyMutation, EmptySubscription},
-    validation::RuleError,
+    validation::{RuleError, RuleErrorExtensions},
     value::{DefaultScalarValue, Object},
 };
 
@@ -282,9 +282,15 @@ async fn variable_error_on_nested_non_null() {
     assert_eq!(
         error,
         RuleError::new(
-            r#"Variable "$input" got invalid value. In field "c": Expected "String!", found null."#,
+            r#"Variable "$input.c" got invalid value. Expected "String!", found null."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$input.c")
+                .with_expected_type("String!")
+                .with_received("null")
+        )
         .into(),
     );
 }
@@ -307,9 +313,16 @@ async fn variable_error_on_incorrect_type() {
     assert_eq!(
         error,
         RuleError::new(
-            r#"Variable "$input" got invalid value. Expected "TestInputObject", found not an object."#,
+            r#"Variable "$input" got invalid value. Expected "TestInputObject", found string."#,
             &[SourcePosition::new(8, 0, 8)],
-        ).into(),
+        )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$input")
+                .with_expected_type("TestInputObject")
+                .with_received("string")
+        )
+        .into(),
     );
 }
 
@@ -336,9 +349,14 @@ async fn variable_error_on_omit_non_null() {
     assert_eq!(
         error,
         RuleError::new(
-            r#"Variable "$input" got invalid value. In field "c": Expected "String!", found null."#,
+            r#"Variable "$input.c" got invalid value. Expected "String!", but it was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$input.c")
+                .with_expected_type("String!")
+        )
         .into(),
     );
 }
@@ -367,17 +385,66 @@ async fn variable_multiple_errors_with_nesting() {
         error,
         vec![
             RuleError::new(
-                r#"Variable "$input" got invalid value. In field "na": In field "c": Expected "String!", found null."#,
+                r#"Variable "$input.na.c" got invalid value. Expected "String!", but it was not provided."#,
                 &[SourcePosition::new(8, 0, 8)],
+            )
+            .with_extensions(
+                RuleErrorExtensions::new("CoerceVariableValues")
+                    .with_input_path("$input.na.c")
+                    .with_expected_type("String!")
             ),
             RuleError::new(
-                r#"Variable "$input" got invalid value. In field "nb": Expected "String!", found null."#,
+                r#"Variable "$input.nb" got invalid value. Expected "String!", but it was not provided."#,
                 &[SourcePosition::new(8, 0, 8)],
+            )
+            .with_extensions(
+                RuleErrorExtensions::new("CoerceVariableValues")
+                    .with_input_path("$input.nb")
+                    .with_expected_type("String!")
             ),
         ].into(),
     );
 }
 
+#[tokio::test]
+async fn variable_error_reports_path_inside_variable() {
+    let schema = RootNode::new(
+        TestType,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let query =
+        r#"query q($input: TestNestedInputObject) { fieldWithNestedObjectInput(input: $input) }"#;
+    let vars = graphql::vars! {
+        "input": {
+            "na": {"b": ["foo", "bar", 3], "c": "baz"},
+            "nb": "qux",
+        },
+    };
+
+    let error = crate::execute(query, None, &schema, &vars, &())
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        RuleError::new(
+            "Variable \"$input.na.b[2]\" got invalid value. \
+             Expected input scalar `String`, found int `3`. \
+             Details: Expected `String`, found: 3.",
+            &[SourcePosition::new(8, 0, 8)],
+        )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$input.na.b[2]")
+                .with_expected_type("String")
+                .with_received("int"),
+        )
+        .into(),
+    );
+}
+
 #[tokio::test]
 async fn variable_error_on_additional_field() {
     let schema = RootNode::new(
@@ -403,9 +470,14 @@ async fn variable_error_on_additional_field() {
     assert_eq!(
         error,
         RuleError::new(
-            r#"Variable "$input" got invalid value. In field "extra": Unknown field."#,
+            r#"Variable "$input.extra" got invalid value. Unknown field."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$input.extra")
+                .with_received("string")
+        )
         .into(),
     );
 }
@@ -517,6 +589,11 @@ async fn does_not_allow_non_nullable_input_to_be_omitted_in_variable() {
             r#"Variable "$value" of required type "String!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$value")
+                .with_expected_type("String!")
+        )
         .into(),
     );
 }
@@ -542,6 +619,11 @@ async fn does_not_allow_non_nullable_input_to_be_set_to_null_in_variable() {
             r#"Variable "$value" of required type "String!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$value")
+                .with_expected_type("String!")
+        )
         .into(),
     );
 }
@@ -626,6 +708,11 @@ async fn does_not_allow_null_for_non_nullable_variable_with_default() {
             r#"Variable "$value" of required type "String!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$value")
+                .with_expected_type("String!")
+        )
         .into(),
     );
 }
@@ -696,6 +783,11 @@ async fn does_not_allow_non_null_lists_to_be_null() {
             r#"Variable "$input" of required type "[String]!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$input")
+                .with_expected_type("[String]!")
+        )
         .into(),
     );
 }
@@ -777,9 +869,16 @@ async fn does_not_allow_lists_of_non_null_to_contain_null() {
     assert_eq!(
         error,
         RuleError::new(
-            r#"Variable "$input" got invalid value. In element #1: Expected "String!", found null."#,
+            r#"Variable "$input[1]" got invalid value. Expected "String!", found null."#,
             &[SourcePosition::new(8, 0, 8)],
-        ).into(),
+        )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$input[1]")
+                .with_expected_type("String!")
+                .with_received("null")
+        )
+        .into(),
     );
 }
 
@@ -801,9 +900,16 @@ async fn does_not_allow_non_null_lists_of_non_null_to_contain_null() {
     assert_eq!(
         error,
         RuleError::new(
-            r#"Variable "$input" got invalid value. In element #1: Expected "String!", found null."#,
+            r#"Variable "$input[1]" got invalid value. Expected "String!", found null."#,
             &[SourcePosition::new(8, 0, 8)],
-        ).into(),
+        )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$input[1]")
+                .with_expected_type("String!")
+                .with_received("null")
+        )
+        .into(),
     );
 }
 
@@ -828,6 +934,11 @@ async fn does_not_allow_non_null_lists_of_non_null_to_be_null() {
             r#"Variable "$input" of required type "[String!]!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$input")
+                .with_expected_type("[String!]!")
+        )
         .into(),
     );
 }
@@ -1034,6 +1145,11 @@ async fn does_not_allow_missing_variable_for_required_field() {
             r#"Variable "$var" of required type "Int!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$var")
+                .with_expected_type("Int!")
+        )
         .into(),
     );
 }
@@ -1059,6 +1175,11 @@ async fn does_not_allow_null_variable_for_required_field() {
             r#"Variable "$var" of required type "Int!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_extensions(
+            RuleErrorExtensions::new("CoerceVariableValues")
+                .with_input_path("$var")
+                .with_expected_type("Int!")
+        )
         .into(),
     );
 }
@@ -1158,10 +1279,16 @@ mod integers {
         assert_eq!(
             error,
             RuleError::new(
-                "Variable \"$var\" got invalid value. Expected input scalar `Int`. \
-                 Got: `10`. Details: Expected `Int`, found: 10.",
+                "Variable \"$var\" got invalid value. Expected input scalar `Int`, \
+                 found float `10`. Details: Expected `Int`, found: 10.",
                 &[SourcePosition::new(8, 0, 8)],
             )
+            .with_extensions(
+                RuleErrorExtensions::new("CoerceVariableValues")
+                    .with_input_path("$var")
+                    .with_expected_type("Int")
+                    .with_received("float")
+            )
             .into(),
         );
     }
@@ -1185,10 +1312,16 @@ mod integers {
             error,
             RuleError::new(
                 "Variable \"$var\" got invalid value. \
-                 Expected input scalar `Int`. Got: `\"10\"`. \
+                 Expected input scalar `Int`, found string `\"10\"`. \
                  Details: Expected `Int`, found: \"10\".",
                 &[SourcePosition::new(8, 0, 8)],
             )
+            .with_extensions(
+                RuleErrorExtensions::new("CoerceVariableValues")
+                    .with_input_path("$var")
+                    .with_expected_type("Int")
+                    .with_received("string")
+            )
             .into(),
         );
     }
@@ -1246,10 +1379,16 @@ mod floats {
             error,
             RuleError::new(
                 "Variable \"$var\" got invalid value. \
-                 Expected input scalar `Float`. Got: `\"10\"`. \
+                 Expected input scalar `Float`, found string `\"10\"`. \
                  Details: Expected `Float`, found: \"10\".",
                 &[SourcePosition::new(8, 0, 8)],
             )
+            .with_extensions(
+                RuleErrorExtensions::new("CoerceVariableValues")
+                    .with_input_path("$var")
+                    .with_expected_type("Float")
+                    .with_received("string")
+            )
             .into(),
         );
     }
@@ -0,0 +1,160 @@
+//! Field guards authorizing the resolution of GraphQL fields.
+//!
+//! A [`Guard`] is specified on a field (or on the whole type) via
+//! `#[graphql(guard = ...)]` attribute of `#[derive(GraphQLObject)]`, `#[graphql_object]`,
+//! `#[graphql_interface]` and `#[graphql_subscription]` macros, and is checked against the
+//! request context before the field resolver runs. Guards are composable via
+//! [`GuardExt::and()`] and [`GuardExt::or()`] combinators.
+
+use crate::{DefaultScalarValue, FieldResult};
+
+/// Guard deciding whether a GraphQL field may be resolved for the provided context.
+///
+/// Implemented for any `Fn(&Ctx) -> FieldResult<(), S>` closure or function too.
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{FieldResult, Guard, ScalarValue};
+/// #
+/// struct Session {
+///     user_role: Option<&'static str>,
+/// }
+///
+/// struct Role(&'static str);
+///
+/// impl<S: ScalarValue> Guard<Session, S> for Role {
+///     fn check(&self, session: &Session) -> FieldResult<(), S> {
+///         if session.user_role == Some(self.0) {
+///             Ok(())
+///         } else {
+///             Err(format!("`{}` role is required", self.0).into())
+///         }
+///     }
+/// }
+/// ```
+pub trait Guard<Ctx: ?Sized, S = DefaultScalarValue> {
+    /// Checks whether a GraphQL field may be resolved for the provided context.
+    ///
+    /// # Errors
+    ///
+    /// With the [`FieldError`] to be returned as the result of the guarded field, if the access is
+    /// denied.
+    ///
+    /// [`FieldError`]: crate::FieldError
+    fn check(&self, ctx: &Ctx) -> FieldResult<(), S>;
+}
+
+impl<Ctx, S, F> Guard<Ctx, S> for F
+where
+    Ctx: ?Sized,
+    F: Fn(&Ctx) -> FieldResult<(), S>,
+{
+    fn check(&self, ctx: &Ctx) -> FieldResult<(), S> {
+        self(ctx)
+    }
+}
+
+/// Combinators of [`Guard`]s.
+pub trait GuardExt: Sized {
+    /// Combines this [`Guard`] with the `other` one, so both of them should
+    /// allow the access.
+    ///
+    /// The `other` [`Guard`] is not checked if this one denies the access.
+    #[must_use]
+    fn and<G>(self, other: G) -> And<Self, G> {
+        And(self, other)
+    }
+
+    /// Combines this [`Guard`] with the `other` one, so any of them should
+    /// allow the access.
+    ///
+    /// The `other` [`Guard`] is not checked if this one allows the access.
+    /// If both deny the access, then the error of the `other` one is returned.
+    #[must_use]
+    fn or<G>(self, other: G) -> Or<Self, G> {
+        Or(self, other)
+    }
+}
+
+impl<G> GuardExt for G {}
+
+/// [`Guard`] allowing the access only if both of the inner [`Guard`]s allow it.
+///
+/// Usually constructed via [`GuardExt::and()`].
+#[derive(Clone, Copy, Debug)]
+pub struct And<A, B>(pub A, pub B);
+
+impl<Ctx, S, A, B> Guard<Ctx, S> for And<A, B>
+where
+    Ctx: ?Sized,
+    A: Guard<Ctx, S>,
+    B: Guard<Ctx, S>,
+{
+    fn check(&self, ctx: &Ctx) -> FieldResult<(), S> {
+        self.0.check(ctx)?;
+        self.1.check(ctx)
+    }
+}
+
+/// [`Guard`] allowing the access if any of the inner [`Guard`]s allows it.
+///
+/// Usually constructed via [`GuardExt::or()`].
+#[derive(Clone, Copy, Debug)]
+pub struct Or<A, B>(pub A, pub B);
+
+impl<Ctx, S, A, B> Guard<Ctx, S> for Or<A, B>
+where
+    Ctx: ?Sized,
+    A: Guard<Ctx, S>,
+    B: Guard<Ctx, S>,
+{
+    fn check(&self, ctx: &Ctx) -> FieldResult<(), S> {
+        self.0.check(ctx).or_else(|_| self.1.check(ctx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FieldError, FieldResult};
+
+    use super::{Guard, GuardExt as _};
+
+    struct Min(i32);
+
+    impl Guard<i32> for Min {
+        fn check(&self, ctx: &i32) -> FieldResult<()> {
+            if *ctx >= self.0 {
+                Ok(())
+            } else {
+                Err(format!("less than {}", self.0).into())
+            }
+        }
+    }
+
+    fn even(ctx: &i32) -> FieldResult<()> {
+        if ctx % 2 == 0 {
+            Ok(())
+        } else {
+            Err("odd".into())
+        }
+    }
+
+    #[test]
+    fn combines_with_and() {
+        let guard = Min(2).and(even);
+
+        assert_eq!(guard.check(&4), Ok(()));
+        assert_eq!(guard.check(&0), Err(FieldError::from("less than 2")));
+        assert_eq!(guard.check(&3), Err(FieldError::from("odd")));
+    }
+
+    #[test]
+    fn combines_with_or() {
+        let guard = Min(2).or(even);
+
+        assert_eq!(guard.check(&3), Ok(()));
+        assert_eq!(guard.check(&0), Ok(()));
+        assert_eq!(guard.check(&1), Err(FieldError::from("odd")));
+    }
+}
@@ -12,7 +12,7 @@ use crate::{
     ast::{InputValue, OperationType},
     executor::ExecutionError,
     parser::{ParseError, SourcePosition, Spanning},
-    validation::RuleError,
+    validation::{RuleError, RuleErrorExtensions},
 };
 
 impl<T: Serialize> Serialize for ExecutionError<T> {
@@ -41,8 +41,8 @@ impl<T: Serialize> Serialize for ExecutionError<T> {
 impl Serialize for GraphQLError {
     fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
         #[derive(Serialize)]
-        struct Helper {
-            message: &'static str,
+        struct Helper<'m> {
+            message: &'m str,
         }
 
         match self {
@@ -77,6 +77,10 @@ impl Serialize for GraphQLError {
                 },
             }]
             .serialize(ser),
+            Self::VisibilityError(e) => [Helper {
+                message: &e.to_string(),
+            }]
+            .serialize(ser),
         }
     }
 }
@@ -219,7 +223,7 @@ impl<T: Serialize> Serialize for InputValue<T> {
 
 impl Serialize for RuleError {
     fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
-        let mut map = ser.serialize_map(Some(2))?;
+        let mut map = ser.serialize_map(Some(2 + usize::from(self.extensions().is_some())))?;
 
         map.serialize_key("message")?;
         map.serialize_value(self.message())?;
@@ -227,6 +231,42 @@ impl Serialize for RuleError {
         map.serialize_key("locations")?;
         map.serialize_value(self.locations())?;
 
+        if let Some(extensions) = self.extensions() {
+            map.serialize_key("extensions")?;
+            map.serialize_value(extensions)?;
+        }
+
+        map.end()
+    }
+}
+
+impl Serialize for RuleErrorExtensions {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        let mut map = ser.serialize_map(None)?;
+
+        map.serialize_key("rule")?;
+        map.serialize_value(self.rule())?;
+
+        if !self.suggestions().is_empty() {
+            map.serialize_key("suggestions")?;
+            map.serialize_value(self.suggestions())?;
+        }
+
+        if let Some(input_path) = self.input_path() {
+            map.serialize_key("inputPath")?;
+            map.serialize_value(input_path)?;
+        }
+
+        if let Some(expected_type) = self.expected_type() {
+            map.serialize_key("expectedType")?;
+            map.serialize_value(expected_type)?;
+        }
+
+        if let Some(received) = self.received() {
+            map.serialize_key("received")?;
+            map.serialize_value(received)?;
+        }
+
         map.end()
     }
 }
@@ -355,7 +395,11 @@ impl<'de> Deserialize<'de> for DefaultScalarValue {
 mod tests {
     use serde_json::{from_str, to_string};
 
-    use crate::{DefaultScalarValue, FieldError, InputValue, graphql};
+    use crate::{
+        DefaultScalarValue, FieldError, InputValue, graphql,
+        parser::SourcePosition,
+        validation::{RuleError, RuleErrorExtensions},
+    };
 
     use super::{ExecutionError, GraphQLError};
 
@@ -398,4 +442,45 @@ mod tests {
             r#"{"message":"foo error","locations":[{"line":1,"column":1}],"path":[],"extensions":{"foo":"bar"}}"#,
         );
     }
+
+    #[test]
+    fn rule_error_extensions() {
+        assert_eq!(
+            to_string(&RuleError::new(
+                r#"Unknown field "nam" on type "Dog""#,
+                &[SourcePosition::new(2, 0, 2)],
+            ))
+            .unwrap(),
+            r#"{"message":"Unknown field \"nam\" on type \"Dog\"","locations":[{"line":1,"column":3}]}"#,
+        );
+        assert_eq!(
+            to_string(
+                &RuleError::new(
+                    r#"Unknown field "nam" on type "Dog". Did you mean "name"?"#,
+                    &[SourcePosition::new(2, 0, 2)],
+                )
+                .with_extensions(
+                    RuleErrorExtensions::new("FieldsOnCorrectType").with_suggestions(&["name"]),
+                ),
+            )
+            .unwrap(),
+            r#"{"message":"Unknown field \"nam\" on type \"Dog\". Did you mean \"name\"?","locations":[{"line":1,"column":3}],"extensions":{"rule":"FieldsOnCorrectType","suggestions":["name"]}}"#,
+        );
+        assert_eq!(
+            to_string(
+                &RuleError::new(
+                    r#"Variable "$input.items[3].price" got invalid value. Expected "Float!", found null."#,
+                    &[SourcePosition::new(8, 0, 8)],
+                )
+                .with_extensions(
+                    RuleErrorExtensions::new("CoerceVariableValues")
+                        .with_input_path("$input.items[3].price")
+                        .with_expected_type("Float!")
+                        .with_received("null"),
+                ),
+            )
+            .unwrap(),
+            r#"{"message":"Variable \"$input.items[3].price\" got invalid value. Expected \"Float!\", found null.","locations":[{"line":1,"column":9}],"extensions":{"rule":"CoerceVariableValues","inputPath":"$input.items[3].price","expectedType":"Float!","received":"null"}}"#,
+        );
+    }
 }
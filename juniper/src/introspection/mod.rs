@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 pub(crate) const INTROSPECTION_QUERY: &str = include_str!("./query.graphql");
 pub(crate) const INTROSPECTION_QUERY_WITHOUT_DESCRIPTIONS: &str =
     include_str!("./query_without_descriptions.graphql");
@@ -13,4 +15,300 @@ pub enum IntrospectionFormat {
 
     /// The canonical GraphQL introspection query without descriptions.
     WithoutDescriptions,
+
+    /// The canonical GraphQL introspection query built with the provided [`IntrospectionOptions`].
+    Custom(IntrospectionOptions),
+}
+
+impl IntrospectionFormat {
+    /// Returns the text of the introspection query of this [`IntrospectionFormat`].
+    #[must_use]
+    pub fn query(&self) -> Cow<'static, str> {
+        match self {
+            Self::All => INTROSPECTION_QUERY.into(),
+            Self::WithoutDescriptions => INTROSPECTION_QUERY_WITHOUT_DESCRIPTIONS.into(),
+            Self::Custom(options) => options.query().into(),
+        }
+    }
+}
+
+impl From<IntrospectionOptions> for IntrospectionFormat {
+    fn from(options: IntrospectionOptions) -> Self {
+        Self::Custom(options)
+    }
+}
+
+/// Options of building the [canonical introspection query][0], mirroring the ones of
+/// [`getIntrospectionQuery()`][0] in [GraphQL.js].
+///
+/// [`Default`] options are the same as in [GraphQL.js], requesting descriptions only. Use
+/// [`IntrospectionOptions::all()`] to request every introspection field [`juniper`] supports.
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{IntrospectionFormat, IntrospectionOptions};
+/// #
+/// let format = IntrospectionFormat::from(
+///     IntrospectionOptions::default()
+///         .specified_by_url(true)
+///         .one_of(true)
+///         .type_depth(4),
+/// );
+///
+/// assert!(format.query().contains("specifiedByURL"));
+/// ```
+///
+/// [`juniper`]: crate
+/// [GraphQL.js]: https://github.com/graphql/graphql-js
+/// [0]: https://github.com/graphql/graphql-js/blob/v16.11.0/src/utilities/getIntrospectionQuery.ts#L75
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IntrospectionOptions {
+    descriptions: bool,
+    specified_by_url: bool,
+    directive_is_repeatable: bool,
+    schema_description: bool,
+    input_value_deprecation: bool,
+    one_of: bool,
+    type_depth: usize,
+}
+
+impl Default for IntrospectionOptions {
+    fn default() -> Self {
+        Self {
+            descriptions: true,
+            specified_by_url: false,
+            directive_is_repeatable: false,
+            schema_description: false,
+            input_value_deprecation: false,
+            one_of: false,
+            type_depth: 9,
+        }
+    }
+}
+
+impl IntrospectionOptions {
+    /// Creates new [`IntrospectionOptions`] requesting every introspection field, the same as
+    /// [`IntrospectionFormat::All`] does.
+    #[must_use]
+    pub fn all() -> Self {
+        Self {
+            descriptions: true,
+            specified_by_url: true,
+            directive_is_repeatable: true,
+            schema_description: true,
+            input_value_deprecation: true,
+            one_of: true,
+            type_depth: 9,
+        }
+    }
+
+    /// Sets whether descriptions of types, fields, arguments, enum values and directives should
+    /// be requested.
+    #[must_use]
+    pub fn descriptions(mut self, enabled: bool) -> Self {
+        self.descriptions = enabled;
+        self
+    }
+
+    /// Sets whether the `__Type.specifiedByURL` field should be requested.
+    #[must_use]
+    pub fn specified_by_url(mut self, enabled: bool) -> Self {
+        self.specified_by_url = enabled;
+        self
+    }
+
+    /// Sets whether the `__Directive.isRepeatable` field should be requested.
+    #[must_use]
+    pub fn directive_is_repeatable(mut self, enabled: bool) -> Self {
+        self.directive_is_repeatable = enabled;
+        self
+    }
+
+    /// Sets whether the `__Schema.description` field should be requested.
+    #[must_use]
+    pub fn schema_description(mut self, enabled: bool) -> Self {
+        self.schema_description = enabled;
+        self
+    }
+
+    /// Sets whether deprecated arguments and input object fields should be requested, along with
+    /// the `__InputValue.isDeprecated` and `__InputValue.deprecationReason` fields.
+    #[must_use]
+    pub fn input_value_deprecation(mut self, enabled: bool) -> Self {
+        self.input_value_deprecation = enabled;
+        self
+    }
+
+    /// Sets whether the `__Type.isOneOf` field should be requested.
+    #[must_use]
+    pub fn one_of(mut self, enabled: bool) -> Self {
+        self.one_of = enabled;
+        self
+    }
+
+    /// Sets how many levels of `__Type.ofType` should be requested for wrapped types.
+    ///
+    /// Types wrapped deeper (like `[[[String!]!]!]!`) are truncated in the introspection result.
+    #[must_use]
+    pub fn type_depth(mut self, depth: usize) -> Self {
+        self.type_depth = depth;
+        self
+    }
+
+    /// Builds the text of the introspection query with these [`IntrospectionOptions`].
+    #[must_use]
+    pub fn query(&self) -> String {
+        let Self {
+            descriptions,
+            specified_by_url,
+            directive_is_repeatable,
+            schema_description,
+            input_value_deprecation,
+            one_of,
+            type_depth,
+        } = *self;
+        let include_deprecated = if input_value_deprecation {
+            "(includeDeprecated: true)"
+        } else {
+            ""
+        };
+
+        let mut query = String::new();
+        let mut line = |enabled: bool, indent: usize, text: &str| {
+            if enabled {
+                query.extend(std::iter::repeat_n("  ", indent));
+                query.push_str(text);
+                query.push('\n');
+            }
+        };
+
+        line(true, 0, "query IntrospectionQuery {");
+        line(true, 1, "__schema {");
+        line(schema_description, 2, "description");
+        line(true, 2, "queryType { name kind }");
+        line(true, 2, "mutationType { name kind }");
+        line(true, 2, "subscriptionType { name kind }");
+        line(true, 2, "types {");
+        line(true, 3, "...FullType");
+        line(true, 2, "}");
+        line(true, 2, "directives {");
+        line(true, 3, "name");
+        line(descriptions, 3, "description");
+        line(directive_is_repeatable, 3, "isRepeatable");
+        line(true, 3, "locations");
+        line(true, 3, &format!("args{include_deprecated} {{"));
+        line(true, 4, "...InputValue");
+        line(true, 3, "}");
+        line(true, 2, "}");
+        line(true, 1, "}");
+        line(true, 0, "}");
+        line(true, 0, "");
+
+        line(true, 0, "fragment FullType on __Type {");
+        line(true, 1, "kind");
+        line(true, 1, "name");
+        line(descriptions, 1, "description");
+        line(specified_by_url, 1, "specifiedByURL");
+        line(one_of, 1, "isOneOf");
+        line(true, 1, "fields(includeDeprecated: true) {");
+        line(true, 2, "name");
+        line(descriptions, 2, "description");
+        line(true, 2, &format!("args{include_deprecated} {{"));
+        line(true, 3, "...InputValue");
+        line(true, 2, "}");
+        line(true, 2, "type {");
+        line(true, 3, "...TypeRef");
+        line(true, 2, "}");
+        line(true, 2, "isDeprecated");
+        line(true, 2, "deprecationReason");
+        line(true, 1, "}");
+        line(true, 1, &format!("inputFields{include_deprecated} {{"));
+        line(true, 2, "...InputValue");
+        line(true, 1, "}");
+        line(true, 1, "interfaces {");
+        line(true, 2, "...TypeRef");
+        line(true, 1, "}");
+        line(true, 1, "enumValues(includeDeprecated: true) {");
+        line(true, 2, "name");
+        line(descriptions, 2, "description");
+        line(true, 2, "isDeprecated");
+        line(true, 2, "deprecationReason");
+        line(true, 1, "}");
+        line(true, 1, "possibleTypes {");
+        line(true, 2, "...TypeRef");
+        line(true, 1, "}");
+        line(true, 0, "}");
+        line(true, 0, "");
+
+        line(true, 0, "fragment InputValue on __InputValue {");
+        line(true, 1, "name");
+        line(descriptions, 1, "description");
+        line(true, 1, "type { ...TypeRef }");
+        line(true, 1, "defaultValue");
+        line(input_value_deprecation, 1, "isDeprecated");
+        line(input_value_deprecation, 1, "deprecationReason");
+        line(true, 0, "}");
+        line(true, 0, "");
+
+        line(true, 0, "fragment TypeRef on __Type {");
+        line(true, 1, "kind");
+        line(true, 1, "name");
+        for depth in 1..=type_depth {
+            line(true, depth, "ofType {");
+            line(true, depth + 1, "kind");
+            line(true, depth + 1, "name");
+        }
+        for depth in (1..=type_depth).rev() {
+            line(true, depth, "}");
+        }
+        line(true, 0, "}");
+
+        query
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        INTROSPECTION_QUERY, INTROSPECTION_QUERY_WITHOUT_DESCRIPTIONS, IntrospectionOptions,
+    };
+
+    #[test]
+    fn builds_canonical_queries() {
+        assert_eq!(
+            IntrospectionOptions::all().query().trim_end(),
+            INTROSPECTION_QUERY.trim_end(),
+        );
+        assert_eq!(
+            IntrospectionOptions::all()
+                .descriptions(false)
+                .schema_description(false)
+                .query()
+                .trim_end(),
+            INTROSPECTION_QUERY_WITHOUT_DESCRIPTIONS.trim_end(),
+        );
+    }
+
+    #[test]
+    fn omits_disabled_fields() {
+        let query = IntrospectionOptions::default().type_depth(2).query();
+
+        for field in [
+            "specifiedByURL",
+            "isRepeatable",
+            "isOneOf",
+            "args(includeDeprecated: true)",
+            "inputFields(includeDeprecated: true)",
+        ] {
+            assert!(!query.contains(field), "`{field}` is present:\n{query}");
+        }
+        let input_value = query
+            .split("fragment ")
+            .find(|f| f.starts_with("InputValue"))
+            .unwrap();
+        assert!(!input_value.contains("isDeprecated"), "{input_value}");
+        assert!(query.contains("__schema {\n    queryType"));
+        assert_eq!(query.matches("ofType").count(), 2);
+    }
 }
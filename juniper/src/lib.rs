@@ -36,20 +36,26 @@ pub use arcstr::{self, ArcStr};
 // This allows users to just depend on juniper and get the derive
 // functionality automatically.
 pub use juniper_codegen::{
-    GraphQLEnum, GraphQLInputObject, GraphQLInterface, GraphQLObject, GraphQLScalar, GraphQLUnion,
-    graphql_interface, graphql_object, graphql_scalar, graphql_subscription, graphql_union,
+    GraphQLEnum, GraphQLInputObject, GraphQLInterface, GraphQLMergedObject, GraphQLObject,
+    GraphQLScalar, GraphQLUnion, graphql_interface, graphql_object, graphql_scalar,
+    graphql_subscription, graphql_union,
 };
+#[cfg(feature = "schema-language")]
+pub use juniper_codegen::{GraphQLQuery, graphql_schema_from_file};
 
 #[doc(hidden)]
 #[macro_use]
 pub mod macros;
 mod ast;
+pub mod client;
 pub mod executor;
 #[cfg(test)]
 pub(crate) mod graphql;
+pub mod guard;
 mod introspection;
 pub mod parser;
-pub(crate) mod schema;
+pub mod relay;
+pub mod schema;
 mod types;
 mod util;
 pub mod validation;
@@ -67,6 +73,8 @@ pub mod tests;
 #[cfg(test)]
 mod executor_tests;
 
+use std::any::Any;
+
 use derive_more::with_trait::{Display, From};
 use itertools::Itertools as _;
 
@@ -75,8 +83,8 @@ pub use crate::util::to_camel_case;
 
 use crate::{
     executor::{execute_validated_query, get_operation},
-    introspection::{INTROSPECTION_QUERY, INTROSPECTION_QUERY_WITHOUT_DESCRIPTIONS},
     parser::parse_document_source,
+    schema::visibility::Visibility,
     validation::{
         MultiVisitorNil, ValidatorContext, rules, validate_input_values, visit as visit_rule,
         visit_all_rules,
@@ -94,7 +102,8 @@ pub use crate::{
         LookAheadList, LookAheadObject, LookAheadSelection, LookAheadValue, OwnedExecutor,
         Registry, ValuesStream, Variables,
     },
-    introspection::IntrospectionFormat,
+    guard::{Guard, GuardExt},
+    introspection::{IntrospectionFormat, IntrospectionOptions},
     macros::helper::subscription::{ExtractTypeFromStream, IntoFieldResult},
     parser::{ParseError, ScalarToken, Span, Spanning, StringLiteral},
     schema::{
@@ -111,8 +120,9 @@ pub use crate::{
             ExecutionOutput, GraphQLSubscriptionType, GraphQLSubscriptionValue,
             SubscriptionConnection, SubscriptionCoordinator,
         },
+        type_info::DynamicTypeInfo,
     },
-    validation::RuleError,
+    validation::{RuleError, RuleErrorExtensions},
     value::{
         AnyExt, DefaultScalarValue, FromScalarValue, IntoValue, Object, ParseScalarResult,
         ParseScalarValue, Scalar, ScalarValue, ToScalarValue, TryToPrimitive, Value,
@@ -149,6 +159,11 @@ pub enum GraphQLError {
         OperationType::Subscription => "subscriptions",
     })]
     NotSupported(OperationType),
+    /// The [`VisibilityFn`]s of the schema cannot be evaluated against the request context.
+    ///
+    /// [`VisibilityFn`]: meta::VisibilityFn
+    #[display("{_0}")]
+    VisibilityError(schema::VisibilityError),
 }
 
 impl From<RuleError> for GraphQLError {
@@ -162,6 +177,7 @@ impl std::error::Error for GraphQLError {
         match self {
             Self::ParseError(e) => Some(e),
             Self::ValidationError(errs) => Some(errs.first()?),
+            Self::VisibilityError(e) => Some(e),
             Self::NoOperationProvided
             | Self::MultipleOperationsProvided
             | Self::UnknownOperationName
@@ -189,7 +205,9 @@ where
     let document = parse_document_source(document_source, &root_node.schema)?;
 
     {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
+        let mut ctx = ValidatorContext::new(&root_node.schema, &document)
+            .with_max_errors(root_node.validation_errors_limit)
+            .with_visibility(root_node.visibility(context)?);
         visit_all_rules(&mut ctx, &document);
         if root_node.introspection_disabled {
             visit_rule(
@@ -215,6 +233,8 @@ where
         }
     }
 
+    root_node.record_usage(&document, &operation.item);
+
     execute_validated_query(&document, operation, root_node, variables, context)
 }
 
@@ -239,7 +259,9 @@ where
     let document = parse_document_source(document_source, &root_node.schema)?;
 
     {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
+        let mut ctx = ValidatorContext::new(&root_node.schema, &document)
+            .with_max_errors(root_node.validation_errors_limit)
+            .with_visibility(root_node.visibility(context)?);
         visit_all_rules(&mut ctx, &document);
         if root_node.introspection_disabled {
             visit_rule(
@@ -265,6 +287,8 @@ where
         }
     }
 
+    root_node.record_usage(&document, &operation.item);
+
     executor::execute_validated_query_async(&document, operation, root_node, variables, context)
         .await
 }
@@ -291,7 +315,9 @@ where
         parse_document_source(document_source, &root_node.schema)?;
 
     {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
+        let mut ctx = ValidatorContext::new(&root_node.schema, &document)
+            .with_max_errors(root_node.validation_errors_limit)
+            .with_visibility(root_node.visibility(context)?);
         visit_all_rules(&mut ctx, &document);
         if root_node.introspection_disabled {
             visit_rule(
@@ -317,10 +343,147 @@ where
         }
     }
 
+    root_node.record_usage(&document, &operation.item);
+
     executor::resolve_validated_subscription(&document, operation, root_node, variables, context)
         .await
 }
 
+/// Validates the provided GraphQL document against the provided `schema` without executing it.
+///
+/// Parses the `document_source` and checks it against all the validation rules of the
+/// [GraphQL spec][0], so neither a context, nor variables are required. This is useful for
+/// linting GraphQL documents (e.g. `.graphql` files of a frontend) against the current schema.
+///
+/// The document is validated against the full `schema`, including the elements hidden by
+/// visibility predicates. Use [`validate_with_context()`] to validate it against the elements
+/// visible to a particular request context.
+///
+/// # Errors
+///
+/// - [`GraphQLError::ParseError`] if the `document_source` cannot be parsed.
+/// - [`GraphQLError::ValidationError`] with all the [`RuleError`]s if the document is invalid.
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{
+/// #     EmptyMutation, EmptySubscription, GraphQLError, RootNode, graphql_object,
+/// # };
+/// #
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn hello() -> &'static str {
+///         "world"
+///     }
+/// }
+///
+/// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+///
+/// assert_eq!(juniper::validate("{ hello }", &schema.schema), Ok(()));
+/// assert!(matches!(
+///     juniper::validate("{ goodbye }", &schema.schema),
+///     Err(GraphQLError::ValidationError(errs)) if errs.len() == 1,
+/// ));
+/// assert!(matches!(
+///     juniper::validate("{ hello", &schema.schema),
+///     Err(GraphQLError::ParseError(_)),
+/// ));
+/// ```
+///
+/// [0]: https://spec.graphql.org/September2025#sec-Validation
+pub fn validate<S>(document_source: &str, schema: &SchemaType<S>) -> Result<(), GraphQLError>
+where
+    S: ScalarValue,
+{
+    validate_with_context(document_source, schema, None)
+}
+
+/// [Validates][`validate()`] the provided GraphQL document against the elements of the provided
+/// `schema` visible to the provided request `context`, without executing it.
+///
+/// The `context` is the request context the visibility predicates of the `schema` are evaluated
+/// against. If it's [`None`], the document is validated against the full `schema`.
+///
+/// # Errors
+///
+/// - [`GraphQLError::ParseError`] if the `document_source` cannot be parsed.
+/// - [`GraphQLError::ValidationError`] with all the [`RuleError`]s if the document is invalid.
+/// - [`GraphQLError::VisibilityError`] if the visibility predicates of the `schema` don't accept
+///   the provided `context`.
+pub fn validate_with_context<S>(
+    document_source: &str,
+    schema: &SchemaType<S>,
+    context: Option<&dyn Any>,
+) -> Result<(), GraphQLError>
+where
+    S: ScalarValue,
+{
+    parse_and_validate(document_source, schema, context).map(drop)
+}
+
+/// Parses and [validates][`validate_with_context()`] the provided `document_source` against the
+/// `schema` elements visible to the provided `context`, returning the parsed document.
+fn parse_and_validate<'a, S>(
+    document_source: &'a str,
+    schema: &SchemaType<S>,
+    context: Option<&dyn Any>,
+) -> Result<ast::OwnedDocument<'a, S>, GraphQLError>
+where
+    S: ScalarValue,
+{
+    let document = parse_document_source(document_source, schema)?;
+
+    let visibility = context
+        .map(|ctx| Visibility::new(schema, Some(ctx)))
+        .transpose()?
+        .unwrap_or_default();
+    let mut ctx = ValidatorContext::new(schema, &document).with_visibility(visibility);
+    visit_all_rules(&mut ctx, &document);
+
+    let errors = ctx.into_errors();
+    if !errors.is_empty() {
+        return Err(errors.into());
+    }
+    Ok(document)
+}
+
+/// Collects the [`SchemaUsage`] of the provided operation without executing it.
+///
+/// Parses and [validates][`validate()`] the `document_source` against the full `schema`, then
+/// walks the operation with the
+/// provided `operation_name` (resolving its fragments against the `schema`). This is useful for
+/// analyzing persisted or logged operations offline. To collect the usage of the executed
+/// operations, use [`RootNode::usage_sink()`] instead.
+///
+/// # Errors
+///
+/// - [`GraphQLError::ParseError`] if the `document_source` cannot be parsed.
+/// - [`GraphQLError::ValidationError`] with all the [`RuleError`]s if the document is invalid.
+/// - [`GraphQLError::UnknownOperationName`] or [`GraphQLError::MultipleOperationsProvided`] if
+///   the operation cannot be selected.
+///
+/// [`SchemaUsage`]: schema::SchemaUsage
+pub fn collect_usage<S>(
+    document_source: &str,
+    operation_name: Option<&str>,
+    schema: &SchemaType<S>,
+) -> Result<schema::SchemaUsage, GraphQLError>
+where
+    S: ScalarValue,
+{
+    let document = parse_and_validate(document_source, schema, None)?;
+    let operation = get_operation(&document, operation_name)?;
+
+    Ok(schema::SchemaUsage::collect(
+        schema,
+        &document,
+        &operation.item,
+    ))
+}
+
 /// Executes the [canonical introspection query][0] in the provided schema.
 ///
 /// [0]: https://github.com/graphql/graphql-js/blob/v16.11.0/src/utilities/getIntrospectionQuery.ts#L75
@@ -335,14 +498,5 @@ where
     MutationT: GraphQLType<S, Context = QueryT::Context>,
     SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
 {
-    execute_sync(
-        match format {
-            IntrospectionFormat::All => INTROSPECTION_QUERY,
-            IntrospectionFormat::WithoutDescriptions => INTROSPECTION_QUERY_WITHOUT_DESCRIPTIONS,
-        },
-        None,
-        root_node,
-        &Variables::new(),
-        context,
-    )
+    execute_sync(&format.query(), None, root_node, &Variables::new(), context)
 }
@@ -0,0 +1,54 @@
+//! Helper definitions for the `#[derive(GraphQLQuery)]` macro.
+
+use indexmap::IndexMap;
+
+use crate::{FieldError, FromInputValue, InputValue, IntoFieldError, ScalarValue, Value};
+
+/// Fields of a decoded GraphQL response object.
+pub type Fields<'a, S> = IndexMap<&'a str, &'a InputValue<S>>;
+
+/// Extracts the [`Fields`] of the GraphQL response object of the provided `ty`pe.
+///
+/// # Errors
+///
+/// If the provided [`InputValue`] is not an object.
+pub fn object<'a, S: ScalarValue>(
+    v: &'a InputValue<S>,
+    ty: &str,
+) -> Result<Fields<'a, S>, FieldError<S>> {
+    v.to_object_value()
+        .ok_or_else(|| FieldError::new(format!("Expected `{ty}` object, found: {v}"), Value::Null))
+}
+
+/// Decodes the value of the `key` field from the provided [`Fields`].
+///
+/// Absent fields are decoded as implicit `null`s.
+///
+/// # Errors
+///
+/// If the field value cannot be decoded into `T`, prefixing the error message with the `key`.
+pub fn field<S, T>(fields: &Fields<'_, S>, key: &str) -> Result<T, FieldError<S>>
+where
+    S: ScalarValue,
+    T: FromInputValue<S>,
+    T::Error: IntoFieldError<S>,
+{
+    fields
+        .get(key)
+        .map_or_else(T::from_implicit_null, |v| T::from_input_value(v))
+        .map_err(|e| {
+            let e = e.into_field_error();
+            FieldError::new(format!("`{key}`: {}", e.message()), e.extensions().clone())
+        })
+}
+
+/// Builds the [`InputValue`] object of GraphQL operation variables from the provided ones,
+/// omitting the absent ones.
+#[must_use]
+pub fn variables<S, const N: usize>(vars: [(&str, Option<InputValue<S>>); N]) -> InputValue<S> {
+    InputValue::object(
+        vars.into_iter()
+            .filter_map(|(name, v)| v.map(|v| (name, v)))
+            .collect(),
+    )
+}
@@ -1,13 +1,22 @@
 //! Helper traits and definitions for macros.
 
+pub mod client;
 pub mod subscription;
+pub mod validate;
 
 use std::convert::Infallible;
 
+use arcstr::ArcStr;
 use derive_more::with_trait::Display;
 use futures::future::{self, BoxFuture};
 
-use crate::{FieldError, InputValue, ScalarValue, ToScalarValue};
+use crate::{
+    Arguments, DynamicTypeInfo, ExecutionResult, Executor, FieldError, FieldResult, GraphQLType,
+    GraphQLValue, InputValue, Registry, ScalarValue, ToScalarValue,
+    macros::reflect::Names,
+    meta::{Argument, Field, InputObjectMeta, MetaType, ObjectMeta},
+    schema::SchemaError,
+};
 
 /// This trait is used by [`graphql_scalar`] macro to retrieve [`Error`] type from a [`Result`].
 ///
@@ -54,11 +63,268 @@ where
     Box::pin(future::err(err_unnamed_type(name)))
 }
 
+/// Returns the GraphQL name of the `T` type parameter, substituted into a name template (like
+/// `{T}Connection`) of a generic GraphQL type.
+///
+/// Resolved via [`GraphQLType::name`] rather than [`BaseType::NAME`], as the latter is the raw
+/// template for generic types, so nested instantiations (like `Connection<Connection<User>>`) are
+/// named properly.
+///
+/// # Panics
+///
+/// If `T` has no GraphQL name (is a list or a `null`able type).
+///
+/// [`BaseType::NAME`]: crate::macros::reflect::BaseType::NAME
+pub fn type_param_name<T, S>() -> ArcStr
+where
+    T: GraphQLType<S, TypeInfo = ()> + ?Sized,
+    S: ScalarValue,
+{
+    T::name(&()).unwrap_or_else(|| {
+        panic!(
+            "type parameter `{}` of a name template must have a GraphQL name",
+            std::any::type_name::<T>(),
+        )
+    })
+}
+
+/// Extracts the non-built-in [`Field`]s of the provided [GraphQL object][1] `meta`, to be merged
+/// into the `into` [GraphQL object][1] by `#[derive(GraphQLMergedObject)]` macro or
+/// `#[graphql(flatten)]` attribute.
+///
+/// # Errors
+///
+/// If the provided `meta` doesn't describe a [GraphQL object][1].
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+pub fn merged_object_fields<S>(
+    into: &str,
+    meta: MetaType<S>,
+) -> Result<Vec<Field<S>>, SchemaError> {
+    match meta {
+        MetaType::Object(ObjectMeta { fields, .. }) => {
+            Ok(fields.into_iter().filter(|f| !f.is_builtin()).collect())
+        }
+        meta => Err(SchemaError::InvalidTypeKind {
+            coordinate: into.into(),
+            name: meta.name().map_or("<unnamed>", |n| n.as_str()).into(),
+            expected: "an object",
+        }),
+    }
+}
+
+/// Extracts the input fields of the provided [GraphQL input object][1] `meta`, to be inlined into
+/// the `into` [GraphQL input object][1] by `#[graphql(flatten)]` attribute.
+///
+/// # Errors
+///
+/// If the provided `meta` doesn't describe a [GraphQL input object][1].
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+pub fn flattened_input_fields<S>(
+    into: &str,
+    meta: MetaType<S>,
+) -> Result<Vec<Argument<S>>, SchemaError> {
+    match meta {
+        MetaType::InputObject(InputObjectMeta { input_fields, .. }) => Ok(input_fields),
+        meta => Err(SchemaError::InvalidTypeKind {
+            coordinate: into.into(),
+            name: meta.name().map_or("<unnamed>", |n| n.as_str()).into(),
+            expected: "an input object",
+        }),
+    }
+}
+
+/// Appends the fields of the `flattened` [GraphQL input object][1] value to the `into` one, as
+/// required by `#[graphql(flatten)]` attribute.
+///
+/// # Errors
+///
+/// If any of the provided [`InputValue`]s is not an object, leaving the `into` one untouched.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+pub fn merge_input_objects<S: ScalarValue>(
+    into: &mut InputValue<S>,
+    flattened: InputValue<S>,
+) -> Result<(), NotInputObjectError<S>> {
+    match (into, flattened) {
+        (InputValue::Object(fields), InputValue::Object(more)) => {
+            fields.extend(more);
+            Ok(())
+        }
+        (InputValue::Object(_), flattened) => Err(NotInputObjectError(flattened)),
+        (into, _) => Err(NotInputObjectError(into.clone())),
+    }
+}
+
+/// Additional [`Field`]s of a [GraphQL object][1] derived by `#[derive(GraphQLObject)]` macro
+/// with `#[graphql(complex)]` attribute, defined in a `#[graphql_object(complex)]` impl block.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+pub trait ComplexObject<S> {
+    /// [`Context`] type of the additional [`Field`]s.
+    ///
+    /// [`Context`]: crate::Context
+    type Context;
+
+    /// Type info of the additional [`Field`]s.
+    type TypeInfo;
+
+    /// [`Names`] of the additional [`Field`]s.
+    const NAMES: Names;
+
+    /// Registers and returns the additional [`Field`]s.
+    fn fields_meta(info: &Self::TypeInfo, registry: &mut Registry<S>) -> Vec<Field<S>>;
+
+    /// Resolves the additional `field` synchronously.
+    ///
+    /// # Errors
+    ///
+    /// If there is no such additional `field`, or resolving it fails.
+    fn resolve_complex_field(
+        &self,
+        info: &Self::TypeInfo,
+        field: &str,
+        args: &Arguments<'_, S>,
+        executor: &Executor<'_, '_, Self::Context, S>,
+    ) -> ExecutionResult<S>;
+}
+
+/// [GraphQL object][1] derived by `#[derive(GraphQLObject)]` macro with `#[graphql(complex)]`
+/// attribute, sharing its [`Context`] with the [`ComplexObject`] fields defined in its
+/// `#[graphql_object(complex)]` impl block.
+///
+/// [`Context`]: crate::Context
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+pub trait ComplexObjectStruct {
+    /// [`Context`] type of the [GraphQL object][1].
+    ///
+    /// [`Context`]: crate::Context
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    type Context;
+
+    /// [`TypeInfo`] type of the [GraphQL object][1].
+    ///
+    /// [`TypeInfo`]: crate::GraphQLValue::TypeInfo
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    type TypeInfo;
+}
+
+/// Asynchronous counterpart of [`ComplexObject`].
+pub trait AsyncComplexObject<S>: ComplexObject<S> {
+    /// Resolves the additional `field` asynchronously.
+    fn resolve_complex_field_async<'b>(
+        &'b self,
+        info: &'b Self::TypeInfo,
+        field: &'b str,
+        args: &'b Arguments<'_, S>,
+        executor: &'b Executor<'_, '_, Self::Context, S>,
+    ) -> BoxFuture<'b, ExecutionResult<S>>;
+}
+
+/// [`Guard`]s of a [GraphQL interface][1] defined by `#[graphql_interface]` macro (or of all the
+/// [GraphQL interfaces][1] implemented by a [GraphQL object][2]), checked by the field resolvers
+/// of [GraphQL objects][2] in macro expansions.
+///
+/// As the fields of a [GraphQL interface][1] are always resolved by its implementers (even when
+/// selected on the [GraphQL interface][1] itself), checking its [`Guard`]s there authorizes
+/// selections on the implementers (like `... on Human { id }`) too.
+///
+/// [`Guard`]: crate::Guard
+/// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+/// [2]: https://spec.graphql.org/October2021#sec-Objects
+pub trait InterfaceGuards<S: ScalarValue>: GraphQLValue<S> {
+    /// Checks the [`Guard`]s of the [GraphQL interface][1] and its `field` against the provided
+    /// `context`.
+    ///
+    /// Succeeds if the `field` doesn't belong to the [GraphQL interface][1].
+    ///
+    /// # Errors
+    ///
+    /// If any of the [`Guard`]s denies the access.
+    ///
+    /// [`Guard`]: crate::Guard
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    fn check_guards(field: &str, context: &Self::Context) -> FieldResult<(), S>;
+}
+
+/// [`TypeInfo`] of a GraphQL type, provided to the GraphQL types of its fields and arguments
+/// in macro expansions.
+///
+/// A custom [`TypeInfo`] is provided as is to the GraphQL types having the same [`TypeInfo`],
+/// while the ones having the unit type `()` as their [`TypeInfo`] are provided with it.
+///
+/// [`TypeInfo`]: crate::GraphQLValue::TypeInfo
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` type info cannot be provided to a GraphQL type with `{T}` type info",
+    note = "fields and arguments of a GraphQL type with custom `type_info` must have either the \
+            same or the unit type `()` as their type info"
+)]
+pub trait ChildTypeInfo<T> {
+    /// Returns the [`TypeInfo`] for the child GraphQL type.
+    ///
+    /// [`TypeInfo`]: crate::GraphQLValue::TypeInfo
+    fn child_type_info(&self) -> &T;
+}
+
+impl ChildTypeInfo<()> for () {
+    fn child_type_info(&self) -> &() {
+        self
+    }
+}
+
+impl<T: DynamicTypeInfo> ChildTypeInfo<()> for T {
+    fn child_type_info(&self) -> &() {
+        &()
+    }
+}
+
+impl<T: DynamicTypeInfo> ChildTypeInfo<T> for T {
+    fn child_type_info(&self) -> &T {
+        self
+    }
+}
+
+/// [`TypeInfo`] of a flattened [GraphQL object][1], provided from the [`TypeInfo`] `T` of its
+/// parent [GraphQL object][1] in macro expansions.
+///
+/// Bounds the flattened [GraphQL object][1] rather than the `T` [`TypeInfo`], so generic parent
+/// [GraphQL objects][1] still resolve [`ChildTypeInfo`] of their own fields via its impls.
+///
+/// [`TypeInfo`]: crate::GraphQLValue::TypeInfo
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+pub trait FlattenedTypeInfo<S: ScalarValue, T: ?Sized>: GraphQLValue<S> {
+    /// Returns the [`TypeInfo`] of this flattened [GraphQL object][1].
+    ///
+    /// [`TypeInfo`]: crate::GraphQLValue::TypeInfo
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    fn flattened_type_info(info: &T) -> &Self::TypeInfo;
+}
+
+impl<S, T, O> FlattenedTypeInfo<S, T> for O
+where
+    S: ScalarValue,
+    T: ChildTypeInfo<O::TypeInfo> + ?Sized,
+    O: GraphQLValue<S> + ?Sized,
+{
+    fn flattened_type_info(info: &T) -> &Self::TypeInfo {
+        info.child_type_info()
+    }
+}
+
 /// Error of an [`InputValue`] not representing a [`ScalarValue`], used in macro expansions.
 #[derive(Display)]
 #[display("Expected GraphQL scalar, found: {_0}")]
 pub struct NotScalarError<'a, S: ScalarValue>(pub &'a InputValue<S>);
 
+/// Error of an [`InputValue`] not representing a [GraphQL input object][1], used in macro
+/// expansions.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+#[derive(Display)]
+#[display("Expected GraphQL input object, found: {_0}")]
+pub struct NotInputObjectError<S: ScalarValue>(pub InputValue<S>);
+
 /// [Autoref-based specialized][0] coercion into a [`Result`] for a function call for providing a
 /// return-type polymorphism in macros.
 ///
@@ -170,3 +436,50 @@ where
         S::from_displayable_non_static(&self(input))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        DefaultScalarValue, InputValue, Registry, graphql_input_value, meta::MetaType,
+        schema::SchemaError,
+    };
+
+    use super::{flattened_input_fields, merge_input_objects, merged_object_fields};
+
+    fn int_meta() -> MetaType<DefaultScalarValue> {
+        let mut registry = Registry::new(Default::default());
+        registry.get_type::<i32>(&());
+        registry.types.remove("Int").unwrap()
+    }
+
+    #[test]
+    fn rejects_merging_non_objects() {
+        assert_eq!(
+            merged_object_fields("Query", int_meta()).map(drop),
+            Err(SchemaError::InvalidTypeKind {
+                coordinate: "Query".into(),
+                name: "Int".into(),
+                expected: "an object",
+            }),
+        );
+        assert_eq!(
+            flattened_input_fields("Filter", int_meta()).map(drop),
+            Err(SchemaError::InvalidTypeKind {
+                coordinate: "Filter".into(),
+                name: "Int".into(),
+                expected: "an input object",
+            }),
+        );
+    }
+
+    #[test]
+    fn merges_input_objects() {
+        let mut value: InputValue = graphql_input_value!({"name": "John"});
+        assert!(merge_input_objects(&mut value, graphql_input_value!({"first": 5})).is_ok());
+        assert_eq!(value, graphql_input_value!({"name": "John", "first": 5}));
+
+        let err = merge_input_objects(&mut value, graphql_input_value!(5)).unwrap_err();
+        assert_eq!(err.to_string(), "Expected GraphQL input object, found: 5");
+        assert_eq!(value, graphql_input_value!({"name": "John", "first": 5}));
+    }
+}
@@ -0,0 +1,430 @@
+//! Helper definitions for the `#[graphql(validate(...))]` attribute argument of macros.
+
+use std::{cell::RefCell, cmp::Ordering, fmt::Display};
+
+#[cfg(feature = "regex")]
+use std::sync::OnceLock;
+
+use arcstr::ArcStr;
+
+use crate::{FieldError, ID, ScalarValue, Value};
+
+#[cfg(feature = "regex")]
+#[doc(inline)]
+pub use regex::Regex;
+
+/// Prefix of the [`FieldError::message`] of validation errors.
+const MESSAGE_PREFIX: &str = "Invalid value";
+
+/// Name of the [`FieldError::extensions`] entry holding the input path of the
+/// offending value.
+const INPUT_PATH_EXTENSION: &str = "inputPath";
+
+/// Value having a length, which can be validated by the
+/// `#[graphql(validate(min_length = ..., max_length = ...))]` attribute arguments.
+pub trait Length {
+    /// Returns the length of this value to be validated, if any.
+    ///
+    /// [`None`] means that there is nothing to validate (like an absent
+    /// [`Option`]al value).
+    #[must_use]
+    fn validated_length(&self) -> Option<usize>;
+}
+
+impl Length for str {
+    fn validated_length(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl Length for String {
+    fn validated_length(&self) -> Option<usize> {
+        self.as_str().validated_length()
+    }
+}
+
+impl Length for ArcStr {
+    fn validated_length(&self) -> Option<usize> {
+        self.as_str().validated_length()
+    }
+}
+
+impl Length for ID {
+    fn validated_length(&self) -> Option<usize> {
+        (**self).validated_length()
+    }
+}
+
+impl<T> Length for [T] {
+    fn validated_length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T> Length for Vec<T> {
+    fn validated_length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T: Length + ?Sized> Length for Box<T> {
+    fn validated_length(&self) -> Option<usize> {
+        (**self).validated_length()
+    }
+}
+
+impl<T: Length> Length for Option<T> {
+    fn validated_length(&self) -> Option<usize> {
+        self.as_ref().and_then(T::validated_length)
+    }
+}
+
+/// Value comparable with a `B`ound, which can be validated by the
+/// `#[graphql(validate(min = ..., max = ...))]` attribute arguments.
+pub trait Bounded<B> {
+    /// Compares this value with the provided `bound`, if there is anything to
+    /// compare.
+    ///
+    /// [`None`] means that there is nothing to validate (like an absent
+    /// [`Option`]al value).
+    #[must_use]
+    fn validated_cmp(&self, bound: &B) -> Option<Ordering>;
+}
+
+macro_rules! impl_bounded_for_primitives {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Bounded<$ty> for $ty {
+            fn validated_cmp(&self, bound: &$ty) -> Option<Ordering> {
+                self.partial_cmp(bound)
+            }
+        }
+    )*};
+}
+
+impl_bounded_for_primitives!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64,
+);
+
+impl<B, T: Bounded<B>> Bounded<B> for Option<T> {
+    fn validated_cmp(&self, bound: &B) -> Option<Ordering> {
+        self.as_ref().and_then(|v| v.validated_cmp(bound))
+    }
+}
+
+/// String value, which can be validated by the
+/// `#[graphql(validate(regex = "..."))]` attribute argument.
+pub trait Matchable {
+    /// Returns the string to be matched, if any.
+    ///
+    /// [`None`] means that there is nothing to validate (like an absent
+    /// [`Option`]al value).
+    #[must_use]
+    fn validated_str(&self) -> Option<&str>;
+}
+
+impl Matchable for str {
+    fn validated_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl Matchable for String {
+    fn validated_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl Matchable for ArcStr {
+    fn validated_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl Matchable for ID {
+    fn validated_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T: Matchable> Matchable for Option<T> {
+    fn validated_str(&self) -> Option<&str> {
+        self.as_ref().and_then(T::validated_str)
+    }
+}
+
+/// Segment of a path to an input value, which altogether is displayed like
+/// `$input.items[3].price`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InputPathSegment {
+    /// Name of an argument or an input object field.
+    Name(&'static str),
+
+    /// Index of a list element.
+    Index(usize),
+}
+
+impl From<&'static str> for InputPathSegment {
+    fn from(name: &'static str) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl From<usize> for InputPathSegment {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+thread_local! {
+    /// Path to the input value being converted via [`FromInputValue`] in the
+    /// current thread.
+    ///
+    /// [`FromInputValue`]: crate::FromInputValue
+    static INPUT_PATH: RefCell<Vec<InputPathSegment>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Runs the provided function `f` converting the input value located at the
+/// given `segment` of the current input path.
+///
+/// Validation [`FieldError`]s created by [`error()`] inside the `f` point to
+/// this input value.
+pub fn at_input_path<T>(segment: impl Into<InputPathSegment>, f: impl FnOnce() -> T) -> T {
+    /// Pops the last [`InputPathSegment`] on [`Drop`], even if `f` panics.
+    struct PopOnDrop;
+
+    impl Drop for PopOnDrop {
+        fn drop(&mut self) {
+            INPUT_PATH.with_borrow_mut(|path| {
+                path.pop();
+            });
+        }
+    }
+
+    INPUT_PATH.with_borrow_mut(|path| path.push(segment.into()));
+    let _pop = PopOnDrop;
+    f()
+}
+
+/// Returns the current input path formatted like `$input.items[3].price`, if
+/// any.
+fn current_input_path() -> Option<String> {
+    INPUT_PATH.with_borrow(|path| {
+        let out = path
+            .iter()
+            .enumerate()
+            .map(|(n, segment)| match segment {
+                InputPathSegment::Name(name) if n == 0 => format!("${name}"),
+                InputPathSegment::Name(name) => format!(".{name}"),
+                InputPathSegment::Index(i) => format!("[{i}]"),
+            })
+            .collect::<String>();
+        (!out.is_empty()).then_some(out)
+    })
+}
+
+/// Creates a validation [`FieldError`] for the value at the current input path
+/// (see [`at_input_path()`]).
+///
+/// The input path is exposed via the `"inputPath"` entry of the
+/// [`FieldError::extensions`].
+#[must_use]
+pub fn error<S: ScalarValue>(reason: impl Display) -> FieldError<S> {
+    match current_input_path() {
+        Some(path) => {
+            let mut extensions = crate::Object::with_capacity(1);
+            extensions.add_field(INPUT_PATH_EXTENSION, Value::scalar(path.clone()));
+            FieldError::new(
+                format!("{MESSAGE_PREFIX} at \"{path}\": {reason}"),
+                Value::object(extensions),
+            )
+        }
+        None => FieldError::from(format!("{MESSAGE_PREFIX}: {reason}")),
+    }
+}
+
+/// Validates that the provided `value` is greater than or equal to the `min` bound.
+///
+/// # Errors
+///
+/// If the `value` is less than the `min` bound.
+pub fn min<S, T, B>(value: &T, min: B) -> Result<(), FieldError<S>>
+where
+    S: ScalarValue,
+    T: Bounded<B> + ?Sized,
+    B: Display,
+{
+    match value.validated_cmp(&min) {
+        Some(Ordering::Less) => Err(error(format_args!(
+            "must be greater than or equal to {min}"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Validates that the provided `value` is less than or equal to the `max` bound.
+///
+/// # Errors
+///
+/// If the `value` is greater than the `max` bound.
+pub fn max<S, T, B>(value: &T, max: B) -> Result<(), FieldError<S>>
+where
+    S: ScalarValue,
+    T: Bounded<B> + ?Sized,
+    B: Display,
+{
+    match value.validated_cmp(&max) {
+        Some(Ordering::Greater) => Err(error(format_args!("must be less than or equal to {max}"))),
+        _ => Ok(()),
+    }
+}
+
+/// Validates that the [`Length`] of the provided `value` is at least `min`.
+///
+/// # Errors
+///
+/// If the `value` is shorter than `min`.
+pub fn min_length<S, T>(value: &T, min: usize) -> Result<(), FieldError<S>>
+where
+    S: ScalarValue,
+    T: Length + ?Sized,
+{
+    match value.validated_length() {
+        Some(len) if len < min => Err(error(format_args!("length must be at least {min}"))),
+        _ => Ok(()),
+    }
+}
+
+/// Validates that the [`Length`] of the provided `value` is at most `max`.
+///
+/// # Errors
+///
+/// If the `value` is longer than `max`.
+pub fn max_length<S, T>(value: &T, max: usize) -> Result<(), FieldError<S>>
+where
+    S: ScalarValue,
+    T: Length + ?Sized,
+{
+    match value.validated_length() {
+        Some(len) if len > max => Err(error(format_args!("length must be at most {max}"))),
+        _ => Ok(()),
+    }
+}
+
+/// Validates that the provided `value` matches the regular expression `pattern`.
+///
+/// The compiled [`Regex`] is stored in the provided `cache` on the first use.
+///
+/// # Errors
+///
+/// If the `value` doesn't match the `pattern`.
+///
+/// # Panics
+///
+/// If the `pattern` is not a valid regular expression, which is rejected by
+/// macros at compile time already.
+#[cfg(feature = "regex")]
+pub fn regex<S, T>(
+    value: &T,
+    pattern: &'static str,
+    cache: &'static OnceLock<Regex>,
+) -> Result<(), FieldError<S>>
+where
+    S: ScalarValue,
+    T: Matchable + ?Sized,
+{
+    let Some(s) = value.validated_str() else {
+        return Ok(());
+    };
+    let re = cache.get_or_init(|| {
+        Regex::new(pattern).unwrap_or_else(|e| panic!("invalid `regex` pattern: {e}"))
+    });
+    if re.is_match(s) {
+        Ok(())
+    } else {
+        Err(error(format_args!("must match the \"{pattern}\" pattern")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DefaultScalarValue, FieldError, graphql_value};
+
+    use super::{at_input_path, error, max, max_length, min, min_length};
+
+    type Error = FieldError<DefaultScalarValue>;
+
+    #[test]
+    fn validates_bounds() {
+        assert!(min::<DefaultScalarValue, _, _>(&5, 1).is_ok());
+        assert!(min::<DefaultScalarValue, _, _>(&None::<i32>, 1).is_ok());
+        assert_eq!(
+            at_input_path("n", || max::<DefaultScalarValue, _, _>(&Some(2.5), 1.0)),
+            Err(Error::new(
+                "Invalid value at \"$n\": must be less than or equal to 1",
+                graphql_value!({"inputPath": "$n"}),
+            )),
+        );
+    }
+
+    #[test]
+    fn validates_length() {
+        assert!(min_length::<DefaultScalarValue, _>("ёж", 2).is_ok());
+        assert!(max_length::<DefaultScalarValue, _>(&vec![1, 2], 2).is_ok());
+        assert_eq!(
+            at_input_path("s", || {
+                min_length::<DefaultScalarValue, _>(&Some(String::new()), 1)
+            }),
+            Err(Error::new(
+                "Invalid value at \"$s\": length must be at least 1",
+                graphql_value!({"inputPath": "$s"}),
+            )),
+        );
+    }
+
+    #[test]
+    fn tracks_input_path() {
+        let err: Error = at_input_path("input", || {
+            at_input_path("items", || {
+                at_input_path(3, || at_input_path("price", || error("must be positive")))
+            })
+        });
+        assert_eq!(
+            err,
+            Error::new(
+                "Invalid value at \"$input.items[3].price\": must be positive",
+                graphql_value!({"inputPath": "$input.items[3].price"}),
+            ),
+        );
+
+        let err: Error = error("must be positive");
+        assert_eq!(err, Error::from("Invalid value: must be positive"));
+    }
+
+    #[test]
+    fn unwinds_input_path_on_panic() {
+        let res = std::panic::catch_unwind(|| at_input_path("input", || panic!("boom")));
+        assert!(res.is_err());
+
+        let err: Error = at_input_path("name", || error("must be set"));
+        assert_eq!(err.extensions(), &graphql_value!({"inputPath": "$name"}),);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn validates_regex() {
+        use std::sync::OnceLock;
+
+        static RE: OnceLock<super::Regex> = OnceLock::new();
+
+        assert!(super::regex::<DefaultScalarValue, _>("abc", "^[a-z]+$", &RE).is_ok());
+        assert_eq!(
+            at_input_path("s", || {
+                super::regex::<DefaultScalarValue, _>(&Some("A1".to_owned()), "^[a-z]+$", &RE)
+            }),
+            Err(Error::new(
+                "Invalid value at \"$s\": must match the \"^[a-z]+$\" pattern",
+                graphql_value!({"inputPath": "$s"}),
+            )),
+        );
+    }
+}
@@ -1066,6 +1066,132 @@ pub const fn const_concat_into_bytes<const LEN: usize>(input: &[&str]) -> [u8; L
     bytes
 }
 
+/// Maximum total number of [`Names`] concatenable by [`concat_names()`].
+///
+/// Limits the total number of fields of a GraphQL object or input object using `flatten` or
+/// `complex` attribute arguments.
+///
+/// The buffer cannot be sized from the lengths of the concatenated [`Names`] instead, as these
+/// are generic `const`s (at least over the [`ScalarValue`]), which cannot be used in array
+/// lengths.
+///
+/// [`ScalarValue`]: crate::ScalarValue
+pub const MAX_CONCAT_NAMES: usize = 256;
+
+/// Concatenates the provided [`Names`] into a fixed-size buffer, returning it along with the
+/// number of its occupied elements.
+///
+/// Unlike [`const_concat!`], may be used with generic `const`s. Use [`names_from_buf()`] to turn
+/// the returned buffer back into [`Names`].
+///
+/// # Panics
+///
+/// With the provided `err` message, if the provided [`Names`] contain more than
+/// [`MAX_CONCAT_NAMES`] elements in total.
+#[must_use]
+pub const fn concat_names(l: Names, r: Names, err: &str) -> ([Name; MAX_CONCAT_NAMES], usize) {
+    if l.len() + r.len() > MAX_CONCAT_NAMES {
+        panic!("{}", err);
+    }
+    let mut buf = [""; MAX_CONCAT_NAMES];
+    let mut i = 0;
+    while i < l.len() {
+        buf[i] = l[i];
+        i += 1;
+    }
+    let mut j = 0;
+    while j < r.len() {
+        buf[i + j] = r[j];
+        j += 1;
+    }
+    (buf, i + j)
+}
+
+/// Returns [`Names`] occupying the provided buffer returned by [`concat_names()`].
+#[must_use]
+pub const fn names_from_buf(buf: &'static ([Name; MAX_CONCAT_NAMES], usize)) -> Names {
+    buf.0.split_at(buf.1).0
+}
+
+/// Returns the first [`Name`] occurring in the provided [`Names`] more than once, if any.
+#[must_use]
+pub const fn find_duplicate(names: Names) -> Option<Name> {
+    let mut i = 0;
+    while i < names.len() {
+        let mut j = i + 1;
+        while j < names.len() {
+            if str_eq(names[i], names[j]) {
+                return Some(names[i]);
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Maximum length of a message formatted by [`const_format_into_buf()`].
+pub const MAX_MESSAGE_LEN: usize = 1024;
+
+/// Concatenates the provided [`str`]s into a fixed-size buffer, returning it along with the
+/// number of its occupied bytes.
+///
+/// Unlike [`const_concat!`], may be used with non-`const` [`str`]s.
+///
+/// # Panics
+///
+/// If the provided [`str`]s are longer than [`MAX_MESSAGE_LEN`] in total.
+///
+/// [`str`]: prim@str
+#[must_use]
+pub const fn const_format_into_buf(input: &[&str]) -> ([u8; MAX_MESSAGE_LEN], usize) {
+    let mut buf = [0; MAX_MESSAGE_LEN];
+    let (mut i, mut len) = (0, 0);
+    while i < input.len() {
+        let bytes = input[i].as_bytes();
+        assert!(
+            len + bytes.len() <= MAX_MESSAGE_LEN,
+            "too long message to format",
+        );
+        let mut b = 0;
+        while b < bytes.len() {
+            buf[len] = bytes[b];
+            len += 1;
+            b += 1;
+        }
+        i += 1;
+    }
+    (buf, len)
+}
+
+/// Asserts that all the [`Fields`] of the provided `$ty` have different names.
+///
+/// This may not be the case when some of them are defined outside of the `$ty` definition (like in
+/// a `#[graphql_object(complex)]` impl block).
+#[macro_export]
+macro_rules! assert_unique_fields {
+    ($ty: ty, $scalar: ty $(,)?) => {
+        const _: () = {
+            if let ::core::option::Option::Some(name) = $crate::macros::reflect::find_duplicate(
+                <$ty as $crate::macros::reflect::Fields<$scalar>>::NAMES,
+            ) {
+                let (buf, len) = $crate::macros::reflect::const_format_into_buf(&[
+                    "field `",
+                    name,
+                    "` is defined multiple times on `",
+                    <$ty as $crate::macros::reflect::BaseType<$scalar>>::NAME,
+                    "`",
+                ]);
+                // TODO: Use `.unwrap()` once it becomes `const`.
+                match ::core::str::from_utf8(buf.split_at(len).0) {
+                    ::core::result::Result::Ok(msg) => ::core::panic!("{}", msg),
+                    _ => ::core::unreachable!(),
+                }
+            }
+        };
+    };
+}
+
 /// Formats the provided [`Type`] and [`WrappedValue`] into a readable GraphQL type name.
 ///
 /// # Examples
@@ -12,7 +12,7 @@ mod tests;
 
 pub use self::document::parse_document_source;
 
-pub(crate) use self::lexer::UnicodeCodePoint;
+pub(crate) use self::{lexer::UnicodeCodePoint, value::parse_value_literal};
 pub use self::{
     lexer::{Lexer, LexerError, ScalarToken, StringLiteral, Token},
     parser::{OptionParseResult, ParseError, ParseResult, Parser, UnlocatedParseResult},
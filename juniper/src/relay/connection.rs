@@ -0,0 +1,798 @@
+//! [Cursor connections][0] and their pagination.
+//!
+//! [0]: https://relay.dev/graphql/connections.htm
+
+use std::{
+    future::{self, Future},
+    ops::Range,
+};
+
+use arcstr::ArcStr;
+
+use derive_more::with_trait::{Display, Error};
+
+use crate::{
+    BoxFuture, GraphQLObject,
+    executor::{ExecutionResult, Executor, FieldError, IntoFieldError, Registry},
+    macros::{
+        helper::type_param_name,
+        reflect::{BaseSubTypes, BaseType, Type, Types, WrappedType, WrappedValue},
+    },
+    schema::meta::MetaType,
+    types::{
+        async_await::GraphQLValueAsync,
+        base::{Arguments, GraphQLType, GraphQLValue},
+        marker::IsOutputType,
+    },
+    value::ScalarValue,
+};
+
+use super::cursor::{cursor_to_offset, offset_to_cursor};
+
+/// Returns the GraphQL name of the `N` node type, asserting it to be neither a list nor a
+/// `null`able type, as these have no GraphQL name.
+///
+/// Resolved via [`GraphQLType::name`] rather than [`BaseType::NAME`], as the latter is the raw
+/// template for generic types (so `Connection<Connection<User>>` would be named as
+/// `{N}ConnectionConnection`).
+fn node_name<N, S>() -> ArcStr
+where
+    N: GraphQLType<S, TypeInfo = ()> + WrappedType<S>,
+    S: ScalarValue,
+{
+    const {
+        assert!(
+            N::VALUE == 1,
+            "`Connection` and `Edge` nodes must be neither lists nor `null`able types",
+        );
+    };
+    type_param_name::<N, S>()
+}
+
+/// [Connection][0] to a paginated list of `N` nodes.
+///
+/// Named as `{N}Connection` in GraphQL schema (like `UserConnection` for `Connection<User>`), so
+/// `N` must be neither a list nor a `null`able type.
+///
+/// As the name cannot be computed in a `const` context, the [`BaseType::NAME`] of every
+/// [`Connection`] is the raw `{N}Connection`, so compile-time reflection (like the checks of
+/// interface implementers' field types) doesn't distinguish `Connection<User>` from
+/// `Connection<Post>`.
+///
+/// [0]: https://relay.dev/graphql/connections.htm#sec-Connection-Types
+#[derive(Clone, Debug, Default)]
+pub struct Connection<N> {
+    /// [Edges][0] of this [`Connection`] in the requested page.
+    ///
+    /// [0]: https://relay.dev/graphql/connections.htm#sec-Edges
+    pub edges: Vec<Edge<N>>,
+
+    /// Information about the requested page.
+    pub page_info: PageInfo,
+}
+
+impl<N> Connection<N> {
+    /// Creates a new [`Connection`] out of the provided `edges` and `page_info`.
+    #[must_use]
+    pub fn new(edges: Vec<Edge<N>>, page_info: PageInfo) -> Self {
+        Self { edges, page_info }
+    }
+}
+
+impl<N, S> GraphQLType<S> for Connection<N>
+where
+    N: GraphQLType<S, TypeInfo = ()> + BaseType<S> + WrappedType<S>,
+    S: ScalarValue,
+{
+    fn name(_: &Self::TypeInfo) -> Option<ArcStr> {
+        Some(arcstr::format!("{}Connection", node_name::<N, S>()))
+    }
+
+    fn meta(info: &Self::TypeInfo, registry: &mut Registry<S>) -> MetaType<S> {
+        let fields = [
+            registry
+                .field::<Vec<Edge<N>>>(arcstr::literal!("edges"), &())
+                .description(arcstr::literal!("Edges of the requested page.")),
+            registry
+                .field::<PageInfo>(arcstr::literal!("pageInfo"), &())
+                .description(arcstr::literal!("Information to aid in pagination.")),
+        ];
+        registry
+            .build_object_type::<Self>(info, &fields)
+            .description(arcstr::format!(
+                "Connection to a list of `{}`s.",
+                node_name::<N, S>(),
+            ))
+            .into_meta()
+    }
+}
+
+impl<N, S> GraphQLValue<S> for Connection<N>
+where
+    N: GraphQLType<S, TypeInfo = ()> + BaseType<S> + WrappedType<S>,
+    S: ScalarValue,
+{
+    type Context = N::Context;
+    type TypeInfo = ();
+
+    fn type_name(&self, info: &Self::TypeInfo) -> Option<ArcStr> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn concrete_type_name(&self, _: &Self::Context, info: &Self::TypeInfo) -> String {
+        <Self as GraphQLType<S>>::name(info).unwrap().to_string()
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field: &str,
+        _: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        match field {
+            "edges" => executor.resolve(&(), &self.edges),
+            "pageInfo" => executor.replaced_context(&()).resolve(&(), &self.page_info),
+            _ => Err(err_unknown_field::<Self, S>(info, field)),
+        }
+    }
+}
+
+impl<N, S> GraphQLValueAsync<S> for Connection<N>
+where
+    N: GraphQLValueAsync<S, TypeInfo = ()> + GraphQLType<S> + BaseType<S> + WrappedType<S>,
+    N::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_field_async<'b>(
+        &'b self,
+        info: &'b Self::TypeInfo,
+        field_name: &'b str,
+        arguments: &'b Arguments<S>,
+        executor: &'b Executor<Self::Context, S>,
+    ) -> BoxFuture<'b, ExecutionResult<S>> {
+        match field_name {
+            "edges" => Box::pin(executor.resolve_async(&(), &self.edges)),
+            _ => {
+                let v = self.resolve_field(info, field_name, arguments, executor);
+                Box::pin(future::ready(v))
+            }
+        }
+    }
+}
+
+impl<N, S> IsOutputType<S> for Connection<N>
+where
+    N: IsOutputType<S, TypeInfo = ()> + BaseType<S> + WrappedType<S>,
+    S: ScalarValue,
+{
+    fn mark() {
+        N::mark();
+    }
+}
+
+impl<N: BaseType<S>, S> BaseType<S> for Connection<N> {
+    const NAME: Type = "{N}Connection";
+}
+
+impl<N: BaseType<S>, S> BaseSubTypes<S> for Connection<N> {
+    const NAMES: Types = &[<Self as BaseType<S>>::NAME];
+}
+
+impl<N, S> WrappedType<S> for Connection<N> {
+    const VALUE: WrappedValue = 1;
+}
+
+/// [Edge][0] of a [`Connection`], containing a `N` node along with its cursor.
+///
+/// Named as `{N}Edge` in GraphQL schema (like `UserEdge` for `Edge<User>`), so `N` must be
+/// neither a list nor a `null`able type.
+///
+/// Similarly to a [`Connection`], the [`BaseType::NAME`] of every [`Edge`] is the raw `{N}Edge`.
+///
+/// [0]: https://relay.dev/graphql/connections.htm#sec-Edge-Types
+#[derive(Clone, Debug)]
+pub struct Edge<N> {
+    /// Node of this [`Edge`].
+    pub node: N,
+
+    /// Opaque cursor of this [`Edge`].
+    pub cursor: String,
+}
+
+impl<N> Edge<N> {
+    /// Creates a new [`Edge`] out of the provided `node` and its `cursor`.
+    #[must_use]
+    pub fn new(node: N, cursor: impl Into<String>) -> Self {
+        Self {
+            node,
+            cursor: cursor.into(),
+        }
+    }
+}
+
+impl<N, S> GraphQLType<S> for Edge<N>
+where
+    N: GraphQLType<S, TypeInfo = ()> + BaseType<S> + WrappedType<S>,
+    S: ScalarValue,
+{
+    fn name(_: &Self::TypeInfo) -> Option<ArcStr> {
+        Some(arcstr::format!("{}Edge", node_name::<N, S>()))
+    }
+
+    fn meta(info: &Self::TypeInfo, registry: &mut Registry<S>) -> MetaType<S> {
+        let fields = [
+            registry
+                .field::<N>(arcstr::literal!("node"), &())
+                .description(arcstr::literal!("Item at the end of the edge.")),
+            registry
+                .field::<String>(arcstr::literal!("cursor"), &())
+                .description(arcstr::literal!("Cursor for use in pagination.")),
+        ];
+        registry
+            .build_object_type::<Self>(info, &fields)
+            .description(arcstr::format!(
+                "Edge in a connection to a list of `{}`s.",
+                node_name::<N, S>(),
+            ))
+            .into_meta()
+    }
+}
+
+impl<N, S> GraphQLValue<S> for Edge<N>
+where
+    N: GraphQLType<S, TypeInfo = ()> + BaseType<S> + WrappedType<S>,
+    S: ScalarValue,
+{
+    type Context = N::Context;
+    type TypeInfo = ();
+
+    fn type_name(&self, info: &Self::TypeInfo) -> Option<ArcStr> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn concrete_type_name(&self, _: &Self::Context, info: &Self::TypeInfo) -> String {
+        <Self as GraphQLType<S>>::name(info).unwrap().to_string()
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field: &str,
+        _: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        match field {
+            "node" => executor.resolve(&(), &self.node),
+            "cursor" => executor.replaced_context(&()).resolve(&(), &self.cursor),
+            _ => Err(err_unknown_field::<Self, S>(info, field)),
+        }
+    }
+}
+
+impl<N, S> GraphQLValueAsync<S> for Edge<N>
+where
+    N: GraphQLValueAsync<S, TypeInfo = ()> + GraphQLType<S> + BaseType<S> + WrappedType<S>,
+    N::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_field_async<'b>(
+        &'b self,
+        info: &'b Self::TypeInfo,
+        field_name: &'b str,
+        arguments: &'b Arguments<S>,
+        executor: &'b Executor<Self::Context, S>,
+    ) -> BoxFuture<'b, ExecutionResult<S>> {
+        match field_name {
+            "node" => Box::pin(executor.resolve_async(&(), &self.node)),
+            _ => {
+                let v = self.resolve_field(info, field_name, arguments, executor);
+                Box::pin(future::ready(v))
+            }
+        }
+    }
+}
+
+impl<N, S> IsOutputType<S> for Edge<N>
+where
+    N: IsOutputType<S, TypeInfo = ()> + BaseType<S> + WrappedType<S>,
+    S: ScalarValue,
+{
+    fn mark() {
+        N::mark();
+    }
+}
+
+impl<N: BaseType<S>, S> BaseType<S> for Edge<N> {
+    const NAME: Type = "{N}Edge";
+}
+
+impl<N: BaseType<S>, S> BaseSubTypes<S> for Edge<N> {
+    const NAMES: Types = &[<Self as BaseType<S>>::NAME];
+}
+
+impl<N, S> WrappedType<S> for Edge<N> {
+    const VALUE: WrappedValue = 1;
+}
+
+/// Information about a page of a [`Connection`].
+///
+/// See the [Relay Cursor Connections Specification][0] for details.
+///
+/// [0]: https://relay.dev/graphql/connections.htm#sec-undefined.PageInfo
+#[derive(Clone, Debug, Default, Eq, GraphQLObject, PartialEq)]
+#[graphql(desc = "Information about pagination in a connection.")]
+pub struct PageInfo {
+    /// Indicator whether more edges exist prior to the page.
+    #[graphql(desc = "When paginating backwards, are there more items?")]
+    pub has_previous_page: bool,
+
+    /// Indicator whether more edges exist following the page.
+    #[graphql(desc = "When paginating forwards, are there more items?")]
+    pub has_next_page: bool,
+
+    /// Cursor of the first edge in the page, if any.
+    #[graphql(desc = "When paginating backwards, the cursor to continue.")]
+    pub start_cursor: Option<String>,
+
+    /// Cursor of the last edge in the page, if any.
+    #[graphql(desc = "When paginating forwards, the cursor to continue.")]
+    pub end_cursor: Option<String>,
+}
+
+/// [Pagination arguments][0] of a [`Connection`] field.
+///
+/// [0]: https://relay.dev/graphql/connections.htm#sec-Arguments
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ConnectionArgs {
+    /// Number of edges to return after the `after` cursor (or from the start).
+    pub first: Option<i32>,
+
+    /// Cursor to return edges after.
+    pub after: Option<String>,
+
+    /// Number of edges to return before the `before` cursor (or from the end).
+    pub last: Option<i32>,
+
+    /// Cursor to return edges before.
+    pub before: Option<String>,
+}
+
+impl ConnectionArgs {
+    /// Creates new [`ConnectionArgs`] out of the provided `first`, `after`, `last` and `before`
+    /// arguments of a [`Connection`] field.
+    #[must_use]
+    pub fn new(
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Self {
+        Self {
+            first,
+            after,
+            last,
+            before,
+        }
+    }
+
+    /// Resolves these [`ConnectionArgs`] against a list of the provided `total` length.
+    ///
+    /// Follows the [pagination algorithm][0] of the Relay specification.
+    ///
+    /// [0]: https://relay.dev/graphql/connections.htm#sec-Pagination-algorithm
+    fn paginate(&self, total: usize) -> Result<Page, ConnectionError> {
+        let limit = |limit: Option<i32>, argument| {
+            limit
+                .map(|n| {
+                    usize::try_from(n).map_err(|_| ConnectionError::NegativeLimit { argument })
+                })
+                .transpose()
+        };
+        let (first, last) = (limit(self.first, "first")?, limit(self.last, "last")?);
+        let offset = |cursor: &Option<String>| {
+            cursor
+                .as_deref()
+                .map(|c| {
+                    cursor_to_offset(c)
+                        .ok_or_else(|| ConnectionError::InvalidCursor { cursor: c.into() })
+                })
+                .transpose()
+        };
+
+        let lower = offset(&self.after)?
+            .map_or(0, |o| o.saturating_add(1))
+            .min(total);
+        let upper = offset(&self.before)?
+            .map_or(total, |o| o.min(total))
+            .max(lower);
+
+        let mut range = lower..upper;
+        if let Some(first) = first {
+            range.end = range.end.min(range.start.saturating_add(first));
+        }
+        if let Some(last) = last {
+            range.start = range.start.max(range.end.saturating_sub(last));
+        }
+
+        Ok(Page {
+            has_previous_page: last.is_some() && range.start > lower,
+            has_next_page: first.is_some() && range.end < upper,
+            range,
+        })
+    }
+}
+
+/// Page of a list, resolved from [`ConnectionArgs`].
+struct Page {
+    range: Range<usize>,
+    has_previous_page: bool,
+    has_next_page: bool,
+}
+
+impl Page {
+    /// Builds a [`Connection`] out of the provided `nodes` of this [`Page`].
+    fn into_connection<N>(self, nodes: impl IntoIterator<Item = N>) -> Connection<N> {
+        let edges: Vec<_> = nodes
+            .into_iter()
+            .zip(self.range)
+            .map(|(node, offset)| Edge::new(node, offset_to_cursor(offset)))
+            .collect();
+        let page_info = PageInfo {
+            has_previous_page: self.has_previous_page,
+            has_next_page: self.has_next_page,
+            start_cursor: edges.first().map(|e| e.cursor.clone()),
+            end_cursor: edges.last().map(|e| e.cursor.clone()),
+        };
+        Connection::new(edges, page_info)
+    }
+}
+
+/// Error of resolving [`ConnectionArgs`].
+#[derive(Clone, Debug, Display, Eq, Error, PartialEq)]
+pub enum ConnectionError {
+    /// Provided cursor is not a valid one.
+    #[display("invalid cursor `{cursor}`")]
+    InvalidCursor {
+        /// Invalid cursor.
+        cursor: String,
+    },
+
+    /// Provided `first` or `last` argument is negative.
+    #[display("`{argument}` argument must be non-negative")]
+    NegativeLimit {
+        /// Name of the negative argument.
+        argument: &'static str,
+    },
+}
+
+impl<S> IntoFieldError<S> for ConnectionError {
+    fn into_field_error(self) -> FieldError<S> {
+        FieldError::from(self)
+    }
+}
+
+/// Paginates the provided in-memory list of `items` according to the provided [`ConnectionArgs`].
+///
+/// Cursors of the returned edges are [offset-based][`offset_to_cursor()`].
+///
+/// # Errors
+///
+/// If the [`ConnectionArgs`] contain an invalid cursor or a negative limit.
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{
+/// #     graphql_object, graphql_value, graphql_vars, EmptyMutation, EmptySubscription,
+/// #     GraphQLObject, RootNode,
+/// #     relay::{self, Connection, ConnectionArgs, ConnectionError},
+/// # };
+/// #
+/// #[derive(Clone, GraphQLObject)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn users(
+///         first: Option<i32>,
+///         after: Option<String>,
+///         last: Option<i32>,
+///         before: Option<String>,
+///     ) -> Result<Connection<User>, ConnectionError> {
+///         let users = ["Alice", "Bob", "Carol"].map(|name| User { name: name.into() });
+///         relay::query_connection(&users, &ConnectionArgs::new(first, after, last, before))
+///     }
+/// }
+///
+/// type Schema = RootNode<Query, EmptyMutation, EmptySubscription>;
+///
+/// let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new());
+///
+/// # // language=GraphQL
+/// let query = "{ users(first: 2) { edges { node { name } } pageInfo { hasNextPage } } }";
+///
+/// assert_eq!(
+///     juniper::execute_sync(query, None, &schema, &graphql_vars! {}, &()),
+///     Ok((
+///         graphql_value!({"users": {
+///             "edges": [{"node": {"name": "Alice"}}, {"node": {"name": "Bob"}}],
+///             "pageInfo": {"hasNextPage": true},
+///         }}),
+///         vec![],
+///     )),
+/// );
+/// ```
+pub fn query_connection<T: Clone>(
+    items: &[T],
+    args: &ConnectionArgs,
+) -> Result<Connection<T>, ConnectionError> {
+    let page = args.paginate(items.len())?;
+    let nodes = items[page.range.clone()].iter().cloned();
+    Ok(page.into_connection(nodes))
+}
+
+/// Paginates an asynchronous data source containing `total_count` items according to the
+/// provided [`ConnectionArgs`].
+///
+/// The `load` function is called with the range of offsets of the requested page, and should
+/// return the items in this range (or fewer, if the data source has shrunk meanwhile).
+///
+/// Cursors of the returned edges are [offset-based][`offset_to_cursor()`].
+///
+/// # Errors
+///
+/// If the [`ConnectionArgs`] contain an invalid cursor or a negative limit, or the `load` function
+/// fails.
+pub async fn query_connection_async<N, E, F, Fut>(
+    args: &ConnectionArgs,
+    total_count: usize,
+    load: F,
+) -> Result<Connection<N>, E>
+where
+    E: From<ConnectionError>,
+    F: FnOnce(Range<usize>) -> Fut,
+    Fut: Future<Output = Result<Vec<N>, E>>,
+{
+    let page = args.paginate(total_count)?;
+    let nodes = if page.range.is_empty() {
+        vec![]
+    } else {
+        load(page.range.clone()).await?
+    };
+    Ok(page.into_connection(nodes))
+}
+
+/// Creates a [`FieldError`] about the unknown `field` of the `T` GraphQL object.
+fn err_unknown_field<T, S>(info: &T::TypeInfo, field: &str) -> FieldError<S>
+where
+    T: GraphQLType<S> + ?Sized,
+    S: ScalarValue,
+{
+    let ty = T::name(info).unwrap_or_default();
+    FieldError::from(format!("Field `{field}` not found on type `{ty}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        EmptyMutation, EmptySubscription, GraphQLObject, RootNode, graphql_object,
+        relay::cursor::offset_to_cursor, schema::SdlOptions,
+    };
+
+    use super::{
+        Connection, ConnectionArgs, ConnectionError, query_connection, query_connection_async,
+    };
+
+    fn page(args: ConnectionArgs) -> (Vec<i32>, bool, bool) {
+        let conn = query_connection(&[0, 1, 2, 3, 4], &args).unwrap();
+        (
+            conn.edges.iter().map(|e| e.node).collect(),
+            conn.page_info.has_previous_page,
+            conn.page_info.has_next_page,
+        )
+    }
+
+    fn cursor(offset: usize) -> Option<String> {
+        Some(offset_to_cursor(offset))
+    }
+
+    #[test]
+    fn paginates_forwards_and_backwards() {
+        for (args, expected) in [
+            (
+                ConnectionArgs::default(),
+                (vec![0, 1, 2, 3, 4], false, false),
+            ),
+            (
+                ConnectionArgs::new(Some(2), None, None, None),
+                (vec![0, 1], false, true),
+            ),
+            (
+                ConnectionArgs::new(Some(2), cursor(1), None, None),
+                (vec![2, 3], false, true),
+            ),
+            (
+                ConnectionArgs::new(Some(5), cursor(2), None, None),
+                (vec![3, 4], false, false),
+            ),
+            (
+                ConnectionArgs::new(None, None, Some(2), None),
+                (vec![3, 4], true, false),
+            ),
+            (
+                ConnectionArgs::new(None, None, Some(2), cursor(3)),
+                (vec![1, 2], true, false),
+            ),
+            (
+                ConnectionArgs::new(None, cursor(0), None, cursor(3)),
+                (vec![1, 2], false, false),
+            ),
+            (
+                ConnectionArgs::new(Some(0), None, None, None),
+                (vec![], false, true),
+            ),
+            (
+                ConnectionArgs::new(Some(2), cursor(10), None, None),
+                (vec![], false, false),
+            ),
+        ] {
+            assert_eq!(page(args.clone()), expected, "{args:?}");
+        }
+    }
+
+    #[test]
+    fn assigns_offset_cursors() {
+        let conn = query_connection(
+            &["a", "b", "c"],
+            &ConnectionArgs::new(None, None, Some(2), None),
+        )
+        .unwrap();
+
+        assert_eq!(conn.edges[0].cursor, offset_to_cursor(1));
+        assert_eq!(conn.page_info.start_cursor, cursor(1));
+        assert_eq!(conn.page_info.end_cursor, cursor(2));
+    }
+
+    #[test]
+    fn rejects_invalid_args() {
+        assert_eq!(
+            query_connection(&[1], &ConnectionArgs::new(Some(-1), None, None, None)).unwrap_err(),
+            ConnectionError::NegativeLimit { argument: "first" },
+        );
+        assert_eq!(
+            query_connection(
+                &[1],
+                &ConnectionArgs::new(None, Some("x".into()), None, None)
+            )
+            .unwrap_err(),
+            ConnectionError::InvalidCursor { cursor: "x".into() },
+        );
+    }
+
+    #[tokio::test]
+    async fn loads_only_requested_range() {
+        let args = ConnectionArgs::new(Some(2), cursor(0), None, None);
+        let conn = query_connection_async(&args, 100, async |range| {
+            assert_eq!(range, 1..3);
+            Ok::<_, ConnectionError>(range.map(|i| i * 10).collect())
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            conn.edges.iter().map(|e| e.node).collect::<Vec<_>>(),
+            [10, 20]
+        );
+        assert!(conn.page_info.has_next_page);
+    }
+
+    #[derive(Clone, GraphQLObject)]
+    struct User {
+        name: String,
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn users(
+            first: Option<i32>,
+            after: Option<String>,
+        ) -> Result<Connection<User>, ConnectionError> {
+            let users = ["Alice", "Bob", "Carol"].map(|name| User { name: name.into() });
+            query_connection(&users, &ConnectionArgs::new(first, after, None, None))
+        }
+
+        fn teams() -> Result<Connection<Connection<User>>, ConnectionError> {
+            let team = query_connection(
+                &[User {
+                    name: "Alice".into(),
+                }],
+                &Default::default(),
+            )?;
+            query_connection(&[team], &Default::default())
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_connection_types() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let query = r#"{
+            users(first: 1, after: "YXJyYXljb25uZWN0aW9uOjA=") {
+                __typename
+                edges { __typename cursor node { name } }
+                pageInfo { hasNextPage endCursor }
+            }
+        }"#;
+        let expected = graphql_value!({"users": {
+            "__typename": "UserConnection",
+            "edges": [{
+                "__typename": "UserEdge",
+                "cursor": "YXJyYXljb25uZWN0aW9uOjE=",
+                "node": {"name": "Bob"},
+            }],
+            "pageInfo": {"hasNextPage": true, "endCursor": "YXJyYXljb25uZWN0aW9uOjE="},
+        }});
+
+        assert_eq!(
+            crate::execute_sync(query, None, &schema, &graphql_vars! {}, &()),
+            Ok((expected.clone(), vec![])),
+        );
+        assert_eq!(
+            crate::execute(query, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((expected, vec![])),
+        );
+
+        let sdl = schema.to_sdl(&SdlOptions::default());
+        assert!(sdl.contains("type UserConnection {"), "{sdl}");
+        assert!(sdl.contains("edges: [UserEdge!]!"), "{sdl}");
+        assert!(sdl.contains("pageInfo: PageInfo!"), "{sdl}");
+        assert!(sdl.contains("node: User!"), "{sdl}");
+    }
+
+    #[test]
+    fn names_nested_connection_types() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let query = r#"{
+            teams {
+                __typename
+                edges { __typename node { __typename edges { node { name } } } }
+            }
+        }"#;
+
+        assert_eq!(
+            crate::execute_sync(query, None, &schema, &graphql_vars! {}, &()),
+            Ok((
+                graphql_value!({"teams": {
+                    "__typename": "UserConnectionConnection",
+                    "edges": [{
+                        "__typename": "UserConnectionEdge",
+                        "node": {
+                            "__typename": "UserConnection",
+                            "edges": [{"node": {"name": "Alice"}}],
+                        },
+                    }],
+                }}),
+                vec![],
+            )),
+        );
+
+        let sdl = schema.to_sdl(&SdlOptions::default());
+        assert!(sdl.contains("type UserConnectionConnection {"), "{sdl}");
+        assert!(sdl.contains("edges: [UserConnectionEdge!]!"), "{sdl}");
+        assert!(sdl.contains("node: UserConnection!"), "{sdl}");
+    }
+}
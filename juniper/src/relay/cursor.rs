@@ -0,0 +1,135 @@
+//! Opaque [cursors][0] of [`Connection`] edges.
+//!
+//! [`Connection`]: super::Connection
+//! [0]: https://relay.dev/graphql/connections.htm#sec-Cursor
+
+/// Prefix of the offset-based cursors, compatible with the ones of [GraphQL Relay.js].
+///
+/// [GraphQL Relay.js]: https://github.com/graphql/graphql-relay-js
+const OFFSET_PREFIX: &str = "arrayconnection:";
+
+/// Encodes the provided `offset` of an item in a list into an opaque cursor.
+///
+/// The cursor is compatible with the ones of [GraphQL Relay.js].
+///
+/// [GraphQL Relay.js]: https://github.com/graphql/graphql-relay-js
+#[must_use]
+pub fn offset_to_cursor(offset: usize) -> String {
+    base64::encode(format!("{OFFSET_PREFIX}{offset}").as_bytes())
+}
+
+/// Decodes an offset of an item in a list from the provided opaque `cursor`.
+///
+/// Returns [`None`] if the `cursor` wasn't produced by the [`offset_to_cursor()`].
+#[must_use]
+pub fn cursor_to_offset(cursor: &str) -> Option<usize> {
+    let decoded = base64::decode(cursor)?;
+    let offset = std::str::from_utf8(&decoded)
+        .ok()?
+        .strip_prefix(OFFSET_PREFIX)?;
+    if offset.starts_with('+') {
+        return None;
+    }
+    offset.parse().ok()
+}
+
+/// Minimal [Base64] encoding with the standard alphabet and padding, used for opaque identifiers.
+///
+/// [Base64]: https://datatracker.ietf.org/doc/html/rfc4648#section-4
+pub(crate) mod base64 {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    /// Encodes the provided `input` bytes into a [Base64] string.
+    ///
+    /// [Base64]: https://datatracker.ietf.org/doc/html/rfc4648#section-4
+    pub(crate) fn encode(input: &[u8]) -> String {
+        let mut out = String::with_capacity(input.len().div_ceil(3) * 4);
+        for chunk in input.chunks(3) {
+            let b = [
+                chunk[0],
+                chunk.get(1).copied().unwrap_or_default(),
+                chunk.get(2).copied().unwrap_or_default(),
+            ];
+            let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    out.push(char::from(ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3F]));
+                } else {
+                    out.push('=');
+                }
+            }
+        }
+        out
+    }
+
+    /// Decodes bytes out of the provided padded [Base64] `input` string.
+    ///
+    /// Returns [`None`] if the `input` is not a valid padded [Base64] string, including the case
+    /// when the bits discarded because of padding are not zero (so every sequence of bytes has a
+    /// single valid encoding).
+    ///
+    /// [Base64]: https://datatracker.ietf.org/doc/html/rfc4648#section-4
+    pub(crate) fn decode(input: &str) -> Option<Vec<u8>> {
+        let input = input.as_bytes();
+        if input.len() % 4 != 0 {
+            return None;
+        }
+
+        let mut out = Vec::with_capacity(input.len() / 4 * 3);
+        for (i, chunk) in input.chunks(4).enumerate() {
+            let is_last = (i + 1) * 4 == input.len();
+            let padding = chunk.iter().rev().take_while(|c| **c == b'=').count();
+            if padding > 2 || (padding > 0 && !is_last) {
+                return None;
+            }
+
+            let mut n = 0u32;
+            for c in &chunk[..4 - padding] {
+                let v = ALPHABET.iter().position(|a| a == c)?;
+                n = (n << 6) | v as u32;
+            }
+            if n & ((1 << (2 * padding)) - 1) != 0 {
+                return None;
+            }
+            n <<= 6 * padding;
+
+            let bytes = n.to_be_bytes();
+            out.extend_from_slice(&bytes[1..4 - padding]);
+        }
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{base64, cursor_to_offset, offset_to_cursor};
+
+    #[test]
+    fn base64_roundtrips() {
+        for (raw, encoded) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("User:1", "VXNlcjox"),
+        ] {
+            assert_eq!(base64::encode(raw.as_bytes()), encoded, "{raw}");
+            assert_eq!(base64::decode(encoded).as_deref(), Some(raw.as_bytes()));
+        }
+        for invalid in ["Zg", "Zg=a", "Z===", "Zg==Zg==", "Zm9*", "Zh==", "Zm9="] {
+            assert_eq!(base64::decode(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn offset_cursors_roundtrip() {
+        assert_eq!(offset_to_cursor(0), "YXJyYXljb25uZWN0aW9uOjA=");
+        for offset in [0, 1, 42, usize::MAX] {
+            assert_eq!(cursor_to_offset(&offset_to_cursor(offset)), Some(offset));
+        }
+        for invalid in ["", "abc", "YXJyYXljb25uZWN0aW9uOg==", "VXNlcjox"] {
+            assert_eq!(cursor_to_offset(invalid), None, "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! [GraphQL Relay] server specifications support.
+//!
+//! Provides [`Connection`]s implementing the [Relay Cursor Connections Specification][0] along
+//! with helpers paginating a slice or an asynchronous data source according to the
+//! `first`/`after`/`last`/`before` arguments.
+//!
+//! Provides the [`Node`] interface implementing the [Relay Global Object Identification
+//! Specification][1], with objects refetchable by their opaque [`GlobalId`]s.
+//!
+//! [GraphQL Relay]: https://relay.dev/docs/guides/graphql-server-specification
+//! [0]: https://relay.dev/graphql/connections.htm
+//! [1]: https://relay.dev/graphql/objectidentification.htm
+
+mod connection;
+mod cursor;
+mod node;
+
+pub use self::{
+    connection::{
+        Connection, ConnectionArgs, ConnectionError, Edge, PageInfo, query_connection,
+        query_connection_async,
+    },
+    cursor::{cursor_to_offset, offset_to_cursor},
+    node::{
+        GlobalId, Node, NodeFetcher, NodeValue, fetch_node, node_fields_meta,
+        resolve_node_field_async,
+    },
+};
@@ -0,0 +1,517 @@
+//! [Global object identification][0] via the `Node` interface.
+//!
+//! [0]: https://relay.dev/graphql/objectidentification.htm
+
+use std::{fmt, future::Future};
+
+use arcstr::ArcStr;
+use futures::future;
+
+use crate::{
+    BoxFuture, DefaultScalarValue, ID,
+    executor::{ExecutionResult, Executor, FieldResult, Registry},
+    macros::{
+        helper::InterfaceGuards,
+        reflect::{
+            self, BaseSubTypes, BaseType, FieldMeta, Fields, Type, Types, WrappedType, WrappedValue,
+        },
+    },
+    schema::meta::{Field, MetaType},
+    types::{
+        async_await::GraphQLValueAsync,
+        base::{Arguments, GraphQLType, GraphQLValue},
+        marker::{GraphQLInterface, IsOutputType},
+    },
+    value::ScalarValue,
+};
+
+use super::cursor::base64;
+
+/// Opaque globally unique identifier of an object, composed of its GraphQL type name and its ID
+/// local to this type.
+///
+/// Encoded into an [`ID`] compatible with the ones of [GraphQL Relay.js].
+///
+/// [GraphQL Relay.js]: https://github.com/graphql/graphql-relay-js
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GlobalId {
+    type_name: String,
+    local_id: String,
+}
+
+impl GlobalId {
+    /// Creates a new [`GlobalId`] of the object with the provided `local_id` among the objects of
+    /// the GraphQL type with the provided `type_name`.
+    #[must_use]
+    pub fn new(type_name: impl Into<String>, local_id: impl Into<String>) -> Self {
+        Self {
+            type_name: type_name.into(),
+            local_id: local_id.into(),
+        }
+    }
+
+    /// Returns the name of the GraphQL type this [`GlobalId`] identifies an object of.
+    #[must_use]
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Returns the ID of the identified object, local to its GraphQL type.
+    #[must_use]
+    pub fn local_id(&self) -> &str {
+        &self.local_id
+    }
+
+    /// Encodes this [`GlobalId`] into an opaque [`ID`].
+    #[must_use]
+    pub fn to_id(&self) -> ID {
+        ID::new(base64::encode(self.to_string().as_bytes()))
+    }
+
+    /// Decodes a [`GlobalId`] from the provided opaque [`ID`].
+    ///
+    /// Returns [`None`] if the `id` wasn't produced by the [`GlobalId::to_id()`].
+    #[must_use]
+    pub fn from_id(id: &str) -> Option<Self> {
+        let decoded = String::from_utf8(base64::decode(id)?).ok()?;
+        let (type_name, local_id) = decoded.split_once(':')?;
+        (!type_name.is_empty()).then(|| Self::new(type_name, local_id))
+    }
+}
+
+impl fmt::Display for GlobalId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.type_name, self.local_id)
+    }
+}
+
+impl From<GlobalId> for ID {
+    fn from(id: GlobalId) -> Self {
+        id.to_id()
+    }
+}
+
+/// GraphQL object implementing the [`Node` interface][0], which is refetchable by its
+/// [`GlobalId`].
+///
+/// The GraphQL object should be marked with `#[graphql(node)]` attribute, exposing its
+/// [`GlobalId`] as the `id: ID!` field, and be listed in the `#[graphql(nodes = [...])]`
+/// attribute of a query root to be fetched by its `node` and `nodes` fields.
+///
+/// ```rust
+/// # use juniper::{
+/// #     graphql_object, graphql_value, graphql_vars, EmptyMutation, EmptySubscription,
+/// #     FieldResult, RootNode, ID,
+/// # };
+/// use juniper::relay::{GlobalId, Node};
+///
+/// struct User {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// #[graphql_object]
+/// #[graphql(node)]
+/// impl User {
+///     fn id(&self) -> ID {
+///         GlobalId::new("User", self.id.to_string()).into()
+///     }
+///
+///     fn name(&self) -> &str {
+///         &self.name
+///     }
+/// }
+///
+/// impl Node for User {
+///     async fn fetch_node(local_id: &str, _: &()) -> FieldResult<Option<Self>> {
+///         Ok((local_id == "1").then(|| User { id: 1, name: "John".into() }))
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// #[graphql(nodes = [User])]
+/// impl Query {
+///     fn users() -> Vec<User> {
+///         vec![]
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let id = GlobalId::new("User", "1").to_id();
+/// let query = format!(r#"{{ node(id: "{id}") {{ id ... on User {{ name }} }} }}"#);
+///
+/// assert_eq!(
+///     juniper::execute(&query, None, &schema, &graphql_vars! {}, &()).await,
+///     Ok((graphql_value!({"node": {"id": "VXNlcjox", "name": "John"}}), vec![])),
+/// );
+/// # }
+/// ```
+///
+/// [0]: https://relay.dev/graphql/objectidentification.htm#sec-Node-Interface
+pub trait Node<S = DefaultScalarValue>:
+    GraphQLValueAsync<S, TypeInfo = ()> + GraphQLType<S> + Send + Sized + 'static
+where
+    Self::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    /// Fetches the object of this GraphQL type by its ID local to this type (see
+    /// [`GlobalId::local_id()`]).
+    ///
+    /// Returns [`None`] if there is no such object.
+    fn fetch_node(
+        local_id: &str,
+        context: &Self::Context,
+    ) -> impl Future<Output = FieldResult<Option<Self>, S>> + Send;
+}
+
+/// Value of the [`Node` interface][0], resolving into its concrete GraphQL object.
+///
+/// [0]: https://relay.dev/graphql/objectidentification.htm#sec-Node-Interface
+pub struct NodeValue<C, S = DefaultScalarValue>(
+    Box<dyn GraphQLValueAsync<S, Context = C, TypeInfo = ()> + Send>,
+);
+
+impl<C, S> NodeValue<C, S>
+where
+    C: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    /// Wraps the provided [`Node`] into a [`NodeValue`].
+    #[must_use]
+    pub fn new<T: Node<S, Context = C>>(node: T) -> Self {
+        Self(Box::new(node))
+    }
+}
+
+impl<C, S> fmt::Debug for NodeValue<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NodeValue").finish_non_exhaustive()
+    }
+}
+
+impl<C, S: ScalarValue> GraphQLType<S> for NodeValue<C, S> {
+    fn name(_: &Self::TypeInfo) -> Option<ArcStr> {
+        Some(arcstr::literal!("Node"))
+    }
+
+    fn meta(info: &Self::TypeInfo, registry: &mut Registry<S>) -> MetaType<S> {
+        let fields = [registry
+            .field::<ID>(arcstr::literal!("id"), &())
+            .description(arcstr::literal!("The ID of the object."))];
+        registry
+            .build_interface_type::<Self>(info, &fields)
+            .description(arcstr::literal!("An object with a globally unique ID."))
+            .into_meta()
+    }
+}
+
+impl<C, S: ScalarValue> GraphQLValue<S> for NodeValue<C, S> {
+    type Context = C;
+    type TypeInfo = ();
+
+    fn type_name(&self, info: &Self::TypeInfo) -> Option<ArcStr> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn concrete_type_name(&self, context: &Self::Context, _: &Self::TypeInfo) -> String {
+        (*self.0).concrete_type_name(context, &())
+    }
+
+    fn resolve_field(
+        &self,
+        _: &Self::TypeInfo,
+        field: &str,
+        args: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        (*self.0).resolve_field(&(), field, args, executor)
+    }
+
+    fn resolve_into_type(
+        &self,
+        _: &Self::TypeInfo,
+        _: &str,
+        _: Option<&[crate::Selection<'_, S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        executor.resolve(&(), &*self.0)
+    }
+}
+
+impl<C, S> GraphQLValueAsync<S> for NodeValue<C, S>
+where
+    C: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_field_async<'b>(
+        &'b self,
+        _: &'b Self::TypeInfo,
+        field_name: &'b str,
+        arguments: &'b Arguments<S>,
+        executor: &'b Executor<Self::Context, S>,
+    ) -> BoxFuture<'b, ExecutionResult<S>> {
+        (*self.0).resolve_field_async(&(), field_name, arguments, executor)
+    }
+
+    fn resolve_into_type_async<'b>(
+        &'b self,
+        _: &'b Self::TypeInfo,
+        _: &str,
+        _: Option<&'b [crate::Selection<'b, S>]>,
+        executor: &'b Executor<'b, 'b, Self::Context, S>,
+    ) -> BoxFuture<'b, ExecutionResult<S>> {
+        Box::pin(executor.resolve_async(&(), &*self.0))
+    }
+}
+
+impl<C, S: ScalarValue> GraphQLInterface<S> for NodeValue<C, S> {}
+
+impl<C, S: ScalarValue> InterfaceGuards<S> for NodeValue<C, S> {
+    fn check_guards(_: &str, _: &Self::Context) -> FieldResult<(), S> {
+        Ok(())
+    }
+}
+
+impl<C, S: ScalarValue> IsOutputType<S> for NodeValue<C, S> {}
+
+impl<C, S> BaseType<S> for NodeValue<C, S> {
+    const NAME: Type = "Node";
+}
+
+impl<C, S> BaseSubTypes<S> for NodeValue<C, S> {
+    const NAMES: Types = &[<Self as BaseType<S>>::NAME];
+}
+
+impl<C, S> WrappedType<S> for NodeValue<C, S> {
+    const VALUE: WrappedValue = 1;
+}
+
+impl<C, S> Fields<S> for NodeValue<C, S> {
+    const NAMES: Types = &["id"];
+}
+
+impl<C, S: ScalarValue> FieldMeta<S, { reflect::fnv1a128("id") }> for NodeValue<C, S> {
+    type Context = C;
+    type TypeInfo = ();
+    const TYPE: Type = <ID as BaseType<S>>::NAME;
+    const SUB_TYPES: Types = <ID as BaseSubTypes<S>>::NAMES;
+    const WRAPPED_VALUE: WrappedValue = <ID as WrappedType<S>>::VALUE;
+    const ARGUMENTS: reflect::Arguments = &[];
+}
+
+/// Fetcher of the [`Node`]s of a single GraphQL object type.
+pub struct NodeFetcher<C, S = DefaultScalarValue> {
+    name: fn(&()) -> Option<ArcStr>,
+    register: fn(&mut Registry<S>),
+    fetch: FetchFn<C, S>,
+}
+
+/// Type-erased [`Node::fetch_node()`] of a single GraphQL object type.
+type FetchFn<C, S> =
+    for<'a> fn(&'a str, &'a C) -> BoxFuture<'a, FieldResult<Option<NodeValue<C, S>>, S>>;
+
+impl<C, S> NodeFetcher<C, S>
+where
+    C: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    /// Creates a new [`NodeFetcher`] of the `T` [`Node`]s.
+    #[must_use]
+    pub fn of<T: Node<S, Context = C>>() -> Self {
+        fn register<T: GraphQLType<S, TypeInfo = ()>, S: ScalarValue>(registry: &mut Registry<S>) {
+            _ = registry.get_type::<T>(&());
+        }
+
+        fn fetch<'a, T, C, S>(
+            local_id: &'a str,
+            context: &'a C,
+        ) -> BoxFuture<'a, FieldResult<Option<NodeValue<C, S>>, S>>
+        where
+            T: Node<S, Context = C>,
+            C: Sync,
+            S: ScalarValue + Send + Sync,
+        {
+            Box::pin(async move { Ok(T::fetch_node(local_id, context).await?.map(NodeValue::new)) })
+        }
+
+        Self {
+            name: T::name,
+            register: register::<T, S>,
+            fetch: fetch::<T, C, S>,
+        }
+    }
+}
+
+impl<C, S> Clone for NodeFetcher<C, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C, S> Copy for NodeFetcher<C, S> {}
+
+impl<C, S> fmt::Debug for NodeFetcher<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeFetcher")
+            .field("type_name", &(self.name)(&()))
+            .finish_non_exhaustive()
+    }
+}
+
+/// Fetches the [`Node`] identified by the provided [`GlobalId`] encoded into the `id`, using the
+/// [`NodeFetcher`] of its GraphQL type.
+///
+/// Returns [`None`] if the `id` is not a valid [`GlobalId`], or there is no [`NodeFetcher`] of its
+/// GraphQL type, or there is no such object.
+pub async fn fetch_node<C, S>(
+    id: &str,
+    context: &C,
+    fetchers: &[NodeFetcher<C, S>],
+) -> FieldResult<Option<NodeValue<C, S>>, S>
+where
+    C: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let Some(id) = GlobalId::from_id(id) else {
+        return Ok(None);
+    };
+    let fetcher = fetchers
+        .iter()
+        .find(|f| (f.name)(&()).is_some_and(|n| n == id.type_name()));
+    match fetcher {
+        Some(f) => (f.fetch)(id.local_id(), context).await,
+        None => Ok(None),
+    }
+}
+
+/// Builds the `node(id: ID!): Node` and `nodes(ids: [ID!]!): [Node]!` fields of a query root,
+/// registering all the GraphQL types of the provided `fetchers`.
+#[doc(hidden)]
+pub fn node_fields_meta<C, S>(
+    registry: &mut Registry<S>,
+    fetchers: &[NodeFetcher<C, S>],
+) -> [Field<S>; 2]
+where
+    C: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    for f in fetchers {
+        (f.register)(registry);
+    }
+    [
+        registry
+            .field::<Option<NodeValue<C, S>>>(arcstr::literal!("node"), &())
+            .argument(
+                registry
+                    .arg::<ID>(arcstr::literal!("id"), &())
+                    .description(arcstr::literal!("The ID of an object.")),
+            )
+            .description(arcstr::literal!("Fetches an object given its ID.")),
+        registry
+            .field::<Vec<Option<NodeValue<C, S>>>>(arcstr::literal!("nodes"), &())
+            .argument(
+                registry
+                    .arg::<Vec<ID>>(arcstr::literal!("ids"), &())
+                    .description(arcstr::literal!("The IDs of objects.")),
+            )
+            .description(arcstr::literal!("Fetches objects given their IDs.")),
+    ]
+}
+
+/// Resolves the `node` or `nodes` field of a query root, built by the [`node_fields_meta()`].
+#[doc(hidden)]
+pub fn resolve_node_field_async<'b, C, S>(
+    field: &'b str,
+    args: &'b Arguments<'_, S>,
+    executor: &'b Executor<'_, '_, C, S>,
+    fetchers: Vec<NodeFetcher<C, S>>,
+) -> BoxFuture<'b, ExecutionResult<S>>
+where
+    C: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    Box::pin(async move {
+        let ctx = executor.context();
+        if field == "nodes" {
+            let ids: Vec<ID> = args.get("ids")?.unwrap_or_default();
+            // Every node is fetched on its own, so a failed fetch nulls only its own entry,
+            // rather than the whole list of nodes.
+            let nodes = future::join_all(ids.iter().map(|id| fetch_node(id, ctx, &fetchers)))
+                .await
+                .into_iter()
+                .map(|node| {
+                    node.unwrap_or_else(|e| {
+                        executor.push_error(e);
+                        None
+                    })
+                })
+                .collect::<Vec<_>>();
+            executor.resolve_async(&(), &nodes).await
+        } else {
+            let id: ID = args.get("id")?.unwrap_or_else(|| ID::new(""));
+            let node = fetch_node(&id, ctx, &fetchers).await?;
+            executor.resolve_async(&(), &node).await
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FieldResult, ID, graphql_object};
+
+    use super::{GlobalId, Node, NodeFetcher, fetch_node};
+
+    #[test]
+    fn global_ids_roundtrip() {
+        let id = GlobalId::new("User", "1");
+        assert_eq!(id.to_id(), ID::new("VXNlcjox"));
+        assert_eq!(GlobalId::from_id("VXNlcjox"), Some(id));
+
+        let id = GlobalId::new("Post", "2024:hello");
+        assert_eq!(GlobalId::from_id(&id.to_id()), Some(id));
+
+        for invalid in ["", "VXNlcjox=", "VXNlcg==", "OjE="] {
+            assert_eq!(GlobalId::from_id(invalid), None, "{invalid}");
+        }
+    }
+
+    struct User(String);
+
+    #[graphql_object]
+    impl User {
+        fn id(&self) -> ID {
+            GlobalId::new("User", &self.0).into()
+        }
+    }
+
+    impl Node for User {
+        async fn fetch_node(local_id: &str, _: &()) -> FieldResult<Option<Self>> {
+            Ok((local_id != "unknown").then(|| Self(local_id.into())))
+        }
+    }
+
+    #[tokio::test]
+    async fn fetches_by_type_name() {
+        let fetchers = [NodeFetcher::of::<User>()];
+        let fetch = async |id: GlobalId| {
+            fetch_node(&id.to_id(), &(), &fetchers)
+                .await
+                .unwrap()
+                .map(|n| format!("{n:?}"))
+        };
+
+        assert!(fetch(GlobalId::new("User", "1")).await.is_some());
+        assert!(fetch(GlobalId::new("User", "unknown")).await.is_none());
+        assert!(fetch(GlobalId::new("Post", "1")).await.is_none());
+        assert!(
+            fetch_node("invalid", &(), &fetchers)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,335 @@
+//! [Schema coordinates][0] of a [`SchemaType`].
+//!
+//! [0]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+
+use std::ptr;
+
+use crate::{
+    schema::{
+        meta::{
+            Argument, EnumMeta, EnumValue, Field, InputObjectMeta, InterfaceMeta, MetaType,
+            ObjectMeta,
+        },
+        model::{DirectiveType, SchemaType},
+    },
+    types::name::Name,
+};
+
+/// Element of a schema, which can be referred to by a [schema coordinate][0].
+///
+/// [0]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+#[derive(Debug)]
+pub enum SchemaElement<'a, S> {
+    /// Named type, referred to as `Type`.
+    Type(&'a MetaType<S>),
+
+    /// Field of an object or an interface, referred to as `Type.field`.
+    Field(&'a Field<S>),
+
+    /// Field of an input object, referred to as `Input.field`.
+    InputField(&'a Argument<S>),
+
+    /// Value of an enum, referred to as `Enum.VALUE`.
+    EnumValue(&'a EnumValue),
+
+    /// Argument of a field, referred to as `Type.field(arg:)`.
+    Argument(&'a Argument<S>),
+
+    /// Directive, referred to as `@directive`.
+    Directive(&'a DirectiveType<S>),
+
+    /// Argument of a directive, referred to as `@directive(arg:)`.
+    DirectiveArgument(&'a Argument<S>),
+}
+
+impl<S> Clone for SchemaElement<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for SchemaElement<'_, S> {}
+
+impl<S> SchemaType<S> {
+    /// Resolves the provided [schema coordinate][0] (like `User.friends(first:)`) to the
+    /// [`SchemaElement`] it refers to.
+    ///
+    /// Returns [`None`] if the `coordinate` is not syntactically valid, or this schema contains no
+    /// element with such coordinate.
+    ///
+    /// [0]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+    pub fn resolve_coordinate(&self, coordinate: &str) -> Option<SchemaElement<'_, S>> {
+        if let Some(directive) = coordinate.strip_prefix('@') {
+            return match split_argument(directive)? {
+                (name, None) => self.directives.get(name).map(SchemaElement::Directive),
+                (name, Some(arg)) => self
+                    .directives
+                    .get(name)?
+                    .arguments
+                    .iter()
+                    .find(|a| a.name == arg)
+                    .map(SchemaElement::DirectiveArgument),
+            };
+        }
+
+        let Some((type_name, member)) = coordinate.split_once('.') else {
+            return Name::is_valid(coordinate)
+                .then(|| self.types.get(coordinate))
+                .flatten()
+                .map(SchemaElement::Type);
+        };
+        if !Name::is_valid(type_name) {
+            return None;
+        }
+        let ty = self.types.get(type_name)?;
+        match split_argument(member)? {
+            (name, None) => match ty {
+                MetaType::Enum(EnumMeta { values, .. }) => values
+                    .iter()
+                    .find(|v| v.name == name)
+                    .map(SchemaElement::EnumValue),
+                MetaType::InputObject(..) => {
+                    ty.input_field_by_name(name).map(SchemaElement::InputField)
+                }
+                _ => ty.field_by_name(name).map(SchemaElement::Field),
+            },
+            (name, Some(arg)) => ty
+                .field_by_name(name)?
+                .arguments
+                .as_ref()?
+                .iter()
+                .find(|a| a.name == arg)
+                .map(SchemaElement::Argument),
+        }
+    }
+
+    /// Returns the [schema coordinate][0] of the provided [`SchemaElement`].
+    ///
+    /// This is the inverse of the [`SchemaType::resolve_coordinate()`]. Returns [`None`] if the
+    /// `element` doesn't belong to this schema.
+    ///
+    /// [0]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+    pub fn coordinate_of(&self, element: SchemaElement<'_, S>) -> Option<String> {
+        match element {
+            SchemaElement::Type(ty) => self
+                .types
+                .values()
+                .find(|t| ptr::eq(*t, ty))
+                .and_then(MetaType::name)
+                .map(ToString::to_string),
+            SchemaElement::Field(field) => self.types.values().find_map(|ty| {
+                let (MetaType::Interface(InterfaceMeta { name, fields, .. })
+                | MetaType::Object(ObjectMeta { name, fields, .. })) = ty
+                else {
+                    return None;
+                };
+                fields
+                    .iter()
+                    .any(|f| ptr::eq(f, field))
+                    .then(|| format!("{name}.{}", field.name))
+            }),
+            SchemaElement::InputField(input_field) => self.types.values().find_map(|ty| {
+                let MetaType::InputObject(InputObjectMeta {
+                    name, input_fields, ..
+                }) = ty
+                else {
+                    return None;
+                };
+                input_fields
+                    .iter()
+                    .any(|f| ptr::eq(f, input_field))
+                    .then(|| format!("{name}.{}", input_field.name))
+            }),
+            SchemaElement::EnumValue(value) => self.types.values().find_map(|ty| {
+                let MetaType::Enum(EnumMeta { name, values, .. }) = ty else {
+                    return None;
+                };
+                values
+                    .iter()
+                    .any(|v| ptr::eq(v, value))
+                    .then(|| format!("{name}.{}", value.name))
+            }),
+            SchemaElement::Argument(arg) => self.types.values().find_map(|ty| {
+                let (MetaType::Interface(InterfaceMeta { name, fields, .. })
+                | MetaType::Object(ObjectMeta { name, fields, .. })) = ty
+                else {
+                    return None;
+                };
+                fields.iter().find_map(|f| {
+                    f.arguments
+                        .as_ref()?
+                        .iter()
+                        .any(|a| ptr::eq(a, arg))
+                        .then(|| format!("{name}.{}({}:)", f.name, arg.name))
+                })
+            }),
+            SchemaElement::Directive(directive) => self
+                .directives
+                .values()
+                .any(|d| ptr::eq(d, directive))
+                .then(|| format!("@{}", directive.name)),
+            SchemaElement::DirectiveArgument(arg) => self.directives.values().find_map(|d| {
+                d.arguments
+                    .iter()
+                    .any(|a| ptr::eq(a, arg))
+                    .then(|| format!("@{}({}:)", d.name, arg.name))
+            }),
+        }
+    }
+}
+
+/// Splits the provided `name` or `name(arg:)` coordinate part into its names, checking them to be
+/// valid [`Name`]s.
+fn split_argument(input: &str) -> Option<(&str, Option<&str>)> {
+    let (name, arg) = match input.split_once('(') {
+        Some((name, rest)) => (name, Some(rest.strip_suffix(":)")?)),
+        None => (input, None),
+    };
+    (Name::is_valid(name) && arg.is_none_or(Name::is_valid)).then_some((name, arg))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        DefaultScalarValue, EmptyMutation, EmptySubscription, GraphQLEnum, GraphQLInputObject,
+        RootNode, graphql_object,
+        schema::{meta::MetaType, model::SchemaType},
+    };
+
+    use super::SchemaElement;
+
+    #[derive(GraphQLEnum)]
+    enum Role {
+        Admin,
+        Guest,
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct UserFilter {
+        role: Option<Role>,
+    }
+
+    struct User;
+
+    #[graphql_object]
+    impl User {
+        fn friends(first: Option<i32>, filter: Option<UserFilter>) -> Vec<User> {
+            _ = (first, filter);
+            vec![]
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn user() -> User {
+            User
+        }
+    }
+
+    fn schema() -> SchemaType<DefaultScalarValue> {
+        RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        )
+        .schema
+    }
+
+    #[test]
+    fn resolves_coordinates() {
+        let schema = schema();
+
+        assert!(matches!(
+            schema.resolve_coordinate("User"),
+            Some(SchemaElement::Type(MetaType::Object(o))) if o.name == "User",
+        ));
+        assert!(matches!(
+            schema.resolve_coordinate("User.friends"),
+            Some(SchemaElement::Field(f)) if f.name == "friends",
+        ));
+        assert!(matches!(
+            schema.resolve_coordinate("User.friends(first:)"),
+            Some(SchemaElement::Argument(a)) if a.name == "first",
+        ));
+        assert!(matches!(
+            schema.resolve_coordinate("UserFilter.role"),
+            Some(SchemaElement::InputField(f)) if f.name == "role",
+        ));
+        assert!(matches!(
+            schema.resolve_coordinate("Role.ADMIN"),
+            Some(SchemaElement::EnumValue(v)) if v.name == "ADMIN",
+        ));
+        assert!(matches!(
+            schema.resolve_coordinate("@deprecated"),
+            Some(SchemaElement::Directive(d)) if d.name == "deprecated",
+        ));
+        assert!(matches!(
+            schema.resolve_coordinate("@include(if:)"),
+            Some(SchemaElement::DirectiveArgument(a)) if a.name == "if",
+        ));
+        assert!(matches!(
+            schema.resolve_coordinate("__Type.fields(includeDeprecated:)"),
+            Some(SchemaElement::Argument(a)) if a.name == "includeDeprecated",
+        ));
+
+        for missing in [
+            "Unknown",
+            "User.unknown",
+            "User.friends(last:)",
+            "Role.OWNER",
+            "Role.ADMIN(x:)",
+            "UserFilter.role(x:)",
+            "@unknown",
+            "@include(unless:)",
+        ] {
+            assert!(schema.resolve_coordinate(missing).is_none(), "{missing}");
+        }
+        for invalid in [
+            "",
+            "User.",
+            ".friends",
+            "User.friends.first",
+            "User.friends(first)",
+            "User.friends(first:",
+            "User.friends( first: )",
+            "User .friends",
+            "@",
+            "@include()",
+            "@@include",
+            "1User",
+        ] {
+            assert!(schema.resolve_coordinate(invalid).is_none(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn coordinates_roundtrip() {
+        let schema = schema();
+
+        for coordinate in [
+            "Query",
+            "User.friends",
+            "User.friends(first:)",
+            "UserFilter.role",
+            "Role.GUEST",
+            "@skip",
+            "@skip(if:)",
+            "__Schema.types",
+        ] {
+            let element = schema.resolve_coordinate(coordinate).unwrap();
+
+            assert_eq!(
+                schema.coordinate_of(element).as_deref(),
+                Some(coordinate),
+                "{coordinate}",
+            );
+        }
+
+        let other = self::schema();
+        let element = other.resolve_coordinate("User.friends").unwrap();
+
+        assert_eq!(schema.coordinate_of(element), None);
+    }
+}
@@ -0,0 +1,1287 @@
+use std::{collections::HashSet, fmt};
+
+use arcstr::ArcStr;
+use derive_more::with_trait::Display;
+
+use crate::{
+    ast::{InputValue, Type, TypeModifier},
+    schema::{
+        meta::{
+            Argument, DeprecationStatus, EnumMeta, Field, InputObjectMeta, InterfaceMeta, MetaType,
+            ObjectMeta,
+        },
+        model::{DirectiveType, SchemaType},
+    },
+    value::ScalarValue,
+};
+
+/// Criticality of a [`SchemaChange`] for the existing clients of a schema.
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Criticality {
+    /// Change is safe for all the existing clients.
+    #[display("safe")]
+    Safe,
+
+    /// Change doesn't break the existing operations, but may change the behavior of the existing
+    /// clients (e.g. a new enum value not handled by them).
+    #[display("dangerous")]
+    Dangerous,
+
+    /// Change breaks the existing operations or the existing clients.
+    #[display("breaking")]
+    Breaking,
+}
+
+/// Kind of a [`SchemaChange`].
+#[expect(missing_docs, reason = "self-explanatory")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SchemaChangeKind {
+    RootOperationTypeAdded,
+    RootOperationTypeRemoved,
+    RootOperationTypeChanged,
+    TypeAdded,
+    TypeRemoved,
+    TypeKindChanged,
+    FieldAdded,
+    FieldRemoved,
+    FieldTypeChanged,
+    ArgumentAdded,
+    ArgumentRemoved,
+    ArgumentTypeChanged,
+    ArgumentDefaultValueChanged,
+    InputFieldAdded,
+    InputFieldRemoved,
+    InputFieldTypeChanged,
+    InputFieldDefaultValueChanged,
+    EnumValueAdded,
+    EnumValueRemoved,
+    UnionMemberAdded,
+    UnionMemberRemoved,
+    InterfaceAdded,
+    InterfaceRemoved,
+    OneOfAdded,
+    OneOfRemoved,
+    DirectiveAdded,
+    DirectiveRemoved,
+    DirectiveLocationRemoved,
+    DirectiveRepeatableRemoved,
+    DeprecationAdded,
+    DeprecationRemoved,
+    DeprecationReasonChanged,
+}
+
+/// Single change between two schemas, detected by the [`diff()`] function.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaChange {
+    kind: SchemaChangeKind,
+    criticality: Criticality,
+    coordinate: String,
+    description: String,
+}
+
+impl SchemaChange {
+    fn new(
+        kind: SchemaChangeKind,
+        criticality: Criticality,
+        coordinate: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            criticality,
+            coordinate: coordinate.into(),
+            description: description.into(),
+        }
+    }
+
+    /// Returns the [`SchemaChangeKind`] of this change.
+    pub fn kind(&self) -> SchemaChangeKind {
+        self.kind
+    }
+
+    /// Returns the [`Criticality`] of this change.
+    pub fn criticality(&self) -> Criticality {
+        self.criticality
+    }
+
+    /// Returns the [schema coordinate][0] of the changed schema element (e.g. `User.email` or
+    /// `Query.users(first:)`).
+    ///
+    /// [0]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+    pub fn coordinate(&self) -> &str {
+        &self.coordinate
+    }
+
+    /// Returns the human-readable description of this change.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+impl fmt::Display for SchemaChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.criticality, self.description)
+    }
+}
+
+/// Compares the `old` and the `new` schemas, returning all the [`SchemaChange`]s between them.
+///
+/// The changes are classified as [breaking][`Criticality::Breaking`],
+/// [dangerous][`Criticality::Dangerous`] or [safe][`Criticality::Safe`] in the same way as
+/// [`findBreakingChanges()` and `findDangerousChanges()` of `graphql-js`][0] do. The returned
+/// changes are ordered from the most critical ones, and then by their
+/// [coordinate][`SchemaChange::coordinate()`].
+///
+/// Changes not covered by `graphql-js` are classified as follows:
+/// - a changed or removed root operation type is [breaking][`Criticality::Breaking`], while an
+///   added one is [safe][`Criticality::Safe`];
+/// - an added deprecation or a changed deprecation reason is [safe][`Criticality::Safe`], while
+///   a removed deprecation is [dangerous][`Criticality::Dangerous`], as clients may have already
+///   migrated away from the element.
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{
+/// #     EmptyMutation, EmptySubscription, RootNode,
+/// #     schema::{Criticality, diff},
+/// # };
+/// #
+/// mod v1 {
+///     # use juniper::graphql_object;
+///     pub struct Query;
+///
+///     #[graphql_object]
+///     impl Query {
+///         fn user_name() -> &'static str {
+///             "John"
+///         }
+///     }
+/// }
+///
+/// mod v2 {
+///     # use juniper::graphql_object;
+///     pub struct Query;
+///
+///     #[graphql_object]
+///     impl Query {
+///         fn name() -> &'static str {
+///             "John"
+///         }
+///     }
+/// }
+///
+/// let old = RootNode::new(v1::Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+/// let new = RootNode::new(v2::Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+///
+/// let changes = diff(&old.schema, &new.schema);
+///
+/// assert_eq!(changes.len(), 2);
+/// assert_eq!(changes[0].criticality(), Criticality::Breaking);
+/// assert_eq!(changes[0].description(), r#"Field "Query.userName" was removed"#);
+/// assert_eq!(changes[1].criticality(), Criticality::Safe);
+/// assert_eq!(changes[1].description(), r#"Field "Query.name" was added"#);
+/// ```
+///
+/// [0]: https://github.com/graphql/graphql-js/blob/v16.11.0/src/utilities/findBreakingChanges.ts
+pub fn diff<S>(old: &SchemaType<S>, new: &SchemaType<S>) -> Vec<SchemaChange>
+where
+    S: ScalarValue,
+{
+    use Criticality as C;
+    use SchemaChangeKind as K;
+
+    let mut changes = Vec::new();
+
+    diff_root_operation_types(&mut changes, old, new);
+
+    let mut old_types = old
+        .concrete_type_list()
+        .into_iter()
+        .filter(|t| !t.is_builtin())
+        .collect::<Vec<_>>();
+    old_types.sort_by_key(|t| t.name());
+
+    for old_type in old_types {
+        let Some(name) = old_type.name() else {
+            continue;
+        };
+        match new.concrete_type_by_name(name) {
+            None => changes.push(SchemaChange::new(
+                K::TypeRemoved,
+                C::Breaking,
+                name.as_str(),
+                format!(r#"Type "{name}" was removed"#),
+            )),
+            Some(new_type) => diff_types(&mut changes, name, old_type, new_type),
+        }
+    }
+
+    let mut added_types = new
+        .concrete_type_list()
+        .into_iter()
+        .filter(|t| !t.is_builtin())
+        .filter_map(MetaType::name)
+        .filter(|name| old.concrete_type_by_name(name).is_none())
+        .collect::<Vec<_>>();
+    added_types.sort();
+    for name in added_types {
+        changes.push(SchemaChange::new(
+            K::TypeAdded,
+            C::Safe,
+            name.as_str(),
+            format!(r#"Type "{name}" was added"#),
+        ));
+    }
+
+    let mut old_directives = old.directive_list();
+    old_directives.sort_by_key(|d| &d.name);
+    for old_directive in old_directives {
+        match new.directive_by_name(&old_directive.name) {
+            None => changes.push(SchemaChange::new(
+                K::DirectiveRemoved,
+                C::Breaking,
+                format!("@{}", old_directive.name),
+                format!(r#"Directive "@{}" was removed"#, old_directive.name),
+            )),
+            Some(new_directive) => diff_directives(&mut changes, old_directive, new_directive),
+        }
+    }
+
+    let mut added_directives = new
+        .directive_list()
+        .into_iter()
+        .filter(|d| old.directive_by_name(&d.name).is_none())
+        .map(|d| &d.name)
+        .collect::<Vec<_>>();
+    added_directives.sort();
+    for name in added_directives {
+        changes.push(SchemaChange::new(
+            K::DirectiveAdded,
+            C::Safe,
+            format!("@{name}"),
+            format!(r#"Directive "@{name}" was added"#),
+        ));
+    }
+
+    changes.sort_by(|a, b| {
+        b.criticality
+            .cmp(&a.criticality)
+            .then_with(|| a.coordinate.cmp(&b.coordinate))
+    });
+    changes
+}
+
+fn diff_root_operation_types<S>(
+    changes: &mut Vec<SchemaChange>,
+    old: &SchemaType<S>,
+    new: &SchemaType<S>,
+) {
+    use Criticality as C;
+    use SchemaChangeKind as K;
+
+    for (operation, old_name, new_name) in [
+        (
+            "query",
+            Some(old.query_type_name.as_str()),
+            Some(new.query_type_name.as_str()),
+        ),
+        (
+            "mutation",
+            old.mutation_type_name.as_deref(),
+            new.mutation_type_name.as_deref(),
+        ),
+        (
+            "subscription",
+            old.subscription_type_name.as_deref(),
+            new.subscription_type_name.as_deref(),
+        ),
+    ] {
+        match (old_name, new_name) {
+            (None, None) => {}
+            (Some(old_name), Some(new_name)) if old_name == new_name => {}
+            (Some(old_name), Some(new_name)) => changes.push(SchemaChange::new(
+                K::RootOperationTypeChanged,
+                C::Breaking,
+                new_name,
+                format!(r#"Root {operation} type changed from "{old_name}" to "{new_name}""#),
+            )),
+            (Some(old_name), None) => changes.push(SchemaChange::new(
+                K::RootOperationTypeRemoved,
+                C::Breaking,
+                old_name,
+                format!(r#"Root {operation} type "{old_name}" was removed"#),
+            )),
+            (None, Some(new_name)) => changes.push(SchemaChange::new(
+                K::RootOperationTypeAdded,
+                C::Safe,
+                new_name,
+                format!(r#"Root {operation} type "{new_name}" was added"#),
+            )),
+        }
+    }
+}
+
+fn diff_types<S>(
+    changes: &mut Vec<SchemaChange>,
+    name: &ArcStr,
+    old: &MetaType<S>,
+    new: &MetaType<S>,
+) where
+    S: ScalarValue,
+{
+    use Criticality as C;
+    use SchemaChangeKind as K;
+
+    match (old, new) {
+        (
+            MetaType::Object(ObjectMeta {
+                fields: old_fields,
+                interface_names: old_interfaces,
+                ..
+            }),
+            MetaType::Object(ObjectMeta {
+                fields: new_fields,
+                interface_names: new_interfaces,
+                ..
+            }),
+        )
+        | (
+            MetaType::Interface(InterfaceMeta {
+                fields: old_fields,
+                interface_names: old_interfaces,
+                ..
+            }),
+            MetaType::Interface(InterfaceMeta {
+                fields: new_fields,
+                interface_names: new_interfaces,
+                ..
+            }),
+        ) => {
+            diff_fields(changes, name, old_fields, new_fields);
+            diff_members(
+                changes,
+                name,
+                old_interfaces,
+                new_interfaces,
+                (K::InterfaceRemoved, |i| {
+                    format!(r#"Type "{name}" no longer implements interface "{i}""#)
+                }),
+                (K::InterfaceAdded, |i| {
+                    format!(r#"Type "{name}" now implements interface "{i}""#)
+                }),
+            );
+        }
+        (MetaType::Union(old), MetaType::Union(new)) => diff_members(
+            changes,
+            name,
+            &old.of_type_names,
+            &new.of_type_names,
+            (K::UnionMemberRemoved, |t| {
+                format!(r#"Type "{t}" was removed from union "{name}""#)
+            }),
+            (K::UnionMemberAdded, |t| {
+                format!(r#"Type "{t}" was added to union "{name}""#)
+            }),
+        ),
+        (
+            MetaType::Enum(EnumMeta { values: old, .. }),
+            MetaType::Enum(EnumMeta { values: new, .. }),
+        ) => {
+            for value in old {
+                let coordinate = format!("{name}.{}", value.name);
+                match new.iter().find(|v| v.name == value.name) {
+                    None => changes.push(SchemaChange::new(
+                        K::EnumValueRemoved,
+                        C::Breaking,
+                        &coordinate,
+                        format!(r#"Enum value "{coordinate}" was removed"#),
+                    )),
+                    Some(new_value) => diff_deprecation(
+                        changes,
+                        "Enum value",
+                        &coordinate,
+                        &value.deprecation_status,
+                        &new_value.deprecation_status,
+                    ),
+                }
+            }
+            for value in new {
+                if !old.iter().any(|v| v.name == value.name) {
+                    changes.push(SchemaChange::new(
+                        K::EnumValueAdded,
+                        C::Dangerous,
+                        format!("{name}.{}", value.name),
+                        format!(r#"Enum value "{name}.{}" was added"#, value.name),
+                    ));
+                }
+            }
+        }
+        (MetaType::InputObject(old), MetaType::InputObject(new)) => {
+            diff_input_object(changes, name, old, new)
+        }
+        (MetaType::Scalar(_), MetaType::Scalar(_)) => {}
+        _ => changes.push(SchemaChange::new(
+            K::TypeKindChanged,
+            C::Breaking,
+            name.as_str(),
+            format!(
+                r#"Type "{name}" changed from {} to {}"#,
+                kind_description(old),
+                kind_description(new),
+            ),
+        )),
+    }
+}
+
+fn diff_fields<S>(
+    changes: &mut Vec<SchemaChange>,
+    type_name: &str,
+    old: &[Field<S>],
+    new: &[Field<S>],
+) where
+    S: ScalarValue,
+{
+    use Criticality as C;
+    use SchemaChangeKind as K;
+
+    for old_field in old.iter().filter(|f| !f.is_builtin()) {
+        let coordinate = format!("{type_name}.{}", old_field.name);
+        let Some(new_field) = new.iter().find(|f| f.name == old_field.name) else {
+            changes.push(SchemaChange::new(
+                K::FieldRemoved,
+                C::Breaking,
+                &coordinate,
+                format!(r#"Field "{coordinate}" was removed"#),
+            ));
+            continue;
+        };
+
+        if old_field.field_type != new_field.field_type {
+            changes.push(SchemaChange::new(
+                K::FieldTypeChanged,
+                if is_safe_output_type_change(&old_field.field_type, &new_field.field_type) {
+                    C::Safe
+                } else {
+                    C::Breaking
+                },
+                &coordinate,
+                format!(
+                    r#"Field "{coordinate}" changed type from "{}" to "{}""#,
+                    old_field.field_type, new_field.field_type,
+                ),
+            ));
+        }
+
+        diff_deprecation(
+            changes,
+            "Field",
+            &coordinate,
+            &old_field.deprecation_status,
+            &new_field.deprecation_status,
+        );
+
+        diff_arguments(
+            changes,
+            &coordinate,
+            old_field.arguments.as_deref().unwrap_or_default(),
+            new_field.arguments.as_deref().unwrap_or_default(),
+        );
+    }
+
+    for new_field in new.iter().filter(|f| !f.is_builtin()) {
+        if !old.iter().any(|f| f.name == new_field.name) {
+            let coordinate = format!("{type_name}.{}", new_field.name);
+            changes.push(SchemaChange::new(
+                K::FieldAdded,
+                C::Safe,
+                &coordinate,
+                format!(r#"Field "{coordinate}" was added"#),
+            ));
+        }
+    }
+}
+
+fn diff_input_object<S>(
+    changes: &mut Vec<SchemaChange>,
+    type_name: &str,
+    old: &InputObjectMeta<S>,
+    new: &InputObjectMeta<S>,
+) where
+    S: ScalarValue,
+{
+    use Criticality as C;
+    use SchemaChangeKind as K;
+
+    match (old.is_one_of, new.is_one_of) {
+        (false, true) => changes.push(SchemaChange::new(
+            K::OneOfAdded,
+            C::Breaking,
+            type_name,
+            format!(r#"Input object "{type_name}" became `@oneOf`"#),
+        )),
+        (true, false) => changes.push(SchemaChange::new(
+            K::OneOfRemoved,
+            C::Safe,
+            type_name,
+            format!(r#"Input object "{type_name}" is no longer `@oneOf`"#),
+        )),
+        (false, false) | (true, true) => {}
+    }
+
+    diff_input_values(
+        changes,
+        &old.input_fields,
+        &new.input_fields,
+        InputValueKinds::INPUT_FIELD,
+        |f| format!("{type_name}.{f}"),
+    );
+}
+
+fn diff_arguments<S>(
+    changes: &mut Vec<SchemaChange>,
+    owner: &str,
+    old: &[Argument<S>],
+    new: &[Argument<S>],
+) where
+    S: ScalarValue,
+{
+    diff_input_values(changes, old, new, InputValueKinds::ARGUMENT, |a| {
+        format!("{owner}({a}:)")
+    });
+}
+
+fn diff_directives<S>(
+    changes: &mut Vec<SchemaChange>,
+    old: &DirectiveType<S>,
+    new: &DirectiveType<S>,
+) where
+    S: ScalarValue,
+{
+    use Criticality as C;
+    use SchemaChangeKind as K;
+
+    let coordinate = format!("@{}", old.name);
+
+    for location in &old.locations {
+        if !new.locations.contains(location) {
+            changes.push(SchemaChange::new(
+                K::DirectiveLocationRemoved,
+                C::Breaking,
+                &coordinate,
+                format!(r#"Location "{location}" was removed from directive "{coordinate}""#),
+            ));
+        }
+    }
+
+    if old.is_repeatable && !new.is_repeatable {
+        changes.push(SchemaChange::new(
+            K::DirectiveRepeatableRemoved,
+            C::Breaking,
+            &coordinate,
+            format!(r#"Directive "{coordinate}" is no longer repeatable"#),
+        ));
+    }
+
+    diff_arguments(changes, &coordinate, &old.arguments, &new.arguments);
+}
+
+/// [`SchemaChangeKind`]s reported by [`diff_input_values()`], being either the ones of
+/// arguments or the ones of input fields.
+struct InputValueKinds {
+    name: &'static str,
+    title: &'static str,
+    added: SchemaChangeKind,
+    removed: SchemaChangeKind,
+    type_changed: SchemaChangeKind,
+    default_value_changed: SchemaChangeKind,
+}
+
+impl InputValueKinds {
+    const ARGUMENT: Self = Self {
+        name: "argument",
+        title: "Argument",
+        added: SchemaChangeKind::ArgumentAdded,
+        removed: SchemaChangeKind::ArgumentRemoved,
+        type_changed: SchemaChangeKind::ArgumentTypeChanged,
+        default_value_changed: SchemaChangeKind::ArgumentDefaultValueChanged,
+    };
+
+    const INPUT_FIELD: Self = Self {
+        name: "input field",
+        title: "Input field",
+        added: SchemaChangeKind::InputFieldAdded,
+        removed: SchemaChangeKind::InputFieldRemoved,
+        type_changed: SchemaChangeKind::InputFieldTypeChanged,
+        default_value_changed: SchemaChangeKind::InputFieldDefaultValueChanged,
+    };
+}
+
+fn diff_input_values<S>(
+    changes: &mut Vec<SchemaChange>,
+    old: &[Argument<S>],
+    new: &[Argument<S>],
+    kinds: InputValueKinds,
+    coordinate: impl Fn(&str) -> String,
+) where
+    S: ScalarValue,
+{
+    use Criticality as C;
+
+    let InputValueKinds { name, title, .. } = kinds;
+
+    for old_value in old.iter().filter(|v| !v.is_builtin()) {
+        let coordinate = coordinate(&old_value.name);
+        let Some(new_value) = new.iter().find(|v| v.name == old_value.name) else {
+            changes.push(SchemaChange::new(
+                kinds.removed,
+                C::Breaking,
+                &coordinate,
+                format!(r#"{title} "{coordinate}" was removed"#),
+            ));
+            continue;
+        };
+
+        if old_value.arg_type != new_value.arg_type {
+            changes.push(SchemaChange::new(
+                kinds.type_changed,
+                if is_safe_input_type_change(&old_value.arg_type, &new_value.arg_type) {
+                    C::Safe
+                } else {
+                    C::Breaking
+                },
+                &coordinate,
+                format!(
+                    r#"{title} "{coordinate}" changed type from "{}" to "{}""#,
+                    old_value.arg_type, new_value.arg_type,
+                ),
+            ));
+        }
+
+        if !is_same_default_value(
+            old_value.default_value.as_ref(),
+            new_value.default_value.as_ref(),
+        ) {
+            changes.push(SchemaChange::new(
+                kinds.default_value_changed,
+                C::Dangerous,
+                &coordinate,
+                format!(
+                    r#"{title} "{coordinate}" changed default value from {} to {}"#,
+                    display_default_value(old_value.default_value.as_ref()),
+                    display_default_value(new_value.default_value.as_ref()),
+                ),
+            ));
+        }
+
+        diff_deprecation(
+            changes,
+            title,
+            &coordinate,
+            &old_value.deprecation_status,
+            &new_value.deprecation_status,
+        );
+    }
+
+    for new_value in new.iter().filter(|v| !v.is_builtin()) {
+        if !old.iter().any(|v| v.name == new_value.name) {
+            let coordinate = coordinate(&new_value.name);
+            let is_required = new_value.arg_type.is_non_null() && new_value.default_value.is_none();
+            changes.push(SchemaChange::new(
+                kinds.added,
+                if is_required {
+                    C::Breaking
+                } else {
+                    C::Dangerous
+                },
+                &coordinate,
+                format!(
+                    r#"{} {name} "{coordinate}" was added"#,
+                    if is_required { "Required" } else { "Optional" },
+                ),
+            ));
+        }
+    }
+}
+
+/// Reports the change of the [`DeprecationStatus`] of the schema element at the provided
+/// `coordinate`, if any.
+fn diff_deprecation(
+    changes: &mut Vec<SchemaChange>,
+    title: &str,
+    coordinate: &str,
+    old: &DeprecationStatus,
+    new: &DeprecationStatus,
+) {
+    use Criticality as C;
+    use SchemaChangeKind as K;
+
+    match (old, new) {
+        (DeprecationStatus::Current, DeprecationStatus::Current) => {}
+        (DeprecationStatus::Current, DeprecationStatus::Deprecated(_)) => {
+            changes.push(SchemaChange::new(
+                K::DeprecationAdded,
+                C::Safe,
+                coordinate,
+                format!(r#"{title} "{coordinate}" was deprecated"#),
+            ))
+        }
+        (DeprecationStatus::Deprecated(_), DeprecationStatus::Current) => {
+            changes.push(SchemaChange::new(
+                K::DeprecationRemoved,
+                C::Dangerous,
+                coordinate,
+                format!(r#"{title} "{coordinate}" is no longer deprecated"#),
+            ))
+        }
+        (DeprecationStatus::Deprecated(old), DeprecationStatus::Deprecated(new)) => {
+            if old != new {
+                changes.push(SchemaChange::new(
+                    K::DeprecationReasonChanged,
+                    C::Safe,
+                    coordinate,
+                    format!(r#"{title} "{coordinate}" changed deprecation reason"#),
+                ))
+            }
+        }
+    }
+}
+
+/// Reports the `old` members (interfaces or union members) missing in the `new` ones as
+/// [breaking][`Criticality::Breaking`] changes, and the added ones as
+/// [dangerous][`Criticality::Dangerous`] changes.
+fn diff_members(
+    changes: &mut Vec<SchemaChange>,
+    coordinate: &str,
+    old: &[ArcStr],
+    new: &[ArcStr],
+    (removed_kind, removed_description): (SchemaChangeKind, impl Fn(&str) -> String),
+    (added_kind, added_description): (SchemaChangeKind, impl Fn(&str) -> String),
+) {
+    let old_set = old.iter().collect::<HashSet<_>>();
+    let new_set = new.iter().collect::<HashSet<_>>();
+
+    for member in old.iter().filter(|m| !new_set.contains(m)) {
+        changes.push(SchemaChange::new(
+            removed_kind,
+            Criticality::Breaking,
+            coordinate,
+            removed_description(member),
+        ));
+    }
+    for member in new.iter().filter(|m| !old_set.contains(m)) {
+        changes.push(SchemaChange::new(
+            added_kind,
+            Criticality::Dangerous,
+            coordinate,
+            added_description(member),
+        ));
+    }
+}
+
+/// Checks whether changing the type of field from `old` to `new` is safe for clients.
+///
+/// Output types may only become stricter, i.e. a `null`able type may become non-`null`.
+fn is_safe_output_type_change(
+    old: &Type<impl AsRef<str>, impl AsRef<[TypeModifier]>>,
+    new: &Type<impl AsRef<str>, impl AsRef<[TypeModifier]>>,
+) -> bool {
+    match (old.modifier(), new.modifier()) {
+        (Some(TypeModifier::NonNull), Some(TypeModifier::NonNull)) => {
+            is_safe_output_type_change(&old.borrow_inner(), &new.borrow_inner())
+        }
+        (_, Some(TypeModifier::NonNull)) => is_safe_output_type_change(old, &new.borrow_inner()),
+        (Some(TypeModifier::List(old_size)), Some(TypeModifier::List(new_size))) => {
+            old_size == new_size
+                && is_safe_output_type_change(&old.borrow_inner(), &new.borrow_inner())
+        }
+        (None, None) => old.innermost_name() == new.innermost_name(),
+        _ => false,
+    }
+}
+
+/// Checks whether changing the type of an argument or an input field from `old` to `new` is safe
+/// for clients.
+///
+/// Input types may only become looser, i.e. a non-`null` type may become `null`able.
+fn is_safe_input_type_change(
+    old: &Type<impl AsRef<str>, impl AsRef<[TypeModifier]>>,
+    new: &Type<impl AsRef<str>, impl AsRef<[TypeModifier]>>,
+) -> bool {
+    match (old.modifier(), new.modifier()) {
+        (Some(TypeModifier::NonNull), Some(TypeModifier::NonNull)) => {
+            is_safe_input_type_change(&old.borrow_inner(), &new.borrow_inner())
+        }
+        (Some(TypeModifier::NonNull), _) => is_safe_input_type_change(&old.borrow_inner(), new),
+        (Some(TypeModifier::List(old_size)), Some(TypeModifier::List(new_size))) => {
+            old_size == new_size
+                && is_safe_input_type_change(&old.borrow_inner(), &new.borrow_inner())
+        }
+        (None, None) => old.innermost_name() == new.innermost_name(),
+        _ => false,
+    }
+}
+
+fn is_same_default_value<S: PartialEq>(
+    old: Option<&InputValue<S>>,
+    new: Option<&InputValue<S>>,
+) -> bool {
+    match (old, new) {
+        (None, None) => true,
+        (Some(old), Some(new)) => old.unlocated_eq(new),
+        _ => false,
+    }
+}
+
+fn display_default_value<S: ScalarValue>(value: Option<&InputValue<S>>) -> String {
+    value.map_or_else(|| "none".into(), |v| format!("`{v}`"))
+}
+
+fn kind_description<S>(meta: &MetaType<S>) -> &'static str {
+    match meta {
+        MetaType::Scalar(..) => "a scalar type",
+        MetaType::Object(..) => "an object type",
+        MetaType::Interface(..) => "an interface type",
+        MetaType::Union(..) => "a union type",
+        MetaType::Enum(..) => "an enum type",
+        MetaType::InputObject(..) => "an input object type",
+        MetaType::List(..) => "a list type",
+        MetaType::Nullable(..) => "a nullable type",
+        MetaType::Placeholder(..) => "a placeholder type",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        DefaultScalarValue, EmptyMutation, EmptySubscription, GraphQLEnum, GraphQLInputObject,
+        InputValue, RootNode, Type, graphql_object,
+        schema::{
+            meta::{Argument, DeprecationStatus, EnumMeta, EnumValue, Field, MetaType},
+            model::SchemaType,
+        },
+        types::name::Name,
+    };
+
+    use super::{Criticality, SchemaChangeKind, diff};
+
+    #[derive(GraphQLEnum)]
+    enum Color {
+        Red,
+        Green,
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct Filter {
+        name: Option<String>,
+        limit: i32,
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn users(filter: Option<Filter>, first: Option<i32>) -> Vec<String> {
+            _ = (filter, first);
+            vec![]
+        }
+
+        fn color() -> Color {
+            Color::Red
+        }
+
+        fn nickname() -> Option<String> {
+            None
+        }
+    }
+
+    fn schema() -> SchemaType<DefaultScalarValue> {
+        RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        )
+        .schema
+    }
+
+    fn query_fields(
+        schema: &mut SchemaType<DefaultScalarValue>,
+    ) -> &mut Vec<Field<DefaultScalarValue>> {
+        match schema.types.get_mut("Query") {
+            Some(MetaType::Object(o)) => &mut o.fields,
+            _ => unreachable!(),
+        }
+    }
+
+    fn query_field<'s>(
+        schema: &'s mut SchemaType<DefaultScalarValue>,
+        name: &str,
+    ) -> &'s mut Field<DefaultScalarValue> {
+        query_fields(schema)
+            .iter_mut()
+            .find(|f| f.name == name)
+            .unwrap()
+    }
+
+    fn users_arguments(
+        schema: &mut SchemaType<DefaultScalarValue>,
+    ) -> &mut Vec<Argument<DefaultScalarValue>> {
+        query_field(schema, "users").arguments.as_mut().unwrap()
+    }
+
+    fn changes(
+        old: &SchemaType<DefaultScalarValue>,
+        new: &SchemaType<DefaultScalarValue>,
+    ) -> Vec<(SchemaChangeKind, Criticality, String)> {
+        diff(old, new)
+            .into_iter()
+            .map(|c| (c.kind(), c.criticality(), c.coordinate().into()))
+            .collect()
+    }
+
+    #[test]
+    fn no_changes_between_identical_schemas() {
+        assert_eq!(diff(&schema(), &schema()), vec![]);
+    }
+
+    #[test]
+    fn detects_removed_and_added_fields() {
+        let old = schema();
+        let mut new = schema();
+        query_field(&mut new, "nickname").name = "nick".into();
+
+        let changes = diff(&old, &new);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].kind(), SchemaChangeKind::FieldRemoved);
+        assert_eq!(changes[0].criticality(), Criticality::Breaking);
+        assert_eq!(changes[0].coordinate(), "Query.nickname");
+        assert_eq!(
+            changes[0].to_string(),
+            r#"[breaking] Field "Query.nickname" was removed"#,
+        );
+        assert_eq!(changes[1].kind(), SchemaChangeKind::FieldAdded);
+        assert_eq!(changes[1].criticality(), Criticality::Safe);
+        assert_eq!(changes[1].coordinate(), "Query.nick");
+    }
+
+    #[test]
+    fn classifies_field_type_changes() {
+        let old = schema();
+
+        let mut new = schema();
+        query_field(&mut new, "nickname").field_type = Type::nullable("String").wrap_non_null();
+        assert_eq!(
+            changes(&old, &new),
+            [(
+                SchemaChangeKind::FieldTypeChanged,
+                Criticality::Safe,
+                "Query.nickname".into(),
+            )],
+        );
+
+        let mut new = schema();
+        query_field(&mut new, "nickname").field_type = Type::nullable("Int");
+        assert_eq!(
+            changes(&old, &new),
+            [(
+                SchemaChangeKind::FieldTypeChanged,
+                Criticality::Breaking,
+                "Query.nickname".into(),
+            )],
+        );
+
+        let mut new = schema();
+        query_field(&mut new, "users").field_type = Type::nullable("String").wrap_list(None);
+        assert_eq!(
+            changes(&old, &new),
+            [(
+                SchemaChangeKind::FieldTypeChanged,
+                Criticality::Breaking,
+                "Query.users".into(),
+            )],
+        );
+    }
+
+    #[test]
+    fn classifies_argument_changes() {
+        let old = schema();
+        let mut new = schema();
+        let args = users_arguments(&mut new);
+        args.retain(|a| a.name != "filter");
+        args.iter_mut()
+            .find(|a| a.name == "first")
+            .unwrap()
+            .arg_type = Type::nullable("Int").wrap_non_null();
+        args.push(Argument::new("after", Type::nullable("String")));
+        args.push(Argument::new(
+            "sort",
+            Type::nullable("Color").wrap_non_null(),
+        ));
+        args.push(
+            Argument::new("last", Type::nullable("Int").wrap_non_null())
+                .default_value(InputValue::scalar(10)),
+        );
+
+        assert_eq!(
+            changes(&old, &new),
+            [
+                (
+                    SchemaChangeKind::ArgumentRemoved,
+                    Criticality::Breaking,
+                    "Query.users(filter:)".into(),
+                ),
+                (
+                    SchemaChangeKind::ArgumentTypeChanged,
+                    Criticality::Breaking,
+                    "Query.users(first:)".into(),
+                ),
+                (
+                    SchemaChangeKind::ArgumentAdded,
+                    Criticality::Breaking,
+                    "Query.users(sort:)".into(),
+                ),
+                (
+                    SchemaChangeKind::ArgumentAdded,
+                    Criticality::Dangerous,
+                    "Query.users(after:)".into(),
+                ),
+                (
+                    SchemaChangeKind::ArgumentAdded,
+                    Criticality::Dangerous,
+                    "Query.users(last:)".into(),
+                ),
+            ],
+        );
+
+        let old = new;
+        let mut new = schema();
+        let args = users_arguments(&mut new);
+        args.retain(|a| a.name != "filter");
+        args.push(Argument::new("after", Type::nullable("String")));
+        args.push(Argument::new(
+            "sort",
+            Type::nullable("Color").wrap_non_null(),
+        ));
+        args.push(
+            Argument::new("last", Type::nullable("Int").wrap_non_null())
+                .default_value(InputValue::scalar(20)),
+        );
+
+        let changes = diff(&old, &new);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes[0].kind(),
+            SchemaChangeKind::ArgumentDefaultValueChanged
+        );
+        assert_eq!(changes[0].criticality(), Criticality::Dangerous);
+        assert_eq!(
+            changes[0].description(),
+            r#"Argument "Query.users(last:)" changed default value from `10` to `20`"#,
+        );
+        assert_eq!(changes[1].kind(), SchemaChangeKind::ArgumentTypeChanged);
+        assert_eq!(changes[1].criticality(), Criticality::Safe);
+        assert_eq!(
+            changes[1].description(),
+            r#"Argument "Query.users(first:)" changed type from "Int!" to "Int""#,
+        );
+    }
+
+    #[test]
+    fn classifies_enum_value_changes() {
+        let old = schema();
+        let mut new = schema();
+        if let Some(MetaType::Enum(e)) = new.types.get_mut("Color") {
+            e.values.retain(|v| v.name != "GREEN");
+            e.values.push(EnumValue::new("BLUE"));
+        }
+
+        assert_eq!(
+            changes(&old, &new),
+            [
+                (
+                    SchemaChangeKind::EnumValueRemoved,
+                    Criticality::Breaking,
+                    "Color.GREEN".into(),
+                ),
+                (
+                    SchemaChangeKind::EnumValueAdded,
+                    Criticality::Dangerous,
+                    "Color.BLUE".into(),
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn classifies_input_object_changes() {
+        let old = schema();
+        let mut new = schema();
+        if let Some(MetaType::InputObject(i)) = new.types.get_mut("Filter") {
+            i.is_one_of = true;
+            i.input_fields.retain(|f| f.name != "name");
+            i.input_fields
+                .iter_mut()
+                .find(|f| f.name == "limit")
+                .unwrap()
+                .arg_type = Type::nullable("Int");
+            i.input_fields.push(Argument::new(
+                "offset",
+                Type::nullable("Int").wrap_non_null(),
+            ));
+        }
+
+        assert_eq!(
+            changes(&old, &new),
+            [
+                (
+                    SchemaChangeKind::OneOfAdded,
+                    Criticality::Breaking,
+                    "Filter".into(),
+                ),
+                (
+                    SchemaChangeKind::InputFieldRemoved,
+                    Criticality::Breaking,
+                    "Filter.name".into(),
+                ),
+                (
+                    SchemaChangeKind::InputFieldAdded,
+                    Criticality::Breaking,
+                    "Filter.offset".into(),
+                ),
+                (
+                    SchemaChangeKind::InputFieldTypeChanged,
+                    Criticality::Safe,
+                    "Filter.limit".into(),
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn detects_removed_and_changed_types() {
+        let old = schema();
+        let mut new = schema();
+        new.types.remove("Color");
+        let filter = new.types.remove("Filter").unwrap();
+        if let MetaType::InputObject(i) = filter {
+            new.types.insert(
+                Name::new("Filter").unwrap(),
+                MetaType::Enum(EnumMeta::new::<Color>(i.name, &[EnumValue::new("ALL")])),
+            );
+        }
+
+        let changes = diff(&old, &new);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].kind(), SchemaChangeKind::TypeRemoved);
+        assert_eq!(changes[0].coordinate(), "Color");
+        assert_eq!(changes[1].kind(), SchemaChangeKind::TypeKindChanged);
+        assert_eq!(
+            changes[1].description(),
+            r#"Type "Filter" changed from an input object type to an enum type"#,
+        );
+    }
+
+    #[test]
+    fn classifies_root_operation_type_changes() {
+        let old = schema();
+        let mut new = schema();
+        new.query_type_name = "Root".into();
+        new.mutation_type_name = Some("Mutation".into());
+
+        assert_eq!(
+            changes(&old, &new),
+            [
+                (
+                    SchemaChangeKind::RootOperationTypeChanged,
+                    Criticality::Breaking,
+                    "Root".into(),
+                ),
+                (
+                    SchemaChangeKind::RootOperationTypeAdded,
+                    Criticality::Safe,
+                    "Mutation".into(),
+                ),
+            ],
+        );
+
+        let changes = diff(&new, &old);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes[0].description(),
+            r#"Root mutation type "Mutation" was removed"#,
+        );
+        assert_eq!(
+            changes[1].description(),
+            r#"Root query type changed from "Root" to "Query""#,
+        );
+    }
+
+    #[test]
+    fn classifies_deprecation_changes() {
+        let old = schema();
+        let mut new = schema();
+        query_field(&mut new, "nickname").deprecation_status = DeprecationStatus::Deprecated(None);
+        users_arguments(&mut new)
+            .iter_mut()
+            .find(|a| a.name == "first")
+            .unwrap()
+            .deprecation_status = DeprecationStatus::Deprecated(Some("Use `last`".into()));
+        if let Some(MetaType::Enum(e)) = new.types.get_mut("Color") {
+            e.values
+                .iter_mut()
+                .find(|v| v.name == "RED")
+                .unwrap()
+                .deprecation_status = DeprecationStatus::Deprecated(None);
+        }
+
+        assert_eq!(
+            changes(&old, &new),
+            [
+                (
+                    SchemaChangeKind::DeprecationAdded,
+                    Criticality::Safe,
+                    "Color.RED".into(),
+                ),
+                (
+                    SchemaChangeKind::DeprecationAdded,
+                    Criticality::Safe,
+                    "Query.nickname".into(),
+                ),
+                (
+                    SchemaChangeKind::DeprecationAdded,
+                    Criticality::Safe,
+                    "Query.users(first:)".into(),
+                ),
+            ],
+        );
+
+        let old = new;
+        let mut new = schema();
+        query_field(&mut new, "nickname").deprecation_status =
+            DeprecationStatus::Deprecated(Some("Use `name`".into()));
+
+        let changes = diff(&old, &new);
+
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[0].kind(), SchemaChangeKind::DeprecationRemoved);
+        assert_eq!(changes[0].criticality(), Criticality::Dangerous);
+        assert_eq!(
+            changes[0].description(),
+            r#"Enum value "Color.RED" is no longer deprecated"#,
+        );
+        assert_eq!(changes[1].kind(), SchemaChangeKind::DeprecationRemoved);
+        assert_eq!(changes[1].coordinate(), "Query.users(first:)");
+        assert_eq!(
+            changes[2].kind(),
+            SchemaChangeKind::DeprecationReasonChanged
+        );
+        assert_eq!(
+            changes[2].description(),
+            r#"Field "Query.nickname" changed deprecation reason"#,
+        );
+    }
+}
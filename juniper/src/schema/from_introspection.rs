@@ -0,0 +1,996 @@
+//! Reconstruction of a [`SchemaType`] from an [introspection][0] result.
+//!
+//! [0]: https://spec.graphql.org/October2021#sec-Introspection
+
+use std::convert::Infallible;
+
+use arcstr::ArcStr;
+use derive_more::with_trait::{Display, Error};
+use fnv::FnvHashMap;
+
+use crate::{
+    ID,
+    ast::{FromInputValue, InputValue, Type},
+    executor::Registry,
+    parser::{Lexer, ParseError, Parser, ScalarToken, Token, parse_value_literal},
+    schema::{
+        meta::{
+            Argument, DeprecationStatus, EnumMeta, EnumValue, Field, InputObjectMeta,
+            InterfaceMeta, MetaType, ObjectMeta, ScalarMeta, UnionMeta,
+        },
+        model::{DirectiveLocation, DirectiveType, SchemaType},
+    },
+    types::name::Name,
+    value::{Object, ParseScalarResult, ParseScalarValue, ScalarValue, Value},
+};
+
+/// Error of reconstructing a [`SchemaType`] from an introspection result.
+#[derive(Clone, Debug, Display, Eq, Error, PartialEq)]
+pub enum FromIntrospectionError {
+    /// Required field is missing in the introspection result.
+    #[display("missing `{path}` in introspection result")]
+    MissingField {
+        /// Path to the missing field.
+        path: String,
+    },
+
+    /// Value in the introspection result has an unexpected shape.
+    #[display("`{path}` in introspection result is expected to be {expected}")]
+    InvalidValue {
+        /// Path to the invalid value.
+        path: String,
+
+        /// Description of the expected value.
+        expected: &'static str,
+    },
+
+    /// Introspection result references a type it doesn't define.
+    #[display("`{path}` references unknown type `{name}`")]
+    UnknownType {
+        /// Path to the reference.
+        path: String,
+
+        /// Name of the referenced type.
+        name: String,
+    },
+
+    /// Default value in the introspection result cannot be parsed.
+    #[display("`{path}` has invalid default value: {message}")]
+    InvalidDefaultValue {
+        /// Path to the default value.
+        path: String,
+
+        /// Description of the parsing error.
+        message: String,
+    },
+}
+
+impl<S: ScalarValue> SchemaType<S> {
+    /// Reconstructs a [`SchemaType`] from the result of the [`INTROSPECTION_QUERY`] (either the
+    /// whole response with the `data` field, or just its data containing the `__schema` field).
+    ///
+    /// The reconstructed [`SchemaType`] is able to parse and validate documents, and to be
+    /// [diffed][`diff()`] with other [`SchemaType`]s. As the actual input coercion rules of
+    /// custom scalars are unknown, they accept any input value.
+    ///
+    /// # Errors
+    ///
+    /// If the provided `value` is not a valid introspection result.
+    ///
+    /// [`diff()`]: crate::schema::diff
+    /// [`INTROSPECTION_QUERY`]: https://github.com/graphql/graphql-js/blob/v16.11.0/src/utilities/getIntrospectionQuery.ts#L75
+    pub fn from_introspection(value: &Value<S>) -> Result<Self, FromIntrospectionError> {
+        let root = object(value, "")?;
+        let (schema, path) = match root.get_field_value("data") {
+            Some(data) => (
+                required(object(data, "data")?, "data", "__schema")?,
+                "data.__schema",
+            ),
+            None => (required(root, "", "__schema")?, "__schema"),
+        };
+        Builder::default().build(object(schema, path)?, path)
+    }
+}
+
+/// Location of an [`Argument`] whose default value is still to be parsed.
+enum ArgumentOwner {
+    /// Argument of a [`Field`] of an object or an interface.
+    Field { type_name: ArcStr, field: usize },
+
+    /// Field of an [`InputObjectMeta`].
+    InputObject { type_name: ArcStr },
+
+    /// Argument of a [`DirectiveType`].
+    Directive { index: usize },
+}
+
+/// [`Argument`] whose default value is still to be parsed.
+struct PendingDefault {
+    owner: ArgumentOwner,
+    argument: usize,
+    arg_type: Type,
+    raw: String,
+    path: String,
+}
+
+/// Builder of a [`SchemaType`] out of an introspection result.
+#[derive(Default)]
+struct Builder {
+    /// Type references to be checked once all the types are known.
+    references: Vec<(String, ArcStr)>,
+
+    /// Default values to be parsed once all the types are known.
+    defaults: Vec<PendingDefault>,
+}
+
+impl Builder {
+    /// Builds a [`SchemaType`] out of the provided introspected `__schema` object.
+    fn build<S: ScalarValue>(
+        mut self,
+        schema: &Object<S>,
+        path: &str,
+    ) -> Result<SchemaType<S>, FromIntrospectionError> {
+        let mut registry = Registry::new(FnvHashMap::default());
+        registry.get_type::<SchemaType<S>>(&());
+
+        let mut definition_order = vec![];
+        for (i, ty) in list(schema, path, "types")?.iter().enumerate() {
+            let ty = object(ty, &format!("{path}.types[{i}]"))?;
+            let name = string(ty, &format!("{path}.types[{i}]"), "name")?;
+            let path = format!("{path}.types[{name}]");
+            if let Ok(name) = Name::new(name) {
+                definition_order.push(name);
+            }
+            let meta = match name {
+                n if n.starts_with("__") => continue,
+                "Boolean" => {
+                    registry.get_type::<bool>(&());
+                    continue;
+                }
+                "Float" => {
+                    registry.get_type::<f64>(&());
+                    continue;
+                }
+                "ID" => {
+                    registry.get_type::<ID>(&());
+                    continue;
+                }
+                "Int" => {
+                    registry.get_type::<i32>(&());
+                    continue;
+                }
+                "String" => {
+                    registry.get_type::<String>(&());
+                    continue;
+                }
+                _ => self.named_type(ty, &path, name, &mut registry)?,
+            };
+            let name = Name::new(name).map_err(|_| FromIntrospectionError::InvalidValue {
+                path: format!("{path}.name"),
+                expected: "a valid GraphQL name",
+            })?;
+            registry.types.insert(name, meta);
+        }
+
+        let query_type_name = root_type_name(schema, path, "queryType")?.ok_or_else(|| {
+            FromIntrospectionError::MissingField {
+                path: format!("{path}.queryType"),
+            }
+        })?;
+        let mutation_type_name = root_type_name(schema, path, "mutationType")?;
+        let subscription_type_name = root_type_name(schema, path, "subscriptionType")?;
+        for (field, name) in [
+            ("queryType", Some(&query_type_name)),
+            ("mutationType", mutation_type_name.as_ref()),
+            ("subscriptionType", subscription_type_name.as_ref()),
+        ] {
+            if let Some(name) = name {
+                self.references
+                    .push((format!("{path}.{field}.name"), name.into()));
+            }
+        }
+
+        let mut directives = vec![];
+        for (i, directive) in list(schema, path, "directives")?.iter().enumerate() {
+            let path = format!("{path}.directives[{i}]");
+            directives.push(self.directive(object(directive, &path)?, &path, i)?);
+        }
+
+        for (path, name) in &self.references {
+            if !registry.types.contains_key(name.as_str()) {
+                return Err(FromIntrospectionError::UnknownType {
+                    path: path.clone(),
+                    name: name.to_string(),
+                });
+            }
+        }
+
+        let mut meta_fields = SchemaType::meta_fields(&mut registry);
+        match registry.types.get_mut(query_type_name.as_str()) {
+            Some(MetaType::Object(ObjectMeta { fields, .. })) => fields.append(&mut meta_fields),
+            _ => {
+                return Err(FromIntrospectionError::InvalidValue {
+                    path: format!("{path}.queryType"),
+                    expected: "an object type",
+                });
+            }
+        }
+
+        let mut schema = SchemaType {
+            description: opt_string(schema, path, "description")?.map(Into::into),
+            types: registry.types,
+            query_type_name,
+            mutation_type_name,
+            subscription_type_name,
+            directives: FnvHashMap::default(),
+            definition_order,
+            has_visibility_fns: false,
+        };
+
+        let defaults =
+            self.defaults
+                .into_iter()
+                .map(|pending| {
+                    let value = parse_default_value(&schema, &pending.raw, &pending.arg_type)
+                        .map_err(|message| FromIntrospectionError::InvalidDefaultValue {
+                            path: pending.path,
+                            message,
+                        })?;
+                    Ok((pending.owner, pending.argument, value))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+        for (owner, argument, value) in defaults {
+            let arguments = match owner {
+                ArgumentOwner::Field { type_name, field } => {
+                    match schema.types.get_mut(type_name.as_str()) {
+                        Some(
+                            MetaType::Object(ObjectMeta { fields, .. })
+                            | MetaType::Interface(InterfaceMeta { fields, .. }),
+                        ) => fields[field].arguments.as_mut(),
+                        _ => None,
+                    }
+                }
+                ArgumentOwner::InputObject { type_name } => {
+                    match schema.types.get_mut(type_name.as_str()) {
+                        Some(MetaType::InputObject(InputObjectMeta { input_fields, .. })) => {
+                            Some(input_fields)
+                        }
+                        _ => None,
+                    }
+                }
+                ArgumentOwner::Directive { index } => Some(&mut directives[index].arguments),
+            };
+            if let Some(arg) = arguments.and_then(|args| args.get_mut(argument)) {
+                arg.default_value = Some(value);
+            }
+        }
+
+        for directive in directives {
+            schema.add_directive(directive);
+        }
+        Ok(schema)
+    }
+
+    /// Converts the provided introspected named type into a [`MetaType`].
+    ///
+    /// Objects and interfaces get the `__typename` field, the same way as
+    /// [`Registry::build_object_type()`] and [`Registry::build_interface_type()`] do.
+    fn named_type<S: ScalarValue>(
+        &mut self,
+        ty: &Object<S>,
+        path: &str,
+        name: &str,
+        registry: &mut Registry<S>,
+    ) -> Result<MetaType<S>, FromIntrospectionError> {
+        let name = ArcStr::from(name);
+        let description = opt_string(ty, path, "description")?;
+        let mut meta = match string(ty, path, "kind")? {
+            "SCALAR" => {
+                let mut meta = ScalarMeta::new::<RemoteScalar>(name);
+                if let Some(url) = opt_string(ty, path, "specifiedByURL")? {
+                    meta = meta.specified_by_url(url);
+                }
+                meta.into_meta()
+            }
+            "OBJECT" => {
+                let mut fields = self.fields(ty, path, &name)?;
+                fields.push(registry.field::<String>(arcstr::literal!("__typename"), &()));
+                let interfaces = self.type_names(ty, path, "interfaces")?;
+                ObjectMeta::new(name, &fields)
+                    .interfaces(&interfaces)
+                    .into_meta()
+            }
+            "INTERFACE" => {
+                let mut fields = self.fields(ty, path, &name)?;
+                fields.push(registry.field::<String>(arcstr::literal!("__typename"), &()));
+                let interfaces = self.type_names(ty, path, "interfaces")?;
+                InterfaceMeta::new(name, &fields)
+                    .interfaces(&interfaces)
+                    .into_meta()
+            }
+            "UNION" => {
+                let members = self.type_names(ty, path, "possibleTypes")?;
+                UnionMeta::new(name, &members).into_meta()
+            }
+            "ENUM" => {
+                let values = list(ty, path, "enumValues")?
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| {
+                        let path = format!("{path}.enumValues[{i}]");
+                        let value = object(value, &path)?;
+                        let mut meta = EnumValue::new(string(value, &path, "name")?);
+                        meta.description = opt_string(value, &path, "description")?.map(Into::into);
+                        meta.deprecation_status = deprecation_status(value, &path)?;
+                        Ok(meta)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                EnumMeta::new::<RemoteEnum>(name, &values).into_meta()
+            }
+            "INPUT_OBJECT" => {
+                let fields =
+                    self.arguments(ty, path, "inputFields", || ArgumentOwner::InputObject {
+                        type_name: name.clone(),
+                    })?;
+                let mut meta = InputObjectMeta::new::<RemoteInputObject>(name, &fields);
+                if opt_bool(ty, path, "isOneOf")?.unwrap_or_default() {
+                    meta = meta.one_of();
+                }
+                meta.into_meta()
+            }
+            _ => {
+                return Err(FromIntrospectionError::InvalidValue {
+                    path: format!("{path}.kind"),
+                    expected: "a named type kind",
+                });
+            }
+        };
+        if let Some(description) = description {
+            set_description(&mut meta, description);
+        }
+        Ok(meta)
+    }
+
+    /// Converts the introspected fields of the provided object or interface type.
+    fn fields<S: ScalarValue>(
+        &mut self,
+        ty: &Object<S>,
+        path: &str,
+        type_name: &ArcStr,
+    ) -> Result<Vec<Field<S>>, FromIntrospectionError> {
+        list(ty, path, "fields")?
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let path = format!("{path}.fields[{i}]");
+                let field = object(field, &path)?;
+                let arguments = self.arguments(field, &path, "args", || ArgumentOwner::Field {
+                    type_name: type_name.clone(),
+                    field: i,
+                })?;
+                Ok(Field {
+                    name: string(field, &path, "name")?.into(),
+                    description: opt_string(field, &path, "description")?.map(Into::into),
+                    arguments: (!arguments.is_empty()).then_some(arguments),
+                    field_type: self
+                        .type_ref(required(field, &path, "type")?, format!("{path}.type"))?,
+                    deprecation_status: deprecation_status(field, &path)?,
+                    directives: vec![],
+                    visible: None,
+                })
+            })
+            .collect()
+    }
+
+    /// Converts the introspected input values stored in the `field` of the provided `owner`.
+    ///
+    /// Their default values are only recorded, to be parsed once all the types are known.
+    fn arguments<S: ScalarValue>(
+        &mut self,
+        owner: &Object<S>,
+        path: &str,
+        field: &str,
+        owner_of: impl Fn() -> ArgumentOwner,
+    ) -> Result<Vec<Argument<S>>, FromIntrospectionError> {
+        list(owner, path, field)?
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| {
+                let path = format!("{path}.{field}[{i}]");
+                let arg = object(arg, &path)?;
+                let arg_type =
+                    self.type_ref(required(arg, &path, "type")?, format!("{path}.type"))?;
+                if let Some(raw) = opt_string(arg, &path, "defaultValue")? {
+                    self.defaults.push(PendingDefault {
+                        owner: owner_of(),
+                        argument: i,
+                        arg_type: arg_type.clone(),
+                        raw: raw.into(),
+                        path: format!("{path}.defaultValue"),
+                    });
+                }
+                let mut meta = Argument::new(string(arg, &path, "name")?, arg_type);
+                meta.description = opt_string(arg, &path, "description")?.map(Into::into);
+                meta.deprecation_status = deprecation_status(arg, &path)?;
+                Ok(meta)
+            })
+            .collect()
+    }
+
+    /// Converts the provided introspected directive into a [`DirectiveType`].
+    fn directive<S: ScalarValue>(
+        &mut self,
+        directive: &Object<S>,
+        path: &str,
+        index: usize,
+    ) -> Result<DirectiveType<S>, FromIntrospectionError> {
+        let locations = list(directive, path, "locations")?
+            .iter()
+            .enumerate()
+            .map(|(i, location)| {
+                location
+                    .as_scalar()
+                    .and_then(ScalarValue::try_as_str)
+                    .and_then(|loc| {
+                        DirectiveLocation::from_input_value(&InputValue::<S>::enum_value(loc)).ok()
+                    })
+                    .ok_or_else(|| FromIntrospectionError::InvalidValue {
+                        path: format!("{path}.locations[{i}]"),
+                        expected: "a directive location",
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let arguments = self.arguments(directive, path, "args", || ArgumentOwner::Directive {
+            index,
+        })?;
+        let mut meta = DirectiveType::new(
+            string(directive, path, "name")?,
+            &locations,
+            &arguments,
+            opt_bool(directive, path, "isRepeatable")?.unwrap_or_default(),
+        );
+        if let Some(description) = opt_string(directive, path, "description")? {
+            meta = meta.description(description);
+        }
+        Ok(meta)
+    }
+
+    /// Converts the provided introspected type reference into a [`Type`].
+    fn type_ref<S: ScalarValue>(
+        &mut self,
+        value: &Value<S>,
+        path: String,
+    ) -> Result<Type, FromIntrospectionError> {
+        let ty = object(value, &path)?;
+        match string(ty, &path, "kind")? {
+            "NON_NULL" => {
+                let of_type = required(ty, &path, "ofType")?;
+                Ok(self
+                    .type_ref(of_type, format!("{path}.ofType"))?
+                    .wrap_non_null())
+            }
+            "LIST" => {
+                let of_type = required(ty, &path, "ofType")?;
+                Ok(self
+                    .type_ref(of_type, format!("{path}.ofType"))?
+                    .wrap_list(None))
+            }
+            _ => {
+                let name = ArcStr::from(string(ty, &path, "name")?);
+                self.references.push((format!("{path}.name"), name.clone()));
+                Ok(Type::nullable(name))
+            }
+        }
+    }
+
+    /// Returns the names of the types listed in the `field` of the provided type.
+    fn type_names<S: ScalarValue>(
+        &mut self,
+        ty: &Object<S>,
+        path: &str,
+        field: &str,
+    ) -> Result<Vec<Type>, FromIntrospectionError> {
+        list(ty, path, field)?
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| self.type_ref(ty, format!("{path}.{field}[{i}]")))
+            .collect()
+    }
+}
+
+/// Sets the `description` of the provided named [`MetaType`].
+fn set_description<S>(meta: &mut MetaType<S>, description: &str) {
+    let description = Some(description.into());
+    match meta {
+        MetaType::Enum(m) => m.description = description,
+        MetaType::InputObject(m) => m.description = description,
+        MetaType::Interface(m) => m.description = description,
+        MetaType::Object(m) => m.description = description,
+        MetaType::Scalar(m) => m.description = description,
+        MetaType::Union(m) => m.description = description,
+        MetaType::List(..) | MetaType::Nullable(..) | MetaType::Placeholder(..) => {}
+    }
+}
+
+/// Parses the provided `raw` default value of an [`Argument`] of the provided `arg_type`.
+fn parse_default_value<S: ScalarValue>(
+    schema: &SchemaType<S>,
+    raw: &str,
+    arg_type: &Type,
+) -> Result<InputValue<S>, String> {
+    let mut lexer = Lexer::new(raw);
+    let mut parser = Parser::new(&mut lexer).map_err(|e| e.item.to_string())?;
+    let value = parse_value_literal(&mut parser, true, schema, schema.lookup_type(arg_type))
+        .map_err(|e| e.item.to_string())?;
+    let next = parser.peek();
+    if next.item != Token::EndOfFile {
+        return Err(ParseError::unexpected_token(next.item).to_string());
+    }
+    Ok(value.item)
+}
+
+/// Returns the name of the root type stored in the `field` of the provided introspected schema.
+fn root_type_name<S: ScalarValue>(
+    schema: &Object<S>,
+    path: &str,
+    field: &str,
+) -> Result<Option<String>, FromIntrospectionError> {
+    match schema.get_field_value(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(ty) => {
+            let path = format!("{path}.{field}");
+            Ok(Some(string(object(ty, &path)?, &path, "name")?.into()))
+        }
+    }
+}
+
+/// Returns the [`DeprecationStatus`] of the provided introspected field, argument or enum value.
+fn deprecation_status<S: ScalarValue>(
+    value: &Object<S>,
+    path: &str,
+) -> Result<DeprecationStatus, FromIntrospectionError> {
+    Ok(
+        if opt_bool(value, path, "isDeprecated")?.unwrap_or_default() {
+            DeprecationStatus::Deprecated(
+                opt_string(value, path, "deprecationReason")?.map(Into::into),
+            )
+        } else {
+            DeprecationStatus::Current
+        },
+    )
+}
+
+fn object<'a, S>(value: &'a Value<S>, path: &str) -> Result<&'a Object<S>, FromIntrospectionError> {
+    value
+        .as_object_value()
+        .ok_or_else(|| FromIntrospectionError::InvalidValue {
+            path: path.into(),
+            expected: "an object",
+        })
+}
+
+fn required<'a, S>(
+    object: &'a Object<S>,
+    path: &str,
+    field: &str,
+) -> Result<&'a Value<S>, FromIntrospectionError> {
+    match object.get_field_value(field) {
+        None | Some(Value::Null) => Err(FromIntrospectionError::MissingField {
+            path: join(path, field),
+        }),
+        Some(v) => Ok(v),
+    }
+}
+
+fn list<'a, S>(
+    object: &'a Object<S>,
+    path: &str,
+    field: &str,
+) -> Result<&'a [Value<S>], FromIntrospectionError> {
+    match object.get_field_value(field) {
+        None | Some(Value::Null) => Ok(&[]),
+        Some(v) => v.as_list_value().map(Vec::as_slice).ok_or_else(|| {
+            FromIntrospectionError::InvalidValue {
+                path: join(path, field),
+                expected: "a list",
+            }
+        }),
+    }
+}
+
+fn string<'a, S: ScalarValue>(
+    object: &'a Object<S>,
+    path: &str,
+    field: &str,
+) -> Result<&'a str, FromIntrospectionError> {
+    opt_string(object, path, field)?.ok_or_else(|| FromIntrospectionError::MissingField {
+        path: join(path, field),
+    })
+}
+
+fn opt_string<'a, S: ScalarValue>(
+    object: &'a Object<S>,
+    path: &str,
+    field: &str,
+) -> Result<Option<&'a str>, FromIntrospectionError> {
+    match object.get_field_value(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(v) => v
+            .as_scalar()
+            .and_then(ScalarValue::try_as_str)
+            .map(Some)
+            .ok_or_else(|| FromIntrospectionError::InvalidValue {
+                path: join(path, field),
+                expected: "a string",
+            }),
+    }
+}
+
+fn opt_bool<S: ScalarValue>(
+    object: &Object<S>,
+    path: &str,
+    field: &str,
+) -> Result<Option<bool>, FromIntrospectionError> {
+    match object.get_field_value(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(v) => v
+            .as_scalar()
+            .and_then(ScalarValue::try_to_bool)
+            .map(Some)
+            .ok_or_else(|| FromIntrospectionError::InvalidValue {
+                path: join(path, field),
+                expected: "a boolean",
+            }),
+    }
+}
+
+fn join(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.into()
+    } else {
+        format!("{path}.{field}")
+    }
+}
+
+/// Custom scalar of a remote schema, accepting any input, as its actual coercion rules are unknown.
+struct RemoteScalar;
+
+impl<S> FromInputValue<S> for RemoteScalar {
+    type Error = Infallible;
+
+    fn from_input_value(_: &InputValue<S>) -> Result<Self, Self::Error> {
+        Ok(Self)
+    }
+}
+
+impl<S: ScalarValue> ParseScalarValue<S> for RemoteScalar {
+    fn from_str(token: ScalarToken<'_>) -> ParseScalarResult<S> {
+        match token {
+            ScalarToken::String(_) => <String as ParseScalarValue<S>>::from_str(token),
+            ScalarToken::Int(_) => <i32 as ParseScalarValue<S>>::from_str(token)
+                .or_else(|_| <f64 as ParseScalarValue<S>>::from_str(token)),
+            ScalarToken::Float(_) => <f64 as ParseScalarValue<S>>::from_str(token),
+        }
+    }
+}
+
+/// Enum of a remote schema, accepting any enum value, as the membership of its values is checked
+/// against its [`EnumMeta`] separately.
+struct RemoteEnum;
+
+impl<S: ScalarValue> FromInputValue<S> for RemoteEnum {
+    type Error = &'static str;
+
+    fn from_input_value(v: &InputValue<S>) -> Result<Self, Self::Error> {
+        match v {
+            InputValue::Enum(_) => Ok(Self),
+            InputValue::Scalar(s) if s.try_as_str().is_some() => Ok(Self),
+            _ => Err("Expected enum value"),
+        }
+    }
+}
+
+/// Input object of a remote schema, accepting any input, as its fields are checked against its
+/// [`InputObjectMeta`] separately.
+struct RemoteInputObject;
+
+impl<S> FromInputValue<S> for RemoteInputObject {
+    type Error = Infallible;
+
+    fn from_input_value(_: &InputValue<S>) -> Result<Self, Self::Error> {
+        Ok(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        DefaultScalarValue, EmptySubscription, GraphQLEnum, GraphQLError, GraphQLInputObject,
+        GraphQLInterface, GraphQLObject, GraphQLScalar, GraphQLUnion, ID, IntrospectionFormat,
+        IntrospectionOptions, RootNode, graphql_object, introspect,
+        schema::{diff, model::SchemaType},
+        validate,
+    };
+
+    use super::FromIntrospectionError;
+
+    /// Identifier of a user.
+    #[derive(GraphQLScalar)]
+    #[graphql(transparent, specified_by_url = "https://example.com/user-id")]
+    struct UserId(String);
+
+    #[derive(GraphQLEnum)]
+    enum Role {
+        Admin,
+        #[graphql(deprecated = "Use `Admin` instead.")]
+        Root,
+        Guest,
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct Page {
+        #[graphql(default = 10)]
+        size: i32,
+        #[graphql(default = vec![Role::Admin, Role::Guest])]
+        roles: Vec<Role>,
+    }
+
+    #[derive(GraphQLInputObject)]
+    enum UserBy {
+        Id(UserId),
+        Name(String),
+    }
+
+    #[derive(GraphQLInterface)]
+    #[graphql(for = [User, Bot])]
+    struct Node {
+        id: UserId,
+    }
+
+    /// Human user.
+    #[derive(GraphQLObject)]
+    #[graphql(impl = NodeValue)]
+    struct User {
+        id: UserId,
+        role: Role,
+        #[graphql(deprecated)]
+        login: Option<String>,
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(impl = NodeValue)]
+    struct Bot {
+        id: UserId,
+        rate: f64,
+    }
+
+    #[expect(dead_code, reason = "GraphQL schema testing")]
+    #[derive(GraphQLUnion)]
+    enum Actor {
+        User(User),
+        Bot(Bot),
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn users(
+            #[graphql(default = Page { size: 5, roles: vec![Role::Guest] })] page: Page,
+            #[graphql(default = "usr")] prefix: String,
+        ) -> Vec<User> {
+            _ = (page, prefix);
+            vec![]
+        }
+
+        fn user(by: UserBy) -> Option<User> {
+            _ = by;
+            None
+        }
+
+        fn actors(ratio: Option<f64>) -> Vec<Actor> {
+            _ = ratio;
+            vec![]
+        }
+
+        fn nodes(ids: Vec<Option<UserId>>) -> Vec<NodeValue> {
+            _ = ids;
+            vec![]
+        }
+    }
+
+    struct Mutation;
+
+    #[graphql_object]
+    impl Mutation {
+        fn set_role(id: ID, role: Role) -> bool {
+            _ = (id, role);
+            true
+        }
+    }
+
+    type Schema = RootNode<Query, Mutation, EmptySubscription>;
+
+    fn root() -> Schema {
+        Schema::new(Query, Mutation, EmptySubscription::new())
+    }
+
+    fn rebuilt(format: IntrospectionFormat) -> SchemaType<DefaultScalarValue> {
+        let (value, errors) = introspect(&root(), &(), format).unwrap();
+        assert!(errors.is_empty(), "{errors:?}");
+        SchemaType::from_introspection(&value).unwrap()
+    }
+
+    #[test]
+    fn rebuilds_identical_schema() {
+        for format in [
+            IntrospectionFormat::All,
+            IntrospectionFormat::WithoutDescriptions,
+        ] {
+            let changes = diff(&root().schema, &rebuilt(format));
+            assert!(changes.is_empty(), "{changes:?}");
+        }
+    }
+
+    #[test]
+    fn rebuilds_from_reduced_introspection() {
+        let schema = rebuilt(IntrospectionOptions::default().descriptions(false).into());
+
+        assert!(schema.concrete_type_by_name("Query").is_some());
+    }
+
+    #[cfg(feature = "schema-language")]
+    #[test]
+    fn rebuilds_identical_sdl() {
+        use crate::schema::translate::{
+            SchemaTranslator as _,
+            graphql_parser::{GraphQLParserTranslator, sort_schema_document},
+        };
+
+        let rebuilt = rebuilt(IntrospectionFormat::All);
+        let mut doc: graphql_parser::schema::Document<'_, &str> =
+            GraphQLParserTranslator::translate_schema(&rebuilt);
+        sort_schema_document(&mut doc);
+
+        assert_eq!(doc.to_string(), root().as_sdl());
+    }
+
+    #[test]
+    fn accepts_whole_response() {
+        let (value, _) = introspect(&root(), &(), IntrospectionFormat::All).unwrap();
+        let mut response = crate::Object::with_capacity(1);
+        response.add_field("data", value);
+
+        let rebuilt = SchemaType::from_introspection(&crate::Value::object(response)).unwrap();
+
+        assert!(diff(&root().schema, &rebuilt).is_empty());
+    }
+
+    #[test]
+    fn validates_documents() {
+        let schema = rebuilt(IntrospectionFormat::All);
+
+        assert_eq!(
+            validate(
+                r#"
+                    query Q($by: UserBy!) {
+                        users(page: {size: 3, roles: [GUEST]}) { id role }
+                        user(by: $by) { ...on Node { id } }
+                        actors(ratio: 1) { __typename ...on Bot { rate } }
+                        nodes(ids: ["a", null]) { id }
+                        __schema { queryType { name } }
+                    }
+                    mutation M { setRole(id: 1, role: ADMIN) }
+                "#,
+                &schema,
+            ),
+            Ok(()),
+        );
+
+        for invalid in [
+            "{ users(page: {roles: [OWNER]}) { id } }",
+            "{ users(page: {size: \"3\"}) { id } }",
+            "{ users { name } }",
+            "{ actors { id } }",
+            "{ user(by: {id: \"1\", name: \"a\"}) { id } }",
+            "mutation { setRole(id: 1, role: \"ADMIN\") }",
+        ] {
+            assert!(
+                matches!(
+                    validate(invalid, &schema),
+                    Err(GraphQLError::ValidationError(_))
+                ),
+                "`{invalid}` should be invalid",
+            );
+        }
+    }
+
+    #[test]
+    fn adds_typename_to_objects_and_interfaces() {
+        let schema = rebuilt(IntrospectionFormat::All);
+
+        for name in ["User", "Node"] {
+            let field = schema
+                .concrete_type_by_name(name)
+                .and_then(|t| t.field_by_name("__typename"))
+                .unwrap_or_else(|| panic!("no `{name}.__typename` field"));
+            assert_eq!(field.field_type.to_string(), "String!");
+        }
+
+        assert_eq!(
+            validate(
+                "{ users { __typename } nodes(ids: []) { __typename id } }",
+                &schema,
+            ),
+            Ok(()),
+        );
+    }
+
+    #[test]
+    fn reports_invalid_introspection() {
+        assert_eq!(
+            SchemaType::<DefaultScalarValue>::from_introspection(&graphql_value!({})).unwrap_err(),
+            FromIntrospectionError::MissingField {
+                path: "__schema".into(),
+            },
+        );
+        assert_eq!(
+            SchemaType::<DefaultScalarValue>::from_introspection(&graphql_value!({
+                "__schema": {"queryType": {"name": "Query"}, "types": [], "directives": []},
+            }))
+            .unwrap_err(),
+            FromIntrospectionError::UnknownType {
+                path: "__schema.queryType.name".into(),
+                name: "Query".into(),
+            },
+        );
+        assert_eq!(
+            SchemaType::<DefaultScalarValue>::from_introspection(&graphql_value!({
+                "__schema": {
+                    "queryType": {"name": "Query"},
+                    "types": [{
+                        "kind": "OBJECT",
+                        "name": "Query",
+                        "fields": [{
+                            "name": "user",
+                            "args": [],
+                            "type": {"kind": "LIST", "ofType": {"kind": "OBJECT", "name": "User"}},
+                        }],
+                    }],
+                },
+            }))
+            .unwrap_err(),
+            FromIntrospectionError::UnknownType {
+                path: "__schema.types[Query].fields[0].type.ofType.name".into(),
+                name: "User".into(),
+            },
+        );
+        assert_eq!(
+            SchemaType::<DefaultScalarValue>::from_introspection(&graphql_value!({
+                "__schema": {
+                    "queryType": {"name": "Query"},
+                    "types": [{
+                        "kind": "OBJECT",
+                        "name": "Query",
+                        "fields": [{
+                            "name": "echo",
+                            "args": [{
+                                "name": "text",
+                                "type": {"kind": "SCALAR", "name": "String"},
+                                "defaultValue": "\"unterminated",
+                            }],
+                            "type": {"kind": "SCALAR", "name": "String"},
+                        }],
+                    }, {
+                        "kind": "SCALAR",
+                        "name": "String",
+                    }],
+                },
+            }))
+            .unwrap_err()
+            .to_string(),
+            "`__schema.types[Query].fields[0].args[0].defaultValue` has invalid default value: \
+             Unterminated string literal",
+        );
+    }
+}
@@ -1,6 +1,6 @@
 //! Types used to describe a `GraphQL` schema
 
-use std::borrow::ToOwned;
+use std::{any::Any, borrow::ToOwned};
 
 use arcstr::ArcStr;
 use derive_more::with_trait::Debug;
@@ -42,6 +42,47 @@ impl DeprecationStatus {
     }
 }
 
+/// [Directive][0] applied to a schema element (type, field, argument, etc.), like
+/// `@tag(name: "public")`.
+///
+/// Applied directives are not exposed via introspection, but are printed in SDL and may be
+/// inspected at runtime via [`MetaType::directives()`] and the `directives` of [`Field`],
+/// [`Argument`] and [`EnumValue`].
+///
+/// [0]: https://spec.graphql.org/September2025#sec-Type-System.Directives
+#[derive(Clone, Debug, PartialEq)]
+pub struct AppliedDirective {
+    /// Name of this directive (without the leading `@`).
+    pub name: ArcStr,
+
+    /// Arguments of this directive, in the order they were specified.
+    pub arguments: Vec<(ArcStr, InputValue)>,
+}
+
+impl AppliedDirective {
+    /// Constructs a new [`AppliedDirective`] with the provided `name` and no arguments.
+    pub fn new(name: impl Into<ArcStr>) -> Self {
+        Self {
+            name: name.into(),
+            arguments: vec![],
+        }
+    }
+
+    /// Adds an argument with the provided `name` and `value` to this [`AppliedDirective`].
+    #[must_use]
+    pub fn argument(mut self, name: impl Into<ArcStr>, value: InputValue) -> Self {
+        self.arguments.push((name.into(), value));
+        self
+    }
+
+    /// Returns the value of the argument with the provided `name`, if it was specified.
+    pub fn argument_value(&self, name: &str) -> Option<&InputValue> {
+        self.arguments
+            .iter()
+            .find_map(|(n, v)| (n == name).then_some(v))
+    }
+}
+
 /// Scalar type metadata
 #[derive(Debug)]
 pub struct ScalarMeta<S> {
@@ -51,6 +92,8 @@ pub struct ScalarMeta<S> {
     pub description: Option<ArcStr>,
     #[doc(hidden)]
     pub specified_by_url: Option<ArcStr>,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective>,
     #[debug(ignore)]
     pub(crate) try_parse_fn: InputValueParseFn<S>,
     #[debug(ignore)]
@@ -68,6 +111,7 @@ impl<S> ScalarMeta<S> {
             name: name.into(),
             description: None,
             specified_by_url: None,
+            directives: vec![],
             try_parse_fn: try_parse_fn::<S, T>,
             parse_fn: <T as ParseScalarValue<S>>::from_str,
         }
@@ -93,6 +137,13 @@ impl<S> ScalarMeta<S> {
         self
     }
 
+    /// Applies the provided [`AppliedDirective`] to this [`ScalarMeta`] type.
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
     /// Wraps this [`ScalarMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<S> {
         MetaType::Scalar(self)
@@ -105,6 +156,18 @@ pub type InputValueParseFn<S> = for<'b> fn(&'b InputValue<S>) -> Result<(), Fiel
 /// Shortcut for a [`ScalarToken`] parsing function.
 pub type ScalarTokenParseFn<S> = for<'b> fn(ScalarToken<'b>) -> Result<S, ParseError>;
 
+/// Predicate deciding whether a schema element is visible for a request, given its context.
+///
+/// The request context is passed type-erased, so the predicate should [downcast][0] it to the
+/// expected context type, returning [`None`] if it's of another type. Whenever the request context
+/// is not available (e.g. in [`validate()`][1]) or is not accepted by the predicate, a
+/// [`VisibilityError`][2] is returned instead of executing or validating an operation.
+///
+/// [0]: https://doc.rust-lang.org/std/any/trait.Any.html#method.downcast_ref
+/// [1]: crate::validate
+/// [2]: crate::schema::VisibilityError
+pub type VisibilityFn = for<'c> fn(&'c dyn Any) -> Option<bool>;
+
 /// List type metadata
 #[derive(Debug)]
 pub struct ListMeta {
@@ -163,6 +226,10 @@ pub struct ObjectMeta<S> {
     pub fields: Vec<Field<S>>,
     #[doc(hidden)]
     pub interface_names: Vec<ArcStr>,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective>,
+    #[doc(hidden)]
+    pub visible: Option<VisibilityFn>,
 }
 
 impl<S> ObjectMeta<S> {
@@ -176,6 +243,8 @@ impl<S> ObjectMeta<S> {
             description: None,
             fields: fields.to_vec(),
             interface_names: vec![],
+            directives: vec![],
+            visible: None,
         }
     }
 
@@ -200,6 +269,23 @@ impl<S> ObjectMeta<S> {
         self
     }
 
+    /// Applies the provided [`AppliedDirective`] to this [`ObjectMeta`] type.
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
+    /// Sets the [`VisibilityFn`] of this [`ObjectMeta`] type, hiding it from the requests it returns
+    /// `false` for.
+    ///
+    /// Overwrites any previously set [`VisibilityFn`].
+    #[must_use]
+    pub fn visible(mut self, predicate: VisibilityFn) -> Self {
+        self.visible = Some(predicate);
+        self
+    }
+
     /// Wraps this [`ObjectMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<S> {
         MetaType::Object(self)
@@ -215,6 +301,8 @@ pub struct EnumMeta<S> {
     pub description: Option<ArcStr>,
     #[doc(hidden)]
     pub values: Vec<EnumValue>,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective>,
     #[debug(ignore)]
     pub(crate) try_parse_fn: InputValueParseFn<S>,
 }
@@ -230,6 +318,7 @@ impl<S> EnumMeta<S> {
             name: name.into(),
             description: None,
             values: values.to_owned(),
+            directives: vec![],
             try_parse_fn: try_parse_fn::<S, T>,
         }
     }
@@ -243,6 +332,13 @@ impl<S> EnumMeta<S> {
         self
     }
 
+    /// Applies the provided [`AppliedDirective`] to this [`EnumMeta`] type.
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
     /// Wraps this [`EnumMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<S> {
         MetaType::Enum(self)
@@ -260,6 +356,10 @@ pub struct InterfaceMeta<S> {
     pub fields: Vec<Field<S>>,
     #[doc(hidden)]
     pub interface_names: Vec<ArcStr>,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective>,
+    #[doc(hidden)]
+    pub visible: Option<VisibilityFn>,
 }
 
 impl<S> InterfaceMeta<S> {
@@ -273,6 +373,8 @@ impl<S> InterfaceMeta<S> {
             description: None,
             fields: fields.to_vec(),
             interface_names: Vec::new(),
+            directives: vec![],
+            visible: None,
         }
     }
 
@@ -297,6 +399,23 @@ impl<S> InterfaceMeta<S> {
         self
     }
 
+    /// Applies the provided [`AppliedDirective`] to this [`InterfaceMeta`] type.
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
+    /// Sets the [`VisibilityFn`] of this [`InterfaceMeta`] type, hiding it from the requests it returns
+    /// `false` for.
+    ///
+    /// Overwrites any previously set [`VisibilityFn`].
+    #[must_use]
+    pub fn visible(mut self, predicate: VisibilityFn) -> Self {
+        self.visible = Some(predicate);
+        self
+    }
+
     /// Wraps this [`InterfaceMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<S> {
         MetaType::Interface(self)
@@ -312,6 +431,10 @@ pub struct UnionMeta {
     pub description: Option<ArcStr>,
     #[doc(hidden)]
     pub of_type_names: Vec<ArcStr>,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective>,
+    #[doc(hidden)]
+    pub visible: Option<VisibilityFn>,
 }
 
 impl UnionMeta {
@@ -321,6 +444,8 @@ impl UnionMeta {
             name: name.into(),
             description: None,
             of_type_names: of_types.iter().map(|t| t.innermost_name().into()).collect(),
+            directives: vec![],
+            visible: None,
         }
     }
 
@@ -333,6 +458,23 @@ impl UnionMeta {
         self
     }
 
+    /// Applies the provided [`AppliedDirective`] to this [`UnionMeta`] type.
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
+    /// Sets the [`VisibilityFn`] of this [`UnionMeta`] type, hiding it from the requests it returns
+    /// `false` for.
+    ///
+    /// Overwrites any previously set [`VisibilityFn`].
+    #[must_use]
+    pub fn visible(mut self, predicate: VisibilityFn) -> Self {
+        self.visible = Some(predicate);
+        self
+    }
+
     /// Wraps this [`UnionMeta`] type into a generic [`MetaType`].
     pub fn into_meta<S>(self) -> MetaType<S> {
         MetaType::Union(self)
@@ -350,6 +492,8 @@ pub struct InputObjectMeta<S> {
     pub input_fields: Vec<Argument<S>>,
     #[doc(hidden)]
     pub is_one_of: bool,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective>,
     #[debug(ignore)]
     pub(crate) try_parse_fn: InputValueParseFn<S>,
 }
@@ -367,6 +511,7 @@ impl<S> InputObjectMeta<S> {
             description: None,
             input_fields: input_fields.to_vec(),
             is_one_of: false,
+            directives: vec![],
             try_parse_fn: try_parse_fn::<S, T>,
         }
     }
@@ -389,6 +534,13 @@ impl<S> InputObjectMeta<S> {
         self
     }
 
+    /// Applies the provided [`AppliedDirective`] to this [`InputObjectMeta`] type.
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
     /// Wraps this [`InputObjectMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<S> {
         MetaType::InputObject(self)
@@ -418,6 +570,10 @@ pub struct Field<S> {
     pub field_type: Type,
     #[doc(hidden)]
     pub deprecation_status: DeprecationStatus,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective>,
+    #[doc(hidden)]
+    pub visible: Option<VisibilityFn>,
 }
 
 impl<S> Field<S> {
@@ -461,6 +617,23 @@ impl<S> Field<S> {
         self.deprecation_status = DeprecationStatus::Deprecated(reason.map(Into::into));
         self
     }
+
+    /// Applies the provided [`AppliedDirective`] to this [`Field`].
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
+    /// Sets the [`VisibilityFn`] of this [`Field`], hiding it from the requests it returns
+    /// `false` for.
+    ///
+    /// Overwrites any previously set [`VisibilityFn`].
+    #[must_use]
+    pub fn visible(mut self, predicate: VisibilityFn) -> Self {
+        self.visible = Some(predicate);
+        self
+    }
 }
 
 /// Metadata for an argument to a field
@@ -476,6 +649,8 @@ pub struct Argument<S> {
     pub default_value: Option<InputValue<S>>,
     #[doc(hidden)]
     pub deprecation_status: DeprecationStatus,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective>,
 }
 
 impl<S> Argument<S> {
@@ -487,6 +662,7 @@ impl<S> Argument<S> {
             arg_type,
             default_value: None,
             deprecation_status: DeprecationStatus::Current,
+            directives: vec![],
         }
     }
 
@@ -523,6 +699,13 @@ impl<S> Argument<S> {
         self.deprecation_status = DeprecationStatus::Deprecated(reason.map(Into::into));
         self
     }
+
+    /// Applies the provided [`AppliedDirective`] to this [`Argument`].
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective) -> Self {
+        self.directives.push(directive);
+        self
+    }
 }
 
 /// Metadata for a single value in an enum
@@ -541,6 +724,9 @@ pub struct EnumValue {
 
     /// Whether the field is deprecated or not, with an optional reason.
     pub deprecation_status: DeprecationStatus,
+
+    /// Directives applied to this enum value.
+    pub directives: Vec<AppliedDirective>,
 }
 
 impl EnumValue {
@@ -550,6 +736,7 @@ impl EnumValue {
             name: name.into(),
             description: None,
             deprecation_status: DeprecationStatus::Current,
+            directives: vec![],
         }
     }
 
@@ -570,6 +757,13 @@ impl EnumValue {
         self.deprecation_status = DeprecationStatus::Deprecated(reason.map(Into::into));
         self
     }
+
+    /// Applies the provided [`AppliedDirective`] to this [`EnumValue`].
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective) -> Self {
+        self.directives.push(directive);
+        self
+    }
 }
 
 /// Generic type metadata
@@ -649,6 +843,40 @@ impl<S> MetaType<S> {
         }
     }
 
+    /// Returns the [`AppliedDirective`]s of the represented type.
+    ///
+    /// [Lists][`ListMeta`], [`null`ables][`NullableMeta`] and [placeholders][`PlaceholderMeta`]
+    /// don't have directives.
+    pub fn directives(&self) -> &[AppliedDirective] {
+        match self {
+            Self::Enum(EnumMeta { directives, .. })
+            | Self::InputObject(InputObjectMeta { directives, .. })
+            | Self::Interface(InterfaceMeta { directives, .. })
+            | Self::Object(ObjectMeta { directives, .. })
+            | Self::Scalar(ScalarMeta { directives, .. })
+            | Self::Union(UnionMeta { directives, .. }) => directives,
+            Self::List(..) | Self::Nullable(..) | Self::Placeholder(..) => &[],
+        }
+    }
+
+    /// Returns the [`VisibilityFn`] of the represented type, if any.
+    ///
+    /// Only [objects][`ObjectMeta`], [interfaces][`InterfaceMeta`] and [unions][`UnionMeta`] can
+    /// have a [`VisibilityFn`].
+    pub fn visibility_fn(&self) -> Option<VisibilityFn> {
+        match self {
+            Self::Interface(InterfaceMeta { visible, .. })
+            | Self::Object(ObjectMeta { visible, .. })
+            | Self::Union(UnionMeta { visible, .. }) => *visible,
+            Self::Enum(..)
+            | Self::InputObject(..)
+            | Self::List(..)
+            | Self::Nullable(..)
+            | Self::Placeholder(..)
+            | Self::Scalar(..) => None,
+        }
+    }
+
     /// Construct a [`TypeKind`] out of this [`MetaType`].
     ///
     /// # Panics
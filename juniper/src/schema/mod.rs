@@ -1,5 +1,23 @@
+//! GraphQL schema definition and tooling.
+
+mod coordinate;
+mod diff;
+mod from_introspection;
 pub mod meta;
-pub mod model;
+pub(crate) mod model;
 #[expect(clippy::module_inception, reason = "intended")]
-pub mod schema;
-pub mod translate;
+pub(crate) mod schema;
+pub(crate) mod translate;
+mod type_system;
+mod usage;
+pub(crate) mod visibility;
+
+pub use self::{
+    coordinate::SchemaElement,
+    diff::{Criticality, SchemaChange, SchemaChangeKind, diff},
+    from_introspection::FromIntrospectionError,
+    translate::sdl::{DescriptionStyle, SdlOptions, SdlOrder},
+    type_system::SchemaError,
+    usage::{SchemaUsage, UsageCounter, UsageSink},
+    visibility::VisibilityError,
+};
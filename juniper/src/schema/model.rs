@@ -1,16 +1,25 @@
-use std::ptr;
+use std::{any::Any, borrow::Borrow, collections::BTreeMap, ptr, sync::Arc};
 
 use arcstr::ArcStr;
 use derive_more::with_trait::Display;
-use fnv::FnvHashMap;
+use fnv::{FnvHashMap, FnvHashSet};
 #[cfg(feature = "schema-language")]
 use graphql_parser::schema::Document;
 
 use crate::{
     GraphQLEnum,
-    ast::{Type, TypeModifier},
+    ast::{Document as AstDocument, InputValue, Operation, Type, TypeModifier},
     executor::{Context, Registry},
-    schema::meta::{Argument, InterfaceMeta, MetaType, ObjectMeta, PlaceholderMeta, UnionMeta},
+    schema::meta::{
+        AppliedDirective, Argument, EnumMeta, Field, InputObjectMeta, InterfaceMeta, MetaType,
+        ObjectMeta, PlaceholderMeta, UnionMeta,
+    },
+    schema::{
+        translate::sdl::SdlOptions,
+        type_system::SchemaError,
+        usage::{SchemaUsage, UsageSink},
+        visibility::{Visibility, VisibilityError},
+    },
     types::{base::GraphQLType, name::Name},
     value::{DefaultScalarValue, ScalarValue},
 };
@@ -44,6 +53,11 @@ pub struct RootNode<
     pub schema: SchemaType<S>,
     #[doc(hidden)]
     pub introspection_disabled: bool,
+    #[doc(hidden)]
+    pub validation_errors_limit: Option<usize>,
+    #[doc(hidden)]
+    pub context_as_any: Option<for<'c> fn(&'c QueryT::Context) -> &'c dyn Any>,
+    usage_sink: Option<Arc<dyn UsageSink>>,
 }
 
 impl<QueryT, MutationT, SubscriptionT>
@@ -58,6 +72,23 @@ where
     pub fn new(query: QueryT, mutation: MutationT, subscription: SubscriptionT) -> Self {
         Self::new_with_info(query, mutation, subscription, (), (), ())
     }
+
+    /// Constructs a new [`RootNode`] from `query`, `mutation` and `subscription` nodes,
+    /// parametrizing it with a [`DefaultScalarValue`] and performing the
+    /// [type system validation][0] of its schema.
+    ///
+    /// # Errors
+    ///
+    /// With all the [`SchemaError`]s found, if the schema is not valid.
+    ///
+    /// [0]: https://spec.graphql.org/September2025#sec-Type-System
+    pub fn try_new(
+        query: QueryT,
+        mutation: MutationT,
+        subscription: SubscriptionT,
+    ) -> Result<Self, Vec<SchemaError>> {
+        Self::try_new_with_info(query, mutation, subscription, (), (), ())
+    }
 }
 
 impl<QueryT, MutationT, SubscriptionT, S> RootNode<QueryT, MutationT, SubscriptionT, S>
@@ -109,9 +140,103 @@ where
             mutation_info,
             subscription_info,
             introspection_disabled: false,
+            validation_errors_limit: None,
+            context_as_any: None,
+            usage_sink: None,
         }
     }
 
+    /// Constructs a new [`RootNode`] from query, mutation and subscription nodes along with their
+    /// type info objects, performing the [type system validation][0] of its schema.
+    ///
+    /// Prefer this over [`RootNode::new_with_info()`] for schemas built dynamically from
+    /// [`GraphQLType::TypeInfo`], as their inconsistencies cannot be caught at compile time.
+    ///
+    /// # Errors
+    ///
+    /// With all the [`SchemaError`]s found, if the schema is not valid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use juniper::{
+    /// #     graphql_object, schema::SchemaError, EmptyMutation, EmptySubscription, GraphQLObject,
+    /// #     RootNode,
+    /// # };
+    /// #
+    /// mod admin {
+    ///     #[derive(juniper::GraphQLObject)]
+    ///     pub struct User {
+    ///         pub login: String,
+    ///     }
+    /// }
+    ///
+    /// #[derive(GraphQLObject)]
+    /// struct User {
+    ///     name: String,
+    /// }
+    ///
+    /// struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn admin() -> admin::User {
+    ///         admin::User { login: "root".into() }
+    ///     }
+    ///
+    ///     fn user() -> User {
+    ///         User { name: "Alice".into() }
+    ///     }
+    /// }
+    ///
+    /// type Schema = RootNode<Query, EmptyMutation, EmptySubscription>;
+    ///
+    /// let Err(errors) = Schema::try_new_with_info(
+    ///     Query,
+    ///     EmptyMutation::<()>::new(),
+    ///     EmptySubscription::<()>::new(),
+    ///     (),
+    ///     (),
+    ///     (),
+    /// )
+    /// else {
+    ///     panic!("schema is expected to be invalid");
+    /// };
+    ///
+    /// assert!(matches!(
+    ///     errors.as_slice(),
+    ///     [SchemaError::TypeNameCollision { name, .. }] if name == "User",
+    /// ));
+    /// ```
+    ///
+    /// [0]: https://spec.graphql.org/September2025#sec-Type-System
+    pub fn try_new_with_info(
+        query_obj: QueryT,
+        mutation_obj: MutationT,
+        subscription_obj: SubscriptionT,
+        query_info: QueryT::TypeInfo,
+        mutation_info: MutationT::TypeInfo,
+        subscription_info: SubscriptionT::TypeInfo,
+    ) -> Result<Self, Vec<SchemaError>> {
+        Ok(Self {
+            query_type: query_obj,
+            mutation_type: mutation_obj,
+            subscription_type: subscription_obj,
+            schema: SchemaType::try_new::<QueryT, MutationT, SubscriptionT>(
+                &query_info,
+                &mutation_info,
+                &subscription_info,
+            )?,
+            query_info,
+            mutation_info,
+            subscription_info,
+            introspection_disabled: false,
+            validation_errors_limit: None,
+            context_as_any: None,
+            usage_sink: None,
+        })
+    }
+
     /// Disables introspection for this [`RootNode`], making it to return a [`FieldError`] whenever
     /// its `__schema` or `__type` field is resolved.
     ///
@@ -167,6 +292,224 @@ where
         self
     }
 
+    /// Limits the number of validation errors reported for a single operation by this
+    /// [`RootNode`] to the provided `limit`, or removes the limit if [`None`] is provided.
+    ///
+    /// Once the `limit` is reached, the validation is aborted and a "too many validation errors"
+    /// error is appended, bounding the time spent on validating (and the size of the response
+    /// for) a malicious document containing a huge number of errors.
+    ///
+    /// By default, the number of validation errors is not limited.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use juniper::{
+    /// #     graphql_object, graphql_vars, EmptyMutation, EmptySubscription, GraphQLError,
+    /// #     RootNode,
+    /// # };
+    /// #
+    /// pub struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn some() -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// type Schema = RootNode<Query, EmptyMutation, EmptySubscription>;
+    ///
+    /// let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    ///     .validation_errors_limit(Some(2));
+    ///
+    /// # // language=GraphQL
+    /// let query = "query { a b c d }";
+    ///
+    /// match juniper::execute_sync(query, None, &schema, &graphql_vars! {}, &()) {
+    ///     Err(GraphQLError::ValidationError(errs)) => {
+    ///         assert_eq!(errs.len(), 3);
+    ///         assert_eq!(
+    ///             errs.last().unwrap().message(),
+    ///             "Too many validation errors, error limit reached. Validation aborted",
+    ///         );
+    ///     }
+    ///     res => panic!("expected `ValidationError`, returned: {res:#?}"),
+    /// }
+    /// ```
+    pub fn validation_errors_limit(mut self, limit: Option<usize>) -> Self {
+        self.validation_errors_limit = limit;
+        self
+    }
+
+    /// Enables evaluating [`VisibilityFn`]s (e.g. specified via `#[graphql(visible = ...)]`
+    /// attribute) of this [`RootNode`] against the request context, so the hidden fields and types
+    /// disappear from both introspection and validation of the request.
+    ///
+    /// Until enabled, executing any request against a [`RootNode`] having [`VisibilityFn`]s fails
+    /// with a [`GraphQLError::VisibilityError`], as the request context cannot be passed to them.
+    ///
+    /// [`GraphQLError::VisibilityError`]: crate::GraphQLError::VisibilityError
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use juniper::{
+    /// #     graphql_object, graphql_vars, EmptyMutation, EmptySubscription, GraphQLError,
+    /// #     RootNode,
+    /// # };
+    /// #
+    /// struct Context {
+    ///     is_internal: bool,
+    /// }
+    ///
+    /// impl juniper::Context for Context {}
+    ///
+    /// fn is_internal(ctx: &Context) -> bool {
+    ///     ctx.is_internal
+    /// }
+    ///
+    /// struct Query;
+    ///
+    /// #[graphql_object]
+    /// #[graphql(context = Context)]
+    /// impl Query {
+    ///     fn version() -> &'static str {
+    ///         "1.0"
+    ///     }
+    ///
+    ///     #[graphql(visible = is_internal)]
+    ///     fn build_host() -> &'static str {
+    ///         "ci-42"
+    ///     }
+    /// }
+    ///
+    /// type Schema = RootNode<Query, EmptyMutation<Context>, EmptySubscription<Context>>;
+    ///
+    /// let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    ///     .enable_visibility();
+    ///
+    /// # // language=GraphQL
+    /// let query = "query { version buildHost }";
+    ///
+    /// let internal = Context { is_internal: true };
+    /// assert!(juniper::execute_sync(query, None, &schema, &graphql_vars! {}, &internal).is_ok());
+    ///
+    /// let public = Context { is_internal: false };
+    /// match juniper::execute_sync(query, None, &schema, &graphql_vars! {}, &public) {
+    ///     Err(GraphQLError::ValidationError(errs)) => {
+    ///         assert_eq!(
+    ///             errs.first().unwrap().message(),
+    ///             r#"Unknown field "buildHost" on type "Query""#,
+    ///         );
+    ///     }
+    ///     res => panic!("expected `ValidationError`, returned: {res:#?}"),
+    /// }
+    /// ```
+    ///
+    /// [`VisibilityFn`]: crate::meta::VisibilityFn
+    pub fn enable_visibility(mut self) -> Self
+    where
+        QueryT::Context: Sized + 'static,
+    {
+        fn as_any<T: Any>(ctx: &T) -> &dyn Any {
+            ctx
+        }
+
+        self.context_as_any = Some(as_any::<QueryT::Context>);
+        self
+    }
+
+    /// Evaluates the [`VisibilityFn`]s of this [`RootNode`] against the provided request
+    /// `context`.
+    ///
+    /// # Errors
+    ///
+    /// If this [`RootNode`] has [`VisibilityFn`]s, but [`RootNode::enable_visibility()`] wasn't
+    /// called, or they don't accept the provided `context`.
+    ///
+    /// [`VisibilityFn`]: crate::meta::VisibilityFn
+    pub(crate) fn visibility(
+        &self,
+        context: &QueryT::Context,
+    ) -> Result<Visibility, VisibilityError> {
+        Visibility::new(&self.schema, self.context_as_any.map(|f| f(context)))
+    }
+
+    /// Sets the [`UsageSink`] receiving the [`SchemaUsage`] of every operation executed by this
+    /// [`RootNode`], after the operation is validated.
+    ///
+    /// Collecting the [`SchemaUsage`] requires an additional walk over the operation, so is
+    /// disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// #
+    /// # use juniper::{
+    /// #     graphql_object, graphql_vars, schema::UsageCounter, EmptyMutation, EmptySubscription,
+    /// #     RootNode,
+    /// # };
+    /// #
+    /// struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn user_name() -> &'static str {
+    ///         "John"
+    ///     }
+    ///
+    ///     #[graphql(deprecated = "Use `userName` instead.")]
+    ///     fn login() -> &'static str {
+    ///         "john"
+    ///     }
+    /// }
+    ///
+    /// type Schema = RootNode<Query, EmptyMutation, EmptySubscription>;
+    ///
+    /// let usage = Arc::new(UsageCounter::new());
+    /// let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    ///     .usage_sink(usage.clone());
+    ///
+    /// # // language=GraphQL
+    /// let query = "query { userName }";
+    ///
+    /// juniper::execute_sync(query, None, &schema, &graphql_vars! {}, &()).unwrap();
+    ///
+    /// assert_eq!(usage.count("Query.userName"), 1);
+    /// assert_eq!(usage.unused_deprecated(&schema.schema), ["Query.login"]);
+    /// ```
+    ///
+    /// [`SchemaUsage`]: crate::schema::SchemaUsage
+    /// [`UsageSink`]: crate::schema::UsageSink
+    #[must_use]
+    pub fn usage_sink(mut self, sink: Arc<dyn UsageSink>) -> Self {
+        self.usage_sink = Some(sink);
+        self
+    }
+
+    /// Records the [`SchemaUsage`] of the provided validated `operation` into the [`UsageSink`] of
+    /// this [`RootNode`], if any.
+    pub(crate) fn record_usage(&self, document: &AstDocument<'_, S>, operation: &Operation<'_, S>) {
+        if let Some(sink) = &self.usage_sink {
+            sink.record(&SchemaUsage::collect(&self.schema, document, operation));
+        }
+    }
+
+    /// Prints the schema of this [`RootNode`] in [SDL (schema definition language)][0] according to
+    /// the provided [`SdlOptions`].
+    ///
+    /// Unlike [`RootNode::as_sdl()`], doesn't require the `schema-language` [Cargo feature].
+    ///
+    /// [`SdlOptions`]: crate::schema::SdlOptions
+    /// [Cargo feature]: https://doc.rust-lang.org/cargo/reference/features.html
+    /// [0]: https://graphql.org/learn/schema#type-language
+    #[must_use]
+    pub fn to_sdl(&self, options: &SdlOptions) -> String {
+        self.schema.to_sdl(options)
+    }
+
     #[cfg(feature = "schema-language")]
     /// Returns this [`RootNode`] as a [`String`] containing the schema in [SDL (schema definition language)].
     ///
@@ -214,7 +557,14 @@ pub struct SchemaType<S> {
     pub(crate) query_type_name: String,
     pub(crate) mutation_type_name: Option<String>,
     pub(crate) subscription_type_name: Option<String>,
-    directives: FnvHashMap<ArcStr, DirectiveType<S>>,
+    pub(crate) directives: FnvHashMap<ArcStr, DirectiveType<S>>,
+    pub(crate) definition_order: Vec<Name>,
+
+    /// Indicates whether any of the [`types`] or their fields has a [`VisibilityFn`].
+    ///
+    /// [`types`]: SchemaType::types
+    /// [`VisibilityFn`]: crate::meta::VisibilityFn
+    pub(crate) has_visibility_fns: bool,
 }
 
 impl<S> Context for SchemaType<S> {}
@@ -226,6 +576,90 @@ impl<S> SchemaType<S> {
         mutation_info: &MutationT::TypeInfo,
         subscription_info: &SubscriptionT::TypeInfo,
     ) -> Self
+    where
+        S: ScalarValue,
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S>,
+        SubscriptionT: GraphQLType<S>,
+    {
+        let (schema, errors) = Self::build::<QueryT, MutationT, SubscriptionT>(
+            query_info,
+            mutation_info,
+            subscription_info,
+        );
+
+        match schema.types.get(schema.query_type_name.as_str()) {
+            None => panic!("Root type not found"),
+            Some(MetaType::Object(..)) => {}
+            Some(_) => panic!("Root type is not an object"),
+        }
+        if let Some(e) = errors
+            .iter()
+            .find(|e| !matches!(e, SchemaError::TypeNameCollision { .. }))
+        {
+            panic!("{e}");
+        }
+        for meta_type in schema.types.values() {
+            if let MetaType::Interface(InterfaceMeta { name, fields, .. })
+            | MetaType::Object(ObjectMeta { name, fields, .. }) = meta_type
+            {
+                let mut names = FnvHashSet::default();
+                if let Some(dup) = fields.iter().find(|f| !names.insert(&f.name)) {
+                    panic!(
+                        "Field `{}` is defined multiple times on type `{name}`",
+                        dup.name,
+                    );
+                }
+            }
+        }
+        schema
+    }
+
+    /// Creates a new schema, performing the [type system validation][0] of it.
+    ///
+    /// Unlike [`SchemaType::new()`], detects type name collisions between different Rust types,
+    /// incorrectly implemented interfaces, empty types, reserved `__` names and cycles of
+    /// non-null input objects.
+    ///
+    /// # Errors
+    ///
+    /// With all the [`SchemaError`]s found, if the schema is not valid.
+    ///
+    /// [0]: https://spec.graphql.org/September2025#sec-Type-System
+    pub fn try_new<QueryT, MutationT, SubscriptionT>(
+        query_info: &QueryT::TypeInfo,
+        mutation_info: &MutationT::TypeInfo,
+        subscription_info: &SubscriptionT::TypeInfo,
+    ) -> Result<Self, Vec<SchemaError>>
+    where
+        S: ScalarValue,
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S>,
+        SubscriptionT: GraphQLType<S>,
+    {
+        let (schema, mut errors) = Self::build::<QueryT, MutationT, SubscriptionT>(
+            query_info,
+            mutation_info,
+            subscription_info,
+        );
+        errors.extend(schema.type_system_errors());
+        if errors.is_empty() {
+            Ok(schema)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Builds a new schema out of the provided root types, without validating it.
+    ///
+    /// Returns the [`SchemaError::TypeNameCollision`]s and other [`SchemaError`]s detected while
+    /// registering types, as they cannot be detected afterwards, along with the
+    /// [`SchemaError::PlaceholderType`]s left unresolved after the registration.
+    fn build<QueryT, MutationT, SubscriptionT>(
+        query_info: &QueryT::TypeInfo,
+        mutation_info: &MutationT::TypeInfo,
+        subscription_info: &SubscriptionT::TypeInfo,
+    ) -> (Self, Vec<SchemaError>)
     where
         S: ScalarValue,
         QueryT: GraphQLType<S>,
@@ -261,30 +695,54 @@ impl<S> SchemaType<S> {
         directives.insert(specified_by_directive.name.clone(), specified_by_directive);
         directives.insert(one_of_directive.name.clone(), one_of_directive);
 
-        let mut meta_fields = vec![
-            registry.field::<SchemaType<S>>(arcstr::literal!("__schema"), &()),
-            registry
-                .field::<TypeType<S>>(arcstr::literal!("__type"), &())
-                .argument(registry.arg::<String>(arcstr::literal!("name"), &())),
-        ];
+        let mut meta_fields = Self::meta_fields(&mut registry);
 
-        if let Some(root_type) = registry.types.get_mut(query_type_name.as_ref()) {
-            if let MetaType::Object(ObjectMeta { ref mut fields, .. }) = *root_type {
-                fields.append(&mut meta_fields);
-            } else {
-                panic!("Root type is not an object");
-            }
-        } else {
-            panic!("Root type not found");
+        if let Some(MetaType::Object(ObjectMeta { fields, .. })) =
+            registry.types.get_mut(query_type_name.as_ref())
+        {
+            fields.append(&mut meta_fields);
         }
 
-        for meta_type in registry.types.values() {
-            if let MetaType::Placeholder(PlaceholderMeta { ref of_type }) = *meta_type {
-                panic!("Type {of_type:?} is still a placeholder type");
-            }
-        }
-        SchemaType {
+        let mut errors = registry
+            .collisions()
+            .iter()
+            .map(|(name, first, second)| SchemaError::TypeNameCollision {
+                name: Borrow::<str>::borrow(name).into(),
+                first: (*first).into(),
+                second: (*second).into(),
+            })
+            .collect::<Vec<_>>();
+        errors.extend(registry.errors().iter().cloned());
+        let mut placeholders = registry
+            .types
+            .values()
+            .filter_map(|t| match t {
+                MetaType::Placeholder(PlaceholderMeta { of_type }) => {
+                    Some(of_type.innermost_name().to_owned())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        placeholders.sort();
+        errors.extend(
+            placeholders
+                .into_iter()
+                .map(|name| SchemaError::PlaceholderType { name }),
+        );
+        let has_visibility_fns = registry.types.values().any(|t| {
+            t.visibility_fn().is_some()
+                || match t {
+                    MetaType::Interface(InterfaceMeta { fields, .. })
+                    | MetaType::Object(ObjectMeta { fields, .. }) => {
+                        fields.iter().any(|f| f.visible.is_some())
+                    }
+                    _ => false,
+                }
+        });
+        let mut schema = SchemaType {
             description: None,
+            has_visibility_fns,
+            definition_order: registry.definition_order().to_vec(),
             types: registry.types,
             query_type_name: query_type_name.into(),
             mutation_type_name: if mutation_type_name.as_ref() != "_EmptyMutation" {
@@ -298,7 +756,24 @@ impl<S> SchemaType<S> {
                 None
             },
             directives,
+        };
+        for directive in schema.inferred_directives() {
+            schema.add_directive(directive);
         }
+        (schema, errors)
+    }
+
+    /// Creates the `__schema` and `__type` introspection fields of the query type.
+    pub(crate) fn meta_fields(registry: &mut Registry<S>) -> Vec<Field<S>>
+    where
+        S: ScalarValue,
+    {
+        vec![
+            registry.field::<SchemaType<S>>(arcstr::literal!("__schema"), &()),
+            registry
+                .field::<Option<TypeType<S>>>(arcstr::literal!("__type"), &())
+                .argument(registry.arg::<String>(arcstr::literal!("name"), &())),
+        ]
     }
 
     /// Add a description.
@@ -311,6 +786,110 @@ impl<S> SchemaType<S> {
         self.directives.insert(directive.name.clone(), directive);
     }
 
+    /// Returns [`DirectiveType`]s of the custom directives applied to the schema elements, but not
+    /// registered in this [`SchemaType`], sorted by their names.
+    ///
+    /// Such definitions are inferred from the directive applications: a directive is allowed at
+    /// the locations it's applied at, it's repeatable if applied more than once to a single
+    /// element, and its arguments are `null`able and typed after the first provided value (enum
+    /// values are typed after the schema enum declaring them). Arguments whose type cannot be
+    /// inferred this way (e.g. having only input object values) are typed as `String`.
+    fn inferred_directives(&self) -> Vec<DirectiveType<S>>
+    where
+        S: ScalarValue,
+    {
+        let mut types = self
+            .types
+            .values()
+            .filter(|t| !t.is_builtin())
+            .collect::<Vec<_>>();
+        types.sort_by(|a, b| a.name().cmp(&b.name()));
+
+        let mut applications = Vec::<(DirectiveLocation, &[AppliedDirective])>::new();
+        for meta in &types {
+            let location = match meta {
+                MetaType::Scalar(..) => DirectiveLocation::Scalar,
+                MetaType::Object(..) => DirectiveLocation::Object,
+                MetaType::Interface(..) => DirectiveLocation::Interface,
+                MetaType::Union(..) => DirectiveLocation::Union,
+                MetaType::Enum(..) => DirectiveLocation::Enum,
+                MetaType::InputObject(..) => DirectiveLocation::InputObject,
+                MetaType::List(..) | MetaType::Nullable(..) | MetaType::Placeholder(..) => continue,
+            };
+            applications.push((location, meta.directives()));
+            match meta {
+                MetaType::Object(ObjectMeta { fields, .. })
+                | MetaType::Interface(InterfaceMeta { fields, .. }) => {
+                    for field in fields {
+                        applications.push((DirectiveLocation::FieldDefinition, &field.directives));
+                        for arg in field.arguments.iter().flatten() {
+                            applications
+                                .push((DirectiveLocation::ArgumentDefinition, &arg.directives));
+                        }
+                    }
+                }
+                MetaType::Enum(EnumMeta { values, .. }) => {
+                    for value in values {
+                        applications.push((DirectiveLocation::EnumValue, &value.directives));
+                    }
+                }
+                MetaType::InputObject(InputObjectMeta { input_fields, .. }) => {
+                    for field in input_fields {
+                        applications
+                            .push((DirectiveLocation::InputFieldDefinition, &field.directives));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut inferred =
+            BTreeMap::<&ArcStr, (DirectiveType<S>, Vec<(&ArcStr, Option<Type>)>)>::new();
+        for (location, directives) in applications {
+            for (i, applied) in directives.iter().enumerate() {
+                if self.directives.contains_key(&applied.name) {
+                    continue;
+                }
+                let (directive, arguments) = inferred.entry(&applied.name).or_insert_with(|| {
+                    (
+                        DirectiveType::new(applied.name.clone(), &[], &[], false),
+                        vec![],
+                    )
+                });
+                if !directive.locations.contains(&location) {
+                    directive.locations.push(location.clone());
+                }
+                if directives[..i].iter().any(|d| d.name == applied.name) {
+                    directive.is_repeatable = true;
+                }
+                for (name, value) in &applied.arguments {
+                    let arg_type = infer_input_type(&types, value);
+                    match arguments.iter_mut().find(|(n, _)| *n == name) {
+                        Some((_, ty @ None)) => *ty = arg_type,
+                        Some((_, Some(_))) => {}
+                        None => arguments.push((name, arg_type)),
+                    }
+                }
+            }
+        }
+
+        inferred
+            .into_values()
+            .map(|(mut directive, arguments)| {
+                directive.arguments = arguments
+                    .into_iter()
+                    .map(|(name, ty)| {
+                        Argument::new(
+                            name.clone(),
+                            ty.unwrap_or_else(|| Type::nullable(arcstr::literal!("String"))),
+                        )
+                    })
+                    .collect();
+                directive
+            })
+            .collect()
+    }
+
     /// Get a type by name.
     pub fn type_by_name(&self, name: impl AsRef<str>) -> Option<TypeType<'_, S>> {
         self.types.get(name.as_ref()).map(|t| TypeType::Concrete(t))
@@ -713,6 +1292,33 @@ pub enum DirectiveLocation {
     InputFieldDefinition,
 }
 
+/// Infers the [`Type`] of the provided `value` of an [`AppliedDirective`]'s argument, if possible.
+///
+/// Enum values are typed after the first of the provided `types` declaring them.
+fn infer_input_type<S>(types: &[&MetaType<S>], value: &InputValue) -> Option<Type> {
+    match value {
+        InputValue::Scalar(scalar) => Some(Type::nullable(match scalar {
+            DefaultScalarValue::Int(_) => arcstr::literal!("Int"),
+            DefaultScalarValue::Float(_) => arcstr::literal!("Float"),
+            DefaultScalarValue::String(_) => arcstr::literal!("String"),
+            DefaultScalarValue::Boolean(_) => arcstr::literal!("Boolean"),
+        })),
+        InputValue::Enum(name) => types.iter().find_map(|t| match t {
+            MetaType::Enum(EnumMeta {
+                name: ty, values, ..
+            }) if values.iter().any(|v| v.name == name.as_str()) => {
+                Some(Type::nullable(ty.clone()))
+            }
+            _ => None,
+        }),
+        InputValue::List(items) => items
+            .iter()
+            .find_map(|i| infer_input_type(types, &i.item))
+            .map(|t| t.wrap_list(None)),
+        InputValue::Null | InputValue::Variable(_) | InputValue::Object(_) => None,
+    }
+}
+
 /// Sorts the provided [`TypeType`]s in the "type-then-name" manner.
 fn sort_concrete_types<S>(types: &mut [TypeType<S>]) {
     types.sort_by(|a, b| {
@@ -933,4 +1539,166 @@ mod root_node_test {
             assert_eq!(actual.as_sdl(), expected.to_string());
         }
     }
+
+    #[cfg(feature = "schema-language")]
+    mod applied_directives {
+        use crate::{
+            EmptyMutation, EmptySubscription, GraphQLEnum, GraphQLInputObject, GraphQLObject,
+            GraphQLScalar, GraphQLUnion, InputValue, RootNode, graphql_interface, graphql_object,
+        };
+
+        #[derive(GraphQLScalar)]
+        #[graphql(transparent, directive = tag(name = "internal"))]
+        struct Sku(String);
+
+        #[expect(dead_code, reason = "GraphQL schema testing")]
+        #[graphql_interface]
+        #[graphql(for = Product, directive = key(fields = "id"))]
+        trait Node {
+            fn id(&self) -> &str;
+        }
+
+        #[derive(GraphQLObject)]
+        #[graphql(impl = NodeValue, directive = key(fields = "id"))]
+        #[graphql(directive = shareable)]
+        struct Product {
+            id: String,
+            #[graphql(directive = auth(requires = ADMIN, scopes = ["read", "write"]))]
+            sku: Sku,
+        }
+
+        #[derive(GraphQLObject)]
+        struct Bundle {
+            size: i32,
+        }
+
+        #[expect(dead_code, reason = "GraphQL schema testing")]
+        #[derive(GraphQLUnion)]
+        #[graphql(directive = tag(name = "public"))]
+        enum Item {
+            Product(Product),
+            Bundle(Bundle),
+        }
+
+        #[derive(GraphQLEnum)]
+        #[graphql(directive = tag(name = "public"))]
+        enum Color {
+            #[graphql(directive = tag(name = "legacy"))]
+            Red,
+            Green,
+        }
+
+        #[derive(GraphQLInputObject)]
+        #[graphql(directive = tag(name = "public"))]
+        struct Filter {
+            #[graphql(directive = constraint(maxLength = 10, trim = true))]
+            name: Option<String>,
+        }
+
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            #[graphql(directive = cost(weight = 2.5))]
+            fn items(#[graphql(directive = sensitive)] filter: Option<Filter>) -> Vec<Item> {
+                _ = filter;
+                vec![]
+            }
+
+            fn color() -> Color {
+                Color::Green
+            }
+
+            fn node() -> Option<NodeValue> {
+                None
+            }
+        }
+
+        fn schema() -> RootNode<Query, EmptyMutation, EmptySubscription> {
+            RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+        }
+
+        #[test]
+        fn prints_in_sdl() {
+            let expected = graphql_parser::parse_schema::<&str>(
+                //language=GraphQL
+                r#"
+                schema {
+                  query: Query
+                }
+                directive @auth(requires: String, scopes: [String]) on FIELD_DEFINITION
+                directive @constraint(maxLength: Int, trim: Boolean) on INPUT_FIELD_DEFINITION
+                directive @cost(weight: Float) on FIELD_DEFINITION
+                directive @key(fields: String) on INTERFACE | OBJECT
+                directive @sensitive on ARGUMENT_DEFINITION
+                directive @shareable on OBJECT
+                directive @tag(name: String) on ENUM | ENUM_VALUE | INPUT_OBJECT | UNION | SCALAR
+                enum Color @tag(name: "public") {
+                  RED @tag(name: "legacy")
+                  GREEN
+                }
+                input Filter @tag(name: "public") {
+                  name: String @constraint(maxLength: 10, trim: true)
+                }
+                interface Node @key(fields: "id") {
+                  id: String!
+                }
+                scalar Sku @tag(name: "internal")
+                type Bundle {
+                  size: Int!
+                }
+                type Product implements Node @key(fields: "id") @shareable {
+                  id: String!
+                  sku: Sku! @auth(requires: ADMIN, scopes: ["read", "write"])
+                }
+                type Query {
+                  items(filter: Filter @sensitive): [Item!]! @cost(weight: 2.5)
+                  color: Color!
+                  node: Node
+                }
+                union Item @tag(name: "public") = Product | Bundle
+                "#,
+            )
+            .unwrap();
+
+            assert_eq!(schema().as_sdl(), expected.to_string());
+        }
+
+        #[test]
+        fn readable_at_runtime() {
+            let schema = schema();
+
+            let product = schema.schema.concrete_type_by_name("Product").unwrap();
+            let names: Vec<_> = product
+                .directives()
+                .iter()
+                .map(|d| d.name.as_str())
+                .collect();
+            assert_eq!(names, ["key", "shareable"]);
+            assert_eq!(
+                product.directives()[0].argument_value("fields"),
+                Some(&InputValue::scalar("id")),
+            );
+
+            let sku = product.field_by_name("sku").unwrap();
+            assert_eq!(sku.directives.len(), 1);
+            assert_eq!(
+                sku.directives[0].argument_value("requires"),
+                Some(&InputValue::enum_value("ADMIN")),
+            );
+            assert_eq!(sku.directives[0].argument_value("unknown"), None);
+
+            let query = schema.schema.concrete_type_by_name("Query").unwrap();
+            let filter = &query
+                .field_by_name("items")
+                .unwrap()
+                .arguments
+                .as_ref()
+                .unwrap()[0];
+            assert_eq!(filter.directives[0].name, "sensitive");
+
+            let bundle = schema.schema.concrete_type_by_name("Bundle").unwrap();
+            assert!(bundle.directives().is_empty());
+        }
+    }
 }
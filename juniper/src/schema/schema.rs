@@ -72,9 +72,13 @@ where
                 .resolve(&(), &self.schema),
             "__type" => {
                 let type_name: String = args.get("name")?.unwrap();
+                let meta_type = self
+                    .schema
+                    .type_by_name(&type_name)
+                    .filter(|_| executor.visibility().is_type_visible(&type_name));
                 executor
                     .replaced_context(&self.schema)
-                    .resolve(&(), &self.schema.type_by_name(&type_name))
+                    .resolve(&(), &meta_type)
             }
             _ => self.query_type.resolve_field(info, field, args, executor),
         }
@@ -146,14 +150,15 @@ impl<S: ScalarValue> SchemaType<S> {
         self.description.as_ref()
     }
 
-    fn types(&self) -> Vec<TypeType<'_, S>> {
+    fn types(&self, executor: &Executor<'_, '_, SchemaType<S>, S>) -> Vec<TypeType<'_, S>> {
         self.type_list()
             .into_iter()
             .filter(|t| {
                 t.to_concrete()
-                    .map(|t| {
-                        !(t.name().map(ArcStr::as_str) == Some("_EmptyMutation")
-                            || t.name().map(ArcStr::as_str) == Some("_EmptySubscription"))
+                    .and_then(|t| t.name())
+                    .map(|n| {
+                        !(n == "_EmptyMutation" || n == "_EmptySubscription")
+                            && executor.visibility().is_type_visible(n)
                     })
                     .unwrap_or(false)
             })
@@ -218,15 +223,20 @@ impl<'a, S: ScalarValue + 'a> TypeType<'a, S> {
         }
     }
 
-    fn fields(&self, #[graphql(default)] include_deprecated: bool) -> Option<Vec<&Field<S>>> {
+    fn fields(
+        &self,
+        #[graphql(default)] include_deprecated: bool,
+        executor: &Executor<'_, '_, SchemaType<S>, S>,
+    ) -> Option<Vec<&Field<S>>> {
         match self {
             Self::Concrete(t) => match t {
-                MetaType::Interface(InterfaceMeta { fields, .. })
-                | MetaType::Object(ObjectMeta { fields, .. }) => Some(
+                MetaType::Interface(InterfaceMeta { name, fields, .. })
+                | MetaType::Object(ObjectMeta { name, fields, .. }) => Some(
                     fields
                         .iter()
                         .filter(|f| include_deprecated || !f.deprecation_status.is_deprecated())
                         .filter(|f| !f.name.starts_with("__"))
+                        .filter(|f| executor.visibility().is_field_visible(name, f))
                         .collect(),
                 ),
                 MetaType::Enum(..)
@@ -241,7 +251,11 @@ impl<'a, S: ScalarValue + 'a> TypeType<'a, S> {
         }
     }
 
-    fn interfaces<'s>(&self, context: &'s SchemaType<S>) -> Option<Vec<TypeType<'s, S>>> {
+    fn interfaces<'s>(
+        &self,
+        context: &'s SchemaType<S>,
+        executor: &Executor<'_, '_, SchemaType<S>, S>,
+    ) -> Option<Vec<TypeType<'s, S>>> {
         match self {
             Self::Concrete(t) => match t {
                 MetaType::Interface(InterfaceMeta {
@@ -252,6 +266,7 @@ impl<'a, S: ScalarValue + 'a> TypeType<'a, S> {
                 }) => Some(
                     interface_names
                         .iter()
+                        .filter(|n| executor.visibility().is_type_visible(n))
                         .filter_map(|n| context.type_by_name(n))
                         .collect(),
                 ),
@@ -267,7 +282,11 @@ impl<'a, S: ScalarValue + 'a> TypeType<'a, S> {
         }
     }
 
-    fn possible_types<'s>(&self, context: &'s SchemaType<S>) -> Option<Vec<TypeType<'s, S>>> {
+    fn possible_types<'s>(
+        &self,
+        context: &'s SchemaType<S>,
+        executor: &Executor<'_, '_, SchemaType<S>, S>,
+    ) -> Option<Vec<TypeType<'s, S>>> {
         match self {
             Self::Concrete(t) => match t {
                 MetaType::Interface(InterfaceMeta {
@@ -296,6 +315,7 @@ impl<'a, S: ScalarValue + 'a> TypeType<'a, S> {
                     Some(
                         type_names
                             .into_iter()
+                            .filter(|n| executor.visibility().is_type_visible(n))
                             .filter_map(|n| context.type_by_name(n))
                             .collect(),
                     )
@@ -303,6 +323,7 @@ impl<'a, S: ScalarValue + 'a> TypeType<'a, S> {
                 MetaType::Union(UnionMeta { of_type_names, .. }) => Some(
                     of_type_names
                         .iter()
+                        .filter(|tn| executor.visibility().is_type_visible(tn))
                         .filter_map(|tn| context.type_by_name(tn))
                         .collect(),
                 ),
@@ -4,7 +4,11 @@ use graphql_parser::{Pos, schema};
 
 use crate::{
     ast,
-    schema::{meta, model::SchemaType, translate::SchemaTranslator},
+    schema::{
+        meta,
+        model::{DirectiveLocation, DirectiveType, SchemaType},
+        translate::{SchemaTranslator, sdl::BUILTIN_DIRECTIVES},
+    },
     value::ScalarValue,
 };
 
@@ -45,6 +49,16 @@ where
             .collect();
         doc.definitions.append(&mut types);
 
+        // Translate custom directive defs.
+        doc.definitions.extend(
+            input
+                .directives
+                .values()
+                .filter(|d| !BUILTIN_DIRECTIVES.contains(&d.name.as_str()))
+                .map(GraphQLParserTranslator::translate_directive)
+                .map(schema::Definition::DirectiveDefinition),
+        );
+
         doc.definitions.push(schema::Definition::SchemaDefinition(
             schema::SchemaDefinition {
                 position: Pos::default(),
@@ -74,6 +88,7 @@ impl GraphQLParserTranslator {
             arg_type,
             default_value,
             deprecation_status,
+            directives,
         } = input;
         schema::InputValue {
             position: Pos::default(),
@@ -84,8 +99,9 @@ impl GraphQLParserTranslator {
                 .as_ref()
                 .map(|x| GraphQLParserTranslator::translate_value(x)),
             directives: deprecation_directive(deprecation_status)
-                .map(|d| vec![d])
-                .unwrap_or_default(),
+                .into_iter()
+                .chain(directives.iter().map(applied_directive))
+                .collect(),
         }
     }
 
@@ -153,6 +169,7 @@ impl GraphQLParserTranslator {
                 name,
                 description,
                 specified_by_url,
+                directives,
                 try_parse_fn: _,
                 parse_fn: _,
             }) => schema::TypeDefinition::Scalar(schema::ScalarType {
@@ -161,19 +178,22 @@ impl GraphQLParserTranslator {
                 name: name.as_str().into(),
                 directives: specified_by_url
                     .as_deref()
-                    .map(|url| vec![specified_by_url_directive(url)])
-                    .unwrap_or_default(),
+                    .map(specified_by_url_directive)
+                    .into_iter()
+                    .chain(directives.iter().map(applied_directive))
+                    .collect(),
             }),
             meta::MetaType::Enum(meta::EnumMeta {
                 name,
                 description,
                 values,
+                directives,
                 try_parse_fn: _,
             }) => schema::TypeDefinition::Enum(schema::EnumType {
                 position: Pos::default(),
                 description: description.as_deref().map(Into::into),
                 name: name.as_str().into(),
-                directives: vec![],
+                directives: directives.iter().map(applied_directive).collect(),
                 values: values
                     .iter()
                     .map(GraphQLParserTranslator::translate_enum_value)
@@ -183,11 +203,13 @@ impl GraphQLParserTranslator {
                 name,
                 description,
                 of_type_names,
+                directives,
+                visible: _,
             }) => schema::TypeDefinition::Union(schema::UnionType {
                 position: Pos::default(),
                 description: description.as_deref().map(Into::into),
                 name: name.as_str().into(),
-                directives: vec![],
+                directives: directives.iter().map(applied_directive).collect(),
                 types: of_type_names.iter().map(|s| s.as_str().into()).collect(),
             }),
             meta::MetaType::Interface(meta::InterfaceMeta {
@@ -195,12 +217,14 @@ impl GraphQLParserTranslator {
                 description,
                 fields,
                 interface_names,
+                directives,
+                visible: _,
             }) => schema::TypeDefinition::Interface(schema::InterfaceType {
                 position: Pos::default(),
                 description: description.as_deref().map(Into::into),
                 name: name.as_str().into(),
                 implements_interfaces: interface_names.iter().map(|s| s.as_str().into()).collect(),
-                directives: vec![],
+                directives: directives.iter().map(applied_directive).collect(),
                 fields: fields
                     .iter()
                     .filter(|x| !x.is_builtin())
@@ -212,14 +236,17 @@ impl GraphQLParserTranslator {
                 description,
                 input_fields,
                 is_one_of,
+                directives,
                 try_parse_fn: _,
             }) => schema::TypeDefinition::InputObject(schema::InputObjectType {
                 position: Pos::default(),
                 description: description.as_deref().map(Into::into),
                 name: name.as_str().into(),
                 directives: is_one_of
-                    .then(|| vec![one_of_directive()])
-                    .unwrap_or_default(),
+                    .then(one_of_directive)
+                    .into_iter()
+                    .chain(directives.iter().map(applied_directive))
+                    .collect(),
                 fields: input_fields
                     .iter()
                     .filter(|x| !x.is_builtin())
@@ -231,11 +258,13 @@ impl GraphQLParserTranslator {
                 description,
                 fields,
                 interface_names,
+                directives,
+                visible: _,
             }) => schema::TypeDefinition::Object(schema::ObjectType {
                 position: Pos::default(),
                 description: description.as_deref().map(Into::into),
                 name: name.as_str().into(),
-                directives: vec![],
+                directives: directives.iter().map(applied_directive).collect(),
                 fields: fields
                     .iter()
                     .filter(|x| !x.is_builtin())
@@ -255,14 +284,43 @@ impl GraphQLParserTranslator {
             name,
             description,
             deprecation_status,
+            directives,
         } = input;
         schema::EnumValue {
             position: Pos::default(),
             name: name.as_str().into(),
             description: description.as_deref().map(Into::into),
             directives: deprecation_directive(deprecation_status)
-                .map(|d| vec![d])
-                .unwrap_or_default(),
+                .into_iter()
+                .chain(directives.iter().map(applied_directive))
+                .collect(),
+        }
+    }
+
+    fn translate_directive<'a, S, T>(
+        input: &'a DirectiveType<S>,
+    ) -> schema::DirectiveDefinition<'a, T>
+    where
+        S: ScalarValue + 'a,
+        T: schema::Text<'a>,
+    {
+        let DirectiveType {
+            name,
+            description,
+            locations,
+            arguments,
+            is_repeatable,
+        } = input;
+        schema::DirectiveDefinition {
+            position: Pos::default(),
+            description: description.as_deref().map(Into::into),
+            name: name.as_str().into(),
+            arguments: arguments
+                .iter()
+                .map(GraphQLParserTranslator::translate_argument)
+                .collect(),
+            repeatable: *is_repeatable,
+            locations: locations.iter().map(directive_location).collect(),
         }
     }
 
@@ -277,14 +335,17 @@ impl GraphQLParserTranslator {
             arguments,
             field_type,
             deprecation_status,
+            directives,
+            visible: _,
         } = input;
         schema::Field {
             position: Pos::default(),
             name: name.as_str().into(),
             description: description.as_deref().map(Into::into),
             directives: deprecation_directive(deprecation_status)
-                .map(|d| vec![d])
-                .unwrap_or_default(),
+                .into_iter()
+                .chain(directives.iter().map(applied_directive))
+                .collect(),
             field_type: GraphQLParserTranslator::translate_type(field_type),
             arguments: arguments
                 .as_ref()
@@ -299,6 +360,31 @@ impl GraphQLParserTranslator {
     }
 }
 
+/// Converts the provided [`DirectiveLocation`] into a [`schema::DirectiveLocation`].
+fn directive_location(location: &DirectiveLocation) -> schema::DirectiveLocation {
+    match location {
+        DirectiveLocation::Query => schema::DirectiveLocation::Query,
+        DirectiveLocation::Mutation => schema::DirectiveLocation::Mutation,
+        DirectiveLocation::Subscription => schema::DirectiveLocation::Subscription,
+        DirectiveLocation::Field => schema::DirectiveLocation::Field,
+        DirectiveLocation::FragmentDefinition => schema::DirectiveLocation::FragmentDefinition,
+        DirectiveLocation::FragmentSpread => schema::DirectiveLocation::FragmentSpread,
+        DirectiveLocation::InlineFragment => schema::DirectiveLocation::InlineFragment,
+        DirectiveLocation::VariableDefinition => schema::DirectiveLocation::VariableDefinition,
+        DirectiveLocation::Schema => schema::DirectiveLocation::Schema,
+        DirectiveLocation::Scalar => schema::DirectiveLocation::Scalar,
+        DirectiveLocation::Object => schema::DirectiveLocation::Object,
+        DirectiveLocation::FieldDefinition => schema::DirectiveLocation::FieldDefinition,
+        DirectiveLocation::ArgumentDefinition => schema::DirectiveLocation::ArgumentDefinition,
+        DirectiveLocation::Interface => schema::DirectiveLocation::Interface,
+        DirectiveLocation::Union => schema::DirectiveLocation::Union,
+        DirectiveLocation::Enum => schema::DirectiveLocation::Enum,
+        DirectiveLocation::EnumValue => schema::DirectiveLocation::EnumValue,
+        DirectiveLocation::InputObject => schema::DirectiveLocation::InputObject,
+        DirectiveLocation::InputFieldDefinition => schema::DirectiveLocation::InputFieldDefinition,
+    }
+}
+
 /// Forms a [`@deprecated(reason:)`] [`schema::Directive`] out of the provided
 /// [`meta::DeprecationStatus`].
 ///
@@ -322,6 +408,27 @@ where
     }
 }
 
+/// Forms a [`schema::Directive`] out of the provided [`meta::AppliedDirective`].
+fn applied_directive<'a, T>(directive: &'a meta::AppliedDirective) -> schema::Directive<'a, T>
+where
+    T: schema::Text<'a>,
+{
+    schema::Directive {
+        position: Pos::default(),
+        name: directive.name.as_str().into(),
+        arguments: directive
+            .arguments
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.as_str().into(),
+                    GraphQLParserTranslator::translate_value(value),
+                )
+            })
+            .collect(),
+    }
+}
+
 /// Forms a [`@oneOf`] [`schema::Directive`].
 ///
 /// [`@oneOf`]: https://spec.graphql.org/September2025#sec--oneOf
@@ -10,3 +10,4 @@ pub trait SchemaTranslator<'a, T> {
 
 #[cfg(feature = "schema-language")]
 pub mod graphql_parser;
+pub(crate) mod sdl;
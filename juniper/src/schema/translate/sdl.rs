@@ -0,0 +1,854 @@
+//! Printing of a [`SchemaType`] in [SDL (schema definition language)][0] directly, without any
+//! third-party parser.
+//!
+//! [0]: https://graphql.org/learn/schema#type-language
+
+use std::fmt::Write as _;
+
+use crate::{
+    ast::InputValue,
+    schema::{
+        meta::{
+            AppliedDirective, Argument, DeprecationStatus, EnumMeta, EnumValue, Field,
+            InputObjectMeta, InterfaceMeta, MetaType, ObjectMeta, ScalarMeta, UnionMeta,
+        },
+        model::{DirectiveType, SchemaType},
+    },
+    value::{Scalar, ScalarValue},
+};
+
+/// Names of the built-in [scalars](https://spec.graphql.org/September2025#sec-Scalars.Built-in-Scalars).
+const BUILTIN_SCALARS: [&str; 5] = ["Boolean", "Float", "ID", "Int", "String"];
+
+/// Names of the built-in [directives](https://spec.graphql.org/September2025#sec-Type-System.Directives.Built-in-Directives).
+pub(crate) const BUILTIN_DIRECTIVES: [&str; 5] =
+    ["deprecated", "include", "oneOf", "skip", "specifiedBy"];
+
+/// Order of the definitions printed in SDL.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SdlOrder {
+    /// Types are printed in the order they were registered in the schema (starting from the root
+    /// types), while fields, arguments and enum values are printed in the order they were defined.
+    #[default]
+    Definition,
+
+    /// Types, fields, arguments, enum values and input fields are printed sorted by their names.
+    Alphabetical,
+}
+
+/// Style of the descriptions printed in SDL.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DescriptionStyle {
+    /// Descriptions are printed as block strings (`"""Description"""`), spanning multiple lines
+    /// whenever they contain line breaks.
+    #[default]
+    Block,
+
+    /// Descriptions are printed as single-line strings (`"Description"`), with line breaks
+    /// escaped.
+    SingleLine,
+}
+
+/// Options of printing a schema in [SDL (schema definition language)][0].
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{
+/// #     graphql_object, schema::{SdlOptions, SdlOrder}, EmptyMutation, EmptySubscription,
+/// #     RootNode,
+/// # };
+/// #
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn version() -> &'static str {
+///         "1.0"
+///     }
+///
+///     #[graphql(deprecated = "Use `version` instead.")]
+///     fn api_version() -> &'static str {
+///         "1.0"
+///     }
+/// }
+///
+/// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+///
+/// assert_eq!(
+///     schema.to_sdl(
+///         &SdlOptions::default()
+///             .order(SdlOrder::Alphabetical)
+///             .comment_deprecations(true),
+///     ),
+///     "type Query {\n  \
+///        ## Deprecated: Use `version` instead.\n  \
+///        apiVersion: String!\n  \
+///        version: String!\n\
+///      }\n",
+/// );
+/// ```
+///
+/// [0]: https://graphql.org/learn/schema#type-language
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SdlOptions {
+    order: SdlOrder,
+    builtins: bool,
+    description_style: DescriptionStyle,
+    comment_deprecations: bool,
+}
+
+impl SdlOptions {
+    /// Sets the [`SdlOrder`] of the printed definitions.
+    ///
+    /// [`SdlOrder::Definition`] by default.
+    #[must_use]
+    pub fn order(mut self, order: SdlOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets whether the built-in scalars (`Int`, `Float`, `String`, `Boolean` and `ID`) and
+    /// directives (`@include`, `@skip`, `@deprecated`, `@specifiedBy` and `@oneOf`) should be
+    /// printed.
+    ///
+    /// Disabled by default.
+    #[must_use]
+    pub fn builtins(mut self, enabled: bool) -> Self {
+        self.builtins = enabled;
+        self
+    }
+
+    /// Sets the [`DescriptionStyle`] of the printed descriptions.
+    ///
+    /// [`DescriptionStyle::Block`] by default.
+    #[must_use]
+    pub fn description_style(mut self, style: DescriptionStyle) -> Self {
+        self.description_style = style;
+        self
+    }
+
+    /// Sets whether deprecations should be printed as `# Deprecated: <reason>` comments instead of
+    /// [`@deprecated`] directives.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`@deprecated`]: https://spec.graphql.org/September2025#sec--deprecated
+    #[must_use]
+    pub fn comment_deprecations(mut self, enabled: bool) -> Self {
+        self.comment_deprecations = enabled;
+        self
+    }
+}
+
+impl<S: ScalarValue> SchemaType<S> {
+    /// Prints this [`SchemaType`] in [SDL (schema definition language)][0] according to the
+    /// provided [`SdlOptions`].
+    ///
+    /// The `schema` definition is printed only if it cannot be omitted, i.e. whenever it has a
+    /// description or its root types are not named `Query`, `Mutation` and `Subscription`.
+    ///
+    /// [0]: https://graphql.org/learn/schema#type-language
+    #[must_use]
+    pub fn to_sdl(&self, options: &SdlOptions) -> String {
+        let printer = Printer {
+            schema: self,
+            options,
+        };
+
+        let mut definitions = vec![];
+        if let Some(def) = printer.schema_definition() {
+            definitions.push(def);
+        }
+        definitions.extend(
+            printer
+                .directives()
+                .into_iter()
+                .map(|d| printer.directive(d)),
+        );
+        definitions.extend(printer.types().into_iter().map(|t| printer.meta_type(t)));
+
+        definitions.join("\n")
+    }
+}
+
+/// Printer of a [`SchemaType`] in SDL.
+struct Printer<'a, S> {
+    /// [`SchemaType`] being printed.
+    schema: &'a SchemaType<S>,
+
+    /// [`SdlOptions`] to print with.
+    options: &'a SdlOptions,
+}
+
+impl<S: ScalarValue> Printer<'_, S> {
+    /// Prints the `schema` definition, unless it can be omitted.
+    fn schema_definition(&self) -> Option<String> {
+        let SchemaType {
+            description,
+            query_type_name,
+            mutation_type_name,
+            subscription_type_name,
+            ..
+        } = self.schema;
+
+        let is_conventional = query_type_name == "Query"
+            && mutation_type_name
+                .as_deref()
+                .is_none_or(|n| n == "Mutation")
+            && subscription_type_name
+                .as_deref()
+                .is_none_or(|n| n == "Subscription");
+        if is_conventional && description.is_none() {
+            return None;
+        }
+
+        let mut out = String::new();
+        self.description(&mut out, description.as_deref(), "");
+        out.push_str("schema {\n");
+        _ = writeln!(out, "  query: {query_type_name}");
+        if let Some(name) = mutation_type_name {
+            _ = writeln!(out, "  mutation: {name}");
+        }
+        if let Some(name) = subscription_type_name {
+            _ = writeln!(out, "  subscription: {name}");
+        }
+        out.push_str("}\n");
+        Some(out)
+    }
+
+    /// Returns the [`DirectiveType`]s to be printed, sorted by their names.
+    fn directives(&self) -> Vec<&DirectiveType<S>> {
+        let mut directives = self
+            .schema
+            .directives
+            .values()
+            .filter(|d| self.options.builtins || !BUILTIN_DIRECTIVES.contains(&d.name.as_str()))
+            .collect::<Vec<_>>();
+        directives.sort_by(|a, b| a.name.cmp(&b.name));
+        directives
+    }
+
+    /// Returns the [`MetaType`]s to be printed, in the [`SdlOrder`] of the [`SdlOptions`].
+    fn types(&self) -> Vec<&MetaType<S>> {
+        let is_printed = |meta: &MetaType<S>| {
+            let name = meta.name().map(|n| n.as_str()).unwrap_or_default();
+            !meta.is_builtin() || self.options.builtins && BUILTIN_SCALARS.contains(&name)
+        };
+
+        let mut sorted = self
+            .schema
+            .types
+            .values()
+            .filter(|t| is_printed(t))
+            .collect::<Vec<_>>();
+        sorted.sort_by(|a, b| a.name().cmp(&b.name()));
+
+        match self.options.order {
+            SdlOrder::Alphabetical => sorted,
+            SdlOrder::Definition => {
+                let mut types = self
+                    .schema
+                    .definition_order
+                    .iter()
+                    .filter_map(|n| self.schema.types.get(n))
+                    .filter(|t| is_printed(t))
+                    .collect::<Vec<_>>();
+                // Types absent in the definition order are the ones inserted into the schema
+                // manually, so are printed last.
+                for meta in sorted {
+                    if !types.iter().any(|t| std::ptr::eq(*t, meta)) {
+                        types.push(meta);
+                    }
+                }
+                types
+            }
+        }
+    }
+
+    /// Sorts the provided `items` by their names, if required by the [`SdlOptions`].
+    fn ordered<'i, T>(&self, items: &'i [T], name: impl Fn(&T) -> &str) -> Vec<&'i T> {
+        let mut items = items.iter().collect::<Vec<_>>();
+        if self.options.order == SdlOrder::Alphabetical {
+            items.sort_by(|a, b| name(a).cmp(name(b)));
+        }
+        items
+    }
+
+    /// Prints the provided [`DirectiveType`] definition.
+    fn directive(&self, directive: &DirectiveType<S>) -> String {
+        let mut out = String::new();
+        self.description(&mut out, directive.description.as_deref(), "");
+        _ = write!(out, "directive @{}", directive.name);
+        self.arguments(&mut out, &directive.arguments, "");
+        if directive.is_repeatable {
+            out.push_str(" repeatable");
+        }
+        let locations = directive
+            .locations
+            .iter()
+            .map(|l| l.to_string().to_uppercase().replace(' ', "_"))
+            .collect::<Vec<_>>();
+        _ = writeln!(out, " on {}", locations.join(" | "));
+        out
+    }
+
+    /// Prints the provided [`MetaType`] definition.
+    fn meta_type(&self, meta: &MetaType<S>) -> String {
+        let mut out = String::new();
+        match meta {
+            MetaType::Scalar(ScalarMeta {
+                name,
+                description,
+                specified_by_url,
+                directives,
+                ..
+            }) => {
+                self.description(&mut out, description.as_deref(), "");
+                _ = write!(out, "scalar {name}");
+                if let Some(url) = specified_by_url {
+                    _ = write!(out, " @specifiedBy(url: {})", string(url));
+                }
+                applied_directives(&mut out, directives);
+                out.push('\n');
+            }
+            MetaType::Object(ObjectMeta {
+                name,
+                description,
+                fields,
+                interface_names,
+                directives,
+                ..
+            })
+            | MetaType::Interface(InterfaceMeta {
+                name,
+                description,
+                fields,
+                interface_names,
+                directives,
+                ..
+            }) => {
+                let keyword = match meta {
+                    MetaType::Object(..) => "type",
+                    _ => "interface",
+                };
+                self.description(&mut out, description.as_deref(), "");
+                _ = write!(out, "{keyword} {name}");
+                if !interface_names.is_empty() {
+                    _ = write!(out, " implements {}", interface_names.join(" & "));
+                }
+                applied_directives(&mut out, directives);
+                let fields = self
+                    .ordered(fields, |f| &f.name)
+                    .into_iter()
+                    .filter(|f| !f.is_builtin())
+                    .collect::<Vec<_>>();
+                block(&mut out, fields, |out, f| self.field(out, f));
+            }
+            MetaType::Union(UnionMeta {
+                name,
+                description,
+                of_type_names,
+                directives,
+                ..
+            }) => {
+                self.description(&mut out, description.as_deref(), "");
+                _ = write!(out, "union {name}");
+                applied_directives(&mut out, directives);
+                if !of_type_names.is_empty() {
+                    _ = write!(out, " = {}", of_type_names.join(" | "));
+                }
+                out.push('\n');
+            }
+            MetaType::Enum(EnumMeta {
+                name,
+                description,
+                values,
+                directives,
+                ..
+            }) => {
+                self.description(&mut out, description.as_deref(), "");
+                _ = write!(out, "enum {name}");
+                applied_directives(&mut out, directives);
+                let values = self.ordered(values, |v| &v.name);
+                block(&mut out, values, |out, v| self.enum_value(out, v));
+            }
+            MetaType::InputObject(InputObjectMeta {
+                name,
+                description,
+                input_fields,
+                is_one_of,
+                directives,
+                ..
+            }) => {
+                self.description(&mut out, description.as_deref(), "");
+                _ = write!(out, "input {name}");
+                if *is_one_of {
+                    out.push_str(" @oneOf");
+                }
+                applied_directives(&mut out, directives);
+                let fields = self.ordered(input_fields, |f| &f.name);
+                block(&mut out, fields, |out, f| self.input_value(out, f, "  "));
+            }
+            MetaType::List(..) | MetaType::Nullable(..) | MetaType::Placeholder(..) => {}
+        }
+        out
+    }
+
+    /// Prints the provided [`Field`] definition.
+    fn field(&self, out: &mut String, field: &Field<S>) {
+        self.description(out, field.description.as_deref(), "  ");
+        self.deprecation_comment(out, &field.deprecation_status, "  ");
+        _ = write!(out, "  {}", field.name);
+        self.arguments(out, field.arguments.as_deref().unwrap_or_default(), "  ");
+        _ = write!(out, ": {}", field.field_type);
+        self.deprecation_directive(out, &field.deprecation_status);
+        applied_directives(out, &field.directives);
+        out.push('\n');
+    }
+
+    /// Prints the provided [`Argument`]s, placing each of them on a separate line whenever any
+    /// of them is preceded by a description or a comment.
+    fn arguments(&self, out: &mut String, args: &[Argument<S>], indent: &str) {
+        let args = self
+            .ordered(args, |a| &a.name)
+            .into_iter()
+            .filter(|a| !a.is_builtin())
+            .collect::<Vec<_>>();
+        if args.is_empty() {
+            return;
+        }
+
+        let is_multiline = args.iter().any(|a| {
+            a.description.is_some()
+                || self.options.comment_deprecations && a.deprecation_status.is_deprecated()
+        });
+        if is_multiline {
+            out.push_str("(\n");
+            let arg_indent = format!("{indent}  ");
+            for arg in args {
+                self.input_value(out, arg, &arg_indent);
+            }
+            _ = write!(out, "{indent})");
+        } else {
+            out.push('(');
+            for (i, arg) in args.into_iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                self.input_value_signature(out, arg);
+            }
+            out.push(')');
+        }
+    }
+
+    /// Prints the provided [`Argument`] definition on a separate line.
+    fn input_value(&self, out: &mut String, arg: &Argument<S>, indent: &str) {
+        self.description(out, arg.description.as_deref(), indent);
+        self.deprecation_comment(out, &arg.deprecation_status, indent);
+        out.push_str(indent);
+        self.input_value_signature(out, arg);
+        out.push('\n');
+    }
+
+    /// Prints the provided [`Argument`] definition without its description.
+    fn input_value_signature(&self, out: &mut String, arg: &Argument<S>) {
+        _ = write!(out, "{}: {}", arg.name, arg.arg_type);
+        if let Some(default) = &arg.default_value {
+            out.push_str(" = ");
+            value(out, default);
+        }
+        self.deprecation_directive(out, &arg.deprecation_status);
+        applied_directives(out, &arg.directives);
+    }
+
+    /// Prints the provided [`EnumValue`] definition.
+    fn enum_value(&self, out: &mut String, val: &EnumValue) {
+        self.description(out, val.description.as_deref(), "  ");
+        self.deprecation_comment(out, &val.deprecation_status, "  ");
+        _ = write!(out, "  {}", val.name);
+        self.deprecation_directive(out, &val.deprecation_status);
+        applied_directives(out, &val.directives);
+        out.push('\n');
+    }
+
+    /// Prints the provided `description` on a separate line, in the [`DescriptionStyle`] of the
+    /// [`SdlOptions`].
+    fn description(&self, out: &mut String, description: Option<&str>, indent: &str) {
+        let Some(text) = description else { return };
+        match self.options.description_style {
+            DescriptionStyle::SingleLine => {
+                _ = writeln!(out, "{indent}{}", string(text));
+            }
+            DescriptionStyle::Block => {
+                let text = text.replace(r#"""""#, r#"\""""#);
+                let is_multiline = text.contains('\n')
+                    || text.ends_with(['"', '\\'])
+                    || text.starts_with([' ', '\t']);
+                if is_multiline {
+                    _ = writeln!(out, r#"{indent}""""#);
+                    for line in text.lines() {
+                        if line.is_empty() {
+                            out.push('\n');
+                        } else {
+                            _ = writeln!(out, "{indent}{line}");
+                        }
+                    }
+                    _ = writeln!(out, r#"{indent}""""#);
+                } else {
+                    _ = writeln!(out, r#"{indent}"""{text}""""#);
+                }
+            }
+        }
+    }
+
+    /// Prints the provided [`DeprecationStatus`] as a comment on a separate line, if required by
+    /// the [`SdlOptions`].
+    fn deprecation_comment(&self, out: &mut String, status: &DeprecationStatus, indent: &str) {
+        if !self.options.comment_deprecations {
+            return;
+        }
+        match status {
+            DeprecationStatus::Current => {}
+            DeprecationStatus::Deprecated(None) => _ = writeln!(out, "{indent}# Deprecated"),
+            DeprecationStatus::Deprecated(Some(reason)) => {
+                let mut lines = reason.lines();
+                _ = writeln!(
+                    out,
+                    "{indent}# Deprecated: {}",
+                    lines.next().unwrap_or_default(),
+                );
+                for line in lines {
+                    _ = writeln!(out, "{indent}# {line}");
+                }
+            }
+        }
+    }
+
+    /// Prints the provided [`DeprecationStatus`] as a [`@deprecated`] directive, unless
+    /// deprecations are printed as comments.
+    ///
+    /// [`@deprecated`]: https://spec.graphql.org/September2025#sec--deprecated
+    fn deprecation_directive(&self, out: &mut String, status: &DeprecationStatus) {
+        if self.options.comment_deprecations {
+            return;
+        }
+        match status {
+            DeprecationStatus::Current => {}
+            DeprecationStatus::Deprecated(None) => out.push_str(" @deprecated"),
+            DeprecationStatus::Deprecated(Some(reason)) => {
+                _ = write!(out, " @deprecated(reason: {})", string(reason));
+            }
+        }
+    }
+}
+
+/// Prints the provided `items` as a `{ ... }` block, omitting it if there are no `items`.
+fn block<T>(out: &mut String, items: Vec<T>, item: impl Fn(&mut String, T)) {
+    if items.is_empty() {
+        out.push('\n');
+        return;
+    }
+    out.push_str(" {\n");
+    for i in items {
+        item(out, i);
+    }
+    out.push_str("}\n");
+}
+
+/// Prints the provided [`AppliedDirective`]s.
+fn applied_directives(out: &mut String, directives: &[AppliedDirective]) {
+    for directive in directives {
+        _ = write!(out, " @{}", directive.name);
+        if !directive.arguments.is_empty() {
+            out.push('(');
+            for (i, (name, val)) in directive.arguments.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                _ = write!(out, "{name}: ");
+                value(out, val);
+            }
+            out.push(')');
+        }
+    }
+}
+
+/// Prints the provided [`InputValue`] as a GraphQL literal.
+fn value<S: ScalarValue>(out: &mut String, val: &InputValue<S>) {
+    match val {
+        InputValue::Null => out.push_str("null"),
+        InputValue::Scalar(s) => match s.try_to_string() {
+            Some(s) => out.push_str(&string(&s)),
+            None => _ = write!(out, "{}", <&Scalar<S>>::from(s)),
+        },
+        InputValue::Enum(v) => out.push_str(v),
+        InputValue::Variable(v) => _ = write!(out, "${v}"),
+        InputValue::List(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                value(out, &item.item);
+            }
+            out.push(']');
+        }
+        InputValue::Object(fields) => {
+            out.push('{');
+            for (i, (name, item)) in fields.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                _ = write!(out, "{}: ", name.item);
+                value(out, &item.item);
+            }
+            out.push('}');
+        }
+    }
+}
+
+/// Prints the provided `text` as a single-line GraphQL string literal.
+fn string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str(r#"\""#),
+            '\\' => out.push_str(r"\\"),
+            '\n' => out.push_str(r"\n"),
+            '\r' => out.push_str(r"\r"),
+            '\t' => out.push_str(r"\t"),
+            c if c.is_control() => _ = write!(out, "\\u{:04X}", u32::from(c)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        EmptyMutation, EmptySubscription, GraphQLEnum, GraphQLInputObject, GraphQLObject,
+        GraphQLUnion, RootNode, graphql_interface, graphql_object,
+    };
+
+    use super::{DescriptionStyle, SdlOptions, SdlOrder};
+
+    /// Kind of a pet.
+    #[derive(GraphQLEnum)]
+    enum Kind {
+        Dog,
+        #[graphql(deprecated = "Cats are \"special\".")]
+        Cat,
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct Filter {
+        /// Name to look for.
+        name: Option<String>,
+        #[graphql(default = 10)]
+        limit: i32,
+    }
+
+    #[graphql_interface(for = Pet)]
+    #[expect(dead_code, reason = "GraphQL schema testing")]
+    trait Named {
+        fn name(&self) -> &str;
+    }
+
+    /// A pet.
+    ///
+    /// Possibly a good one.
+    #[derive(GraphQLObject)]
+    #[graphql(impl = NamedValue)]
+    struct Pet {
+        name: String,
+        kind: Kind,
+    }
+
+    #[derive(GraphQLObject)]
+    struct Owner {
+        pets: Vec<Pet>,
+    }
+
+    #[derive(GraphQLUnion)]
+    #[expect(dead_code, reason = "GraphQL schema testing")]
+    enum Entity {
+        Pet(Pet),
+        Owner(Owner),
+    }
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn pets(filter: Filter, #[graphql(desc = "Maximum age.")] age: Option<i32>) -> Vec<Pet> {
+            _ = (filter, age);
+            vec![]
+        }
+
+        fn entity() -> Option<Entity> {
+            None
+        }
+
+        #[graphql(deprecated)]
+        fn named() -> Option<NamedValue> {
+            None
+        }
+    }
+
+    fn schema() -> RootNode<Root, EmptyMutation, EmptySubscription> {
+        RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[test]
+    fn prints_in_definition_order() {
+        assert_eq!(
+            schema().to_sdl(&SdlOptions::default()),
+            r#"schema {
+  query: Root
+}
+
+type Root {
+  pets(
+    filter: Filter!
+    """Maximum age."""
+    age: Int
+  ): [Pet!]!
+  entity: Entity
+  named: Named @deprecated
+}
+
+"""
+A pet.
+
+Possibly a good one.
+"""
+type Pet implements Named {
+  name: String!
+  kind: Kind!
+}
+
+"""Kind of a pet."""
+enum Kind {
+  DOG
+  CAT @deprecated(reason: "Cats are \"special\".")
+}
+
+interface Named {
+  name: String!
+}
+
+input Filter {
+  """Name to look for."""
+  name: String
+  limit: Int! = 10
+}
+
+union Entity = Pet | Owner
+
+type Owner {
+  pets: [Pet!]!
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn prints_in_alphabetical_order() {
+        assert_eq!(
+            schema().to_sdl(
+                &SdlOptions::default()
+                    .order(SdlOrder::Alphabetical)
+                    .description_style(DescriptionStyle::SingleLine)
+                    .comment_deprecations(true),
+            ),
+            r#"schema {
+  query: Root
+}
+
+union Entity = Pet | Owner
+
+input Filter {
+  limit: Int! = 10
+  "Name to look for."
+  name: String
+}
+
+"Kind of a pet."
+enum Kind {
+  # Deprecated: Cats are "special".
+  CAT
+  DOG
+}
+
+interface Named {
+  name: String!
+}
+
+type Owner {
+  pets: [Pet!]!
+}
+
+"A pet.\n\nPossibly a good one."
+type Pet implements Named {
+  kind: Kind!
+  name: String!
+}
+
+type Root {
+  entity: Entity
+  # Deprecated
+  named: Named
+  pets(
+    "Maximum age."
+    age: Int
+    filter: Filter!
+  ): [Pet!]!
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn prints_builtins() {
+        let sdl = schema().to_sdl(&SdlOptions::default().builtins(true));
+
+        for definition in [
+            "scalar Boolean\n",
+            "scalar String\n",
+            "directive @deprecated(reason: String! = \"No longer supported\") \
+             on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION | ENUM_VALUE\n",
+            "directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT\n",
+            "directive @oneOf on INPUT_OBJECT\n",
+        ] {
+            assert!(
+                sdl.contains(definition),
+                "missing `{definition}` in:\n{sdl}"
+            );
+        }
+        assert!(!sdl.contains("__Type"), "{sdl}");
+    }
+
+    #[cfg(feature = "schema-language")]
+    #[test]
+    fn prints_parsable_sdl() {
+        for options in [
+            SdlOptions::default().builtins(true),
+            SdlOptions::default()
+                .order(SdlOrder::Alphabetical)
+                .description_style(DescriptionStyle::SingleLine)
+                .comment_deprecations(true),
+        ] {
+            let sdl = schema().to_sdl(&options);
+
+            if let Err(e) = graphql_parser::parse_schema::<&str>(&sdl) {
+                panic!("invalid SDL: {e}\n{sdl}");
+            }
+        }
+    }
+}
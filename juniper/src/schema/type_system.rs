@@ -0,0 +1,877 @@
+//! [Type system validation][0] of a [`SchemaType`].
+//!
+//! [0]: https://spec.graphql.org/September2025#sec-Type-System
+
+use derive_more::with_trait::{Display, Error};
+use fnv::FnvHashSet;
+
+use crate::{
+    ast::{Type, TypeModifier},
+    schema::{
+        meta::{
+            Argument, EnumMeta, Field, InputObjectMeta, InterfaceMeta, MetaType, ObjectMeta,
+            UnionMeta,
+        },
+        model::SchemaType,
+    },
+};
+
+/// Names of the types used exclusively by the GraphQL introspection system.
+const INTROSPECTION_TYPES: [&str; 8] = [
+    "__Directive",
+    "__DirectiveLocation",
+    "__EnumValue",
+    "__Field",
+    "__InputValue",
+    "__Schema",
+    "__Type",
+    "__TypeKind",
+];
+
+/// Names of the fields used exclusively by the GraphQL introspection system.
+const INTROSPECTION_FIELDS: [&str; 3] = ["__schema", "__type", "__typename"];
+
+/// Error of the [type system validation][0] of a schema.
+///
+/// Schema elements are referred to by their [schema coordinates][1] (like `Query.user(id:)`).
+///
+/// [0]: https://spec.graphql.org/September2025#sec-Type-System
+/// [1]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+#[derive(Clone, Debug, Display, Eq, Error, PartialEq)]
+pub enum SchemaError {
+    /// Several different Rust types define a GraphQL type with the same name.
+    #[display("type `{name}` is defined by multiple Rust types: `{first}` and `{second}`")]
+    TypeNameCollision {
+        /// Name of the GraphQL type.
+        name: String,
+
+        /// Name of the Rust type registered first.
+        first: String,
+
+        /// Name of the Rust type colliding with the first one.
+        second: String,
+    },
+
+    /// Type is referenced, but its definition has never been registered.
+    #[display("type `{name}` is referenced, but never defined")]
+    PlaceholderType {
+        /// Name of the type.
+        name: String,
+    },
+
+    /// Root operation type is not defined.
+    #[display("{operation} root type `{name}` is not defined")]
+    MissingRootType {
+        /// Operation the root type is used for (`query`, `mutation` or `subscription`).
+        operation: &'static str,
+
+        /// Name of the root type.
+        name: String,
+    },
+
+    /// Root operation type is not an object.
+    #[display("{operation} root type `{name}` is not an object")]
+    InvalidRootType {
+        /// Operation the root type is used for (`query`, `mutation` or `subscription`).
+        operation: &'static str,
+
+        /// Name of the root type.
+        name: String,
+    },
+
+    /// Name uses the `__` prefix reserved for the introspection system.
+    #[display("`{coordinate}` uses the `__` prefix reserved for introspection")]
+    ReservedName {
+        /// Coordinate of the schema element.
+        coordinate: String,
+    },
+
+    /// Name is defined multiple times in the same scope.
+    #[display("`{coordinate}` is defined multiple times")]
+    DuplicateName {
+        /// Coordinate of the duplicated schema element.
+        coordinate: String,
+    },
+
+    /// Type defines no fields, members or values, while it's required to define at least one.
+    #[display("type `{name}` must define at least one {expected}")]
+    EmptyType {
+        /// Name of the empty type.
+        name: String,
+
+        /// Description of what is expected to be defined.
+        expected: &'static str,
+    },
+
+    /// Schema element references a type the schema doesn't define.
+    #[display("`{coordinate}` references unknown type `{name}`")]
+    UnknownType {
+        /// Coordinate of the referencing schema element.
+        coordinate: String,
+
+        /// Name of the referenced type.
+        name: String,
+    },
+
+    /// Schema element references a type of a kind it cannot reference.
+    #[display("`{coordinate}` must reference {expected} type, but `{name}` is not")]
+    InvalidTypeKind {
+        /// Coordinate of the referencing schema element.
+        coordinate: String,
+
+        /// Name of the referenced type.
+        name: String,
+
+        /// Description of the expected kind of type.
+        expected: &'static str,
+    },
+
+    /// Type doesn't implement an interface correctly.
+    #[display("type `{name}` doesn't implement interface `{interface}` correctly: {reason}")]
+    InvalidImplementation {
+        /// Name of the implementing type.
+        name: String,
+
+        /// Name of the implemented interface.
+        interface: String,
+
+        /// Description of the violation.
+        reason: String,
+    },
+
+    /// Input object references itself through non-null singular fields, so no finite value of it
+    /// can be provided.
+    #[display("input object `{name}` references itself through non-null fields: {path}")]
+    InputObjectCycle {
+        /// Name of the input object.
+        name: String,
+
+        /// Coordinates of the fields forming the cycle.
+        path: String,
+    },
+
+    /// Field of a [`@oneOf`] input object is non-null or has a default value.
+    ///
+    /// [`@oneOf`]: https://spec.graphql.org/September2025#sec--oneOf
+    #[display("`{coordinate}` of `@oneOf` input object must be nullable without a default value")]
+    InvalidOneOfField {
+        /// Coordinate of the input field.
+        coordinate: String,
+    },
+}
+
+impl<S> SchemaType<S> {
+    /// Performs the [type system validation][0] of this [`SchemaType`], returning all the found
+    /// [`SchemaError`]s.
+    ///
+    /// [0]: https://spec.graphql.org/September2025#sec-Type-System
+    pub(crate) fn type_system_errors(&self) -> Vec<SchemaError> {
+        let mut errors = vec![];
+
+        let roots = [
+            ("query", Some(&self.query_type_name)),
+            ("mutation", self.mutation_type_name.as_ref()),
+            ("subscription", self.subscription_type_name.as_ref()),
+        ];
+        for (operation, name) in roots {
+            let Some(name) = name else { continue };
+            match self.types.get(name.as_str()) {
+                None => errors.push(SchemaError::MissingRootType {
+                    operation,
+                    name: name.clone(),
+                }),
+                Some(MetaType::Object(..)) => {}
+                Some(_) => errors.push(SchemaError::InvalidRootType {
+                    operation,
+                    name: name.clone(),
+                }),
+            }
+        }
+
+        let mut types = self.types.values().collect::<Vec<_>>();
+        types.sort_by_key(|t| t.name());
+        for meta in &types {
+            let Some(name) = meta.name() else { continue };
+            if name.starts_with("__") && !INTROSPECTION_TYPES.contains(&name.as_str()) {
+                errors.push(SchemaError::ReservedName {
+                    coordinate: name.to_string(),
+                });
+            } else if meta.is_builtin() {
+                continue;
+            }
+
+            match meta {
+                MetaType::Object(ObjectMeta {
+                    fields,
+                    interface_names,
+                    ..
+                })
+                | MetaType::Interface(InterfaceMeta {
+                    fields,
+                    interface_names,
+                    ..
+                }) => {
+                    self.check_fields(name, fields, &mut errors);
+                    self.check_implementations(name, fields, interface_names, &mut errors);
+                }
+                MetaType::Union(meta) => self.check_union(meta, &mut errors),
+                MetaType::Enum(meta) => check_enum(meta, &mut errors),
+                MetaType::InputObject(meta) => self.check_input_object(meta, &mut errors),
+                MetaType::List(..)
+                | MetaType::Nullable(..)
+                | MetaType::Placeholder(..)
+                | MetaType::Scalar(..) => {}
+            }
+        }
+
+        self.check_input_cycles(&types, &mut errors);
+
+        errors
+    }
+
+    /// Checks the `fields` of the object or interface type with the provided `name`.
+    fn check_fields(&self, name: &str, fields: &[Field<S>], errors: &mut Vec<SchemaError>) {
+        let mut names = FnvHashSet::default();
+        let mut has_fields = false;
+        for field in fields {
+            let coordinate = format!("{name}.{}", field.name);
+            if INTROSPECTION_FIELDS.contains(&field.name.as_str()) {
+                continue;
+            }
+            has_fields = true;
+            if !names.insert(&field.name) {
+                errors.push(SchemaError::DuplicateName { coordinate });
+                continue;
+            }
+            if field.name.starts_with("__") {
+                errors.push(SchemaError::ReservedName {
+                    coordinate: coordinate.clone(),
+                });
+            }
+            self.check_type_ref(&coordinate, &field.field_type, false, errors);
+
+            let mut arg_names = FnvHashSet::default();
+            for arg in field.arguments.iter().flatten() {
+                let coordinate = format!("{name}.{}({}:)", field.name, arg.name);
+                if !arg_names.insert(&arg.name) {
+                    errors.push(SchemaError::DuplicateName { coordinate });
+                    continue;
+                }
+                if arg.name.starts_with("__") {
+                    errors.push(SchemaError::ReservedName {
+                        coordinate: coordinate.clone(),
+                    });
+                }
+                self.check_type_ref(&coordinate, &arg.arg_type, true, errors);
+            }
+        }
+        if !has_fields {
+            errors.push(SchemaError::EmptyType {
+                name: name.into(),
+                expected: "field",
+            });
+        }
+    }
+
+    /// Checks that the type referenced by the schema element with the provided `coordinate`
+    /// exists and is either an input or an output one.
+    fn check_type_ref(
+        &self,
+        coordinate: &str,
+        ty: &Type,
+        input: bool,
+        errors: &mut Vec<SchemaError>,
+    ) {
+        let name = ty.innermost_name();
+        match self.types.get(name) {
+            None => errors.push(SchemaError::UnknownType {
+                coordinate: coordinate.into(),
+                name: name.into(),
+            }),
+            Some(meta) => {
+                let is_output = !matches!(meta, MetaType::InputObject(..));
+                if input && !meta.is_input() || !input && !is_output {
+                    errors.push(SchemaError::InvalidTypeKind {
+                        coordinate: coordinate.into(),
+                        name: name.into(),
+                        expected: if input { "an input" } else { "an output" },
+                    });
+                }
+            }
+        }
+    }
+
+    /// Checks that the object or interface type with the provided `name` and `fields` implements
+    /// all the `interfaces` it declares correctly.
+    fn check_implementations(
+        &self,
+        name: &str,
+        fields: &[Field<S>],
+        interfaces: &[arcstr::ArcStr],
+        errors: &mut Vec<SchemaError>,
+    ) {
+        for iface_name in interfaces {
+            let invalid = |reason: String| SchemaError::InvalidImplementation {
+                name: name.into(),
+                interface: iface_name.to_string(),
+                reason,
+            };
+
+            let Some(MetaType::Interface(iface)) = self.types.get(iface_name.as_str()) else {
+                errors.push(match self.types.get(iface_name.as_str()) {
+                    None => SchemaError::UnknownType {
+                        coordinate: name.into(),
+                        name: iface_name.to_string(),
+                    },
+                    Some(_) => SchemaError::InvalidTypeKind {
+                        coordinate: name.into(),
+                        name: iface_name.to_string(),
+                        expected: "an interface",
+                    },
+                });
+                continue;
+            };
+            if iface.name == name {
+                errors.push(invalid("type cannot implement itself".into()));
+                continue;
+            }
+
+            for transitive in &iface.interface_names {
+                if !interfaces.contains(transitive) {
+                    errors.push(invalid(format!(
+                        "interface `{transitive}` implemented by `{iface_name}` is not implemented",
+                    )));
+                }
+            }
+
+            for iface_field in &iface.fields {
+                if INTROSPECTION_FIELDS.contains(&iface_field.name.as_str()) {
+                    continue;
+                }
+                let field_name = &iface_field.name;
+                let Some(field) = fields.iter().find(|f| f.name == *field_name) else {
+                    errors.push(invalid(format!("field `{field_name}` is missing")));
+                    continue;
+                };
+                if !self.is_valid_implementation_type(&field.field_type, &iface_field.field_type) {
+                    errors.push(invalid(format!(
+                        "field `{field_name}` is of type `{}`, but `{}` or its subtype is expected",
+                        field.field_type, iface_field.field_type,
+                    )));
+                }
+
+                let args = field.arguments.as_deref().unwrap_or_default();
+                let iface_args = iface_field.arguments.as_deref().unwrap_or_default();
+                for iface_arg in iface_args {
+                    let arg_name = &iface_arg.name;
+                    match args.iter().find(|a| a.name == *arg_name) {
+                        None => errors.push(invalid(format!(
+                            "argument `{field_name}({arg_name}:)` is missing",
+                        ))),
+                        Some(arg) if arg.arg_type != iface_arg.arg_type => {
+                            errors.push(invalid(format!(
+                                "argument `{field_name}({arg_name}:)` is of type `{}`, but `{}` \
+                                 is expected",
+                                arg.arg_type, iface_arg.arg_type,
+                            )))
+                        }
+                        Some(_) => {}
+                    }
+                }
+                for arg in args {
+                    let is_extra = !iface_args.iter().any(|a| a.name == arg.name);
+                    if is_extra && arg.arg_type.is_non_null() && arg.default_value.is_none() {
+                        errors.push(invalid(format!(
+                            "additional argument `{field_name}({}:)` must not be required",
+                            arg.name,
+                        )));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Indicates whether the provided `sub_type` of an implementing field is a valid
+    /// implementation of the provided `super_type` of an interface field.
+    ///
+    /// See the [`IsValidImplementationFieldType()`][0] algorithm for details.
+    ///
+    /// [0]: https://spec.graphql.org/September2025#IsValidImplementationFieldType()
+    fn is_valid_implementation_type(
+        &self,
+        sub_type: &Type<impl AsRef<str>, impl AsRef<[TypeModifier]>>,
+        super_type: &Type<impl AsRef<str>, impl AsRef<[TypeModifier]>>,
+    ) -> bool {
+        match (sub_type.modifier(), super_type.modifier()) {
+            (Some(TypeModifier::NonNull), Some(TypeModifier::NonNull))
+            | (Some(TypeModifier::List(..)), Some(TypeModifier::List(..))) => self
+                .is_valid_implementation_type(&sub_type.borrow_inner(), &super_type.borrow_inner()),
+            (Some(TypeModifier::NonNull), _) => {
+                self.is_valid_implementation_type(&sub_type.borrow_inner(), super_type)
+            }
+            (None, None) => {
+                let (sub_name, super_name) =
+                    (sub_type.innermost_name(), super_type.innermost_name());
+                sub_name == super_name
+                    || match (self.types.get(sub_name), self.types.get(super_name)) {
+                        (
+                            Some(
+                                MetaType::Object(ObjectMeta {
+                                    interface_names, ..
+                                })
+                                | MetaType::Interface(InterfaceMeta {
+                                    interface_names, ..
+                                }),
+                            ),
+                            Some(MetaType::Interface(..)),
+                        ) => interface_names.iter().any(|n| n == super_name),
+                        (
+                            Some(MetaType::Object(..)),
+                            Some(MetaType::Union(UnionMeta { of_type_names, .. })),
+                        ) => of_type_names.iter().any(|n| n == sub_name),
+                        _ => false,
+                    }
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks the members of the provided union type.
+    fn check_union(&self, meta: &UnionMeta, errors: &mut Vec<SchemaError>) {
+        if meta.of_type_names.is_empty() {
+            errors.push(SchemaError::EmptyType {
+                name: meta.name.to_string(),
+                expected: "member type",
+            });
+        }
+        for member in &meta.of_type_names {
+            match self.types.get(member.as_str()) {
+                None => errors.push(SchemaError::UnknownType {
+                    coordinate: meta.name.to_string(),
+                    name: member.to_string(),
+                }),
+                Some(MetaType::Object(..)) => {}
+                Some(_) => errors.push(SchemaError::InvalidTypeKind {
+                    coordinate: meta.name.to_string(),
+                    name: member.to_string(),
+                    expected: "an object",
+                }),
+            }
+        }
+    }
+
+    /// Checks the fields of the provided input object type.
+    fn check_input_object(&self, meta: &InputObjectMeta<S>, errors: &mut Vec<SchemaError>) {
+        if meta.input_fields.is_empty() {
+            errors.push(SchemaError::EmptyType {
+                name: meta.name.to_string(),
+                expected: "input field",
+            });
+        }
+        let mut names = FnvHashSet::default();
+        for field in &meta.input_fields {
+            let coordinate = format!("{}.{}", meta.name, field.name);
+            if !names.insert(&field.name) {
+                errors.push(SchemaError::DuplicateName { coordinate });
+                continue;
+            }
+            if field.name.starts_with("__") {
+                errors.push(SchemaError::ReservedName {
+                    coordinate: coordinate.clone(),
+                });
+            }
+            if meta.is_one_of && (field.arg_type.is_non_null() || field.default_value.is_some()) {
+                errors.push(SchemaError::InvalidOneOfField {
+                    coordinate: coordinate.clone(),
+                });
+            }
+            self.check_type_ref(&coordinate, &field.arg_type, true, errors);
+        }
+    }
+
+    /// Checks that none of the provided input object `types` references itself through non-null
+    /// singular fields.
+    ///
+    /// Every cycle is reported only once.
+    fn check_input_cycles(&self, types: &[&MetaType<S>], errors: &mut Vec<SchemaError>) {
+        let mut visited = FnvHashSet::default();
+        for meta in types {
+            if let MetaType::InputObject(meta) = meta {
+                let mut path = vec![];
+                self.detect_input_cycle(meta, &mut visited, &mut path, errors);
+            }
+        }
+    }
+
+    /// Walks the non-null singular fields of the provided input object depth-first, reporting
+    /// the cycles formed by the fields on the current `path`.
+    fn detect_input_cycle<'s>(
+        &'s self,
+        meta: &'s InputObjectMeta<S>,
+        visited: &mut FnvHashSet<&'s str>,
+        path: &mut Vec<(&'s str, &'s Argument<S>)>,
+        errors: &mut Vec<SchemaError>,
+    ) {
+        if !visited.insert(meta.name.as_str()) {
+            return;
+        }
+        for field in &meta.input_fields {
+            let ty = &field.arg_type;
+            if !ty.is_non_null() || ty.is_list() {
+                continue;
+            }
+            let Some(MetaType::InputObject(next)) = self.types.get(ty.innermost_name()) else {
+                continue;
+            };
+            path.push((meta.name.as_str(), field));
+            if let Some(start) = path.iter().position(|(n, _)| *n == next.name) {
+                errors.push(SchemaError::InputObjectCycle {
+                    name: next.name.to_string(),
+                    path: path[start..]
+                        .iter()
+                        .map(|(n, f)| format!("{n}.{}", f.name))
+                        .collect::<Vec<_>>()
+                        .join(" -> "),
+                });
+            } else {
+                self.detect_input_cycle(next, visited, path, errors);
+            }
+            path.pop();
+        }
+    }
+}
+
+/// Checks the values of the provided enum type.
+fn check_enum<S>(meta: &EnumMeta<S>, errors: &mut Vec<SchemaError>) {
+    if meta.values.is_empty() {
+        errors.push(SchemaError::EmptyType {
+            name: meta.name.to_string(),
+            expected: "value",
+        });
+    }
+    let mut names = FnvHashSet::default();
+    for value in &meta.values {
+        let coordinate = format!("{}.{}", meta.name, value.name);
+        if !names.insert(&value.name) {
+            errors.push(SchemaError::DuplicateName { coordinate });
+        } else if value.name.starts_with("__") {
+            errors.push(SchemaError::ReservedName { coordinate });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::marker::PhantomData;
+
+    use arcstr::ArcStr;
+
+    use crate::{
+        DefaultScalarValue, EmptyMutation, EmptySubscription, GraphQLObject, GraphQLType,
+        GraphQLValue, Registry, RootNode, graphql_object,
+        schema::{
+            meta::{Argument, InputObjectMeta, InterfaceMeta, MetaType, ObjectMeta, UnionMeta},
+            model::SchemaType,
+        },
+    };
+
+    use super::SchemaError;
+
+    /// Function building the [`MetaType`] of a [`Dynamic`] type.
+    type MetaFn = fn(&mut Registry) -> MetaType;
+
+    /// Type defined dynamically by its [`GraphQLType::TypeInfo`], consisting of its name and the
+    /// function building its [`MetaType`].
+    struct Dynamic;
+
+    impl GraphQLType for Dynamic {
+        fn name(info: &Self::TypeInfo) -> Option<ArcStr> {
+            Some(info.0.into())
+        }
+
+        fn meta(info: &Self::TypeInfo, registry: &mut Registry) -> MetaType {
+            (info.1)(registry)
+        }
+    }
+
+    impl GraphQLValue for Dynamic {
+        type Context = ();
+        type TypeInfo = (&'static str, MetaFn);
+
+        fn type_name(&self, info: &Self::TypeInfo) -> Option<ArcStr> {
+            <Self as GraphQLType>::name(info)
+        }
+    }
+
+    fn errors_of(query: MetaFn) -> Vec<SchemaError> {
+        SchemaType::<DefaultScalarValue>::try_new::<
+            Dynamic,
+            EmptyMutation<()>,
+            EmptySubscription<()>,
+        >(&("Query", query), &(), &())
+        .err()
+        .unwrap_or_default()
+    }
+
+    mod user {
+        use crate::GraphQLObject;
+
+        #[derive(GraphQLObject)]
+        pub(super) struct User {
+            pub(super) name: String,
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    struct User {
+        id: i32,
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn user() -> User {
+            User { id: 1 }
+        }
+
+        fn boxed_user() -> Box<User> {
+            Box::new(User { id: 2 })
+        }
+    }
+
+    struct CollidingQuery;
+
+    #[graphql_object]
+    impl CollidingQuery {
+        fn user() -> User {
+            User { id: 1 }
+        }
+
+        fn named_user() -> user::User {
+            user::User {
+                name: "Alice".into(),
+            }
+        }
+    }
+
+    #[test]
+    fn accepts_valid_schema() {
+        let root = RootNode::try_new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        assert!(root.is_ok(), "unexpected errors: {:?}", root.err());
+    }
+
+    #[test]
+    fn detects_type_name_collisions() {
+        let errors = RootNode::try_new(
+            CollidingQuery,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        )
+        .err()
+        .unwrap_or_default();
+
+        assert_eq!(
+            errors,
+            [SchemaError::TypeNameCollision {
+                name: "User".into(),
+                first: "juniper::schema::type_system::tests::User".into(),
+                second: "juniper::schema::type_system::tests::user::User".into(),
+            }],
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "type `User` is defined by multiple Rust types: \
+             `juniper::schema::type_system::tests::User` and \
+             `juniper::schema::type_system::tests::user::User`",
+        );
+    }
+
+    #[test]
+    fn detects_generic_type_name_collisions() {
+        /// Generic type representing the same GraphQL type regardless of its parameter.
+        struct Connection<T>(PhantomData<T>);
+
+        impl<T> GraphQLType for Connection<T> {
+            fn name(_: &Self::TypeInfo) -> Option<ArcStr> {
+                Some(arcstr::literal!("Connection"))
+            }
+
+            fn meta(_: &Self::TypeInfo, registry: &mut Registry) -> MetaType {
+                let fields = [registry.field::<i32>("totalCount", &())];
+                ObjectMeta::new("Connection", &fields).into_meta()
+            }
+        }
+
+        impl<T> GraphQLValue for Connection<T> {
+            type Context = ();
+            type TypeInfo = ();
+
+            fn type_name(&self, info: &Self::TypeInfo) -> Option<ArcStr> {
+                <Self as GraphQLType>::name(info)
+            }
+        }
+
+        fn query(registry: &mut Registry) -> MetaType {
+            let fields = [
+                registry.field::<Connection<User>>("users", &()),
+                registry.field::<Connection<user::User>>("namedUsers", &()),
+                registry.field::<Box<Connection<User>>>("boxedUsers", &()),
+            ];
+            ObjectMeta::new("Query", &fields).into_meta()
+        }
+
+        assert_eq!(
+            errors_of(query),
+            [SchemaError::TypeNameCollision {
+                name: "Connection".into(),
+                first: "juniper::schema::type_system::tests::\
+                        detects_generic_type_name_collisions::Connection<\
+                        juniper::schema::type_system::tests::User>"
+                    .into(),
+                second: "juniper::schema::type_system::tests::\
+                         detects_generic_type_name_collisions::Connection<\
+                         juniper::schema::type_system::tests::user::User>"
+                    .into(),
+            }],
+        );
+    }
+
+    #[test]
+    fn detects_missing_root_type() {
+        fn query(_: &mut Registry) -> MetaType {
+            ObjectMeta::new("Root", &[]).into_meta()
+        }
+
+        let errors = errors_of(query);
+
+        assert!(
+            errors.contains(&SchemaError::MissingRootType {
+                operation: "query",
+                name: "Root".into(),
+            }),
+            "unexpected errors: {errors:?}",
+        );
+    }
+
+    #[test]
+    fn detects_invalid_interface_implementations() {
+        fn query(registry: &mut Registry) -> MetaType {
+            let fields = [registry.field::<Dynamic>("human", &("Human", human))];
+            ObjectMeta::new("Query", &fields).into_meta()
+        }
+        fn node(registry: &mut Registry) -> MetaType {
+            let fields = [
+                registry.field::<String>("id", &()),
+                registry
+                    .field::<Option<Dynamic>>("friend", &("Node", node))
+                    .argument(registry.arg::<i32>("index", &())),
+                registry.field::<i32>("rank", &()),
+            ];
+            InterfaceMeta::new("Node", &fields).into_meta()
+        }
+        fn human(registry: &mut Registry) -> MetaType {
+            let fields = [
+                registry.field::<Option<String>>("id", &()),
+                registry
+                    .field::<Dynamic>("friend", &("Human", human))
+                    .argument(registry.arg::<Option<i32>>("index", &()))
+                    .argument(registry.arg::<bool>("strict", &())),
+            ];
+            let node = registry.get_type::<Dynamic>(&("Node", node));
+            ObjectMeta::new("Human", &fields)
+                .interfaces(&[node])
+                .into_meta()
+        }
+
+        assert_eq!(
+            errors_of(query)
+                .into_iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>(),
+            [
+                "type `Human` doesn't implement interface `Node` correctly: \
+                 field `id` is of type `String`, but `String!` or its subtype is expected",
+                "type `Human` doesn't implement interface `Node` correctly: \
+                 argument `friend(index:)` is of type `Int`, but `Int!` is expected",
+                "type `Human` doesn't implement interface `Node` correctly: \
+                 additional argument `friend(strict:)` must not be required",
+                "type `Human` doesn't implement interface `Node` correctly: \
+                 field `rank` is missing",
+            ],
+        );
+    }
+
+    #[test]
+    fn detects_non_null_input_cycles() {
+        fn query(registry: &mut Registry) -> MetaType {
+            let fields = [registry.field::<i32>("count", &()).argument(Argument::new(
+                "filter",
+                registry.get_type::<Dynamic>(&("Filter", filter)),
+            ))];
+            ObjectMeta::new("Query", &fields).into_meta()
+        }
+        fn filter(registry: &mut Registry) -> MetaType {
+            let fields = [
+                Argument::new("and", registry.get_type::<Dynamic>(&("And", and))),
+                Argument::new(
+                    "or",
+                    registry.get_type::<Option<Dynamic>>(&("Filter", filter)),
+                ),
+            ];
+            InputObjectMeta::new::<bool>("Filter", &fields).into_meta()
+        }
+        fn and(registry: &mut Registry) -> MetaType {
+            let fields = [Argument::new(
+                "left",
+                registry.get_type::<Dynamic>(&("Filter", filter)),
+            )];
+            InputObjectMeta::new::<bool>("And", &fields).into_meta()
+        }
+
+        assert_eq!(
+            errors_of(query),
+            [SchemaError::InputObjectCycle {
+                name: "And".into(),
+                path: "And.left -> Filter.and".into(),
+            }],
+        );
+    }
+
+    #[test]
+    fn detects_empty_types_and_reserved_names() {
+        fn query(registry: &mut Registry) -> MetaType {
+            let fields = [
+                registry.field::<Option<Dynamic>>("nothing", &("Nothing", nothing)),
+                registry.field::<i32>("__secret", &()),
+                registry.field::<i32>("count", &()),
+                registry.field::<String>("count", &()),
+            ];
+            ObjectMeta::new("Query", &fields).into_meta()
+        }
+        fn nothing(_: &mut Registry) -> MetaType {
+            UnionMeta::new("Nothing", &[]).into_meta()
+        }
+
+        assert_eq!(
+            errors_of(query),
+            [
+                SchemaError::EmptyType {
+                    name: "Nothing".into(),
+                    expected: "member type",
+                },
+                SchemaError::ReservedName {
+                    coordinate: "Query.__secret".into(),
+                },
+                SchemaError::DuplicateName {
+                    coordinate: "Query.count".into(),
+                },
+            ],
+        );
+    }
+}
@@ -0,0 +1,422 @@
+//! Analytics of the schema elements used by executed operations.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    sync::RwLock,
+};
+
+use fnv::FnvHashSet;
+
+use crate::{
+    ast::{Definition, Document, Operation, OperationType, Selection},
+    schema::{
+        meta::{InterfaceMeta, MetaType, ObjectMeta},
+        model::SchemaType,
+    },
+};
+
+/// [Schema coordinates][0] of the fields and arguments used by a single operation.
+///
+/// Fields selected via fragments are attributed to the type condition of the fragment, so
+/// `... on User { name }` uses `User.name`, while `... on Named { name }` uses `Named.name`.
+///
+/// [0]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SchemaUsage {
+    operation_name: Option<String>,
+    coordinates: BTreeSet<String>,
+}
+
+impl SchemaUsage {
+    /// Collects the [`SchemaUsage`] of the provided `operation` of the `document`, resolving its
+    /// fragments against the provided `schema`.
+    ///
+    /// Expects the `document` to be validated already, silently skipping any unknown fields.
+    #[must_use]
+    pub fn collect<S>(
+        schema: &SchemaType<S>,
+        document: &Document<'_, S>,
+        operation: &Operation<'_, S>,
+    ) -> Self {
+        let root = match operation.operation_type {
+            OperationType::Query => Some(schema.concrete_query_type()),
+            OperationType::Mutation => schema.concrete_mutation_type(),
+            OperationType::Subscription => schema.concrete_subscription_type(),
+        };
+
+        let mut collector = Collector {
+            schema,
+            document,
+            visited_fragments: FnvHashSet::default(),
+            coordinates: BTreeSet::new(),
+        };
+        if let Some(root) = root {
+            collector.collect(root, &operation.selection_set);
+        }
+
+        Self {
+            operation_name: operation.name.as_ref().map(|n| n.item.into()),
+            coordinates: collector.coordinates,
+        }
+    }
+
+    /// Returns the name of the operation this [`SchemaUsage`] is collected for, if it has one.
+    #[must_use]
+    pub fn operation_name(&self) -> Option<&str> {
+        self.operation_name.as_deref()
+    }
+
+    /// Iterates over the used [schema coordinates][0] in alphabetical order.
+    ///
+    /// [0]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+    pub fn coordinates(&self) -> impl Iterator<Item = &str> {
+        self.coordinates.iter().map(String::as_str)
+    }
+
+    /// Indicates whether the schema element with the provided [`coordinate`][0] is used.
+    ///
+    /// [0]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+    #[must_use]
+    pub fn contains(&self, coordinate: &str) -> bool {
+        self.coordinates.contains(coordinate)
+    }
+}
+
+/// Walker of selection sets collecting the used [schema coordinates][0].
+///
+/// [0]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+struct Collector<'s, 'd, 'a, S> {
+    schema: &'s SchemaType<S>,
+    document: &'d Document<'a, S>,
+    visited_fragments: FnvHashSet<&'d str>,
+    coordinates: BTreeSet<String>,
+}
+
+impl<'d, S> Collector<'_, 'd, '_, S> {
+    fn collect(&mut self, ty: &MetaType<S>, selection_set: &'d [Selection<'_, S>]) {
+        for selection in selection_set {
+            match selection {
+                Selection::Field(field) => {
+                    let (MetaType::Interface(InterfaceMeta { name, fields, .. })
+                    | MetaType::Object(ObjectMeta { name, fields, .. })) = ty
+                    else {
+                        continue;
+                    };
+                    let Some(meta) = fields.iter().find(|f| f.name == field.item.name.item) else {
+                        continue;
+                    };
+
+                    self.coordinates.insert(format!("{name}.{}", meta.name));
+                    for (arg, _) in field.item.arguments.iter().flat_map(|a| a.item.iter()) {
+                        self.coordinates
+                            .insert(format!("{name}.{}({}:)", meta.name, arg.item));
+                    }
+
+                    if let Some(selection_set) = &field.item.selection_set {
+                        let inner = meta.field_type.innermost_name();
+                        if let Some(inner) = self.schema.concrete_type_by_name(inner) {
+                            self.collect(inner, selection_set);
+                        }
+                    }
+                }
+                Selection::InlineFragment(fragment) => {
+                    let ty = match &fragment.item.type_condition {
+                        Some(cond) => match self.schema.concrete_type_by_name(cond.item) {
+                            Some(ty) => ty,
+                            None => continue,
+                        },
+                        None => ty,
+                    };
+                    self.collect(ty, &fragment.item.selection_set);
+                }
+                Selection::FragmentSpread(spread) => {
+                    if !self.visited_fragments.insert(spread.item.name.item) {
+                        continue;
+                    }
+                    let fragment = self.document.iter().find_map(|def| match def {
+                        Definition::Fragment(f) if f.item.name.item == spread.item.name.item => {
+                            Some(&f.item)
+                        }
+                        Definition::Fragment(..) | Definition::Operation(..) => None,
+                    });
+                    let Some(fragment) = fragment else {
+                        continue;
+                    };
+                    if let Some(ty) = self
+                        .schema
+                        .concrete_type_by_name(fragment.type_condition.item)
+                    {
+                        self.collect(ty, &fragment.selection_set);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Sink receiving the [`SchemaUsage`] of every operation executed by a [`RootNode`].
+///
+/// Any `Fn(&SchemaUsage)` closure is a [`UsageSink`]. Use the [`UsageCounter`] for aggregating the
+/// usage in memory.
+///
+/// [`RootNode`]: crate::RootNode
+pub trait UsageSink: Send + Sync {
+    /// Records the provided [`SchemaUsage`] of a single operation.
+    ///
+    /// Called after the operation is validated, but before it's executed.
+    fn record(&self, usage: &SchemaUsage);
+}
+
+impl fmt::Debug for dyn UsageSink + '_ {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("UsageSink")
+    }
+}
+
+impl<F> UsageSink for F
+where
+    F: Fn(&SchemaUsage) + Send + Sync,
+{
+    fn record(&self, usage: &SchemaUsage) {
+        self(usage)
+    }
+}
+
+/// In-memory [`UsageSink`] counting the number of operations using every
+/// [schema coordinate][0].
+///
+/// [0]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+#[derive(Debug, Default)]
+pub struct UsageCounter {
+    counts: RwLock<BTreeMap<String, u64>>,
+}
+
+impl UsageCounter {
+    /// Creates a new empty [`UsageCounter`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of recorded operations using the provided [`coordinate`][0].
+    ///
+    /// [0]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+    #[must_use]
+    pub fn count(&self, coordinate: &str) -> u64 {
+        self.counts
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(coordinate)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the numbers of recorded operations using every [schema coordinate][0] used at
+    /// least once.
+    ///
+    /// [0]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+    #[must_use]
+    pub fn counts(&self) -> BTreeMap<String, u64> {
+        self.counts
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Returns [schema coordinates][0] of the deprecated fields and arguments of the provided
+    /// `schema` not used by any recorded operation, in alphabetical order.
+    ///
+    /// Introspection types are not considered. A field (or argument) of an object or interface is
+    /// considered used if it's selected either directly or via any interface the type implements
+    /// (like `... on Named { name }` using `User.name` of every `Named` implementer).
+    ///
+    /// These are the candidates to be removed from the schema safely.
+    ///
+    /// [0]: https://spec.graphql.org/September2025#sec-Schema-Coordinates
+    #[must_use]
+    pub fn unused_deprecated<S>(&self, schema: &SchemaType<S>) -> Vec<String> {
+        let counts = self.counts.read().unwrap_or_else(|e| e.into_inner());
+
+        let mut unused = vec![];
+        for ty in schema.types.values() {
+            let (MetaType::Interface(InterfaceMeta {
+                name,
+                fields,
+                interface_names,
+                ..
+            })
+            | MetaType::Object(ObjectMeta {
+                name,
+                fields,
+                interface_names,
+                ..
+            })) = ty
+            else {
+                continue;
+            };
+            if name.starts_with("__") {
+                continue;
+            }
+            let is_used = |member: &str| {
+                [name]
+                    .into_iter()
+                    .chain(interface_names)
+                    .any(|ty| counts.contains_key(&format!("{ty}.{member}")))
+            };
+            for field in fields {
+                if field.deprecation_status.is_deprecated() && !is_used(&field.name) {
+                    unused.push(format!("{name}.{}", field.name));
+                }
+                for arg in field.arguments.iter().flatten() {
+                    let member = format!("{}({}:)", field.name, arg.name);
+                    if arg.deprecation_status.is_deprecated() && !is_used(&member) {
+                        unused.push(format!("{name}.{member}"));
+                    }
+                }
+            }
+        }
+        unused.sort();
+        unused
+    }
+}
+
+impl UsageSink for UsageCounter {
+    fn record(&self, usage: &SchemaUsage) {
+        let mut counts = self.counts.write().unwrap_or_else(|e| e.into_inner());
+        for coordinate in usage.coordinates() {
+            *counts.entry(coordinate.into()).or_default() += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{EmptyMutation, EmptySubscription, RootNode, graphql_interface, graphql_object};
+
+    use super::UsageCounter;
+
+    #[graphql_interface(for = User)]
+    #[expect(dead_code, reason = "GraphQL schema testing")]
+    trait Named {
+        fn name(&self) -> &str;
+
+        #[graphql(deprecated = "Use `name` instead.")]
+        fn nickname(&self) -> &str;
+    }
+
+    struct User;
+
+    #[graphql_object(impl = NamedValue)]
+    impl User {
+        fn name(&self) -> &str {
+            "John"
+        }
+
+        #[graphql(deprecated = "Use `name` instead.")]
+        fn login(&self) -> &str {
+            "john"
+        }
+
+        #[graphql(deprecated = "Use `name` instead.")]
+        fn nickname(&self) -> &str {
+            "Johnny"
+        }
+
+        fn friends(
+            &self,
+            first: Option<i32>,
+            #[graphql(deprecated = "Use `first` instead.")] limit: Option<i32>,
+        ) -> Vec<User> {
+            _ = (first, limit);
+            vec![User]
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn user() -> User {
+            User
+        }
+
+        #[graphql(deprecated = "Use `user` instead.")]
+        fn me() -> User {
+            User
+        }
+    }
+
+    type Schema = RootNode<Query, EmptyMutation, EmptySubscription>;
+
+    fn schema() -> Schema {
+        Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[test]
+    fn collects_through_fragments() {
+        let usage = crate::collect_usage(
+            r#"
+                query Friends {
+                    user {
+                        ...UserFields
+                        friends(first: 2) { ... on Named { name } }
+                    }
+                }
+                fragment UserFields on User { login ...UserFields2 }
+                fragment UserFields2 on User { ... { name } }
+                query Other { me { name } }
+            "#,
+            Some("Friends"),
+            &schema().schema,
+        )
+        .unwrap();
+
+        assert_eq!(usage.operation_name(), Some("Friends"));
+        assert_eq!(
+            usage.coordinates().collect::<Vec<_>>(),
+            [
+                "Named.name",
+                "Query.user",
+                "User.friends",
+                "User.friends(first:)",
+                "User.login",
+                "User.name",
+            ],
+        );
+        assert!(!usage.contains("Query.me"));
+    }
+
+    #[test]
+    fn aggregates_executed_operations() {
+        let counter = Arc::new(UsageCounter::new());
+        let schema = schema().usage_sink(counter.clone());
+
+        for query in [
+            "{ user { name } }",
+            "{ user { login friends(first: 1) { name } } }",
+            "{ user { name friends { name } } }",
+            "{ user { ... on Named { nickname } } }",
+        ] {
+            crate::execute_sync(query, None, &schema, &graphql_vars! {}, &()).unwrap();
+        }
+        assert!(
+            crate::execute_sync("{ me { unknown } }", None, &schema, &graphql_vars! {}, &())
+                .is_err()
+        );
+
+        assert_eq!(counter.count("Query.user"), 4);
+        assert_eq!(counter.count("User.name"), 3);
+        assert_eq!(counter.count("User.friends"), 2);
+        assert_eq!(counter.count("User.friends(first:)"), 1);
+        assert_eq!(counter.count("Query.me"), 0);
+        assert_eq!(counter.count("Named.nickname"), 1);
+        assert_eq!(counter.count("User.nickname"), 0);
+        assert_eq!(
+            counter.unused_deprecated(&schema.schema),
+            ["Query.me", "User.friends(limit:)"],
+        );
+    }
+}
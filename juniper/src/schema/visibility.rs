@@ -0,0 +1,400 @@
+//! Per-request visibility of schema elements.
+
+use std::{any::Any, sync::Arc};
+
+use arcstr::ArcStr;
+use derive_more::with_trait::{Display, Error};
+use fnv::{FnvHashMap, FnvHashSet};
+
+use crate::schema::{
+    meta::{Field, InterfaceMeta, MetaType, ObjectMeta, VisibilityFn},
+    model::SchemaType,
+};
+
+/// Error of evaluating the [`VisibilityFn`]s of a schema against a request context.
+#[derive(Clone, Debug, Display, Eq, Error, PartialEq)]
+pub enum VisibilityError {
+    /// Schema has [`VisibilityFn`]s, but no request context is available to evaluate them against.
+    ///
+    /// Happens whenever [`RootNode::enable_visibility()`] wasn't called.
+    ///
+    /// [`RootNode::enable_visibility()`]: crate::RootNode::enable_visibility
+    #[display(
+        "schema has visibility predicates, but no request context to evaluate them against \
+         (is `RootNode::enable_visibility()` called?)"
+    )]
+    MissingContext,
+
+    /// [`VisibilityFn`] of a schema element doesn't accept the type of the provided request
+    /// context.
+    #[display("visibility predicate of `{element}` doesn't accept the provided request context")]
+    ContextMismatch {
+        /// Name of the schema element (e.g. `User` or `User.email`).
+        #[error(not(source))]
+        element: ArcStr,
+    },
+}
+
+/// Schema elements hidden from a particular request by their [`VisibilityFn`]s.
+///
+/// Cheap to clone.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Visibility {
+    /// Hidden schema elements, if there are any.
+    hidden: Option<Arc<Hidden>>,
+}
+
+#[derive(Debug)]
+struct Hidden {
+    /// Names of the hidden types.
+    types: FnvHashSet<ArcStr>,
+
+    /// Names of the hidden fields, grouped by the names of the types they belong to.
+    ///
+    /// Includes the fields returning hidden types.
+    fields: FnvHashMap<ArcStr, FnvHashSet<ArcStr>>,
+}
+
+impl Visibility {
+    /// Evaluates the [`VisibilityFn`]s of the provided `schema` against the provided request
+    /// `context`.
+    ///
+    /// Everything is visible if the `schema` has no [`VisibilityFn`]s, without inspecting it.
+    ///
+    /// # Errors
+    ///
+    /// - [`VisibilityError::MissingContext`] if the `schema` has [`VisibilityFn`]s, but no
+    ///   `context` is provided.
+    /// - [`VisibilityError::ContextMismatch`] if any [`VisibilityFn`] doesn't accept the provided
+    ///   `context`.
+    pub(crate) fn new<S>(
+        schema: &SchemaType<S>,
+        context: Option<&dyn Any>,
+    ) -> Result<Self, VisibilityError> {
+        if !schema.has_visibility_fns {
+            return Ok(Self::default());
+        }
+        let context = context.ok_or(VisibilityError::MissingContext)?;
+        let is_visible = |f: Option<VisibilityFn>, element: &dyn Fn() -> ArcStr| {
+            f.map_or(Ok(true), |f| {
+                f(context).ok_or_else(|| VisibilityError::ContextMismatch { element: element() })
+            })
+        };
+
+        let mut types = FnvHashSet::default();
+        for t in schema.types.values() {
+            if let Some(name) = t.name() {
+                if !is_visible(t.visibility_fn(), &|| name.clone())? {
+                    types.insert(name.clone());
+                }
+            }
+        }
+
+        let mut fields = FnvHashMap::<_, FnvHashSet<_>>::default();
+        for t in schema.types.values() {
+            if let MetaType::Interface(InterfaceMeta {
+                name, fields: fs, ..
+            })
+            | MetaType::Object(ObjectMeta {
+                name, fields: fs, ..
+            }) = t
+            {
+                for f in fs {
+                    if types.contains(f.field_type.innermost_name())
+                        || !is_visible(f.visible, &|| format!("{name}.{}", f.name).into())?
+                    {
+                        fields
+                            .entry(name.clone())
+                            .or_default()
+                            .insert(f.name.clone());
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            hidden: (!types.is_empty() || !fields.is_empty())
+                .then(|| Arc::new(Hidden { types, fields })),
+        })
+    }
+
+    /// Indicates whether the type with the provided `name` is visible.
+    pub(crate) fn is_type_visible(&self, name: &str) -> bool {
+        self.hidden
+            .as_ref()
+            .is_none_or(|hidden| !hidden.types.contains(name))
+    }
+
+    /// Indicates whether the provided [`Field`] of the type with the provided `type_name` is
+    /// visible.
+    ///
+    /// [`Field`]s returning hidden types are hidden too.
+    pub(crate) fn is_field_visible<S>(&self, type_name: &str, field: &Field<S>) -> bool {
+        self.hidden.as_ref().is_none_or(|hidden| {
+            hidden
+                .fields
+                .get(type_name)
+                .is_none_or(|fields| !fields.contains(&field.name))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        EmptyMutation, EmptySubscription, GraphQLError, RootNode, Value, execute_sync,
+        graphql_object,
+    };
+
+    use super::{Visibility, VisibilityError};
+
+    struct Context {
+        is_internal: bool,
+    }
+
+    impl crate::Context for Context {}
+
+    fn is_internal(ctx: &Context) -> bool {
+        ctx.is_internal
+    }
+
+    struct Build;
+
+    #[graphql_object]
+    #[graphql(context = Context, visible = is_internal)]
+    impl Build {
+        fn host() -> &'static str {
+            "ci-42"
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    #[graphql(context = Context)]
+    impl Query {
+        fn version() -> &'static str {
+            "1.0"
+        }
+
+        #[graphql(visible = is_internal)]
+        fn build_host() -> &'static str {
+            "ci-42"
+        }
+
+        fn build() -> Build {
+            Build
+        }
+    }
+
+    type Schema = RootNode<Query, EmptyMutation<Context>, EmptySubscription<Context>>;
+
+    fn schema() -> Schema {
+        Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    fn run(schema: &Schema, query: &str, is_internal: bool) -> Result<Value, GraphQLError> {
+        execute_sync(
+            query,
+            None,
+            schema,
+            &graphql_vars! {},
+            &Context { is_internal },
+        )
+        .map(|(res, errs)| {
+            assert_eq!(errs, []);
+            res
+        })
+    }
+
+    fn validation_errors(res: Result<Value, GraphQLError>) -> Vec<String> {
+        match res {
+            Err(GraphQLError::ValidationError(errs)) => {
+                errs.iter().map(|e| e.message().into()).collect()
+            }
+            res => panic!("expected `ValidationError`, returned: {res:#?}"),
+        }
+    }
+
+    #[test]
+    fn hides_from_introspection() {
+        let schema = schema().enable_visibility();
+
+        // language=GraphQL
+        let query = r#"{
+            __type(name: "Query") { fields { name } }
+            build: __type(name: "Build") { name }
+            __schema { types { name } }
+        }"#;
+
+        let internal = run(&schema, query, true).unwrap();
+        let public = run(&schema, query, false).unwrap();
+
+        let fields = |res: &Value| {
+            res.as_object_value()
+                .unwrap()
+                .get_field_value("__type")
+                .unwrap()
+                .as_object_value()
+                .unwrap()
+                .get_field_value("fields")
+                .unwrap()
+                .as_list_value()
+                .unwrap()
+                .iter()
+                .map(|f| {
+                    f.as_object_value()
+                        .unwrap()
+                        .get_field_value("name")
+                        .unwrap()
+                        .clone()
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            fields(&internal),
+            [
+                graphql_value!("version"),
+                graphql_value!("buildHost"),
+                graphql_value!("build"),
+            ],
+        );
+        assert_eq!(fields(&public), [graphql_value!("version")]);
+
+        let build = |res: &Value| {
+            res.as_object_value()
+                .unwrap()
+                .get_field_value("build")
+                .cloned()
+                .unwrap()
+        };
+        assert_eq!(build(&internal), graphql_value!({"name": "Build"}));
+        assert_eq!(build(&public), graphql_value!(null));
+
+        let has_type = |res: &Value, name: &str| {
+            res.as_object_value()
+                .unwrap()
+                .get_field_value("__schema")
+                .unwrap()
+                .as_object_value()
+                .unwrap()
+                .get_field_value("types")
+                .unwrap()
+                .as_list_value()
+                .unwrap()
+                .iter()
+                .any(|t| {
+                    t.as_object_value().unwrap().get_field_value("name")
+                        == Some(&graphql_value!(name))
+                })
+        };
+        assert!(has_type(&internal, "Build"));
+        assert!(!has_type(&public, "Build"));
+    }
+
+    #[test]
+    fn hides_from_validation() {
+        let schema = schema().enable_visibility();
+
+        assert_eq!(
+            run(&schema, "{ version buildHost build { host } }", true),
+            Ok(graphql_value!({
+                "version": "1.0",
+                "buildHost": "ci-42",
+                "build": {"host": "ci-42"},
+            })),
+        );
+        assert_eq!(
+            validation_errors(run(&schema, "{ buildHost }", false)),
+            [r#"Unknown field "buildHost" on type "Query""#],
+        );
+        assert_eq!(
+            validation_errors(run(&schema, "{ build { host } }", false)),
+            [r#"Unknown field "build" on type "Query""#],
+        );
+        assert!(
+            validation_errors(run(&schema, "{ ... on Build { host } }", false))
+                .contains(&r#"Unknown type "Build""#.into()),
+        );
+    }
+
+    #[test]
+    fn requires_context_until_enabled() {
+        let schema = schema();
+
+        let err = run(&schema, "{ version }", true).unwrap_err();
+        assert_eq!(
+            err,
+            GraphQLError::VisibilityError(VisibilityError::MissingContext),
+        );
+    }
+
+    #[test]
+    fn validates_against_full_schema_without_context() {
+        let schema = schema();
+
+        assert_eq!(
+            crate::validate("{ version buildHost build { host } }", &schema.schema),
+            Ok(()),
+        );
+    }
+
+    #[test]
+    fn validates_against_visible_schema_with_context() {
+        let schema = schema();
+        let validate = |query, is_internal| {
+            crate::validate_with_context(query, &schema.schema, Some(&Context { is_internal }))
+        };
+
+        assert_eq!(
+            validate("{ version buildHost build { host } }", true),
+            Ok(())
+        );
+        assert_eq!(
+            validation_errors(validate("{ buildHost }", false).map(|()| Value::null())),
+            [r#"Unknown field "buildHost" on type "Query""#],
+        );
+        assert_eq!(
+            crate::validate_with_context("{ version }", &schema.schema, Some(&())),
+            Err(GraphQLError::VisibilityError(
+                VisibilityError::ContextMismatch {
+                    element: "Build".into(),
+                },
+            )),
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_context() {
+        let schema = schema();
+
+        assert_eq!(
+            Visibility::new(&schema.schema, Some(&())).unwrap_err(),
+            VisibilityError::ContextMismatch {
+                element: "Build".into(),
+            },
+        );
+    }
+
+    #[test]
+    fn ignores_context_without_visibility_fns() {
+        struct Public;
+
+        #[graphql_object]
+        impl Public {
+            fn version() -> &'static str {
+                "1.0"
+            }
+        }
+
+        let schema = RootNode::new(
+            Public,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        assert!(!schema.schema.has_visibility_fns);
+        assert!(Visibility::new(&schema.schema, None).is_ok());
+        assert_eq!(crate::validate("{ version }", &schema.schema), Ok(()));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    GraphQLError, RuleError, graphql,
+    GraphQLError, RuleError, RuleErrorExtensions, graphql,
     parser::SourcePosition,
     schema::model::RootNode,
     tests::fixtures::starwars::schema::{Database, Query},
@@ -303,10 +303,13 @@ async fn test_rejects_unknown_argument_on_query_field_without_args() {
 
     assert_eq!(
         crate::execute(doc, None, &schema, &graphql::vars! {}, &database).await,
-        Err(GraphQLError::ValidationError(vec![RuleError::new(
-            r#"Unknown argument "unknownArg" on field "name" of type "Character""#,
-            &[SourcePosition::new(14, 0, 14)],
-        )])),
+        Err(GraphQLError::ValidationError(vec![
+            RuleError::new(
+                r#"Unknown argument "unknownArg" on field "name" of type "Character""#,
+                &[SourcePosition::new(14, 0, 14)],
+            )
+            .with_extensions(RuleErrorExtensions::new("KnownArgumentNames")),
+        ])),
         "expected validation error, got successful response",
     );
 }
@@ -448,3 +451,104 @@ async fn interface_inline_fragment_friends() {
         ))
     );
 }
+
+#[tokio::test]
+async fn test_aborts_validation_once_errors_limit_reached() {
+    let doc = "{ hero { a b c d e f g h } }";
+    let database = Database::new();
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<Database>::new(),
+        EmptySubscription::<Database>::new(),
+    );
+
+    match crate::execute(doc, None, &schema, &graphql::vars! {}, &database).await {
+        Err(GraphQLError::ValidationError(errs)) => assert_eq!(errs.len(), 8),
+        res => panic!("expected `ValidationError`, returned: {res:#?}"),
+    }
+
+    let schema = schema.validation_errors_limit(Some(3));
+
+    assert_eq!(
+        crate::execute(doc, None, &schema, &graphql::vars! {}, &database).await,
+        Err(GraphQLError::ValidationError(vec![
+            RuleError::new(
+                r#"Unknown field "a" on type "Character""#,
+                &[SourcePosition::new(9, 0, 9)],
+            )
+            .with_extensions(RuleErrorExtensions::new("FieldsOnCorrectType")),
+            RuleError::new(
+                r#"Unknown field "b" on type "Character""#,
+                &[SourcePosition::new(11, 0, 11)],
+            )
+            .with_extensions(RuleErrorExtensions::new("FieldsOnCorrectType")),
+            RuleError::new(
+                r#"Unknown field "c" on type "Character""#,
+                &[SourcePosition::new(13, 0, 13)],
+            )
+            .with_extensions(RuleErrorExtensions::new("FieldsOnCorrectType")),
+            RuleError::new(
+                "Too many validation errors, error limit reached. Validation aborted",
+                &[SourcePosition::new(15, 0, 15)],
+            ),
+        ])),
+    );
+}
+
+#[tokio::test]
+async fn test_does_not_abort_validation_below_errors_limit() {
+    let doc = "{ hero { a b } }";
+    let database = Database::new();
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<Database>::new(),
+        EmptySubscription::<Database>::new(),
+    )
+    .validation_errors_limit(Some(2));
+
+    match crate::execute(doc, None, &schema, &graphql::vars! {}, &database).await {
+        Err(GraphQLError::ValidationError(errs)) => {
+            assert_eq!(errs.len(), 2);
+            assert!(
+                errs.iter()
+                    .all(|e| e.message().starts_with("Unknown field"))
+            );
+        }
+        res => panic!("expected `ValidationError`, returned: {res:#?}"),
+    }
+}
+
+#[test]
+fn test_validates_document_without_executing() {
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<Database>::new(),
+        EmptySubscription::<Database>::new(),
+    );
+
+    assert_eq!(
+        crate::validate(
+            "query HeroNameQuery { hero { name friends { name } } }",
+            &schema.schema,
+        ),
+        Ok(()),
+    );
+    assert_eq!(
+        crate::validate("{ hero { a } human(id: true) { name } }", &schema.schema),
+        Err(GraphQLError::ValidationError(vec![
+            RuleError::new(
+                r#"Unknown field "a" on type "Character""#,
+                &[SourcePosition::new(9, 0, 9)],
+            )
+            .with_extensions(RuleErrorExtensions::new("FieldsOnCorrectType")),
+            RuleError::new(
+                r#"Invalid value for argument "id", reason: Invalid value "true" for type "String""#,
+                &[SourcePosition::new(23, 0, 23)],
+            ),
+        ])),
+    );
+    assert!(matches!(
+        crate::validate("{ hero { name }", &schema.schema),
+        Err(GraphQLError::ParseError(_)),
+    ));
+}
@@ -8,6 +8,7 @@ use arcstr::ArcStr;
 use crate::{
     ast::{FromInputValue, InputValue, Selection, ToInputValue},
     executor::{ExecutionResult, Executor, FieldError, IntoFieldError, Registry},
+    macros::helper::validate::at_input_path,
     schema::meta::MetaType,
     types::{
         async_await::GraphQLValueAsync,
@@ -163,7 +164,10 @@ impl<S: ScalarValue, T: FromInputValue<S>> FromInputValue<S> for Vec<T> {
         match v {
             InputValue::List(l) => l
                 .iter()
-                .map(|i| i.item.convert().map_err(FromInputValueVecError::Item))
+                .enumerate()
+                .map(|(n, i)| {
+                    at_input_path(n, || i.item.convert()).map_err(FromInputValueVecError::Item)
+                })
                 .collect(),
             // See "Input Coercion" on List types:
             // https://spec.graphql.org/October2021#sec-Combining-List-and-Non-Null
@@ -399,7 +403,10 @@ where
                     no_drop: false,
                 };
 
-                let mut items = ls.iter().map(|i| i.item.convert());
+                let mut items = ls
+                    .iter()
+                    .enumerate()
+                    .map(|(n, i)| at_input_path(n, || i.item.convert()));
                 for elem in &mut out.arr[..] {
                     if let Some(i) = items
                         .next()
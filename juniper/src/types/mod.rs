@@ -7,4 +7,5 @@ pub mod nullable;
 pub mod pointers;
 pub mod scalars;
 pub mod subscriptions;
+pub mod type_info;
 pub mod utilities;
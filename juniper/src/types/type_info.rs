@@ -0,0 +1,92 @@
+//! Runtime information of GraphQL types, generated by macros.
+
+use arcstr::ArcStr;
+
+/// Runtime information of a GraphQL type, specified via `#[graphql(type_info = ...)]` attribute
+/// argument of `#[derive(GraphQLObject)]`, `#[graphql_object]`, `#[graphql_subscription]`,
+/// `#[derive(GraphQLEnum)]`, `#[derive(GraphQLInputObject)]`, `#[derive(GraphQLUnion)]`,
+/// `#[graphql_union]`, `#[derive(GraphQLScalar)]` and `#[graphql_scalar]` macros.
+///
+/// Used as the [`GraphQLValue::TypeInfo`] of the generated GraphQL type, allowing to compute
+/// parts of its schema at runtime, when it's constructed via [`RootNode::new_with_info()`].
+///
+/// GraphQL objects with a custom type info cannot implement GraphQL interfaces, as the name and
+/// fields overridden at runtime would bypass the compile-time checks of implementing them (like
+/// hiding a field required by an interface), so macros reject such a combination.
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{
+/// #     ArcStr, DynamicTypeInfo, EmptyMutation, EmptySubscription, GraphQLObject, RootNode,
+/// # };
+/// #
+/// struct Config {
+///     name: ArcStr,
+///     hidden: Vec<&'static str>,
+/// }
+///
+/// impl DynamicTypeInfo for Config {
+///     fn name(&self) -> Option<ArcStr> {
+///         Some(self.name.clone())
+///     }
+///
+///     fn has_field(&self, name: &str) -> bool {
+///         !self.hidden.contains(&name)
+///     }
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(type_info = Config)]
+/// struct Settings {
+///     theme: String,
+///     secret: String,
+/// }
+///
+/// let config = Config {
+///     name: "PublicSettings".into(),
+///     hidden: vec!["secret"],
+/// };
+/// type Schema = RootNode<Settings, EmptyMutation, EmptySubscription>;
+///
+/// let schema = Schema::new_with_info(
+///     Settings { theme: "dark".into(), secret: "42".into() },
+///     EmptyMutation::new(),
+///     EmptySubscription::new(),
+///     config,
+///     (),
+///     (),
+/// );
+///
+/// let meta = schema.schema.concrete_type_by_name("PublicSettings").unwrap();
+/// assert!(meta.field_by_name("theme").is_some());
+/// assert!(meta.field_by_name("secret").is_none());
+/// ```
+///
+/// [`GraphQLValue::TypeInfo`]: crate::GraphQLValue::TypeInfo
+/// [`RootNode::new_with_info()`]: crate::RootNode::new_with_info
+pub trait DynamicTypeInfo {
+    /// Returns the name of the GraphQL type, overriding the one specified statically (or
+    /// inferred from the Rust type name), if any.
+    #[must_use]
+    fn name(&self) -> Option<ArcStr> {
+        None
+    }
+
+    /// Returns the description of the GraphQL type, overriding the one specified statically (or
+    /// taken from the Rust doc comment), if any.
+    #[must_use]
+    fn description(&self) -> Option<ArcStr> {
+        None
+    }
+
+    /// Indicates whether the GraphQL field of the provided `name` should be registered in the
+    /// schema for the GraphQL object.
+    ///
+    /// Registers all the fields by default.
+    #[must_use]
+    fn has_field(&self, name: &str) -> bool {
+        _ = name;
+        true
+    }
+}
@@ -8,6 +8,7 @@ use crate::{
         meta::{Argument, EnumMeta, InputObjectMeta, MetaType},
         model::{SchemaType, TypeType},
     },
+    util::{did_you_mean, suggestion_list},
     value::ScalarValue,
 };
 
@@ -60,6 +61,14 @@ pub(crate) mod error {
     }
 }
 
+/// Appends the provided "Did you mean ...?" `hint` to the error `message`, if there is any.
+fn with_hint(message: String, hint: Option<String>) -> String {
+    match hint {
+        Some(hint) => format!("{message}. {hint}"),
+        None => message,
+    }
+}
+
 /// Validates the specified field of a GraphQL object and returns an error message if the field is
 /// invalid.
 fn validate_object_field<S>(
@@ -83,7 +92,11 @@ where
 
         error_message.map(|m| error::field(object_type, field_key, m))
     } else {
-        Some(error::unknown_field(object_type, field_key))
+        let suggestions = suggestion_list(field_key, object_fields.iter().map(|f| f.name.as_str()));
+        Some(with_hint(
+            error::unknown_field(object_type, field_key),
+            did_you_mean(None, &suggestions),
+        ))
     }
 }
 
@@ -138,8 +151,21 @@ where
                 InputValue::Null | InputValue::Variable(_) => None,
                 v @ InputValue::Scalar(_) | v @ InputValue::Enum(_) => {
                     if let Some(parse_fn) = t.input_value_parse_fn() {
-                        if parse_fn(v).is_ok() {
+                        let is_known_value = match (v, t) {
+                            (InputValue::Enum(name), MetaType::Enum(meta)) => {
+                                meta.values.iter().any(|v| v.name == name.as_str())
+                            }
+                            _ => true,
+                        };
+                        if is_known_value && parse_fn(v).is_ok() {
                             None
+                        } else if let (InputValue::Enum(name), MetaType::Enum(meta)) = (v, t) {
+                            let suggestions =
+                                suggestion_list(name, meta.values.iter().map(|v| v.name.as_str()));
+                            Some(with_hint(
+                                error::type_value(arg_value, arg_type),
+                                did_you_mean(None, &suggestions),
+                            ))
                         } else {
                             Some(error::type_value(arg_value, arg_type))
                         }
@@ -63,3 +63,168 @@ pub(crate) enum Either<L, R> {
     /// Right value of the second type.
     Right(R),
 }
+
+/// Maximum number of suggestions listed by [`did_you_mean()`].
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Filters the provided `options` down to the ones similar enough to the `input` to be suggested
+/// as a replacement for it, ordered from the most to the least similar.
+///
+/// Similarity is measured as the [Damerau-Levenshtein distance][0] (optimal string alignment)
+/// between the strings, where a difference in case only is counted as a single edit. An option is
+/// considered similar if its distance is within 40% of the `input` length.
+///
+/// [0]: https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance
+pub(crate) fn suggestion_list<'a>(
+    input: &str,
+    options: impl IntoIterator<Item = &'a str>,
+) -> Vec<&'a str> {
+    let threshold = input.chars().count() * 2 / 5 + 1;
+    let input_lowercase = input.to_lowercase();
+
+    let mut suggestions = options
+        .into_iter()
+        .filter_map(|opt| {
+            lexical_distance(input, &input_lowercase, opt, threshold).map(|dist| (dist, opt))
+        })
+        .collect::<Vec<_>>();
+    suggestions.sort_unstable();
+    suggestions.dedup();
+    suggestions.into_iter().map(|(_, opt)| opt).collect()
+}
+
+/// Computes the lexical distance between the `input` and the `option`, if it doesn't exceed the
+/// provided `threshold`.
+fn lexical_distance(
+    input: &str,
+    input_lowercase: &str,
+    option: &str,
+    threshold: usize,
+) -> Option<usize> {
+    if input == option {
+        return Some(0);
+    }
+    let option_lowercase = option.to_lowercase();
+    if input_lowercase == option_lowercase {
+        return Some(1);
+    }
+
+    let mut a = input_lowercase.chars().collect::<Vec<_>>();
+    let mut b = option_lowercase.chars().collect::<Vec<_>>();
+    if a.len() < b.len() {
+        (a, b) = (b, a);
+    }
+    if a.len() - b.len() > threshold {
+        return None;
+    }
+
+    // Only the last three rows are required for detecting transpositions.
+    let mut rows = [
+        (0..=b.len()).collect::<Vec<_>>(),
+        vec![0; b.len() + 1],
+        vec![0; b.len() + 1],
+    ];
+    for i in 1..=a.len() {
+        let mut smallest = i;
+        rows[i % 3][0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut current = (rows[(i - 1) % 3][j] + 1)
+                .min(rows[i % 3][j - 1] + 1)
+                .min(rows[(i - 1) % 3][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current = current.min(rows[(i - 2) % 3][j - 2] + 1);
+            }
+            smallest = smallest.min(current);
+            rows[i % 3][j] = current;
+        }
+        // Early exit, since the distance can't get smaller than the smallest cell of a row.
+        if smallest > threshold {
+            return None;
+        }
+    }
+
+    let distance = rows[a.len() % 3][b.len()];
+    (distance <= threshold).then_some(distance)
+}
+
+/// Formats a "Did you mean ...?" hint listing the provided `suggestions`, if there are any.
+///
+/// The optional `sub_message` is inserted right after "Did you mean" (e.g. "to use an inline
+/// fragment on").
+pub(crate) fn did_you_mean(
+    sub_message: Option<&str>,
+    suggestions: &[impl AsRef<str>],
+) -> Option<String> {
+    let suggestions = &suggestions[..suggestions.len().min(MAX_SUGGESTIONS)];
+    let (last, rest) = suggestions.split_last()?;
+
+    let mut hint = String::from("Did you mean ");
+    if let Some(sub_message) = sub_message {
+        hint.push_str(sub_message);
+        hint.push(' ');
+    }
+    for s in rest {
+        hint.push('"');
+        hint.push_str(s.as_ref());
+        hint.push_str(if rest.len() > 1 { "\", " } else { "\" " });
+    }
+    if !rest.is_empty() {
+        hint.push_str("or ");
+    }
+    hint.push('"');
+    hint.push_str(last.as_ref());
+    hint.push_str("\"?");
+    Some(hint)
+}
+
+#[test]
+fn test_suggestion_list() {
+    assert_eq!(suggestion_list("", ["a"]), ["a"]);
+    assert_eq!(suggestion_list("a", [] as [&str; 0]), Vec::<&str>::new());
+    assert_eq!(suggestion_list("a", ["a"]), ["a"]);
+    assert_eq!(suggestion_list("a", ["a", "b"]), ["a", "b"]);
+    assert_eq!(suggestion_list("abc", ["ABC"]), ["ABC"]);
+    assert_eq!(suggestion_list("abc", ["acb"]), ["acb"]);
+    assert_eq!(
+        suggestion_list("nam", ["name", "nickname", "barks"]),
+        ["name"]
+    );
+    assert_eq!(
+        suggestion_list("descritpion", ["description", "deprecationReason"]),
+        ["description"],
+    );
+    assert_eq!(
+        suggestion_list("ab", ["ac", "abc", "a", "b", "ab"]),
+        ["ab", "a", "abc", "ac", "b"],
+    );
+    assert_eq!(
+        suggestion_list("GraphQl", ["graphics", "SQL", "GraphQL", "quarks", "mark"]),
+        ["GraphQL", "graphics"],
+    );
+}
+
+#[test]
+fn test_did_you_mean() {
+    assert_eq!(did_you_mean(None, &[] as &[&str]), None);
+    assert_eq!(
+        did_you_mean(None, &["a"]).as_deref(),
+        Some(r#"Did you mean "a"?"#),
+    );
+    assert_eq!(
+        did_you_mean(None, &["a", "b"]).as_deref(),
+        Some(r#"Did you mean "a" or "b"?"#),
+    );
+    assert_eq!(
+        did_you_mean(None, &["a", "b", "c"]).as_deref(),
+        Some(r#"Did you mean "a", "b", or "c"?"#),
+    );
+    assert_eq!(
+        did_you_mean(None, &["a", "b", "c", "d", "e", "f"]).as_deref(),
+        Some(r#"Did you mean "a", "b", "c", "d", or "e"?"#),
+    );
+    assert_eq!(
+        did_you_mean(Some("to use an inline fragment on"), &["Dog"]).as_deref(),
+        Some(r#"Did you mean to use an inline fragment on "Dog"?"#),
+    );
+}
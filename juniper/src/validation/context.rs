@@ -6,7 +6,7 @@ use itertools::Itertools as _;
 use crate::{
     ast::{BorrowedType, Definition, Document},
     parser::SourcePosition,
-    schema::{meta::MetaType, model::SchemaType},
+    schema::{meta::MetaType, model::SchemaType, visibility::Visibility},
 };
 
 /// Query validation error
@@ -15,6 +15,17 @@ use crate::{
 pub struct RuleError {
     locations: Vec<SourcePosition>,
     message: String,
+    extensions: Option<RuleErrorExtensions>,
+}
+
+/// Structured details of a [`RuleError`], exposed in its `extensions` entry of a response.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct RuleErrorExtensions {
+    rule: &'static str,
+    suggestions: Vec<String>,
+    input_path: Option<String>,
+    expected_type: Option<String>,
+    received: Option<String>,
 }
 
 #[doc(hidden)]
@@ -27,6 +38,9 @@ pub struct ValidatorContext<'a, S: Debug + 'a> {
     input_type_literal_stack: Vec<Option<BorrowedType<'a>>>,
     parent_type_stack: Vec<Option<&'a MetaType<S>>>,
     fragment_names: HashSet<&'a str>,
+    max_errors: Option<usize>,
+    abort_error: Option<RuleError>,
+    visibility: Visibility,
 }
 
 impl RuleError {
@@ -35,9 +49,19 @@ impl RuleError {
         Self {
             message: message.into(),
             locations: locations.to_vec(),
+            extensions: None,
         }
     }
 
+    /// Attaches the provided [`RuleErrorExtensions`] to this validation error.
+    ///
+    /// Overwrites any previously set extensions.
+    #[must_use]
+    pub fn with_extensions(mut self, extensions: RuleErrorExtensions) -> Self {
+        self.extensions = Some(extensions);
+        self
+    }
+
     /// Access the message for a validation error
     pub fn message(&self) -> &str {
         &self.message
@@ -50,6 +74,81 @@ impl RuleError {
     pub fn locations(&self) -> &[SourcePosition] {
         &self.locations
     }
+
+    /// Access the structured details of the validation error, if any
+    pub fn extensions(&self) -> Option<&RuleErrorExtensions> {
+        self.extensions.as_ref()
+    }
+}
+
+impl RuleErrorExtensions {
+    /// Creates new [`RuleErrorExtensions`] of an error reported by the validation `rule` with the
+    /// provided identifier (e.g. `FieldsOnCorrectType`).
+    pub fn new(rule: &'static str) -> Self {
+        Self {
+            rule,
+            suggestions: Vec::new(),
+            input_path: None,
+            expected_type: None,
+            received: None,
+        }
+    }
+
+    /// Sets the names suggested as replacements of the erroneous one.
+    ///
+    /// Overwrites any previously set suggestions.
+    #[must_use]
+    pub fn with_suggestions(mut self, suggestions: &[impl AsRef<str>]) -> Self {
+        self.suggestions = suggestions.iter().map(|s| s.as_ref().into()).collect();
+        self
+    }
+
+    /// Sets the path to the erroneous input value inside a variable (e.g. `$input.items[3].price`).
+    #[must_use]
+    pub fn with_input_path(mut self, path: impl Into<String>) -> Self {
+        self.input_path = Some(path.into());
+        self
+    }
+
+    /// Sets the GraphQL type expected for the erroneous input value (e.g. `Float!`).
+    #[must_use]
+    pub fn with_expected_type(mut self, type_name: impl Into<String>) -> Self {
+        self.expected_type = Some(type_name.into());
+        self
+    }
+
+    /// Sets the kind of the erroneous input value actually received (e.g. `string` or `null`).
+    #[must_use]
+    pub fn with_received(mut self, kind: impl Into<String>) -> Self {
+        self.received = Some(kind.into());
+        self
+    }
+
+    /// Identifier of the validation rule that reported the error.
+    pub fn rule(&self) -> &'static str {
+        self.rule
+    }
+
+    /// Names suggested as replacements of the erroneous one, ordered from the most to the least
+    /// similar.
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+
+    /// Path to the erroneous input value inside a variable, if any.
+    pub fn input_path(&self) -> Option<&str> {
+        self.input_path.as_deref()
+    }
+
+    /// GraphQL type expected for the erroneous input value, if any.
+    pub fn expected_type(&self) -> Option<&str> {
+        self.expected_type.as_deref()
+    }
+
+    /// Kind of the erroneous input value actually received, if any.
+    pub fn received(&self) -> Option<&str> {
+        self.received.as_deref()
+    }
 }
 
 impl<'a, S: Debug> ValidatorContext<'a, S> {
@@ -70,26 +169,89 @@ impl<'a, S: Debug> ValidatorContext<'a, S> {
                     _ => None,
                 })
                 .collect(),
+            max_errors: None,
+            abort_error: None,
+            visibility: Visibility::default(),
         }
     }
 
+    /// Limits the number of errors this [`ValidatorContext`] collects to the provided `max`.
+    ///
+    /// Once the limit is reached, the validation is aborted: no more errors are collected and the
+    /// remaining document isn't visited anymore.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn with_max_errors(mut self, max: Option<usize>) -> Self {
+        self.max_errors = max;
+        self
+    }
+
+    /// Validates against the schema elements of the provided [`Visibility`] only.
+    ///
+    /// By default, all the schema elements are visible.
+    #[must_use]
+    pub(crate) fn with_visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    /// Returns the [`Visibility`] of the schema elements being validated against.
+    pub(crate) fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
     #[doc(hidden)]
-    pub fn append_errors(&mut self, mut errors: Vec<RuleError>) {
-        self.errors.append(&mut errors);
+    pub fn append_errors(&mut self, errors: Vec<RuleError>) {
+        for err in errors {
+            self.push_error(err);
+        }
     }
 
     #[doc(hidden)]
     pub fn report_error(&mut self, message: &str, locations: &[SourcePosition]) {
-        self.errors.push(RuleError::new(message, locations))
+        self.push_error(RuleError::new(message, locations))
+    }
+
+    #[doc(hidden)]
+    pub fn report_error_with_extensions(
+        &mut self,
+        message: &str,
+        locations: &[SourcePosition],
+        extensions: RuleErrorExtensions,
+    ) {
+        self.push_error(RuleError::new(message, locations).with_extensions(extensions))
+    }
+
+    fn push_error(&mut self, err: RuleError) {
+        if self.is_aborted() {
+            return;
+        }
+        if self.max_errors.is_some_and(|max| self.errors.len() >= max) {
+            self.abort_error = Some(RuleError::new(
+                "Too many validation errors, error limit reached. Validation aborted",
+                err.locations(),
+            ));
+            return;
+        }
+        self.errors.push(err);
     }
 
     pub(crate) fn has_errors(&self) -> bool {
         !self.errors.is_empty()
     }
 
+    /// Indicates whether the validation has been aborted due to reaching the
+    /// [maximum number of errors][0].
+    ///
+    /// [0]: ValidatorContext::with_max_errors
+    pub(crate) fn is_aborted(&self) -> bool {
+        self.abort_error.is_some()
+    }
+
     #[doc(hidden)]
     pub fn into_errors(mut self) -> Vec<RuleError> {
         self.errors.sort();
+        self.errors.extend(self.abort_error);
         self.errors
     }
 
@@ -10,17 +10,21 @@ use crate::{
         meta::{EnumMeta, InputObjectMeta, MetaType, ScalarMeta},
         model::{SchemaType, TypeType},
     },
-    validation::RuleError,
+    validation::{RuleError, RuleErrorExtensions},
     value::ScalarValue,
 };
 
+/// Identifier of the errors reported by variables coercion in [`RuleErrorExtensions`].
+const RULE: &str = "CoerceVariableValues";
+
+/// Path to an input value inside a variable, displayed like `$input.items[3].price`.
 #[derive(Debug, Display)]
 enum Path<'a> {
-    #[display("")]
-    Root,
-    #[display("{_1}In element #{_0}: ")]
+    #[display("${_0}")]
+    Root(&'a str),
+    #[display("{_1}[{_0}]")]
     ArrayElement(usize, &'a Path<'a>),
-    #[display(r#"{_1}In field "{_0}": "#)]
+    #[display("{_1}.{_0}")]
     ObjectField(&'a str, &'a Path<'a>),
 }
 
@@ -64,21 +68,27 @@ fn validate_var_defs<S>(
                 let ct = schema.make_type(&def.var_type.item);
 
                 if def.var_type.item.is_non_null() && is_absent_or_null(values.get(name.item)) {
-                    errors.push(RuleError::new(
-                        &format!(
-                            r#"Variable "${}" of required type "{}" was not provided."#,
-                            name.item, def.var_type.item,
+                    errors.push(
+                        RuleError::new(
+                            &format!(
+                                r#"Variable "${}" of required type "{}" was not provided."#,
+                                name.item, def.var_type.item,
+                            ),
+                            &[name.span.start],
+                        )
+                        .with_extensions(
+                            RuleErrorExtensions::new(RULE)
+                                .with_input_path(Path::Root(name.item).to_string())
+                                .with_expected_type(def.var_type.item.to_string()),
                         ),
-                        &[name.span.start],
-                    ));
+                    );
                 } else if let Some(v) = values.get(name.item) {
                     errors.append(&mut unify_value(
-                        name.item,
                         &name.span.start,
                         v,
                         &ct,
                         schema,
-                        Path::Root,
+                        Path::Root(name.item),
                     ));
                 }
             }
@@ -91,7 +101,6 @@ fn validate_var_defs<S>(
 }
 
 fn unify_value<'a, S>(
-    var_name: &str,
     var_pos: &SourcePosition,
     value: &InputValue<S>,
     meta_type: &TypeType<'a, S>,
@@ -107,15 +116,14 @@ where
         TypeType::NonNull(ref inner) => {
             if value.is_null() {
                 errors.push(unification_error(
-                    var_name,
                     var_pos,
                     &path,
+                    Some(meta_type),
+                    Some(value),
                     format!(r#"Expected "{meta_type}", found null"#),
                 ));
             } else {
-                errors.append(&mut unify_value(
-                    var_name, var_pos, value, inner, schema, path,
-                ));
+                errors.append(&mut unify_value(var_pos, value, inner, schema, path));
             }
         }
 
@@ -129,9 +137,10 @@ where
                     if let Some(expected) = expected_size {
                         if l.len() != expected {
                             errors.push(unification_error(
-                                var_name,
                                 var_pos,
                                 &path,
+                                Some(meta_type),
+                                Some(value),
                                 format!(
                                     "Expected list of {expected} elements, \
                                      found {} elements",
@@ -142,7 +151,6 @@ where
                     }
                     for (i, v) in l.iter().enumerate() {
                         errors.append(&mut unify_value(
-                            var_name,
                             var_pos,
                             v,
                             inner,
@@ -151,9 +159,7 @@ where
                         ));
                     }
                 }
-                _ => errors.append(&mut unify_value(
-                    var_name, var_pos, value, inner, schema, path,
-                )),
+                _ => errors.append(&mut unify_value(var_pos, value, inner, schema, path)),
             }
         }
 
@@ -164,26 +170,25 @@ where
 
             match *mt {
                 MetaType::Scalar(ref sm) => {
-                    errors.append(&mut unify_scalar(var_name, var_pos, value, sm, &path))
-                }
-                MetaType::Enum(ref em) => {
-                    errors.append(&mut unify_enum(var_name, var_pos, value, em, &path))
+                    errors.append(&mut unify_scalar(var_pos, value, sm, &path))
                 }
+                MetaType::Enum(ref em) => errors.append(&mut unify_enum(var_pos, value, em, &path)),
                 MetaType::InputObject(ref iom) => {
-                    let mut e = unify_input_object(var_name, var_pos, value, iom, schema, &path);
+                    let mut e = unify_input_object(var_pos, value, iom, schema, &path);
                     if e.is_empty() {
                         // All the fields didn't have errors, see if there is an
                         // overall error when parsing the input value.
                         if let Err(e) = (iom.try_parse_fn)(value) {
                             errors.push(unification_error(
-                                var_name,
                                 var_pos,
                                 &path,
+                                Some(&iom.name),
+                                Some(value),
                                 format!(
-                                    "Expected input of type `{}`. \
-                                     Got: `{value}`. \
+                                    "Expected input of type `{}`, found {} `{value}`. \
                                      Details: {}",
                                     iom.name,
+                                    value_kind(value),
                                     e.message(),
                                 ),
                             ));
@@ -200,7 +205,6 @@ where
 }
 
 fn unify_scalar<S>(
-    var_name: &str,
     var_pos: &SourcePosition,
     value: &InputValue<S>,
     meta: &ScalarMeta<S>,
@@ -213,12 +217,14 @@ where
 
     if let Err(e) = (meta.try_parse_fn)(value) {
         return vec![unification_error(
-            var_name,
             var_pos,
             path,
+            Some(&meta.name),
+            Some(value),
             format!(
-                "Expected input scalar `{}`. Got: `{value}`. Details: {}",
+                "Expected input scalar `{}`, found {} `{value}`. Details: {}",
                 meta.name,
+                value_kind(value),
                 e.message(),
             ),
         )];
@@ -226,15 +232,17 @@ where
 
     match *value {
         InputValue::List(_) => errors.push(unification_error(
-            var_name,
             var_pos,
             path,
+            Some(&meta.name),
+            Some(value),
             format!(r#"Expected "{}", found list"#, meta.name),
         )),
         InputValue::Object(_) => errors.push(unification_error(
-            var_name,
             var_pos,
             path,
+            Some(&meta.name),
+            Some(value),
             format!(r#"Expected "{}", found object"#, meta.name),
         )),
         _ => (),
@@ -243,7 +251,6 @@ where
 }
 
 fn unify_enum<S>(
-    var_name: &str,
     var_pos: &SourcePosition,
     value: &InputValue<S>,
     meta: &EnumMeta<S>,
@@ -260,10 +267,15 @@ where
             if let Some(name) = scalar.try_as_str() {
                 if !meta.values.iter().any(|ev| ev.name == *name) {
                     errors.push(unification_error(
-                        var_name,
                         var_pos,
                         path,
-                        format!(r#"Invalid value for enum "{}""#, meta.name),
+                        Some(&meta.name),
+                        Some(value),
+                        format!(
+                            r#"Invalid value for enum "{}", found {} `{value}`"#,
+                            meta.name,
+                            value_kind(value),
+                        ),
                     ))
                 }
             }
@@ -271,25 +283,30 @@ where
         InputValue::Enum(name) => {
             if !meta.values.iter().any(|ev| &ev.name == name) {
                 errors.push(unification_error(
-                    var_name,
                     var_pos,
                     path,
-                    format!(r#"Invalid value for enum "{}""#, meta.name),
+                    Some(&meta.name),
+                    Some(value),
+                    format!(
+                        r#"Invalid value for enum "{}", found {} `{value}`"#,
+                        meta.name,
+                        value_kind(value),
+                    ),
                 ))
             }
         }
         _ => errors.push(unification_error(
-            var_name,
             var_pos,
             path,
-            format!(r#"Expected "{}", found not a string or enum"#, meta.name),
+            Some(&meta.name),
+            Some(value),
+            format!(r#"Expected "{}", found {}"#, meta.name, value_kind(value)),
         )),
     }
     errors
 }
 
 fn unify_input_object<S>(
-    var_name: &str,
     var_pos: &SourcePosition,
     value: &InputValue<S>,
     meta: &InputObjectMeta<S>,
@@ -313,7 +330,6 @@ where
                     has_value = true;
 
                     errors.append(&mut unify_value(
-                        var_name,
                         var_pos,
                         value,
                         &schema.make_type(&input_field.arg_type),
@@ -325,28 +341,38 @@ where
 
             if !has_value && input_field.arg_type.is_non_null() {
                 errors.push(unification_error(
-                    var_name,
                     var_pos,
                     &Path::ObjectField(&input_field.name, path),
-                    format!(r#"Expected "{}", found null"#, input_field.arg_type),
+                    Some(&input_field.arg_type),
+                    obj.get(input_field.name.as_str()).copied(),
+                    if obj.contains_key(input_field.name.as_str()) {
+                        format!(r#"Expected "{}", found null"#, input_field.arg_type)
+                    } else {
+                        format!(
+                            r#"Expected "{}", but it was not provided"#,
+                            input_field.arg_type
+                        )
+                    },
                 ));
             }
         }
 
         for key in keys {
             errors.push(unification_error(
-                var_name,
                 var_pos,
                 &Path::ObjectField(key, path),
+                None::<&str>,
+                obj.get(*key).copied(),
                 "Unknown field",
             ));
         }
     } else {
         errors.push(unification_error(
-            var_name,
             var_pos,
             path,
-            format!(r#"Expected "{}", found not an object"#, meta.name),
+            Some(&meta.name),
+            Some(value),
+            format!(r#"Expected "{}", found {}"#, meta.name, value_kind(value)),
         ));
     }
     errors
@@ -359,14 +385,40 @@ where
     v.is_none_or(InputValue::is_null)
 }
 
-fn unification_error(
-    var_name: impl Display,
+/// Returns a human-readable kind of the provided [`InputValue`] (e.g. `string` or `object`).
+fn value_kind<S: ScalarValue>(value: &InputValue<S>) -> &'static str {
+    match value {
+        InputValue::Null => "null",
+        InputValue::Scalar(s) if s.try_to_bool().is_some() => "boolean",
+        InputValue::Scalar(s) if s.try_to_int().is_some() => "int",
+        InputValue::Scalar(s) if s.try_to_float().is_some() => "float",
+        InputValue::Scalar(s) if s.try_as_str().is_some() => "string",
+        InputValue::Scalar(_) => "scalar",
+        InputValue::Enum(_) => "enum value",
+        InputValue::Variable(_) => "variable",
+        InputValue::List(_) => "list",
+        InputValue::Object(_) => "object",
+    }
+}
+
+fn unification_error<S: ScalarValue>(
     var_pos: &SourcePosition,
     path: &Path<'_>,
+    expected_type: Option<impl Display>,
+    received: Option<&InputValue<S>>,
     message: impl Display,
 ) -> RuleError {
+    let mut extensions = RuleErrorExtensions::new(RULE).with_input_path(path.to_string());
+    if let Some(expected_type) = expected_type {
+        extensions = extensions.with_expected_type(expected_type.to_string());
+    }
+    if let Some(received) = received {
+        extensions = extensions.with_received(value_kind(received));
+    }
+
     RuleError::new(
-        &format!(r#"Variable "${var_name}" got invalid value. {path}{message}."#),
+        &format!(r#"Variable "{path}" got invalid value. {message}."#),
         &[*var_pos],
     )
+    .with_extensions(extensions)
 }
@@ -11,7 +11,7 @@ mod visitor;
 pub(crate) mod test_harness;
 
 pub use self::{
-    context::{RuleError, ValidatorContext},
+    context::{RuleError, RuleErrorExtensions, ValidatorContext},
     input_value::validate_input_values,
     multi_visitor::MultiVisitorNil,
     rules::visit_all_rules,
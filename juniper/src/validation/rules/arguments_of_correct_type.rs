@@ -682,7 +682,13 @@ mod tests {
             }
             "#,
             &[RuleError::new(
-                &error_message("dogCommand", error::type_value("sit", "DogCommand")),
+                &error_message(
+                    "dogCommand",
+                    format!(
+                        r#"{}. Did you mean "SIT"?"#,
+                        error::type_value("sit", "DogCommand"),
+                    ),
+                ),
                 &[SourcePosition::new(79, 3, 44)],
             )],
         );
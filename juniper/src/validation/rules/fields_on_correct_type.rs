@@ -4,8 +4,9 @@ use crate::{
     Operation, OperationType, Selection,
     ast::Field,
     parser::Spanning,
-    schema::meta::MetaType,
-    validation::{ValidatorContext, Visitor},
+    schema::meta::{InterfaceMeta, MetaType, ObjectMeta},
+    util::{did_you_mean, suggestion_list},
+    validation::{RuleErrorExtensions, ValidatorContext, Visitor},
     value::ScalarValue,
 };
 
@@ -53,7 +54,10 @@ where
                     .map(ArcStr::as_str)
                     .unwrap_or("<unknown>");
 
-                if parent_type.field_by_name(field_name.item).is_none() {
+                let is_known = parent_type
+                    .field_by_name(field_name.item)
+                    .is_some_and(|f| context.visibility().is_field_visible(type_name, f));
+                if !is_known {
                     if let MetaType::Union(..) = *parent_type {
                         // You can query for `__typename` on a union,
                         // but it isn't a field on the union...it is
@@ -63,9 +67,30 @@ where
                         }
                     }
 
-                    context.report_error(
-                        &error_message(field_name.item, type_name),
+                    // Querying a field of a concrete type via its abstract type is a common
+                    // mistake, so suggesting an inline fragment is more helpful here than
+                    // suggesting similarly named fields.
+                    let type_suggestions =
+                        suggested_type_names(context, parent_type, field_name.item);
+                    let (sub_message, suggestions) = if !type_suggestions.is_empty() {
+                        (Some("to use an inline fragment on"), type_suggestions)
+                    } else {
+                        (
+                            None,
+                            suggested_field_names(context, parent_type, field_name.item),
+                        )
+                    };
+
+                    let mut message = error_message(field_name.item, type_name);
+                    if let Some(hint) = did_you_mean(sub_message, &suggestions) {
+                        message = format!("{message}. {hint}");
+                    }
+
+                    context.report_error_with_extensions(
+                        &message,
                         &[field_name.span.start],
+                        RuleErrorExtensions::new("FieldsOnCorrectType")
+                            .with_suggestions(&suggestions),
                     );
                 }
             }
@@ -73,6 +98,59 @@ where
     }
 }
 
+/// Returns names of the possible types of the provided abstract `parent_type` having a field with
+/// the provided `field_name`.
+fn suggested_type_names<'a, S>(
+    context: &ValidatorContext<'a, S>,
+    parent_type: &'a MetaType<S>,
+    field_name: &str,
+) -> Vec<&'a str>
+where
+    S: ScalarValue,
+{
+    if !parent_type.is_abstract() {
+        return vec![];
+    }
+
+    let mut names = context
+        .schema
+        .possible_types(parent_type)
+        .into_iter()
+        .filter_map(|t| {
+            let name = t.name()?;
+            let field = t.field_by_name(field_name)?;
+            context
+                .visibility()
+                .is_field_visible(name, field)
+                .then_some(name.as_str())
+        })
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names
+}
+
+/// Returns names of the fields of the provided `parent_type` similar to the provided `field_name`.
+fn suggested_field_names<'a, S>(
+    context: &ValidatorContext<'a, S>,
+    parent_type: &'a MetaType<S>,
+    field_name: &str,
+) -> Vec<&'a str>
+where
+    S: ScalarValue,
+{
+    match parent_type {
+        MetaType::Interface(InterfaceMeta { name, fields, .. })
+        | MetaType::Object(ObjectMeta { name, fields, .. }) => suggestion_list(
+            field_name,
+            fields
+                .iter()
+                .filter(|f| !f.is_builtin() && context.visibility().is_field_visible(name, f))
+                .map(|f| f.name.as_str()),
+        ),
+        _ => vec![],
+    }
+}
+
 fn error_message(field: &str, type_name: &str) -> String {
     format!(r#"Unknown field "{field}" on type "{type_name}""#)
 }
@@ -83,7 +161,7 @@ mod tests {
 
     use crate::{
         parser::SourcePosition,
-        validation::{RuleError, expect_fails_rule, expect_passes_rule},
+        validation::{RuleError, RuleErrorExtensions, expect_fails_rule, expect_passes_rule},
         value::DefaultScalarValue,
     };
 
@@ -179,11 +257,13 @@ mod tests {
                 RuleError::new(
                     &error_message("unknown_pet_field", "Pet"),
                     &[SourcePosition::new(56, 2, 12)],
-                ),
+                )
+                .with_extensions(RuleErrorExtensions::new("FieldsOnCorrectType")),
                 RuleError::new(
                     &error_message("unknown_cat_field", "Cat"),
                     &[SourcePosition::new(119, 4, 16)],
-                ),
+                )
+                .with_extensions(RuleErrorExtensions::new("FieldsOnCorrectType")),
             ],
         );
     }
@@ -198,8 +278,14 @@ mod tests {
           }
         "#,
             &[RuleError::new(
-                &error_message("meowVolume", "Dog"),
+                &format!(
+                    r#"{}. Did you mean "barkVolume"?"#,
+                    error_message("meowVolume", "Dog"),
+                ),
                 &[SourcePosition::new(57, 2, 12)],
+            )
+            .with_extensions(
+                RuleErrorExtensions::new("FieldsOnCorrectType").with_suggestions(&["barkVolume"]),
             )],
         );
     }
@@ -218,7 +304,8 @@ mod tests {
             &[RuleError::new(
                 &error_message("unknown_field", "Dog"),
                 &[SourcePosition::new(61, 2, 12)],
-            )],
+            )
+            .with_extensions(RuleErrorExtensions::new("FieldsOnCorrectType"))],
         );
     }
 
@@ -236,7 +323,8 @@ mod tests {
             &[RuleError::new(
                 &error_message("unknown_field", "Pet"),
                 &[SourcePosition::new(83, 3, 14)],
-            )],
+            )
+            .with_extensions(RuleErrorExtensions::new("FieldsOnCorrectType"))],
         );
     }
 
@@ -252,8 +340,14 @@ mod tests {
           }
         "#,
             &[RuleError::new(
-                &error_message("meowVolume", "Dog"),
+                &format!(
+                    r#"{}. Did you mean "barkVolume"?"#,
+                    error_message("meowVolume", "Dog"),
+                ),
                 &[SourcePosition::new(84, 3, 14)],
+            )
+            .with_extensions(
+                RuleErrorExtensions::new("FieldsOnCorrectType").with_suggestions(&["barkVolume"]),
             )],
         );
     }
@@ -268,8 +362,14 @@ mod tests {
           }
         "#,
             &[RuleError::new(
-                &error_message("mooVolume", "Dog"),
+                &format!(
+                    r#"{}. Did you mean "barkVolume"?"#,
+                    error_message("mooVolume", "Dog"),
+                ),
                 &[SourcePosition::new(79, 2, 21)],
+            )
+            .with_extensions(
+                RuleErrorExtensions::new("FieldsOnCorrectType").with_suggestions(&["barkVolume"]),
             )],
         );
     }
@@ -284,8 +384,14 @@ mod tests {
           }
         "#,
             &[RuleError::new(
-                &error_message("kawVolume", "Dog"),
+                &format!(
+                    r#"{}. Did you mean "barkVolume"?"#,
+                    error_message("kawVolume", "Dog"),
+                ),
                 &[SourcePosition::new(88, 2, 25)],
+            )
+            .with_extensions(
+                RuleErrorExtensions::new("FieldsOnCorrectType").with_suggestions(&["barkVolume"]),
             )],
         );
     }
@@ -302,7 +408,8 @@ mod tests {
             &[RuleError::new(
                 &error_message("tailLength", "Pet"),
                 &[SourcePosition::new(63, 2, 12)],
-            )],
+            )
+            .with_extensions(RuleErrorExtensions::new("FieldsOnCorrectType"))],
         );
     }
 
@@ -316,8 +423,14 @@ mod tests {
           }
         "#,
             &[RuleError::new(
-                &error_message("nickname", "Pet"),
+                &format!(
+                    r#"{}. Did you mean to use an inline fragment on "Cat" or "Dog"?"#,
+                    error_message("nickname", "Pet"),
+                ),
                 &[SourcePosition::new(78, 2, 12)],
+            )
+            .with_extensions(
+                RuleErrorExtensions::new("FieldsOnCorrectType").with_suggestions(&["Cat", "Dog"]),
             )],
         );
     }
@@ -344,8 +457,14 @@ mod tests {
           }
         "#,
             &[RuleError::new(
-                &error_message("name", "CatOrDog"),
+                &format!(
+                    r#"{}. Did you mean to use an inline fragment on "Cat" or "Dog"?"#,
+                    error_message("name", "CatOrDog"),
+                ),
                 &[SourcePosition::new(82, 2, 12)],
+            )
+            .with_extensions(
+                RuleErrorExtensions::new("FieldsOnCorrectType").with_suggestions(&["Cat", "Dog"]),
             )],
         );
     }
@@ -2,7 +2,8 @@ use crate::{
     ast::{Directive, Field, InputValue},
     parser::Spanning,
     schema::meta::Argument,
-    validation::{ValidatorContext, Visitor},
+    util::{did_you_mean, suggestion_list},
+    validation::{RuleErrorExtensions, ValidatorContext, Visitor},
     value::ScalarValue,
 };
 use std::fmt::Debug;
@@ -71,7 +72,7 @@ where
     ) {
         if let Some((ref pos, args)) = self.current_args {
             if !args.iter().any(|a| a.name == arg_name.item) {
-                let message = match *pos {
+                let mut message = match *pos {
                     ArgumentPosition::Field(field_name, type_name) => {
                         field_error_message(arg_name.item, field_name, type_name)
                     }
@@ -80,7 +81,17 @@ where
                     }
                 };
 
-                ctx.report_error(&message, &[arg_name.span.start]);
+                let suggestions =
+                    suggestion_list(arg_name.item, args.iter().map(|a| a.name.as_str()));
+                if let Some(hint) = did_you_mean(None, &suggestions) {
+                    message = format!("{message}. {hint}");
+                }
+
+                ctx.report_error_with_extensions(
+                    &message,
+                    &[arg_name.span.start],
+                    RuleErrorExtensions::new("KnownArgumentNames").with_suggestions(&suggestions),
+                );
             }
         }
     }
@@ -100,7 +111,7 @@ mod tests {
 
     use crate::{
         parser::SourcePosition,
-        validation::{RuleError, expect_fails_rule, expect_passes_rule},
+        validation::{RuleError, RuleErrorExtensions, expect_fails_rule, expect_passes_rule},
         value::DefaultScalarValue,
     };
 
@@ -148,7 +159,8 @@ mod tests {
             &[RuleError::new(
                 &field_error_message("unknownArg", "nickname", "Dog"),
                 &[SourcePosition::new(17, 0, 17)],
-            )],
+            )
+            .with_extensions(RuleErrorExtensions::new("KnownArgumentNames"))],
         );
     }
 
@@ -221,7 +233,8 @@ mod tests {
             &[RuleError::new(
                 &directive_error_message("unless", "skip"),
                 &[SourcePosition::new(35, 2, 22)],
-            )],
+            )
+            .with_extensions(RuleErrorExtensions::new("KnownArgumentNames"))],
         );
     }
 
@@ -237,6 +250,29 @@ mod tests {
             &[RuleError::new(
                 &field_error_message("unknown", "doesKnowCommand", "Dog"),
                 &[SourcePosition::new(72, 2, 28)],
+            )
+            .with_extensions(RuleErrorExtensions::new("KnownArgumentNames"))],
+        );
+    }
+
+    #[test]
+    fn misspelled_arg_name_suggests_known_one() {
+        expect_fails_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          fragment misspelledArgName on Dog {
+            doesKnowCommand(dogcommand: true)
+          }
+        "#,
+            &[RuleError::new(
+                &format!(
+                    r#"{}. Did you mean "dogCommand"?"#,
+                    field_error_message("dogcommand", "doesKnowCommand", "Dog"),
+                ),
+                &[SourcePosition::new(75, 2, 28)],
+            )
+            .with_extensions(
+                RuleErrorExtensions::new("KnownArgumentNames").with_suggestions(&["dogCommand"]),
             )],
         );
     }
@@ -254,11 +290,13 @@ mod tests {
                 RuleError::new(
                     &field_error_message("whoknows", "doesKnowCommand", "Dog"),
                     &[SourcePosition::new(81, 2, 28)],
-                ),
+                )
+                .with_extensions(RuleErrorExtensions::new("KnownArgumentNames")),
                 RuleError::new(
                     &field_error_message("unknown", "doesKnowCommand", "Dog"),
                     &[SourcePosition::new(111, 2, 58)],
-                ),
+                )
+                .with_extensions(RuleErrorExtensions::new("KnownArgumentNames")),
             ],
         );
     }
@@ -285,11 +323,13 @@ mod tests {
                 RuleError::new(
                     &field_error_message("unknown", "doesKnowCommand", "Dog"),
                     &[SourcePosition::new(61, 3, 30)],
-                ),
+                )
+                .with_extensions(RuleErrorExtensions::new("KnownArgumentNames")),
                 RuleError::new(
                     &field_error_message("unknown", "doesKnowCommand", "Dog"),
                     &[SourcePosition::new(193, 8, 34)],
-                ),
+                )
+                .with_extensions(RuleErrorExtensions::new("KnownArgumentNames")),
             ],
         );
     }
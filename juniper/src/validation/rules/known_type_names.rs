@@ -1,10 +1,14 @@
+use std::fmt::Debug;
+
+use arcstr::ArcStr;
+
 use crate::{
     ast::{Fragment, InlineFragment, VariableDefinition},
     parser::{SourcePosition, Spanning},
-    validation::{ValidatorContext, Visitor},
+    util::{did_you_mean, suggestion_list},
+    validation::{RuleErrorExtensions, ValidatorContext, Visitor},
     value::ScalarValue,
 };
-use std::fmt::Debug;
 
 pub struct KnownTypeNames;
 
@@ -50,8 +54,28 @@ fn validate_type<S: Debug>(
     type_name: &str,
     location: &SourcePosition,
 ) {
-    if ctx.schema.type_by_name(type_name).is_none() {
-        ctx.report_error(&error_message(type_name), &[*location]);
+    let is_known =
+        ctx.schema.type_by_name(type_name).is_some() && ctx.visibility().is_type_visible(type_name);
+    if !is_known {
+        let mut message = error_message(type_name);
+
+        let suggestions = suggestion_list(
+            type_name,
+            ctx.schema
+                .concrete_type_list()
+                .into_iter()
+                .filter_map(|t| t.name().map(ArcStr::as_str))
+                .filter(|name| ctx.visibility().is_type_visible(name)),
+        );
+        if let Some(hint) = did_you_mean(None, &suggestions) {
+            message = format!("{message}. {hint}");
+        }
+
+        ctx.report_error_with_extensions(
+            &message,
+            &[*location],
+            RuleErrorExtensions::new("KnownTypeNames").with_suggestions(&suggestions),
+        );
     }
 }
 
@@ -65,7 +89,7 @@ mod tests {
 
     use crate::{
         parser::SourcePosition,
-        validation::{RuleError, expect_fails_rule, expect_passes_rule},
+        validation::{RuleError, RuleErrorExtensions, expect_fails_rule, expect_passes_rule},
         value::DefaultScalarValue,
     };
 
@@ -105,9 +129,17 @@ mod tests {
                 RuleError::new(
                     &error_message("JumbledUpLetters"),
                     &[SourcePosition::new(27, 1, 26)],
+                )
+                .with_extensions(RuleErrorExtensions::new("KnownTypeNames")),
+                RuleError::new(&error_message("Badger"), &[SourcePosition::new(120, 4, 28)])
+                    .with_extensions(RuleErrorExtensions::new("KnownTypeNames")),
+                RuleError::new(
+                    &format!(r#"{}. Did you mean "Pet"?"#, error_message("Peettt")),
+                    &[SourcePosition::new(210, 7, 32)],
+                )
+                .with_extensions(
+                    RuleErrorExtensions::new("KnownTypeNames").with_suggestions(&["Pet"]),
                 ),
-                RuleError::new(&error_message("Badger"), &[SourcePosition::new(120, 4, 28)]),
-                RuleError::new(&error_message("Peettt"), &[SourcePosition::new(210, 7, 32)]),
             ],
         );
     }
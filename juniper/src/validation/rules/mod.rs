@@ -83,7 +83,7 @@ where
 mod tests {
     use crate::{DefaultScalarValue, parser::SourcePosition};
 
-    use crate::validation::{RuleError, expect_fails_fn};
+    use crate::validation::{RuleError, RuleErrorExtensions, expect_fails_fn};
 
     #[test]
     fn handles_recursive_fragments() {
@@ -116,7 +116,8 @@ mod tests {
                 RuleError::new(
                     r#"Unknown field "a" on type "QueryRoot""#,
                     &[SourcePosition::new(26, 0, 26)],
-                ),
+                )
+                .with_extensions(RuleErrorExtensions::new("FieldsOnCorrectType")),
                 RuleError::new(
                     "Cannot spread fragment \"f\"",
                     &[SourcePosition::new(30, 0, 30)],
@@ -1,4 +1,11 @@
-use std::{borrow::Borrow, cell::RefCell, collections::HashMap, fmt::Debug, hash::Hash};
+use std::{
+    borrow::Borrow,
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    hash::Hash,
+    rc::Rc,
+};
 
 use arcstr::ArcStr;
 use derive_more::with_trait::Display;
@@ -31,6 +38,8 @@ struct AstAndDef<'a, S: Debug + 'a>(
 
 type AstAndDefCollection<'a, S> = OrderedMap<&'a str, Vec<AstAndDef<'a, S>>>;
 
+type FieldsAndFragmentNames<'a, S> = (AstAndDefCollection<'a, S>, Vec<&'a str>);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum ConflictReasonMessage {
     Message(String),
@@ -135,12 +144,21 @@ impl<'a> PairSet<'a> {
 pub struct OverlappingFieldsCanBeMerged<'a, S: Debug + 'a> {
     named_fragments: HashMap<&'a str, &'a Fragment<'a, S>>,
     compared_fragments: RefCell<PairSet<'a>>,
+
+    /// Cache of the fields and fragment names collected from a selection set, keyed by the
+    /// selection set's address, as the same selection sets are compared over and over again.
+    ///
+    /// The parent type of a selection set is always the same within a document, so the address
+    /// alone identifies the collected fields unambiguously.
+    cached_fields_and_fragment_names:
+        RefCell<HashMap<*const Selection<'a, S>, Rc<FieldsAndFragmentNames<'a, S>>>>,
 }
 
 pub fn factory<'a, S: Debug>() -> OverlappingFieldsCanBeMerged<'a, S> {
     OverlappingFieldsCanBeMerged {
         named_fragments: HashMap::new(),
         compared_fragments: RefCell::new(PairSet::new()),
+        cached_fields_and_fragment_names: RefCell::new(HashMap::new()),
     }
 }
 
@@ -156,15 +174,16 @@ impl<'a, S: Debug> OverlappingFieldsCanBeMerged<'a, S> {
     {
         let mut conflicts = Vec::new();
 
-        let (field_map, fragment_names) =
+        let fields_and_fragment_names =
             self.get_fields_and_fragment_names(parent_type, selection_set, ctx);
+        let (field_map, fragment_names) = &*fields_and_fragment_names;
 
-        self.collect_conflicts_within(&mut conflicts, &field_map, ctx);
+        self.collect_conflicts_within(&mut conflicts, field_map, ctx);
 
         for (i, frag_name1) in fragment_names.iter().enumerate() {
             self.collect_conflicts_between_fields_and_fragment(
                 &mut conflicts,
-                &field_map,
+                field_map,
                 frag_name1,
                 false,
                 ctx,
@@ -228,20 +247,16 @@ impl<'a, S: Debug> OverlappingFieldsCanBeMerged<'a, S> {
             );
         }
 
-        let (field_map1, fragment_names1) =
+        let fields_and_fragment_names1 =
             self.get_referenced_fields_and_fragment_names(fragment1, ctx);
-        let (field_map2, fragment_names2) =
+        let (field_map1, fragment_names1) = &*fields_and_fragment_names1;
+        let fields_and_fragment_names2 =
             self.get_referenced_fields_and_fragment_names(fragment2, ctx);
+        let (field_map2, fragment_names2) = &*fields_and_fragment_names2;
 
-        self.collect_conflicts_between(
-            conflicts,
-            mutually_exclusive,
-            &field_map1,
-            &field_map2,
-            ctx,
-        );
+        self.collect_conflicts_between(conflicts, mutually_exclusive, field_map1, field_map2, ctx);
 
-        for fragment_name2 in &fragment_names2 {
+        for fragment_name2 in fragment_names2 {
             self.collect_conflicts_between_fragments(
                 conflicts,
                 fragment_name1,
@@ -251,7 +266,7 @@ impl<'a, S: Debug> OverlappingFieldsCanBeMerged<'a, S> {
             );
         }
 
-        for fragment_name1 in &fragment_names1 {
+        for fragment_name1 in fragment_names1 {
             self.collect_conflicts_between_fragments(
                 conflicts,
                 fragment_name1,
@@ -266,39 +281,38 @@ impl<'a, S: Debug> OverlappingFieldsCanBeMerged<'a, S> {
         &self,
         conflicts: &mut Vec<Conflict>,
         field_map: &AstAndDefCollection<'a, S>,
-        fragment_name: &str,
+        fragment_name: &'a str,
         mutually_exclusive: bool,
         ctx: &ValidatorContext<'a, S>,
     ) where
         S: ScalarValue,
     {
-        let mut to_check = Vec::new();
-        if let Some(fragments) = self.collect_conflicts_between_fields_and_fragment_inner(
-            conflicts,
-            field_map,
-            fragment_name,
-            mutually_exclusive,
-            ctx,
-        ) {
-            to_check.push((fragment_name, fragments))
-        }
-
-        while let Some((fragment_name, fragment_names2)) = to_check.pop() {
-            for fragment_name2 in fragment_names2 {
-                // Early return on fragment recursion, as it makes no sense.
-                // Fragment recursions are prevented by `no_fragment_cycles` validator.
-                if fragment_name == fragment_name2 {
-                    return;
-                }
-                if let Some(fragments) = self.collect_conflicts_between_fields_and_fragment_inner(
+        // Every fragment is compared with the `field_map` only once, even if it's spread multiple
+        // times, which also prevents stalling on fragment recursions (those are reported by the
+        // `no_fragment_cycles` validator).
+        let mut visited = HashSet::new();
+        let mut to_check = vec![fragment_name];
+
+        while let Some(fragment_name) = to_check.pop() {
+            if !visited.insert(fragment_name) {
+                continue;
+            }
+            if let Some(fields_and_fragment_names) = self
+                .collect_conflicts_between_fields_and_fragment_inner(
                     conflicts,
                     field_map,
-                    fragment_name2,
+                    fragment_name,
                     mutually_exclusive,
                     ctx,
-                ) {
-                    to_check.push((fragment_name2, fragments));
-                };
+                )
+            {
+                to_check.extend(
+                    fields_and_fragment_names
+                        .1
+                        .iter()
+                        .copied()
+                        .filter(|name| !visited.contains(name)),
+                );
             }
         }
     }
@@ -315,18 +329,24 @@ impl<'a, S: Debug> OverlappingFieldsCanBeMerged<'a, S> {
         fragment_name: &str,
         mutually_exclusive: bool,
         ctx: &ValidatorContext<'a, S>,
-    ) -> Option<Vec<&'a str>>
+    ) -> Option<Rc<FieldsAndFragmentNames<'a, S>>>
     where
         S: ScalarValue,
     {
         let fragment = self.named_fragments.get(fragment_name)?;
 
-        let (field_map2, fragment_names2) =
+        let fields_and_fragment_names =
             self.get_referenced_fields_and_fragment_names(fragment, ctx);
 
-        self.collect_conflicts_between(conflicts, mutually_exclusive, field_map, &field_map2, ctx);
+        self.collect_conflicts_between(
+            conflicts,
+            mutually_exclusive,
+            field_map,
+            &fields_and_fragment_names.0,
+            ctx,
+        );
 
-        Some(fragment_names2)
+        Some(fields_and_fragment_names)
     }
 
     fn collect_conflicts_between(
@@ -483,41 +503,43 @@ impl<'a, S: Debug> OverlappingFieldsCanBeMerged<'a, S> {
         let parent_type1 = parent_type1.and_then(|t| ctx.schema.concrete_type_by_name(t));
         let parent_type2 = parent_type2.and_then(|t| ctx.schema.concrete_type_by_name(t));
 
-        let (field_map1, fragment_names1) =
+        let fields_and_fragment_names1 =
             self.get_fields_and_fragment_names(parent_type1, selection_set1, ctx);
-        let (field_map2, fragment_names2) =
+        let (field_map1, fragment_names1) = &*fields_and_fragment_names1;
+        let fields_and_fragment_names2 =
             self.get_fields_and_fragment_names(parent_type2, selection_set2, ctx);
+        let (field_map2, fragment_names2) = &*fields_and_fragment_names2;
 
         self.collect_conflicts_between(
             &mut conflicts,
             mutually_exclusive,
-            &field_map1,
-            &field_map2,
+            field_map1,
+            field_map2,
             ctx,
         );
 
-        for fragment_name in &fragment_names2 {
+        for fragment_name in fragment_names2 {
             self.collect_conflicts_between_fields_and_fragment(
                 &mut conflicts,
-                &field_map1,
+                field_map1,
                 fragment_name,
                 mutually_exclusive,
                 ctx,
             );
         }
 
-        for fragment_name in &fragment_names1 {
+        for fragment_name in fragment_names1 {
             self.collect_conflicts_between_fields_and_fragment(
                 &mut conflicts,
-                &field_map2,
+                field_map2,
                 fragment_name,
                 mutually_exclusive,
                 ctx,
             );
         }
 
-        for fragment_name1 in &fragment_names1 {
-            for fragment_name2 in &fragment_names2 {
+        for fragment_name1 in fragment_names1 {
+            for fragment_name2 in fragment_names2 {
                 self.collect_conflicts_between_fragments(
                     &mut conflicts,
                     fragment_name1,
@@ -639,7 +661,7 @@ impl<'a, S: Debug> OverlappingFieldsCanBeMerged<'a, S> {
         &self,
         fragment: &'a Fragment<S>,
         ctx: &ValidatorContext<'a, S>,
-    ) -> (AstAndDefCollection<'a, S>, Vec<&'a str>) {
+    ) -> Rc<FieldsAndFragmentNames<'a, S>> {
         let fragment_type = ctx
             .schema
             .concrete_type_by_name(fragment.type_condition.item);
@@ -652,7 +674,15 @@ impl<'a, S: Debug> OverlappingFieldsCanBeMerged<'a, S> {
         parent_type: Option<&'a MetaType<S>>,
         selection_set: &'a [Selection<S>],
         ctx: &ValidatorContext<'a, S>,
-    ) -> (AstAndDefCollection<'a, S>, Vec<&'a str>) {
+    ) -> Rc<FieldsAndFragmentNames<'a, S>> {
+        if let Some(cached) = self
+            .cached_fields_and_fragment_names
+            .borrow()
+            .get(&selection_set.as_ptr())
+        {
+            return Rc::clone(cached);
+        }
+
         let mut ast_and_defs = OrderedMap::new();
         let mut fragment_names = Vec::new();
 
@@ -664,7 +694,12 @@ impl<'a, S: Debug> OverlappingFieldsCanBeMerged<'a, S> {
             &mut fragment_names,
         );
 
-        (ast_and_defs, fragment_names)
+        let fields_and_fragment_names = Rc::new((ast_and_defs, fragment_names));
+        self.cached_fields_and_fragment_names.borrow_mut().insert(
+            selection_set.as_ptr(),
+            Rc::clone(&fields_and_fragment_names),
+        );
+        fields_and_fragment_names
     }
 
     fn collect_fields_and_fragment_names(
@@ -1120,6 +1155,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reports_conflict_with_fragment_spread_multiple_times_once() {
+        expect_fails_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            dog {
+              x: name
+              ...A
+            }
+          }
+          fragment A on Dog {
+            ...B
+            ...C
+          }
+          fragment B on Dog {
+            ...C
+          }
+          fragment C on Dog {
+            x: barks
+          }
+        "#,
+            &[RuleError::new(
+                &error_message("x", &Message("name and barks are different fields".into())),
+                &[
+                    SourcePosition::new(45, 3, 14),
+                    SourcePosition::new(275, 15, 12),
+                ],
+            )],
+        );
+    }
+
     #[test]
     fn deep_conflict() {
         expect_fails_rule::<_, _, DefaultScalarValue>(
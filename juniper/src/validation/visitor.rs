@@ -29,6 +29,10 @@ where
     V: Visitor<'a, S>,
 {
     for def in d {
+        if ctx.is_aborted() {
+            break;
+        }
+
         let def_type = match def {
             Definition::Fragment(Spanning {
                 item:
@@ -191,6 +195,10 @@ fn visit_arguments<'a, S, V>(
 {
     if let Some(ref arguments) = *arguments {
         for argument in arguments.item.iter() {
+            if ctx.is_aborted() {
+                break;
+            }
+
             let arg_type = meta_args
                 .and_then(|args| args.iter().find(|a| a.name == argument.0.item))
                 .map(|a| &a.arg_type);
@@ -218,6 +226,9 @@ fn visit_selection_set<'a, S, V>(
         v.enter_selection_set(ctx, selection_set);
 
         for selection in selection_set.iter() {
+            if ctx.is_aborted() {
+                break;
+            }
             visit_selection(v, ctx, selection);
         }
 
@@ -324,6 +335,10 @@ fn visit_input_value<'a, S, V>(
     match &input_value.item {
         InputValue::Object(fields) => {
             for (key, value) in fields {
+                if ctx.is_aborted() {
+                    break;
+                }
+
                 let inner_type = ctx
                     .current_input_type_literal()
                     .and_then(|t| t.name().and_then(|n| ctx.schema.concrete_type_by_name(n)))
@@ -344,6 +359,9 @@ fn visit_input_value<'a, S, V>(
 
             ctx.with_pushed_input_type(inner_type, |ctx| {
                 for value in ls {
+                    if ctx.is_aborted() {
+                        break;
+                    }
                     visit_input_value(v, ctx, value);
                 }
             })
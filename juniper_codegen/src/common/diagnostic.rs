@@ -20,13 +20,21 @@ pub(crate) enum Scope {
     #[display("interface")]
     InterfaceDerive,
     #[display("object")]
+    MergedObjectDerive,
+    #[display("object")]
     ObjectAttr,
     #[display("object")]
     ObjectDerive,
+    #[cfg(feature = "schema-language")]
+    #[display("operation")]
+    QueryDerive,
     #[display("scalar")]
     ScalarAttr,
     #[display("scalar")]
     ScalarDerive,
+    #[cfg(feature = "schema-language")]
+    #[display("schema")]
+    SchemaFromFile,
     #[display("built-in scalars")]
     ScalarValueDerive,
     #[display("union")]
@@ -41,9 +49,13 @@ impl Scope {
             Self::EnumDerive => "#sec-Enums",
             Self::InputObjectDerive => "#sec-Input-Objects",
             Self::InterfaceAttr | Self::InterfaceDerive => "#sec-Interfaces",
-            Self::ObjectAttr | Self::ObjectDerive => "#sec-Objects",
+            Self::MergedObjectDerive | Self::ObjectAttr | Self::ObjectDerive => "#sec-Objects",
+            #[cfg(feature = "schema-language")]
+            Self::QueryDerive => "#sec-Executable-Definitions",
             Self::ScalarAttr | Self::ScalarDerive => "#sec-Scalars",
             Self::ScalarValueDerive => "#sec-Scalars.Built-in-Scalars",
+            #[cfg(feature = "schema-language")]
+            Self::SchemaFromFile => "#sec-Schema",
             Self::UnionAttr | Self::UnionDerive => "#sec-Unions",
         }
     }
@@ -0,0 +1,241 @@
+//! Common functions, definitions and extensions for parsing and code generation
+//! of [GraphQL directives][0] applied to schema elements.
+//!
+//! [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{
+    bracketed,
+    ext::IdentExt as _,
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token,
+};
+
+/// [GraphQL directive][0] applied to a schema element via
+/// `#[graphql(directive = name(arg = value, ...))]` attribute.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+#[derive(Debug)]
+pub(crate) struct Directive {
+    /// Name of this [GraphQL directive][0] (without the leading `@`).
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    name: syn::Ident,
+
+    /// Arguments of this [GraphQL directive][0], in the order they were
+    /// specified.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    arguments: Vec<(syn::Ident, Value)>,
+}
+
+impl Parse for Directive {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let name = input.call(syn::Ident::parse_any)?;
+        let arguments = if input.peek(token::Paren) {
+            let content;
+            _ = parenthesized!(content in input);
+            content
+                .parse_terminated(
+                    |input| {
+                        let name = input.call(syn::Ident::parse_any)?;
+                        input.parse::<token::Eq>()?;
+                        Ok((name, input.parse::<Value>()?))
+                    },
+                    token::Comma,
+                )?
+                .into_iter()
+                .collect()
+        } else {
+            vec![]
+        };
+        Ok(Self { name, arguments })
+    }
+}
+
+impl Directive {
+    /// Returns the [`Span`] of this [`Directive`]'s name.
+    #[must_use]
+    pub(crate) fn span(&self) -> Span {
+        self.name.span()
+    }
+}
+
+impl ToTokens for Directive {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        let name = self.name.unraw().to_string();
+        let args = self.arguments.iter().map(|(name, value)| {
+            let name = name.unraw().to_string();
+            quote! {
+                .argument(::juniper::arcstr::literal!(#name), #value)
+            }
+        });
+        quote! {
+            .directive(
+                ::juniper::meta::AppliedDirective::new(::juniper::arcstr::literal!(#name))
+                    #( #args )*
+            )
+        }
+        .to_tokens(into);
+    }
+}
+
+/// Constant value of a [`Directive`] argument.
+///
+/// Rust literals are mapped onto GraphQL scalars, `null` onto GraphQL `null`,
+/// other bare identifiers onto GraphQL enum values, and `[...]` onto GraphQL
+/// lists.
+#[derive(Debug)]
+enum Value {
+    /// GraphQL `null`.
+    Null(Span),
+
+    /// GraphQL scalar represented by a Rust literal.
+    Scalar(syn::Lit),
+
+    /// GraphQL enum value.
+    Enum(syn::Ident),
+
+    /// GraphQL list of values.
+    List(Span, Vec<Value>),
+}
+
+impl Parse for Value {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        if input.peek(token::Bracket) {
+            let content;
+            let bracket = bracketed!(content in input);
+            let items = Punctuated::<Self, token::Comma>::parse_terminated(&content)?;
+            Ok(Self::List(bracket.span.join(), items.into_iter().collect()))
+        } else if input.peek(syn::Lit) {
+            let lit = input.parse::<syn::Lit>()?;
+            match &lit {
+                syn::Lit::Str(_) | syn::Lit::Bool(_) => {}
+                syn::Lit::Int(i) => _ = i.base10_parse::<i32>()?,
+                syn::Lit::Float(f) => _ = f.base10_parse::<f64>()?,
+                _ => {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "only string, integer, float and boolean literals are allowed as \
+                         directive argument values",
+                    ));
+                }
+            }
+            Ok(Self::Scalar(lit))
+        } else {
+            let ident = input.call(syn::Ident::parse_any)?;
+            Ok(if ident == "null" {
+                Self::Null(ident.span())
+            } else {
+                Self::Enum(ident)
+            })
+        }
+    }
+}
+
+impl ToTokens for Value {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        match self {
+            Self::Null(span) => quote_spanned! { *span =>
+                ::juniper::InputValue::null()
+            },
+            Self::Scalar(lit) => {
+                let val = match lit {
+                    syn::Lit::Str(s) => quote! { ::std::string::String::from(#s) },
+                    syn::Lit::Int(i) => {
+                        let i = i.base10_parse::<i32>().unwrap();
+                        quote! { #i }
+                    }
+                    syn::Lit::Float(f) => {
+                        let f = f.base10_parse::<f64>().unwrap();
+                        quote! { #f }
+                    }
+                    lit => quote! { #lit },
+                };
+                quote_spanned! { lit.span() =>
+                    ::juniper::InputValue::scalar(#val)
+                }
+            }
+            Self::Enum(ident) => {
+                let name = ident.unraw().to_string();
+                quote_spanned! { ident.span() =>
+                    ::juniper::InputValue::enum_value(#name)
+                }
+            }
+            Self::List(span, items) => quote_spanned! { *span =>
+                ::juniper::InputValue::list(::std::vec![#( #items ),*])
+            },
+        }
+        .to_tokens(into);
+    }
+}
+
+#[cfg(test)]
+mod parse_test {
+    use quote::quote;
+    use syn::parse_quote;
+
+    use super::Directive;
+
+    #[test]
+    fn without_arguments() {
+        let directive: Directive = parse_quote! { shareable };
+        assert_eq!(
+            quote! { #directive }.to_string(),
+            quote! {
+                .directive(
+                    ::juniper::meta::AppliedDirective::new(::juniper::arcstr::literal!("shareable"))
+                )
+            }
+            .to_string(),
+        );
+    }
+
+    #[test]
+    fn with_arguments() {
+        let directive: Directive = parse_quote! {
+            auth(requires = ADMIN, scopes = ["read", "write"], max = 5, inherit = false, note = null)
+        };
+        assert_eq!(
+            quote! { #directive }.to_string(),
+            quote! {
+                .directive(
+                    ::juniper::meta::AppliedDirective::new(::juniper::arcstr::literal!("auth"))
+                        .argument(
+                            ::juniper::arcstr::literal!("requires"),
+                            ::juniper::InputValue::enum_value("ADMIN")
+                        )
+                        .argument(
+                            ::juniper::arcstr::literal!("scopes"),
+                            ::juniper::InputValue::list(::std::vec![
+                                ::juniper::InputValue::scalar(::std::string::String::from("read")),
+                                ::juniper::InputValue::scalar(::std::string::String::from("write"))
+                            ])
+                        )
+                        .argument(
+                            ::juniper::arcstr::literal!("max"),
+                            ::juniper::InputValue::scalar(5i32)
+                        )
+                        .argument(
+                            ::juniper::arcstr::literal!("inherit"),
+                            ::juniper::InputValue::scalar(false)
+                        )
+                        .argument(
+                            ::juniper::arcstr::literal!("note"),
+                            ::juniper::InputValue::null()
+                        )
+                )
+            }
+            .to_string(),
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_literals() {
+        assert!(syn::parse2::<Directive>(quote! { tag(name = b'x') }).is_err());
+        assert!(syn::parse2::<Directive>(quote! { tag(name = 10000000000) }).is_err());
+    }
+}
@@ -15,12 +15,15 @@ use syn::{
 };
 
 use crate::common::{
-    Description, SpanContainer, default, deprecation, diagnostic, filter_attrs,
+    Description, SpanContainer, default, deprecation, diagnostic, directive, filter_attrs,
     parse::{
         ParseBufferExt as _, TypeExt as _,
-        attr::{OptionExt as _, err},
+        attr::{
+            OptionExt as _,
+            err::{self, AsSpan},
+        },
     },
-    path_eq_single, rename, scalar,
+    path_eq_single, rename, scalar, type_info, validate,
 };
 
 /// Available metadata (arguments) behind `#[graphql]` attribute placed on a
@@ -52,6 +55,12 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/October2021#sec-Deprecation
     pub(crate) deprecated: Option<SpanContainer<deprecation::Directive>>,
 
+    /// [Directives][2] explicitly applied to this [GraphQL argument][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Vec<directive::Directive>,
+
     /// Explicitly specified [default value][2] of this [GraphQL argument][1].
     ///
     /// If [`None`], then this [GraphQL argument][1] is considered as
@@ -84,6 +93,11 @@ pub(crate) struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
     /// [2]: https://spec.graphql.org/October2021#sec-Language.Fields
     pub(crate) executor: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified validation rules of a [GraphQL argument][1] value.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    pub(crate) validate: Option<SpanContainer<validate::Rules>>,
 }
 
 impl Parse for Attr {
@@ -116,6 +130,10 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse()?);
+                }
                 "default" => {
                     let val = input.parse::<default::Value>()?;
                     out.default
@@ -134,6 +152,12 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(span, Some(span), ident))
                         .none_or_else(|_| err::dup_arg(span))?
                 }
+                "validate" => {
+                    let rules = input.parse::<validate::Rules>()?;
+                    out.validate
+                        .replace(SpanContainer::new(ident.span(), None, rules))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -152,9 +176,15 @@ impl Attr {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
             deprecated: try_merge_opt!(deprecated: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
             default: try_merge_opt!(default: self, another),
             context: try_merge_opt!(context: self, another),
             executor: try_merge_opt!(executor: self, another),
+            validate: try_merge_opt!(validate: self, another),
         })
     }
 
@@ -169,8 +199,10 @@ impl Attr {
             if attr.name.is_some()
                 || attr.description.is_some()
                 || attr.deprecated.is_some()
+                || !attr.directives.is_empty()
                 || attr.default.is_some()
                 || attr.executor.is_some()
+                || attr.validate.is_some()
             {
                 return Err(syn::Error::new(
                     context.span(),
@@ -183,8 +215,10 @@ impl Attr {
             if attr.name.is_some()
                 || attr.description.is_some()
                 || attr.deprecated.is_some()
+                || !attr.directives.is_empty()
                 || attr.default.is_some()
                 || attr.context.is_some()
+                || attr.validate.is_some()
             {
                 return Err(syn::Error::new(
                     executor.span(),
@@ -204,13 +238,19 @@ impl Attr {
     /// [`OnField`] argument.
     fn ensure_no_regular_arguments(&self) -> syn::Result<()> {
         if let Some(span) = &self.name {
-            return Err(Self::err_disallowed(&span, "name"));
+            return Err(Self::err_disallowed(span, "name"));
         }
         if let Some(span) = &self.description {
-            return Err(Self::err_disallowed(&span, "description"));
+            return Err(Self::err_disallowed(span, "description"));
         }
         if let Some(span) = &self.default {
-            return Err(Self::err_disallowed(&span, "default"));
+            return Err(Self::err_disallowed(span, "default"));
+        }
+        if let Some(d) = self.directives.first() {
+            return Err(Self::err_disallowed(d.span(), "directive"));
+        }
+        if let Some(span) = &self.validate {
+            return Err(Self::err_disallowed(span.span_ident(), "validate"));
         }
         Ok(())
     }
@@ -218,9 +258,9 @@ impl Attr {
     /// Emits "argument is not allowed" [`syn::Error`] for the given `arg`
     /// pointing to the given `span`.
     #[must_use]
-    fn err_disallowed<S: Spanned>(span: &S, arg: &str) -> syn::Error {
+    fn err_disallowed(span: impl AsSpan, arg: &str) -> syn::Error {
         syn::Error::new(
-            span.span(),
+            span.as_span(),
             format!("attribute argument `#[graphql({arg} = ...)]` is not allowed here",),
         )
     }
@@ -261,6 +301,18 @@ pub(crate) struct OnField {
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
     /// [2]: https://spec.graphql.org/October2021#sec-Deprecation
     pub(crate) deprecated: Option<deprecation::Directive>,
+
+    /// [Directives][2] applied to this [GraphQL field argument][1] in GraphQL
+    /// schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Vec<directive::Directive>,
+
+    /// Validation rules of this [GraphQL field argument][1] value.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    pub(crate) validate: Option<SpanContainer<validate::Rules>>,
 }
 
 /// Possible kinds of Rust method arguments for code generation.
@@ -306,6 +358,23 @@ impl OnMethod {
         }
     }
 
+    /// Checks whether this argument has no validation rules specified, emitting
+    /// an error into the given `scope` otherwise.
+    ///
+    /// Returns `true` if the error has been emitted.
+    pub(crate) fn check_no_validate(&self, scope: diagnostic::Scope) -> bool {
+        let Some(validate) = self.as_regular().and_then(|arg| arg.validate.as_ref()) else {
+            return false;
+        };
+        scope.emit_custom(
+            validate.span_ident(),
+            "cannot have `validate` arguments, as `validate` attribute argument is \
+             supported by `#[graphql_object]` and `#[graphql_subscription]` method \
+             arguments only",
+        );
+        true
+    }
+
     /// Returns generated code statically asserting deprecability for this argument, if it
     /// represents an [`OnField`] one.
     #[must_use]
@@ -356,18 +425,20 @@ impl OnMethod {
 
         let description = &arg.description;
         let deprecated = &arg.deprecated;
+        let directives = &arg.directives;
 
+        let info = type_info::child_tokens();
         let method = if let Some(val) = &arg.default {
             quote_spanned! { val.span() =>
-                .arg_with_default::<#ty>(::juniper::arcstr::literal!(#name), &#val, info)
+                .arg_with_default::<#ty>(::juniper::arcstr::literal!(#name), &#val, #info)
             }
         } else {
             quote! {
-                .arg::<#ty>(::juniper::arcstr::literal!(#name), info)
+                .arg::<#ty>(::juniper::arcstr::literal!(#name), #info)
             }
         };
 
-        Some(quote! { .argument(registry #method #description #deprecated) })
+        Some(quote! { .argument(registry #method #description #deprecated #( #directives )*) })
     }
 
     /// Returns generated code for the [`GraphQLValue::resolve_field`] method,
@@ -386,14 +457,28 @@ impl OnMethod {
                 let (name, ty) = (&arg.name, &arg.ty);
                 let err_text = format!("Missing argument `{name}`: {{}}");
 
+                let validate = arg.validate.as_ref().map(|rules| {
+                    let checks = rules.checks_tokens(&quote! { &v }, scalar);
+                    quote! {
+                        .and_then(|v| {
+                            #checks
+                            ::core::result::Result::Ok(v)
+                        })
+                    }
+                });
+
                 let arg = quote! {
-                    args.get::<#ty>(#name).and_then(|opt| opt.map_or_else(|| {
-                        <#ty as ::juniper::FromInputValue<#scalar>>::from_implicit_null()
-                            .map_err(|e| {
-                                ::juniper::IntoFieldError::<#scalar>::into_field_error(e)
-                                    .map_message(|m| format!(#err_text, m))
-                            })
-                    }, ::core::result::Result::Ok))
+                    ::juniper::macros::helper::validate::at_input_path(#name, || {
+                        args.get::<#ty>(#name)
+                            .and_then(|opt| opt.map_or_else(|| {
+                                <#ty as ::juniper::FromInputValue<#scalar>>::from_implicit_null()
+                                    .map_err(|e| {
+                                        ::juniper::IntoFieldError::<#scalar>::into_field_error(e)
+                                            .map_message(|m| format!(#err_text, m))
+                                    })
+                            }, ::core::result::Result::Ok))
+                            #validate
+                    })
                 };
                 if for_async {
                     quote! {
@@ -494,6 +579,8 @@ impl OnMethod {
             description: attr.description.map(SpanContainer::into_inner),
             default: attr.default.map(SpanContainer::into_inner),
             deprecated: attr.deprecated.map(SpanContainer::into_inner),
+            directives: attr.directives,
+            validate: attr.validate,
         })))
     }
 }
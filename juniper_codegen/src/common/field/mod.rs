@@ -15,12 +15,12 @@ use syn::{
 };
 
 use crate::common::{
-    Description, SpanContainer, deprecation, filter_attrs,
+    Description, SpanContainer, deprecation, diagnostic, directive, filter_attrs, guard,
     parse::{
         ParseBufferExt as _,
         attr::{OptionExt as _, err},
     },
-    scalar,
+    scalar, type_info, visibility,
 };
 
 pub(crate) use self::arg::OnMethod as MethodArgument;
@@ -56,12 +56,38 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/October2021#sec-Deprecation
     pub(crate) deprecated: Option<SpanContainer<deprecation::Directive>>,
 
+    /// [Directives][2] explicitly applied to this [GraphQL field][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Vec<directive::Directive>,
+
+    /// Explicitly specified [`visibility::Predicate`] of this
+    /// [GraphQL field][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) visible: Option<SpanContainer<visibility::Predicate>>,
+
+    /// Explicitly specified [`guard::Guard`] of this [GraphQL field][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) guard: Option<SpanContainer<guard::Guard>>,
+
     /// Explicitly specified marker indicating that this method (or struct
     /// field) should be omitted by code generation and not considered as the
     /// [GraphQL field][1] definition.
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
     pub(crate) ignore: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified marker indicating that the fields of this struct
+    /// field's [GraphQL object][0] should be inlined into the parent one,
+    /// instead of this struct field being considered as a [GraphQL field][1]
+    /// definition.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Objects
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) flatten: Option<SpanContainer<syn::Ident>>,
 }
 
 impl Parse for Attr {
@@ -94,10 +120,36 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse()?);
+                }
+                "visible" => {
+                    input.parse::<token::Eq>()?;
+                    let predicate = input.parse::<visibility::Predicate>()?;
+                    out.visible
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(predicate.span()),
+                            predicate,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "guard" => {
+                    input.parse::<token::Eq>()?;
+                    let guard = input.parse::<guard::Guard>()?;
+                    out.guard
+                        .replace(SpanContainer::new(ident.span(), Some(guard.span()), guard))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ignore" | "skip" => out
                     .ignore
                     .replace(SpanContainer::new(ident.span(), None, ident.clone()))
                     .none_or_else(|_| err::dup_arg(&ident))?,
+                "flatten" => out
+                    .flatten
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -116,7 +168,15 @@ impl Attr {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
             deprecated: try_merge_opt!(deprecated: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            visible: try_merge_opt!(visible: self, another),
+            guard: try_merge_opt!(guard: self, another),
             ignore: try_merge_opt!(ignore: self, another),
+            flatten: try_merge_opt!(flatten: self, another),
         })
     }
 
@@ -129,11 +189,25 @@ impl Attr {
             .map(|attr| attr.parse_args())
             .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
 
-        if let Some(ignore) = &attr.ignore {
-            if attr.name.is_some() || attr.description.is_some() || attr.deprecated.is_some() {
+        let exclusive_arg = attr
+            .ignore
+            .as_ref()
+            .map(|a| ("ignore", a))
+            .or_else(|| attr.flatten.as_ref().map(|a| ("flatten", a)));
+        if let Some((name, arg)) = exclusive_arg {
+            if attr.name.is_some()
+                || attr.description.is_some()
+                || attr.deprecated.is_some()
+                || !attr.directives.is_empty()
+                || attr.visible.is_some()
+                || attr.guard.is_some()
+                || (attr.ignore.is_some() && attr.flatten.is_some())
+            {
                 return Err(syn::Error::new(
-                    ignore.span(),
-                    "`ignore` attribute argument is not composable with any other arguments",
+                    arg.span(),
+                    format!(
+                        "`{name}` attribute argument is not composable with any other arguments"
+                    ),
                 ));
             }
         }
@@ -148,6 +222,22 @@ impl Attr {
 
         Ok(attr)
     }
+
+    /// Emits an error about the `flatten` argument of this [`Attr`] being
+    /// placed where it's not supported, if any.
+    ///
+    /// Returns `true` if the error has been emitted.
+    pub(crate) fn check_no_flatten(&self, scope: diagnostic::Scope) -> bool {
+        let Some(flatten) = &self.flatten else {
+            return false;
+        };
+        scope.emit_custom(
+            flatten.span_ident(),
+            "cannot have `flatten` fields, as `flatten` attribute argument is \
+             supported by `#[derive(GraphQLObject)]` struct fields only",
+        );
+        true
+    }
 }
 
 /// Representation of a [GraphQL field][1] for code generation.
@@ -178,6 +268,23 @@ pub(crate) struct Definition {
     /// [2]: https://spec.graphql.org/October2021#sec-Deprecation
     pub(crate) deprecated: Option<deprecation::Directive>,
 
+    /// [Directives][2] applied to this [GraphQL field][1] in GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Vec<directive::Directive>,
+
+    /// [`visibility::Predicate`] of this [GraphQL field][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) visible: Option<visibility::Predicate>,
+
+    /// [`guard::Guard`] of this [GraphQL field][1], checked before resolving
+    /// it.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) guard: Option<guard::Guard>,
+
     /// Ident of the Rust method (or struct field) representing this
     /// [GraphQL field][1].
     ///
@@ -292,18 +399,23 @@ impl Definition {
 
         let description = &self.description;
         let deprecated = &self.deprecated;
+        let directives = &self.directives;
+        let visible = &self.visible;
 
         let args = self
             .arguments
             .iter()
             .flat_map(|args| args.iter().filter_map(MethodArgument::method_meta_tokens));
+        let info = type_info::child_tokens();
 
         quote! {
             registry
-                .field_convert::<#ty, _, Self::Context>(::juniper::arcstr::literal!(#name), info)
+                .field_convert::<#ty, _, Self::Context>(::juniper::arcstr::literal!(#name), #info)
                 #( #args )*
                 #description
                 #deprecated
+                #( #directives )*
+                #visible
         }
     }
 
@@ -311,12 +423,16 @@ impl Definition {
     /// [`GraphQLSubscriptionValue::resolve_field_into_stream`][0] method, which
     /// resolves this [GraphQL field][1] as [subscription][2].
     ///
+    /// The provided `type_guard` is checked before the own [`guard::Guard`] of
+    /// this [GraphQL field][1], if any.
+    ///
     /// [0]: juniper::GraphQLSubscriptionValue::resolve_field_into_stream
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
     /// [2]: https://spec.graphql.org/October2021#sec-Subscription
     #[must_use]
     pub(crate) fn method_resolve_field_into_stream_tokens(
         &self,
+        type_guard: Option<&guard::Guard>,
         scalar: &scalar::Type,
     ) -> TokenStream {
         let (name, mut ty, ident) = (&self.name, self.ty.clone(), &self.ident);
@@ -342,9 +458,12 @@ impl Definition {
             fut = quote! { ::juniper::futures::future::ready(#fut) };
         }
 
+        let guards = guard::checks_tokens(type_guard.into_iter().chain(&self.guard), scalar, false);
+
         quote! {
             #name => {
                 ::juniper::futures::FutureExt::boxed(async move {
+                    #guards
                     let res: #ty = #fut.await;
                     let res = ::juniper::IntoFieldResult::<_, #scalar>::into_result(res)?;
                     let executor = executor.as_owned_executor();
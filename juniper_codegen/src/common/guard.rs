@@ -0,0 +1,79 @@
+//! Common functions, definitions and extensions for parsing and code generation
+//! of field guards specified via `#[graphql(guard = ...)]` attribute argument.
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned as _,
+};
+
+use crate::common::scalar;
+
+/// Expression of a [`Guard`] specified via `#[graphql(guard = ...)]` attribute.
+///
+/// The expression is evaluated each time the guarded field is resolved, and
+/// checked against the request context before calling the field resolver.
+///
+/// [`Guard`]: juniper::Guard
+#[derive(Debug)]
+pub(crate) struct Guard(syn::Expr);
+
+impl Parse for Guard {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        input.parse::<syn::Expr>().map(Self)
+    }
+}
+
+impl Guard {
+    /// Returns the [`Span`] of this [`Guard`].
+    ///
+    /// [`Span`]: proc_macro2::Span
+    pub(crate) fn span(&self) -> proc_macro2::Span {
+        self.0.span()
+    }
+
+    /// Returns generated code checking this [`Guard`] against the provided
+    /// `context` expression, and evaluating to a [`FieldResult`].
+    ///
+    /// [`FieldResult`]: juniper::FieldResult
+    #[must_use]
+    pub(crate) fn check_tokens(&self, scalar: &scalar::Type, context: &TokenStream) -> TokenStream {
+        let expr = &self.0;
+        quote_spanned! { expr.span() =>
+            ::juniper::Guard::<_, #scalar>::check(&(#expr), #context)
+        }
+    }
+}
+
+/// Returns generated code checking the provided [`Guard`]s in order against
+/// the `executor.context()`, and returning the [`FieldError`] of the first
+/// denying one from the surrounding function.
+///
+/// If `for_async` is `true`, then the [`FieldError`] is returned as a boxed
+/// [`Future`], otherwise via `?` operator.
+///
+/// [`FieldError`]: juniper::FieldError
+/// [`Future`]: std::future::Future
+#[must_use]
+pub(crate) fn checks_tokens<'a>(
+    guards: impl IntoIterator<Item = &'a Guard>,
+    scalar: &scalar::Type,
+    for_async: bool,
+) -> TokenStream {
+    guards
+        .into_iter()
+        .map(|guard| {
+            let check = guard.check_tokens(scalar, &quote! { executor.context() });
+            if for_async {
+                quote! {
+                    if let ::core::result::Result::Err(e) = #check {
+                        return ::std::boxed::Box::pin(::juniper::futures::future::err(e));
+                    }
+                }
+            } else {
+                quote! { #check?; }
+            }
+        })
+        .collect()
+}
@@ -4,12 +4,18 @@ pub(crate) mod default;
 pub(crate) mod deprecation;
 mod description;
 pub(crate) mod diagnostic;
+pub(crate) mod directive;
 pub(crate) mod field;
 pub(crate) mod generate;
+pub(crate) mod guard;
+pub(crate) mod name;
 pub(crate) mod parse;
 pub(crate) mod rename;
 pub(crate) mod scalar;
 mod span_container;
+pub(crate) mod type_info;
+pub(crate) mod validate;
+pub(crate) mod visibility;
 
 use std::slice;
 
@@ -0,0 +1,172 @@
+//! Common functions, definitions and extensions for parsing and code generation
+//! of GraphQL type names templated with type parameters of their Rust type
+//! (like `#[graphql(name = "{T}Connection")]`).
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::common::scalar;
+
+/// Name of a GraphQL type, possibly containing `{Param}` placeholders, which
+/// are substituted with the GraphQL names of the corresponding type parameters
+/// of its Rust type.
+///
+/// Placeholders are resolved in runtime via [`GraphQLType::name`] of type
+/// parameters, so `Connection<User>` and `Connection<Post>` are named
+/// differently in GraphQL schema, as well as the nested instantiations (like
+/// `Connection<Connection<User>>`). Type parameters used in placeholders are
+/// asserted to be neither lists nor `null`able types (via their
+/// [`WrappedType::VALUE`]), as these have no GraphQL name.
+///
+/// As [`BaseType::NAME`] of a generic type cannot be computed in const
+/// context, it contains the template itself, so compile-time reflection doesn't
+/// distinguish instantiations of the same generic type.
+///
+/// [`BaseType::NAME`]: juniper::macros::reflect::BaseType::NAME
+/// [`GraphQLType::name`]: juniper::GraphQLType::name
+/// [`WrappedType::VALUE`]: juniper::macros::reflect::WrappedType::VALUE
+#[derive(Clone, Debug)]
+pub(crate) struct Template {
+    /// Raw template, as specified by user.
+    raw: String,
+
+    /// [`format!`] string with every placeholder replaced by `{}`.
+    format: String,
+
+    /// Type parameters used in placeholders, in order of their appearance.
+    params: Vec<syn::Ident>,
+}
+
+impl Template {
+    /// Parses the provided `name`, resolving its placeholders against type
+    /// parameters of the provided `generics`.
+    ///
+    /// # Errors
+    ///
+    /// If a placeholder is not closed or refers to an unknown type parameter.
+    pub(crate) fn parse(name: String, generics: &syn::Generics) -> Result<Self, String> {
+        let mut format = String::with_capacity(name.len());
+        let mut params = vec![];
+
+        let mut rest = name.as_str();
+        while let Some(start) = rest.find(['{', '}']) {
+            format.push_str(&rest[..start]);
+            if rest[start..].starts_with('}') {
+                return Err(format!("name template `{name}` has unexpected `}}`"));
+            }
+            let Some(end) = rest[start..].find('}').map(|end| start + end) else {
+                return Err(format!("name template `{name}` has unclosed `{{`"));
+            };
+            let param = &rest[start + 1..end];
+            let Some(param) = generics
+                .type_params()
+                .map(|p| &p.ident)
+                .find(|ident| *ident == param)
+            else {
+                return Err(format!(
+                    "name template `{name}` refers to unknown type parameter `{param}`",
+                ));
+            };
+            format.push_str("{}");
+            params.push(param.clone());
+            rest = &rest[end + 1..];
+        }
+        format.push_str(rest);
+
+        Ok(Self {
+            raw: name,
+            format,
+            params,
+        })
+    }
+
+    /// Returns the raw template of this name, as specified by user.
+    ///
+    /// Equals to the name itself, if it contains no placeholders.
+    #[must_use]
+    pub(crate) fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns [`syn::WherePredicate`]s required for resolving placeholders of
+    /// this [`Template`].
+    #[must_use]
+    pub(crate) fn bounds(&self, scalar: &scalar::Type) -> Vec<syn::WherePredicate> {
+        let mut params: Vec<&syn::Ident> = vec![];
+        for p in &self.params {
+            if !params.contains(&p) {
+                params.push(p);
+            }
+        }
+        params
+            .into_iter()
+            .flat_map(|p| -> [syn::WherePredicate; 2] {
+                [
+                    parse_quote! { #p: ::juniper::GraphQLType<#scalar, TypeInfo = ()> },
+                    parse_quote! { #p: ::juniper::macros::reflect::WrappedType<#scalar> },
+                ]
+            })
+            .collect()
+    }
+
+    /// Returns generated code asserting that type parameters used in
+    /// placeholders of this [`Template`] are neither lists nor `null`able.
+    #[must_use]
+    fn assertions_tokens(&self, scalar: &scalar::Type) -> TokenStream {
+        let raw = self.raw.replace('{', "{{").replace('}', "}}");
+        let assertions = self.params.iter().map(|p| {
+            let msg = format!(
+                "type parameter `{p}` of name template `{raw}` must not be a list or `null`able \
+                 type",
+            );
+            quote! {
+                const {
+                    ::core::assert!(
+                        <#p as ::juniper::macros::reflect::WrappedType<#scalar>>::VALUE == 1,
+                        #msg,
+                    );
+                };
+            }
+        });
+        quote! { #( #assertions )* }
+    }
+
+    /// Returns generated code of an [`ArcStr`] expression evaluating this name.
+    ///
+    /// [`ArcStr`]: juniper::ArcStr
+    #[must_use]
+    pub(crate) fn arcstr_tokens(&self, scalar: &scalar::Type) -> TokenStream {
+        let (raw, format) = (&self.raw, &self.format);
+        if self.params.is_empty() {
+            return quote! { ::juniper::arcstr::literal!(#raw) };
+        }
+        let params = &self.params;
+        let assertions = self.assertions_tokens(scalar);
+        quote! {{
+            #assertions
+            ::juniper::arcstr::format!(
+                #format,
+                #( ::juniper::macros::helper::type_param_name::<#params, #scalar>() ),*
+            )
+        }}
+    }
+
+    /// Returns generated code of a [`String`] expression evaluating this name.
+    #[must_use]
+    pub(crate) fn string_tokens(&self, scalar: &scalar::Type) -> TokenStream {
+        let (raw, format) = (&self.raw, &self.format);
+        if self.params.is_empty() {
+            return quote! { #raw.into() };
+        }
+        let params = &self.params;
+        let assertions = self.assertions_tokens(scalar);
+        quote! {{
+            #assertions
+            ::std::format!(
+                #format,
+                #( ::juniper::macros::helper::type_param_name::<#params, #scalar>() ),*
+            )
+        }}
+    }
+}
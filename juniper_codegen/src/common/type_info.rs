@@ -0,0 +1,144 @@
+//! Common functions, definitions and extensions for parsing and code generation
+//! of custom [`TypeInfo`] specified via `#[graphql(type_info = ...)]` attribute
+//! argument.
+//!
+//! [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned as _,
+};
+
+/// Rust type of custom [`TypeInfo`] specified via `#[graphql(type_info = ...)]`
+/// attribute.
+///
+/// The type should implement [`DynamicTypeInfo`], which is used for computing
+/// the name, description and fields of the GraphQL type at runtime.
+///
+/// [`DynamicTypeInfo`]: juniper::DynamicTypeInfo
+/// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+#[derive(Debug)]
+pub(crate) struct TypeInfo(syn::Type);
+
+impl Parse for TypeInfo {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        input.parse::<syn::Type>().map(Self)
+    }
+}
+
+impl ToTokens for TypeInfo {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        self.0.to_tokens(into);
+    }
+}
+
+impl From<syn::Type> for TypeInfo {
+    fn from(ty: syn::Type) -> Self {
+        Self(ty)
+    }
+}
+
+impl TypeInfo {
+    /// Returns the [`Span`] of this [`TypeInfo`].
+    ///
+    /// [`Span`]: proc_macro2::Span
+    pub(crate) fn span(&self) -> proc_macro2::Span {
+        self.0.span()
+    }
+}
+
+/// Returns Rust type of [`TypeInfo`] to generate code with: the provided
+/// custom one, or unit type `()` otherwise.
+///
+/// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+#[must_use]
+pub(crate) fn ty_tokens(type_info: Option<&TypeInfo>) -> TokenStream {
+    type_info.map_or_else(|| quote! { () }, ToTokens::into_token_stream)
+}
+
+/// Returns generated code of the [`TypeInfo`] to resolve the GraphQL types of
+/// fields and arguments with, provided from the `info` binding of their parent
+/// GraphQL type.
+///
+/// Custom [`TypeInfo`] is passed as is to the GraphQL types having the same
+/// [`TypeInfo`], while the ones having the unit type `()` as their
+/// [`TypeInfo`] are resolved with it.
+///
+/// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+#[must_use]
+pub(crate) fn child_tokens() -> TokenStream {
+    quote! { ::juniper::macros::helper::ChildTypeInfo::child_type_info(info) }
+}
+
+/// Returns generated code of the [`TypeInfo`] to resolve the flattened `ty`
+/// GraphQL object with, provided from the `info` binding of its parent GraphQL
+/// object, having the `type_info` as its [`TypeInfo`].
+///
+/// Same as [`child_tokens()`], but bound on the flattened `ty` via
+/// `FlattenedTypeInfo` trait, so may be used in `where` clauses of generic
+/// parent GraphQL objects.
+///
+/// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+#[must_use]
+pub(crate) fn flattened_tokens(
+    ty: &syn::Type,
+    scalar: impl ToTokens,
+    type_info: Option<&TypeInfo>,
+) -> TokenStream {
+    let type_info = ty_tokens(type_info);
+    quote! {
+        <#ty as ::juniper::macros::helper::FlattenedTypeInfo<#scalar, #type_info>>
+            ::flattened_type_info(info)
+    }
+}
+
+/// Returns generated code of the [`GraphQLType::name`] method, returning the
+/// provided static `name`, unless overridden by the custom [`TypeInfo`].
+///
+/// [`GraphQLType::name`]: juniper::GraphQLType::name
+#[must_use]
+pub(crate) fn name_method_tokens(type_info: Option<&TypeInfo>, name: &TokenStream) -> TokenStream {
+    let Some(ti) = type_info else {
+        return quote! {
+            fn name(_: &Self::TypeInfo) -> ::core::option::Option<::juniper::ArcStr> {
+                ::core::option::Option::Some(#name)
+            }
+        };
+    };
+    quote! {
+        fn name(info: &Self::TypeInfo) -> ::core::option::Option<::juniper::ArcStr> {
+            ::core::option::Option::Some(
+                <#ti as ::juniper::DynamicTypeInfo>::name(info).unwrap_or_else(|| #name),
+            )
+        }
+    }
+}
+
+/// Returns generated code overriding the description of the `meta` builder
+/// binding with the one of the custom [`TypeInfo`], if any.
+#[must_use]
+pub(crate) fn description_tokens(type_info: Option<&TypeInfo>) -> Option<TokenStream> {
+    type_info.map(|ti| {
+        quote! {
+            let meta = match <#ti as ::juniper::DynamicTypeInfo>::description(info) {
+                ::core::option::Option::Some(desc) => meta.description(desc),
+                ::core::option::Option::None => meta,
+            };
+        }
+    })
+}
+
+/// Returns generated code retaining in the `fields` binding only the fields
+/// registered by the custom [`TypeInfo`], if any.
+#[must_use]
+pub(crate) fn fields_filter_tokens(type_info: Option<&TypeInfo>) -> Option<TokenStream> {
+    type_info.map(|ti| {
+        quote! {
+            let fields: ::std::vec::Vec<_> = ::core::iter::IntoIterator::into_iter(fields)
+                .filter(|f| <#ti as ::juniper::DynamicTypeInfo>::has_field(info, &f.name))
+                .collect();
+        }
+    })
+}
@@ -0,0 +1,166 @@
+//! Common functions, definitions and extensions for parsing and code generation
+//! of declarative input validation via `#[graphql(validate(...))]` attribute
+//! argument.
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::common::{
+    parse::{
+        ParseBufferExt as _,
+        attr::{OptionExt as _, err},
+    },
+    scalar,
+};
+
+/// Validation rules of a [GraphQL input value][0] specified via
+/// `#[graphql(validate(...))]` attribute argument.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Input-Values
+#[derive(Debug, Default)]
+pub(crate) struct Rules {
+    /// Inclusive lower bound of the value.
+    min: Option<syn::Expr>,
+
+    /// Inclusive upper bound of the value.
+    max: Option<syn::Expr>,
+
+    /// Minimal length of the value.
+    min_length: Option<syn::LitInt>,
+
+    /// Maximal length of the value.
+    max_length: Option<syn::LitInt>,
+
+    /// Regular expression the value should match.
+    regex: Option<syn::LitStr>,
+}
+
+impl Parse for Rules {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let content;
+        let paren = syn::parenthesized!(content in input);
+
+        let mut out = Self::default();
+        while !content.is_empty() {
+            let ident = content.parse_any_ident()?;
+            content.parse::<token::Eq>()?;
+            match ident.to_string().as_str() {
+                "min" => out
+                    .min
+                    .replace(content.parse()?)
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                "max" => out
+                    .max
+                    .replace(content.parse()?)
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                "min_length" => out
+                    .min_length
+                    .replace(content.parse()?)
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                "max_length" => out
+                    .max_length
+                    .replace(content.parse()?)
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                "regex" => {
+                    let pattern = content.parse::<syn::LitStr>()?;
+                    #[cfg(feature = "regex")]
+                    if let Err(e) = regex::Regex::new(&pattern.value()) {
+                        // Syntax errors span multiple lines, with the reason at the last one.
+                        let e = e.to_string();
+                        let reason = e.lines().last().unwrap_or_default();
+                        return Err(syn::Error::new(
+                            pattern.span(),
+                            format!(
+                                "invalid `regex` pattern: {}",
+                                reason.trim_start_matches("error: "),
+                            ),
+                        ));
+                    }
+                    out.regex
+                        .replace(pattern)
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            content.try_parse::<token::Comma>()?;
+        }
+
+        if out.is_empty() {
+            return Err(syn::Error::new(
+                paren.span.join(),
+                "`validate` attribute argument requires at least one validation rule",
+            ));
+        }
+        Ok(out)
+    }
+}
+
+impl Rules {
+    /// Indicates whether these [`Rules`] contain no validation rules at all.
+    #[must_use]
+    fn is_empty(&self) -> bool {
+        self.min.is_none()
+            && self.max.is_none()
+            && self.min_length.is_none()
+            && self.max_length.is_none()
+            && self.regex.is_none()
+    }
+
+    /// Returns generated code validating the provided `value` reference
+    /// against these [`Rules`], and returning a [`FieldError`] pointing to the
+    /// current input path via `?` operator on the first violation.
+    ///
+    /// Should be placed inside [`at_input_path()`] call of the validated value.
+    ///
+    /// [`at_input_path()`]: juniper::macros::helper::validate::at_input_path
+    /// [`FieldError`]: juniper::FieldError
+    #[must_use]
+    pub(crate) fn checks_tokens(&self, value: &TokenStream, scalar: &scalar::Type) -> TokenStream {
+        let helper = quote! { ::juniper::macros::helper::validate };
+
+        let min = self.min.as_ref().map(|expr| {
+            quote_spanned! { expr.span() =>
+                #helper::min::<#scalar, _, _>(#value, #expr)?;
+            }
+        });
+        let max = self.max.as_ref().map(|expr| {
+            quote_spanned! { expr.span() =>
+                #helper::max::<#scalar, _, _>(#value, #expr)?;
+            }
+        });
+        let min_length = self.min_length.as_ref().map(|len| {
+            quote_spanned! { len.span() =>
+                #helper::min_length::<#scalar, _>(#value, #len)?;
+            }
+        });
+        let max_length = self.max_length.as_ref().map(|len| {
+            quote_spanned! { len.span() =>
+                #helper::max_length::<#scalar, _>(#value, #len)?;
+            }
+        });
+        let regex = self.regex.as_ref().map(|pattern| {
+            quote_spanned! { pattern.span() =>
+                {
+                    static REGEX: ::std::sync::OnceLock<#helper::Regex> =
+                        ::std::sync::OnceLock::new();
+                    #helper::regex::<#scalar, _>(#value, #pattern, &REGEX)?;
+                }
+            }
+        });
+
+        quote! {
+            #min
+            #max
+            #min_length
+            #max_length
+            #regex
+        }
+    }
+}
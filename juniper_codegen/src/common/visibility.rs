@@ -0,0 +1,45 @@
+//! Common functions, definitions and extensions for parsing and code generation
+//! of per-request visibility predicates of schema elements.
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned as _,
+};
+
+/// Visibility predicate of a schema element specified via
+/// `#[graphql(visible = path::to::fn)]` attribute.
+///
+/// The referenced function is called with the request context and decides
+/// whether the schema element is visible to that request. Contexts of other
+/// types than the `Context` of the GraphQL type are rejected.
+#[derive(Debug)]
+pub(crate) struct Predicate(syn::ExprPath);
+
+impl Parse for Predicate {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        input.parse::<syn::ExprPath>().map(Self)
+    }
+}
+
+impl Predicate {
+    /// Returns the [`Span`] of this [`Predicate`].
+    ///
+    /// [`Span`]: proc_macro2::Span
+    pub(crate) fn span(&self) -> proc_macro2::Span {
+        self.0.span()
+    }
+}
+
+impl ToTokens for Predicate {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        let path = &self.0;
+        quote_spanned! { self.span() =>
+            .visible(|context: &dyn ::std::any::Any| {
+                context.downcast_ref::<Self::Context>().map(#path)
+            })
+        }
+        .to_tokens(into);
+    }
+}
@@ -83,7 +83,9 @@ pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         generics: ast.generics,
         name,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
         context,
+        type_info: attr.type_info.map(SpanContainer::into_inner),
         scalar,
         values,
         has_ignored_variants,
@@ -120,6 +122,7 @@ fn parse_value(v: &syn::Variant, renaming: rename::Policy) -> Option<ValueDefini
         ident: v.ident.clone(),
         name,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
         deprecated: attr.deprecated.map(SpanContainer::into_inner),
     })
 }
@@ -15,12 +15,13 @@ use syn::{
 };
 
 use crate::common::{
-    Description, SpanContainer, deprecation, filter_attrs,
+    Description, SpanContainer, deprecation, directive, filter_attrs,
     parse::{
         ParseBufferExt as _,
         attr::{OptionExt as _, err},
     },
     rename, scalar,
+    type_info::{self, TypeInfo},
 };
 
 /// Available arguments behind `#[graphql]` attribute placed on a Rust enum
@@ -45,6 +46,12 @@ struct ContainerAttr {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<SpanContainer<Description>>,
 
+    /// Explicitly specified [directives][2] applied to this [GraphQL enum][0].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Enums
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
     /// Explicitly specified type of [`Context`] to use for resolving this
     /// [GraphQL enum][0] type with.
     ///
@@ -54,6 +61,15 @@ struct ContainerAttr {
     /// [0]: https://spec.graphql.org/October2021#sec-Enums
     context: Option<SpanContainer<syn::Type>>,
 
+    /// Explicitly specified type of [`TypeInfo`] to use for resolving this
+    /// [GraphQL enum][0] type with.
+    ///
+    /// If [`None`], then unit type `()` is assumed as a type of [`TypeInfo`].
+    ///
+    /// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+    /// [0]: https://spec.graphql.org/October2021#sec-Enums
+    type_info: Option<SpanContainer<TypeInfo>>,
+
     /// Explicitly specified type (or type parameter with its bounds) of
     /// [`ScalarValue`] to resolve this [GraphQL enum][0] type with.
     ///
@@ -104,6 +120,10 @@ impl Parse for ContainerAttr {
                         .replace(SpanContainer::new(ident.span(), Some(desc.span()), desc))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse()?);
+                }
                 "ctx" | "context" | "Context" => {
                     input.parse::<token::Eq>()?;
                     let ctx = input.parse::<syn::Type>()?;
@@ -111,6 +131,13 @@ impl Parse for ContainerAttr {
                         .replace(SpanContainer::new(ident.span(), Some(ctx.span()), ctx))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "type_info" => {
+                    input.parse::<token::Eq>()?;
+                    let ti = input.parse::<TypeInfo>()?;
+                    out.type_info
+                        .replace(SpanContainer::new(ident.span(), Some(ti.span()), ti))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "scalar" | "Scalar" | "ScalarValue" => {
                     input.parse::<token::Eq>()?;
                     let scl = input.parse::<scalar::AttrValue>()?;
@@ -149,7 +176,13 @@ impl ContainerAttr {
         Ok(Self {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
             context: try_merge_opt!(context: self, another),
+            type_info: try_merge_opt!(type_info: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             rename_values: try_merge_opt!(rename_values: self, another),
             is_internal: self.is_internal || another.is_internal,
@@ -194,6 +227,13 @@ struct VariantAttr {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<SpanContainer<Description>>,
 
+    /// Explicitly specified [directives][2] applied to this [GraphQL enum
+    /// value][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Enum-Value
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
     /// Explicitly specified [deprecation][2] of this [GraphQL enum value][1].
     ///
     /// If [`None`], then Rust `#[deprecated]` attribute will be used as the
@@ -236,6 +276,10 @@ impl Parse for VariantAttr {
                         .replace(SpanContainer::new(ident.span(), Some(desc.span()), desc))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse()?);
+                }
                 "deprecated" => {
                     let directive = input.parse::<deprecation::Directive>()?;
                     out.deprecated
@@ -267,6 +311,11 @@ impl VariantAttr {
         Ok(Self {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
             deprecated: try_merge_opt!(deprecated: self, another),
             ignore: try_merge_opt!(ignore: self, another),
         })
@@ -314,6 +363,13 @@ struct ValueDefinition {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<Description>,
 
+    /// [Directives][2] applied to this [GraphQL enum value][1] in GraphQL
+    /// schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Enum-Value
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
     /// [Deprecation][2] of this [GraphQL enum value][1] to put into GraphQL
     /// schema.
     ///
@@ -348,6 +404,12 @@ struct Definition {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<Description>,
 
+    /// [Directives][2] applied to this [GraphQL enum][0] in GraphQL schema.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Enums
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
     /// Rust type of [`Context`] to generate [`GraphQLType`] implementation with
     /// for this [GraphQL enum][0].
     ///
@@ -356,6 +418,16 @@ struct Definition {
     /// [0]: https://spec.graphql.org/October2021#sec-Enums
     context: syn::Type,
 
+    /// Custom Rust type of [`TypeInfo`] to generate [`GraphQLType`]
+    /// implementation with for this [GraphQL enum][0].
+    ///
+    /// If [`None`], then unit type `()` is used.
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+    /// [0]: https://spec.graphql.org/October2021#sec-Enums
+    type_info: Option<TypeInfo>,
+
     /// [`ScalarValue`] parametrization to generate [`GraphQLType`]
     /// implementation with for this [GraphQL enum][0].
     ///
@@ -431,28 +503,35 @@ impl Definition {
 
         let name = &self.name;
         let description = &self.description;
+        let directives = &self.directives;
 
         let variants_meta = self.values.iter().map(|v| {
             let v_name = &v.name;
             let v_description = &v.description;
             let v_deprecation = &v.deprecated;
+            let v_directives = &v.directives;
 
             quote! {
                 ::juniper::meta::EnumValue::new(::juniper::arcstr::literal!(#v_name))
                     #v_description
                     #v_deprecation
+                    #( #v_directives )*
             }
         });
 
+        let name_method = type_info::name_method_tokens(
+            self.type_info.as_ref(),
+            &quote! { ::juniper::arcstr::literal!(#name) },
+        );
+        let dyn_description = type_info::description_tokens(self.type_info.as_ref());
+
         quote! {
             #[automatically_derived]
             impl #impl_generics ::juniper::GraphQLType<#scalar>
                 for #ident #ty_generics
                 #where_clause
             {
-                fn name(_ : &Self::TypeInfo) -> ::core::option::Option<::juniper::ArcStr> {
-                    ::core::option::Option::Some(::juniper::arcstr::literal!(#name))
-                }
+                #name_method
 
                 fn meta(
                     info: &Self::TypeInfo,
@@ -460,9 +539,11 @@ impl Definition {
                 ) -> ::juniper::meta::MetaType<#scalar> {
                     let variants = [#( #variants_meta ),*];
 
-                    registry.build_enum_type::<#ident #ty_generics>(info, &variants)
+                    let meta = registry.build_enum_type::<#ident #ty_generics>(info, &variants)
                         #description
-                        .into_meta()
+                        #( #directives )*;
+                    #dyn_description
+                    meta.into_meta()
                 }
             }
         }
@@ -477,6 +558,7 @@ impl Definition {
         let ident = &self.ident;
         let scalar = &self.scalar;
         let context = &self.context;
+        let type_info = type_info::ty_tokens(self.type_info.as_ref());
 
         let generics = self.impl_generics(false);
         let (impl_generics, _, where_clause) = generics.split_for_impl();
@@ -507,7 +589,7 @@ impl Definition {
                 #where_clause
             {
                 type Context = #context;
-                type TypeInfo = ();
+                type TypeInfo = #type_info;
 
                 fn type_name(
                     &self,
@@ -518,7 +600,7 @@ impl Definition {
 
                 fn resolve(
                     &self,
-                    _: &(),
+                    _: &Self::TypeInfo,
                     _: ::core::option::Option<&[::juniper::Selection<#scalar>]>,
                     _: &::juniper::Executor<Self::Context, #scalar>,
                 ) -> ::juniper::ExecutionResult<#scalar> {
@@ -51,8 +51,9 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         return Err(ERR.custom_error(fields_span, "expected at least 1 non-ignored field"));
     }
 
-    let unique_fields = fields.iter().map(|v| &v.name).collect::<HashSet<_>>();
-    if unique_fields.len() != fields.len() {
+    let named_fields = fields.iter().filter(|f| !f.flatten).collect::<Vec<_>>();
+    let unique_fields = named_fields.iter().map(|v| &v.name).collect::<HashSet<_>>();
+    if unique_fields.len() != named_fields.len() {
         return Err(ERR.custom_error(fields_span, "expected all fields to have unique names"));
     }
 
@@ -84,7 +85,9 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         generics: ast.generics,
         name,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
         context,
+        type_info: attr.type_info.map(SpanContainer::into_inner),
         scalar,
         fields,
         is_one_of: matches!(ast.data, syn::Data::Enum(_)),
@@ -124,8 +127,11 @@ fn parse_struct_field(
         default: field_attr.default.map(SpanContainer::into_inner),
         name,
         description: field_attr.description.map(SpanContainer::into_inner),
+        directives: field_attr.directives,
         deprecated: field_attr.deprecated.map(SpanContainer::into_inner),
         ignored: field_attr.ignore.is_some(),
+        flatten: field_attr.flatten.is_some(),
+        validate: field_attr.validate.map(SpanContainer::into_inner),
     })
 }
 
@@ -162,6 +168,20 @@ fn parse_enum_variant(
         );
     }
 
+    if let Some(flatten) = &field_attr.flatten {
+        ERR.emit_custom(
+            flatten.span_ident(),
+            "`flatten` attribute argument is not supported by `@oneOf` input object variants",
+        );
+    }
+
+    if let Some(validate) = &field_attr.validate {
+        ERR.emit_custom(
+            validate.span_ident(),
+            "`validate` attribute argument is not supported by `@oneOf` input object variants",
+        );
+    }
+
     let ident = &v.ident;
 
     let name = field_attr
@@ -190,8 +210,11 @@ fn parse_enum_variant(
         default: None,
         name,
         description: field_attr.description.map(SpanContainer::into_inner),
+        directives: field_attr.directives,
         deprecated: field_attr.deprecated.map(SpanContainer::into_inner),
         ignored,
+        flatten: false,
+        validate: None,
     })
 }
 
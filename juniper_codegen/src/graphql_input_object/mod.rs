@@ -16,12 +16,14 @@ use syn::{
 };
 
 use crate::common::{
-    Description, SpanContainer, default, deprecation, filter_attrs,
+    Description, SpanContainer, default, deprecation, directive, filter_attrs,
     parse::{
         GenericsExt as _, ParseBufferExt as _,
         attr::{OptionExt as _, err},
     },
     rename, scalar,
+    type_info::{self, TypeInfo},
+    validate,
 };
 
 /// Available arguments behind `#[graphql]` attribute placed on a Rust struct
@@ -46,6 +48,13 @@ struct ContainerAttr {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<SpanContainer<Description>>,
 
+    /// Explicitly specified [directives][2] applied to this [GraphQL input
+    /// object][0].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
     /// Explicitly specified type of [`Context`] to use for resolving this
     /// [GraphQL input object][0] type with.
     ///
@@ -55,6 +64,15 @@ struct ContainerAttr {
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     context: Option<SpanContainer<syn::Type>>,
 
+    /// Explicitly specified type of [`TypeInfo`] to use for resolving this
+    /// [GraphQL input object][0] type with.
+    ///
+    /// If [`None`], then unit type `()` is assumed as a type of [`TypeInfo`].
+    ///
+    /// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    type_info: Option<SpanContainer<TypeInfo>>,
+
     /// Explicitly specified type (or type parameter with its bounds) of
     /// [`ScalarValue`] to use for resolving this [GraphQL input object][0] type
     /// with.
@@ -105,6 +123,10 @@ impl Parse for ContainerAttr {
                         .replace(SpanContainer::new(ident.span(), Some(desc.span()), desc))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse()?);
+                }
                 "ctx" | "context" | "Context" => {
                     input.parse::<token::Eq>()?;
                     let ctx = input.parse::<syn::Type>()?;
@@ -112,6 +134,13 @@ impl Parse for ContainerAttr {
                         .replace(SpanContainer::new(ident.span(), Some(ctx.span()), ctx))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "type_info" => {
+                    input.parse::<token::Eq>()?;
+                    let ti = input.parse::<TypeInfo>()?;
+                    out.type_info
+                        .replace(SpanContainer::new(ident.span(), Some(ti.span()), ti))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "scalar" | "Scalar" | "ScalarValue" => {
                     input.parse::<token::Eq>()?;
                     let scl = input.parse::<scalar::AttrValue>()?;
@@ -150,7 +179,13 @@ impl ContainerAttr {
         Ok(Self {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
             context: try_merge_opt!(context: self, another),
+            type_info: try_merge_opt!(type_info: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             rename_fields: try_merge_opt!(rename_fields: self, another),
             is_internal: self.is_internal || another.is_internal,
@@ -205,6 +240,13 @@ struct FieldAttr {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<SpanContainer<Description>>,
 
+    /// Explicitly specified [directives][2] applied to this [GraphQL input
+    /// object field][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
     /// Explicitly specified [deprecation][2] of this [GraphQL input object field][1].
     ///
     /// If [`None`], then Rust `#[deprecated]` attribute will be used as the [deprecation][2], if
@@ -224,6 +266,18 @@ struct FieldAttr {
     /// [`default`]: Self::default
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     ignore: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified marker for the fields of the Rust struct field's
+    /// [GraphQL input object][0] to be inlined into the parent one.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    flatten: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified validation rules of this
+    /// [GraphQL input object field][1] value.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    validate: Option<SpanContainer<validate::Rules>>,
 }
 
 impl Parse for FieldAttr {
@@ -256,6 +310,10 @@ impl Parse for FieldAttr {
                         .replace(SpanContainer::new(ident.span(), Some(desc.span()), desc))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse()?);
+                }
                 "deprecated" => {
                     let directive = input.parse::<deprecation::Directive>()?;
                     out.deprecated
@@ -270,6 +328,16 @@ impl Parse for FieldAttr {
                     .ignore
                     .replace(SpanContainer::new(ident.span(), None, ident.clone()))
                     .none_or_else(|_| err::dup_arg(&ident))?,
+                "flatten" => out
+                    .flatten
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                "validate" => {
+                    let rules = input.parse::<validate::Rules>()?;
+                    out.validate
+                        .replace(SpanContainer::new(ident.span(), None, rules))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -288,8 +356,15 @@ impl FieldAttr {
             name: try_merge_opt!(name: self, another),
             default: try_merge_opt!(default: self, another),
             description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
             deprecated: try_merge_opt!(deprecated: self, another),
             ignore: try_merge_opt!(ignore: self, another),
+            flatten: try_merge_opt!(flatten: self, another),
+            validate: try_merge_opt!(validate: self, another),
         })
     }
 
@@ -300,6 +375,30 @@ impl FieldAttr {
             .map(|attr| attr.parse_args())
             .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
 
+        if let Some(flatten) = &attr.flatten {
+            if attr.name.is_some()
+                || attr.default.is_some()
+                || attr.description.is_some()
+                || !attr.directives.is_empty()
+                || attr.deprecated.is_some()
+                || attr.ignore.is_some()
+                || attr.validate.is_some()
+            {
+                return Err(syn::Error::new(
+                    flatten.span(),
+                    "`flatten` attribute argument is not composable with any other arguments",
+                ));
+            }
+        }
+
+        if let (Some(validate), Some(_)) = (&attr.validate, &attr.ignore) {
+            return Err(syn::Error::new(
+                validate.span_ident(),
+                "`validate` attribute argument has no meaning for ignored fields, as they are \
+                 never parsed from input",
+            ));
+        }
+
         if attr.description.is_none() {
             attr.description = Description::parse_from_doc_attrs(attrs)?;
         }
@@ -350,6 +449,13 @@ struct FieldDefinition {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<Description>,
 
+    /// [Directives][2] applied to this [GraphQL input object field][1] in
+    /// GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
     /// [Deprecation][2] of this [GraphQL input object field][1] to put into GraphQL schema.
     ///
     /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
@@ -366,6 +472,19 @@ struct FieldDefinition {
     /// [`default`]: Self::default
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     ignored: bool,
+
+    /// Indicator whether the Rust struct field behind this
+    /// [GraphQL input object field][1] holds a [GraphQL input object][0],
+    /// whose fields should be inlined into the parent one instead.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    flatten: bool,
+
+    /// Validation rules of this [GraphQL input object field][1] value, if any.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    validate: Option<validate::Rules>,
 }
 
 /// Representation of [GraphQL input object][0] for code generation.
@@ -397,6 +516,13 @@ struct Definition {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<Description>,
 
+    /// [Directives][2] applied to this [GraphQL input object][0] in GraphQL
+    /// schema.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
     /// Rust type of [`Context`] to generate [`GraphQLType`] implementation with
     /// for this [GraphQL input object][0].
     ///
@@ -405,6 +531,16 @@ struct Definition {
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     context: syn::Type,
 
+    /// Custom Rust type of [`TypeInfo`] to generate [`GraphQLType`]
+    /// implementation with for this [GraphQL input object][0].
+    ///
+    /// If [`None`], then unit type `()` is used.
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    type_info: Option<TypeInfo>,
+
     /// [`ScalarValue`] parametrization to generate [`GraphQLType`]
     /// implementation with for this [GraphQL input object][0].
     ///
@@ -476,6 +612,16 @@ impl Definition {
             }
         });
 
+        let unique_fields_check = self
+            .fields
+            .iter()
+            .any(|f| !f.ignored && f.flatten)
+            .then(|| {
+                let mut const_ty: syn::Type = parse_quote! { #ident #ty_generics };
+                generics.replace_type_with_defaults(&mut const_ty);
+                quote! { ::juniper::assert_unique_fields!(#const_ty, #const_scalar); }
+            });
+
         quote! {
             #[automatically_derived]
             impl #impl_generics ::juniper::marker::IsInputType<#scalar>
@@ -483,6 +629,7 @@ impl Definition {
                 #where_clause
             {
                 fn mark() {
+                    #unique_fields_check
                     #( #assert_fields_input_values )*
                 }
             }
@@ -499,58 +646,92 @@ impl Definition {
         let ident = &self.ident;
         let scalar = &self.scalar;
         let name = &self.name;
+        let info = type_info::child_tokens();
 
         let generics = self.impl_generics(false);
         let (impl_generics, _, where_clause) = generics.split_for_impl();
         let (_, ty_generics, _) = self.generics.split_for_impl();
 
         let description = &self.description;
+        let directives = &self.directives;
 
         let one_of = self.is_one_of.then(|| quote! { .one_of() });
 
+        let flattened_tys = self
+            .fields
+            .iter()
+            .filter_map(|f| (!f.ignored && f.flatten).then_some(&f.ty))
+            .collect::<Vec<_>>();
+        let flattened_fields = (!flattened_tys.is_empty()).then(|| {
+            quote! {
+                let fields: ::std::vec::Vec<_> = ::core::iter::IntoIterator::into_iter(fields)
+                    #( .chain(
+                        ::juniper::macros::helper::flattened_input_fields(
+                            #name,
+                            <#flattened_tys as ::juniper::GraphQLType<#scalar>>::meta(
+                                #info, registry,
+                            ),
+                        )
+                        .unwrap_or_else(|e| {
+                            registry.add_error(e);
+                            ::std::vec::Vec::new()
+                        }),
+                    ) )*
+                    .collect();
+            }
+        });
+
         let fields = self.fields.iter().filter_map(|f| {
             let ty = &f.ty;
             let name = &f.name;
 
-            (!f.ignored).then(|| {
+            (!f.ignored && !f.flatten).then(|| {
                 let arg = if let Some(default) = &f.default {
                     quote! {
                         .arg_with_default::<#ty>(
-                            ::juniper::arcstr::literal!(#name), &#default, info,
+                            ::juniper::arcstr::literal!(#name), &#default, #info,
                         )
                     }
                 } else {
                     quote! {
-                        .arg::<#ty>(::juniper::arcstr::literal!(#name), info)
+                        .arg::<#ty>(::juniper::arcstr::literal!(#name), #info)
                     }
                 };
                 let description = &f.description;
                 let deprecated = &f.deprecated;
+                let directives = &f.directives;
 
-                quote! { registry #arg #description #deprecated }
+                quote! { registry #arg #description #deprecated #( #directives )* }
             })
         });
 
+        let name_method = type_info::name_method_tokens(
+            self.type_info.as_ref(),
+            &quote! { ::juniper::arcstr::literal!(#name) },
+        );
+        let dyn_description = type_info::description_tokens(self.type_info.as_ref());
+
         quote! {
             #[automatically_derived]
             impl #impl_generics ::juniper::GraphQLType<#scalar>
                 for #ident #ty_generics
                 #where_clause
             {
-                fn name(_: &Self::TypeInfo) -> ::core::option::Option<::juniper::ArcStr> {
-                    ::core::option::Option::Some(::juniper::arcstr::literal!(#name))
-                }
+                #name_method
 
                 fn meta(
                     info: &Self::TypeInfo,
                     registry: &mut ::juniper::Registry<#scalar>,
                 ) -> ::juniper::meta::MetaType<#scalar> {
                     let fields = [#( #fields ),*];
-                    registry
+                    #flattened_fields
+                    let meta = registry
                         .build_input_object_type::<#ident #ty_generics>(info, &fields)
                         #description
-                        #one_of
-                        .into_meta()
+                        #( #directives )*
+                        #one_of;
+                    #dyn_description
+                    meta.into_meta()
                 }
             }
         }
@@ -566,6 +747,7 @@ impl Definition {
         let ident = &self.ident;
         let scalar = &self.scalar;
         let context = &self.context;
+        let type_info = type_info::ty_tokens(self.type_info.as_ref());
 
         let generics = self.impl_generics(false);
         let (impl_generics, _, where_clause) = generics.split_for_impl();
@@ -578,7 +760,7 @@ impl Definition {
                 #where_clause
             {
                 type Context = #context;
-                type TypeInfo = ();
+                type TypeInfo = #type_info;
 
                 fn type_name(
                     &self,
@@ -644,11 +826,15 @@ impl Definition {
                 let pre_none_pats = iter::repeat_n(&none_pat, n);
                 let post_none_pats = iter::repeat_n(&none_pat, variants.len() - n - 1);
 
+                let name = &v.name;
+
                 quote! {
                     (#( #pre_none_pats, )* #some_pat, #( #post_none_pats, )*) => {
                         Self::#variant_ident(
-                            ::juniper::FromInputValue::<#scalar>::from_input_value(v)
-                                .map_err(::juniper::IntoFieldError::into_field_error)?
+                            ::juniper::macros::helper::validate::at_input_path(#name, || {
+                                ::juniper::FromInputValue::<#scalar>::from_input_value(v)
+                                    .map_err(::juniper::IntoFieldError::into_field_error)
+                            })?
                         )
                     }
                 }
@@ -666,7 +852,12 @@ impl Definition {
             let fields = self.fields.iter().map(|f| {
                 let ident = &f.ident;
 
-                let construct = if f.ignored {
+                let construct = if f.flatten {
+                    quote! {
+                        ::juniper::FromInputValue::<#scalar>::from_input_value(value)
+                            .map_err(::juniper::IntoFieldError::into_field_error)?
+                    }
+                } else if f.ignored {
                     f.default.as_ref().map_or_else(
                         || {
                             let expr = default::Value::default();
@@ -687,14 +878,23 @@ impl Definition {
                         |expr| quote! { #expr },
                     );
 
+                    let checks = f
+                        .validate
+                        .as_ref()
+                        .map(|rules| rules.checks_tokens(&quote! { &v }, scalar));
+
                     quote! {
-                        match obj.get(#name) {
-                            ::core::option::Option::Some(v) => {
-                                ::juniper::FromInputValue::<#scalar>::from_input_value(v)
-                                    .map_err(::juniper::IntoFieldError::into_field_error)?
-                            }
-                            ::core::option::Option::None => { #fallback }
-                        }
+                        ::juniper::macros::helper::validate::at_input_path(#name, || {
+                            let v = match obj.get(#name) {
+                                ::core::option::Option::Some(v) => {
+                                    ::juniper::FromInputValue::<#scalar>::from_input_value(v)
+                                        .map_err(::juniper::IntoFieldError::into_field_error)?
+                                }
+                                ::core::option::Option::None => { #fallback }
+                            };
+                            #checks
+                            ::core::result::Result::<_, ::juniper::FieldError<#scalar>>::Ok(v)
+                        })?
                     }
                 };
 
@@ -740,26 +940,37 @@ impl Definition {
         let (impl_generics, _, where_clause) = generics.split_for_impl();
         let (_, ty_generics, _) = self.generics.split_for_impl();
 
-        let fields = self.fields.iter().filter(|&f| !f.ignored).map(|f| {
-            let ident = &f.ident;
-            let name = &f.name;
+        let fields = self
+            .fields
+            .iter()
+            .filter(|&f| !f.ignored && !f.flatten)
+            .map(|f| {
+                let ident = &f.ident;
+                let name = &f.name;
 
-            let value_expr = if self.is_one_of {
-                quote! {
-                    if let Self::#ident(v) = self {
-                        ::core::option::Option::Some(v)
-                    } else {
-                        ::core::option::Option::None
+                let value_expr = if self.is_one_of {
+                    quote! {
+                        if let Self::#ident(v) = self {
+                            ::core::option::Option::Some(v)
+                        } else {
+                            ::core::option::Option::None
+                        }
                     }
-                }
-            } else {
-                quote! { self.#ident }
-            };
+                } else {
+                    quote! { self.#ident }
+                };
 
-            quote! {
-                (#name, ::juniper::ToInputValue::to_input_value(&#value_expr))
-            }
-        });
+                quote! {
+                    (#name, ::juniper::ToInputValue::to_input_value(&#value_expr))
+                }
+            });
+        let flattened = self
+            .fields
+            .iter()
+            .filter(|&f| !f.ignored && f.flatten)
+            .map(|f| &f.ident)
+            .collect::<Vec<_>>();
+        let mutability = (!flattened.is_empty()).then(|| quote! { mut });
 
         quote! {
             #[automatically_derived]
@@ -767,9 +978,15 @@ impl Definition {
                  #where_clause
             {
                 fn to_input_value(&self) -> ::juniper::InputValue<#scalar> {
-                    ::juniper::InputValue::object(
+                    let #mutability value = ::juniper::InputValue::object(
                         ::core::iter::IntoIterator::into_iter([#( #fields ),*]).collect()
-                    )
+                    );
+                    // Flattened value, not being an object, has no fields to merge.
+                    #( _ = ::juniper::macros::helper::merge_input_objects(
+                        &mut value,
+                        ::juniper::ToInputValue::to_input_value(&self.#flattened),
+                    ); )*
+                    value
                 }
             }
         }
@@ -792,7 +1009,31 @@ impl Definition {
         let (impl_generics, _, where_clause) = generics.split_for_impl();
         let (_, ty_generics, _) = self.generics.split_for_impl();
 
-        let fields_names = self.fields.iter().filter(|f| !f.ignored).map(|f| &f.name);
+        let fields_names = self
+            .fields
+            .iter()
+            .filter(|f| !f.ignored && !f.flatten)
+            .map(|f| &f.name);
+        let fields_names = self.fields.iter().filter(|f| !f.ignored && f.flatten).fold(
+            quote! { &[#(#fields_names),*] },
+            |names, f| {
+                let ty = &f.ty;
+                let err = format!(
+                    "GraphQL input object `{name}` cannot have more than \
+                     `juniper::macros::reflect::MAX_CONCAT_NAMES` fields in total, including the \
+                     flattened ones",
+                );
+                quote! {
+                    ::juniper::macros::reflect::names_from_buf(
+                        &::juniper::macros::reflect::concat_names(
+                            #names,
+                            <#ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES,
+                            #err,
+                        ),
+                    )
+                }
+            },
+        );
 
         quote! {
             #[automatically_derived]
@@ -825,7 +1066,7 @@ impl Definition {
                 for #ident #ty_generics
                 #where_clause
             {
-                const NAMES: ::juniper::macros::reflect::Names = &[#(#fields_names),*];
+                const NAMES: ::juniper::macros::reflect::Names = #fields_names;
             }
         }
     }
@@ -838,6 +1079,7 @@ impl Definition {
     fn impl_field_meta_tokens(&self) -> TokenStream {
         let ident = &self.ident;
         let context = &self.context;
+        let type_info = type_info::ty_tokens(self.type_info.as_ref());
         let scalar = &self.scalar;
 
         let generics = self.impl_generics(false);
@@ -846,7 +1088,7 @@ impl Definition {
 
         self.fields
             .iter()
-            .filter(|f| !f.ignored)
+            .filter(|f| !f.ignored && !f.flatten)
             .map(|field| {
                 let field_name = &field.name;
                 let mut field_ty = field.ty.clone();
@@ -860,7 +1102,7 @@ impl Definition {
                         { ::juniper::macros::reflect::fnv1a128(#field_name) }
                     > for #ident #ty_generics #where_clause {
                         type Context = #context;
-                        type TypeInfo = ();
+                        type TypeInfo = #type_info;
                         const TYPE: ::juniper::macros::reflect::Type =
                             <#field_ty as ::juniper::macros::reflect::BaseType<#scalar>>::NAME;
                         const SUB_TYPES: ::juniper::macros::reflect::Types =
@@ -118,6 +118,9 @@ fn expand_on_trait(
         enum_alias_ident,
         name,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
+        guard: attr.guard.map(SpanContainer::into_inner),
         context,
         scalar,
         fields,
@@ -164,7 +167,7 @@ fn parse_trait_method(
         .map_err(diagnostic::emit_error)
         .ok()?;
 
-    if attr.ignore.is_some() {
+    if attr.ignore.is_some() || attr.check_no_flatten(ERR) {
         return None;
     }
 
@@ -193,7 +196,8 @@ fn parse_trait_method(
         .iter_mut()
         .filter_map(|arg| match arg {
             syn::FnArg::Receiver(_) => None,
-            syn::FnArg::Typed(arg) => field::MethodArgument::parse(arg, renaming, &ERR),
+            syn::FnArg::Typed(arg) => field::MethodArgument::parse(arg, renaming, &ERR)
+                .filter(|arg| !arg.check_no_validate(ERR)),
         })
         .collect();
 
@@ -208,6 +212,9 @@ fn parse_trait_method(
         ty,
         description: attr.description.map(SpanContainer::into_inner),
         deprecated: attr.deprecated.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
+        guard: attr.guard.map(SpanContainer::into_inner),
         ident: method_ident.clone(),
         arguments: Some(arguments),
         has_receiver: method.sig.receiver().is_some(),
@@ -301,6 +308,9 @@ fn expand_on_derive_input(
         enum_alias_ident,
         name,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
+        guard: attr.guard.map(SpanContainer::into_inner),
         context,
         scalar,
         fields,
@@ -346,7 +356,7 @@ fn parse_struct_field(
         .map_err(diagnostic::emit_error)
         .ok()?;
 
-    if attr.ignore.is_some() {
+    if attr.ignore.is_some() || attr.check_no_flatten(ERR) {
         return None;
     }
 
@@ -373,6 +383,9 @@ fn parse_struct_field(
         ty,
         description: attr.description.map(SpanContainer::into_inner),
         deprecated: attr.deprecated.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
+        guard: attr.guard.map(SpanContainer::into_inner),
         ident: field_ident.clone(),
         arguments: None,
         has_receiver: false,
@@ -89,6 +89,9 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         enum_alias_ident,
         name,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
+        guard: attr.guard.map(SpanContainer::into_inner),
         context,
         scalar,
         fields,
@@ -119,7 +122,7 @@ fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::D
         .map_err(diagnostic::emit_error)
         .ok()?;
 
-    if attr.ignore.is_some() {
+    if attr.ignore.is_some() || attr.check_no_flatten(ERR) {
         return None;
     }
 
@@ -146,6 +149,9 @@ fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::D
         ty,
         description: attr.description.map(SpanContainer::into_inner),
         deprecated: attr.deprecated.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
+        guard: attr.guard.map(SpanContainer::into_inner),
         ident: field_ident.clone(),
         arguments: None,
         has_receiver: false,
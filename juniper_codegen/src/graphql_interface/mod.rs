@@ -20,12 +20,12 @@ use syn::{
 };
 
 use crate::common::{
-    AttrNames, Description, SpanContainer, field, filter_attrs, generate,
+    AttrNames, Description, SpanContainer, directive, field, filter_attrs, generate, guard,
     parse::{
         GenericsExt as _, ParseBufferExt as _,
         attr::{OptionExt as _, err},
     },
-    rename, scalar,
+    rename, scalar, visibility,
 };
 
 /// Returns [`syn::Ident`]s for a generic enum deriving [`Clone`] and [`Copy`]
@@ -70,6 +70,25 @@ struct Attr {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<SpanContainer<Description>>,
 
+    /// Explicitly specified [directives][2] applied to this [GraphQL
+    /// interface][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
+    /// Explicitly specified [`visibility::Predicate`] of this [GraphQL
+    /// interface][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    visible: Option<SpanContainer<visibility::Predicate>>,
+
+    /// Explicitly specified [`guard::Guard`] of all the fields of this
+    /// [GraphQL interface][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    guard: Option<SpanContainer<guard::Guard>>,
+
     /// Explicitly specified identifier of the type alias of Rust enum type
     /// behind the trait or struct, being an actual implementation of a
     /// [GraphQL interface][1] type.
@@ -161,6 +180,28 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(desc.span()), desc))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse()?);
+                }
+                "visible" => {
+                    input.parse::<token::Eq>()?;
+                    let predicate = input.parse::<visibility::Predicate>()?;
+                    out.visible
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(predicate.span()),
+                            predicate,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "guard" => {
+                    input.parse::<token::Eq>()?;
+                    let guard = input.parse::<guard::Guard>()?;
+                    out.guard
+                        .replace(SpanContainer::new(ident.span(), Some(guard.span()), guard))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ctx" | "context" | "Context" => {
                     input.parse::<token::Eq>()?;
                     let ctx = input.parse::<syn::Type>()?;
@@ -243,6 +284,13 @@ impl Attr {
         Ok(Self {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            visible: try_merge_opt!(visible: self, another),
+            guard: try_merge_opt!(guard: self, another),
             context: try_merge_opt!(context: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             implemented_for: try_merge_hashset!(implemented_for: self, another => span_joined),
@@ -308,6 +356,25 @@ struct Definition {
     /// [0]: https://spec.graphql.org/October2021#sec-Interfaces
     description: Option<Description>,
 
+    /// [Directives][2] applied to this [GraphQL interface][0] in GraphQL
+    /// schema.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Interfaces
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
+    /// [`visibility::Predicate`] of this [GraphQL interface][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    visible: Option<visibility::Predicate>,
+
+    /// [`guard::Guard`] of all the fields of this [GraphQL interface][1],
+    /// checked before the own [`guard::Guard`] of a field by the field
+    /// resolvers of its implementers.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    guard: Option<guard::Guard>,
+
     /// Rust type of [`Context`] to generate [`GraphQLType`] implementation with
     /// for this [GraphQL interface][1].
     ///
@@ -368,6 +435,7 @@ impl ToTokens for Definition {
         self.impl_field_meta_tokens().to_tokens(into);
         self.impl_field_tokens().to_tokens(into);
         self.impl_async_field_tokens().to_tokens(into);
+        self.impl_interface_guards_tokens().to_tokens(into);
     }
 }
 
@@ -695,6 +763,8 @@ impl Definition {
 
         let name = &self.name;
         let description = &self.description;
+        let directives = &self.directives;
+        let visible = &self.visible;
 
         // Sorting is required to preserve/guarantee the order of implementers registered in schema.
         let mut implemented_for = self.implemented_for.clone();
@@ -741,6 +811,8 @@ impl Definition {
                     ];
                     registry.build_interface_type::<#ty #ty_generics>(info, &fields)
                         #description
+                        #( #directives )*
+                        #visible
                         #impl_interfaces
                         .into_meta()
                 }
@@ -1185,6 +1257,75 @@ impl Definition {
             .collect()
     }
 
+    /// Returns generated code implementing [`InterfaceGuards`] trait for this
+    /// [GraphQL interface][1], checking its [`guard::Guard`]s in the field
+    /// resolvers of its implementers.
+    ///
+    /// [`InterfaceGuards`]: juniper::macros::helper::InterfaceGuards
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    #[must_use]
+    fn impl_interface_guards_tokens(&self) -> TokenStream {
+        let ty = &self.enum_alias_ident;
+        let scalar = &self.scalar;
+
+        let generics = self.impl_generics(false);
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        let context = quote! { context };
+        let checks = self
+            .fields
+            .iter()
+            .filter_map(|field| {
+                let name = &field.name;
+                let checks = self
+                    .guard
+                    .iter()
+                    .chain(&field.guard)
+                    .map(|guard| guard.check_tokens(scalar, &context))
+                    .collect::<Vec<_>>();
+                (!checks.is_empty()).then(|| {
+                    quote! {
+                        #name => {
+                            #( #checks?; )*
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        let body = if checks.is_empty() {
+            quote! { ::core::result::Result::Ok(()) }
+        } else {
+            quote! {
+                match field {
+                    #( #checks )*
+                    _ => {}
+                }
+                ::core::result::Result::Ok(())
+            }
+        };
+        let (field, context) = if checks.is_empty() {
+            (quote! { _ }, quote! { _ })
+        } else {
+            (quote! { field }, context)
+        };
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::juniper::macros::helper::InterfaceGuards<#scalar>
+                for #ty #ty_generics
+                #where_clause
+            {
+                fn check_guards(
+                    #field: &::core::primitive::str,
+                    #context: &Self::Context,
+                ) -> ::juniper::FieldResult<(), #scalar> {
+                    #body
+                }
+            }
+        }
+    }
+
     /// Returns generated code for the [`GraphQLValue::concrete_type_name`][0]
     /// method, which returns name of the underlying [`implementers`][1] GraphQL
     /// type contained in this enum.
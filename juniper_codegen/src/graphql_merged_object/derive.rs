@@ -0,0 +1,71 @@
+//! Code generation for `#[derive(GraphQLMergedObject)]` macro.
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned as _};
+
+use crate::common::{
+    SpanContainer,
+    diagnostic::{self, ResultExt as _},
+    scalar,
+};
+
+use super::{Attr, Definition};
+
+/// [`diagnostic::Scope`] of errors for `#[derive(GraphQLMergedObject)]` macro.
+const ERR: diagnostic::Scope = diagnostic::Scope::MergedObjectDerive;
+
+/// Expands `#[derive(GraphQLMergedObject)]` macro into generated code.
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let ast = syn::parse2::<syn::DeriveInput>(input).unwrap_or_abort();
+
+    let syn::Data::Struct(data) = &ast.data else {
+        return Err(ERR.custom_error(ast.span(), "can only be derived for structs"));
+    };
+
+    let attr = Attr::from_attrs("graphql", &ast.attrs)?;
+
+    let struct_ident = &ast.ident;
+
+    let name = attr
+        .name
+        .clone()
+        .map(SpanContainer::into_inner)
+        .unwrap_or_else(|| struct_ident.unraw().to_string());
+    if name.starts_with("__") {
+        ERR.no_double_underscore(
+            attr.name
+                .as_ref()
+                .map(SpanContainer::span_ident)
+                .unwrap_or_else(|| struct_ident.span()),
+        );
+    }
+
+    let parts = data
+        .fields
+        .iter()
+        .zip(data.fields.members())
+        .map(|(field, member)| (member, field.ty.clone()))
+        .collect::<Vec<_>>();
+    if parts.is_empty() {
+        ERR.emit_custom(data.fields.span(), "expects at least one object to merge");
+    }
+
+    diagnostic::abort_if_dirty();
+
+    Ok(Definition {
+        name,
+        ty: parse_quote! { #struct_ident },
+        description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
+        context: attr
+            .context
+            .map(SpanContainer::into_inner)
+            .unwrap_or_else(|| parse_quote! { () }),
+        scalar: scalar::Type::parse(attr.scalar.as_deref(), &ast.generics),
+        generics: ast.generics,
+        parts,
+    }
+    .into_token_stream())
+}
@@ -0,0 +1,575 @@
+//! Code generation for [GraphQL object][1] merged from multiple other
+//! [GraphQL objects][1].
+//!
+//! [1]: https://spec.graphql.org/October2021#sec-Objects
+
+pub mod derive;
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    ext::IdentExt as _,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::common::{
+    AttrNames, Description, SpanContainer, directive, field, filter_attrs,
+    parse::{
+        ParseBufferExt as _,
+        attr::{OptionExt as _, err},
+    },
+    scalar, visibility,
+};
+
+/// Available arguments behind `#[graphql]` attribute when generating code for
+/// a merged [GraphQL object][1] type.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+#[derive(Debug, Default)]
+struct Attr {
+    /// Explicitly specified name of this merged [GraphQL object][1] type.
+    ///
+    /// If [`None`], then Rust type name is used by default.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    name: Option<SpanContainer<String>>,
+
+    /// Explicitly specified [description][2] of this merged [GraphQL
+    /// object][1] type.
+    ///
+    /// If [`None`], then Rust doc comment will be used as the [description][2],
+    /// if any.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
+    description: Option<SpanContainer<Description>>,
+
+    /// Explicitly specified [directives][2] applied to this merged [GraphQL
+    /// object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
+    /// Explicitly specified [`visibility::Predicate`] of this merged [GraphQL
+    /// object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    visible: Option<SpanContainer<visibility::Predicate>>,
+
+    /// Explicitly specified type of [`Context`] to use for resolving this
+    /// merged [GraphQL object][1] type with.
+    ///
+    /// If [`None`], then unit type `()` is assumed as a type of [`Context`].
+    ///
+    /// [`Context`]: juniper::Context
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    context: Option<SpanContainer<syn::Type>>,
+
+    /// Explicitly specified type of [`ScalarValue`] to use for resolving this
+    /// merged [GraphQL object][1] type with.
+    ///
+    /// If [`None`], then generated code will be generic over any
+    /// [`ScalarValue`] type, which, in turn, requires all the merged
+    /// [GraphQL objects][1] to be generic over any [`ScalarValue`] type too.
+    ///
+    /// [`ScalarValue`]: juniper::ScalarValue
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    scalar: Option<SpanContainer<scalar::AttrValue>>,
+}
+
+impl Parse for Attr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse_any_ident()?;
+            match ident.to_string().as_str() {
+                "name" => {
+                    input.parse::<token::Eq>()?;
+                    let name = input.parse::<syn::LitStr>()?;
+                    out.name
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(name.span()),
+                            name.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "desc" | "description" => {
+                    input.parse::<token::Eq>()?;
+                    let desc = input.parse::<Description>()?;
+                    out.description
+                        .replace(SpanContainer::new(ident.span(), Some(desc.span()), desc))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse()?);
+                }
+                "visible" => {
+                    input.parse::<token::Eq>()?;
+                    let predicate = input.parse::<visibility::Predicate>()?;
+                    out.visible
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(predicate.span()),
+                            predicate,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "ctx" | "context" | "Context" => {
+                    input.parse::<token::Eq>()?;
+                    let ctx = input.parse::<syn::Type>()?;
+                    out.context
+                        .replace(SpanContainer::new(ident.span(), Some(ctx.span()), ctx))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "scalar" | "Scalar" | "ScalarValue" => {
+                    input.parse::<token::Eq>()?;
+                    let scl = input.parse::<scalar::AttrValue>()?;
+                    out.scalar
+                        .replace(SpanContainer::new(ident.span(), Some(scl.span()), scl))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            input.try_parse::<token::Comma>()?;
+        }
+        Ok(out)
+    }
+}
+
+impl Attr {
+    /// Tries to merge two [`Attr`]s into a single one, reporting about
+    /// duplicates, if any.
+    fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        Ok(Self {
+            name: try_merge_opt!(name: self, another),
+            description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            visible: try_merge_opt!(visible: self, another),
+            context: try_merge_opt!(context: self, another),
+            scalar: try_merge_opt!(scalar: self, another),
+        })
+    }
+
+    /// Parses an [`Attr`] from the provided multiple [`syn::Attribute`]s with
+    /// the specified `names`, placed on a struct definition.
+    fn from_attrs(names: impl AttrNames, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut attr = filter_attrs(names, attrs)
+            .map(|attr| attr.parse_args())
+            .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
+
+        if attr.description.is_none() {
+            attr.description = Description::parse_from_doc_attrs(attrs)?;
+        }
+
+        Ok(attr)
+    }
+}
+
+/// Definition of a merged [GraphQL object][1] for code generation.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+struct Definition {
+    /// Name of this merged [GraphQL object][1] in GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    name: String,
+
+    /// Rust type that this merged [GraphQL object][1] is represented with.
+    ///
+    /// It should contain no generics, as they're stored in
+    /// [`Definition::generics`].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    ty: syn::Type,
+
+    /// Generics of the Rust type that this merged [GraphQL object][1] is
+    /// implemented for.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    generics: syn::Generics,
+
+    /// Description of this merged [GraphQL object][1] to put into GraphQL
+    /// schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    description: Option<Description>,
+
+    /// [Directives][2] applied to this merged [GraphQL object][1] in GraphQL
+    /// schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
+    /// [`visibility::Predicate`] of this merged [GraphQL object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    visible: Option<visibility::Predicate>,
+
+    /// Rust type of [`Context`] to generate [`GraphQLType`] implementation with
+    /// for this merged [GraphQL object][1].
+    ///
+    /// [`Context`]: juniper::Context
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    context: syn::Type,
+
+    /// [`ScalarValue`] parametrization to generate [`GraphQLType`]
+    /// implementation with for this merged [GraphQL object][1].
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [`ScalarValue`]: juniper::ScalarValue
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    scalar: scalar::Type,
+
+    /// Merged [GraphQL objects][1] along with the Rust struct fields holding
+    /// them.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    parts: Vec<(syn::Member, syn::Type)>,
+}
+
+impl ToTokens for Definition {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        self.impl_graphql_object_tokens().to_tokens(into);
+        self.impl_output_type_tokens().to_tokens(into);
+        self.impl_graphql_type_tokens().to_tokens(into);
+        self.impl_graphql_value_tokens().to_tokens(into);
+        self.impl_graphql_value_async_tokens().to_tokens(into);
+        self.impl_reflection_traits_tokens().to_tokens(into);
+    }
+}
+
+impl Definition {
+    /// Returns prepared [`syn::Generics::split_for_impl`] for [`GraphQLType`]
+    /// trait (and similar) implementation of this merged [GraphQL object][1].
+    ///
+    /// If `for_async` is `true`, then additional predicates are added to suit
+    /// the [`GraphQLAsyncValue`] trait (and similar) requirements.
+    ///
+    /// [`GraphQLAsyncValue`]: juniper::GraphQLAsyncValue
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn impl_generics(
+        &self,
+        for_async: bool,
+    ) -> (TokenStream, TokenStream, Option<syn::WhereClause>) {
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let ty = &self.ty;
+        let ty_full = quote! { #ty #ty_generics };
+
+        let mut generics = self.generics.clone();
+
+        let scalar = &self.scalar;
+        if scalar.is_implicit_generic() {
+            generics.params.push(parse_quote! { #scalar });
+        }
+        if scalar.is_generic() {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #scalar: ::juniper::ScalarValue });
+        }
+        if let Some(bound) = scalar.bounds() {
+            generics.make_where_clause().predicates.push(bound);
+        }
+
+        if for_async {
+            let self_ty = if self.generics.lifetimes().next().is_some() {
+                // Modify lifetime names to omit "lifetime name `'a` shadows a
+                // lifetime name that is already in scope" error.
+                let mut generics = self.generics.clone();
+                for lt in generics.lifetimes_mut() {
+                    let ident = lt.lifetime.ident.unraw();
+                    lt.lifetime.ident = format_ident!("__fa__{ident}");
+                }
+
+                let lifetimes = generics.lifetimes().map(|lt| &lt.lifetime);
+                let (_, ty_generics, _) = generics.split_for_impl();
+
+                quote! { for<#( #lifetimes ),*> #ty #ty_generics }
+            } else {
+                quote! { Self }
+            };
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #self_ty: ::core::marker::Sync });
+
+            if scalar.is_generic() {
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote! { #scalar: ::core::marker::Send + ::core::marker::Sync });
+            }
+        }
+
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        (
+            quote! { #impl_generics },
+            quote! { #ty_full },
+            where_clause.cloned(),
+        )
+    }
+
+    /// Returns generated code implementing [`GraphQLObject`] trait for this
+    /// merged [GraphQL object][1].
+    ///
+    /// [`GraphQLObject`]: juniper::GraphQLObject
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn impl_graphql_object_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, ty_full, where_clause) = self.impl_generics(false);
+
+        let part_tys = self.parts.iter().map(|(_, ty)| ty);
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::juniper::marker::GraphQLObject<#scalar> for #ty_full #where_clause
+            {
+                fn mark() {
+                    #( <#part_tys as ::juniper::marker::GraphQLObject<#scalar>>::mark(); )*
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`marker::IsOutputType`] trait for
+    /// this merged [GraphQL object][1].
+    ///
+    /// [`marker::IsOutputType`]: juniper::marker::IsOutputType
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn impl_output_type_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, ty_full, where_clause) = self.impl_generics(false);
+
+        let part_tys = self.parts.iter().map(|(_, ty)| ty);
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::juniper::marker::IsOutputType<#scalar> for #ty_full #where_clause
+            {
+                fn mark() {
+                    #( <#part_tys as ::juniper::marker::IsOutputType<#scalar>>::mark(); )*
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`GraphQLType`] trait for this
+    /// merged [GraphQL object][1].
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn impl_graphql_type_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, ty_full, where_clause) = self.impl_generics(false);
+
+        let name = &self.name;
+        let description = &self.description;
+        let directives = &self.directives;
+        let visible = &self.visible;
+
+        let part_tys = self.parts.iter().map(|(_, ty)| ty);
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::juniper::GraphQLType<#scalar> for #ty_full #where_clause
+            {
+                fn name(_ : &Self::TypeInfo) -> ::core::option::Option<::juniper::ArcStr> {
+                    ::core::option::Option::Some(::juniper::arcstr::literal!(#name))
+                }
+
+                fn meta(
+                    info: &Self::TypeInfo,
+                    registry: &mut ::juniper::Registry<#scalar>,
+                ) -> ::juniper::meta::MetaType<#scalar> {
+                    let mut fields = ::std::vec::Vec::new();
+                    #( match ::juniper::macros::helper::merged_object_fields(
+                        #name,
+                        <#part_tys as ::juniper::GraphQLType<#scalar>>::meta(info, registry),
+                    ) {
+                        ::core::result::Result::Ok(more) => fields.extend(more),
+                        ::core::result::Result::Err(e) => registry.add_error(e),
+                    } )*
+                    registry.build_object_type::<#ty_full>(info, &fields)
+                        #description
+                        #( #directives )*
+                        #visible
+                        .into_meta()
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`GraphQLValue`] trait for this
+    /// merged [GraphQL object][1].
+    ///
+    /// [`GraphQLValue`]: juniper::GraphQLValue
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn impl_graphql_value_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+        let context = &self.context;
+
+        let (impl_generics, ty_full, where_clause) = self.impl_generics(false);
+        let ty_name = ty_full.to_string();
+
+        let name = &self.name;
+
+        let parts_resolvers = self.parts.iter().map(|(member, part_ty)| {
+            quote! {
+                if <#part_ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES
+                    .contains(&field)
+                {
+                    return <#part_ty as ::juniper::GraphQLValue<#scalar>>::resolve_field(
+                        &self.#member, info, field, args, executor,
+                    );
+                }
+            }
+        });
+
+        let no_field_err =
+            field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::juniper::GraphQLValue<#scalar> for #ty_full #where_clause
+            {
+                type Context = #context;
+                type TypeInfo = ();
+
+                fn type_name(
+                    &self,
+                    info: &Self::TypeInfo,
+                ) -> ::core::option::Option<::juniper::ArcStr> {
+                    <Self as ::juniper::GraphQLType<#scalar>>::name(info)
+                }
+
+                fn resolve_field(
+                    &self,
+                    info: &Self::TypeInfo,
+                    field: &::core::primitive::str,
+                    args: &::juniper::Arguments<'_, #scalar>,
+                    executor: &::juniper::Executor<'_, '_, Self::Context, #scalar>,
+                ) -> ::juniper::ExecutionResult<#scalar> {
+                    #( #parts_resolvers )*
+                    #no_field_err
+                }
+
+                fn concrete_type_name(
+                    &self,
+                    _: &Self::Context,
+                    _: &Self::TypeInfo,
+                ) -> ::std::string::String {
+                    #name.into()
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`GraphQLValueAsync`] trait for this
+    /// merged [GraphQL object][1].
+    ///
+    /// [`GraphQLValueAsync`]: juniper::GraphQLValueAsync
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn impl_graphql_value_async_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, ty_full, where_clause) = self.impl_generics(true);
+        let ty_name = ty_full.to_string();
+
+        let parts_resolvers = self.parts.iter().map(|(member, part_ty)| {
+            quote! {
+                if <#part_ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES
+                    .contains(&field)
+                {
+                    return <#part_ty as ::juniper::GraphQLValueAsync<#scalar>>::resolve_field_async(
+                        &self.#member, info, field, args, executor,
+                    );
+                }
+            }
+        });
+
+        let no_field_err =
+            field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::juniper::GraphQLValueAsync<#scalar> for #ty_full #where_clause
+            {
+                fn resolve_field_async<'b>(
+                    &'b self,
+                    info: &'b Self::TypeInfo,
+                    field: &'b ::core::primitive::str,
+                    args: &'b ::juniper::Arguments<'_, #scalar>,
+                    executor: &'b ::juniper::Executor<'_, '_, Self::Context, #scalar>,
+                ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
+                    #( #parts_resolvers )*
+                    ::std::boxed::Box::pin(async move { #no_field_err })
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`BaseType`], [`BaseSubTypes`] and
+    /// [`WrappedType`] traits for this merged [GraphQL object][1].
+    ///
+    /// [`BaseSubTypes`]: juniper::macros::reflect::BaseSubTypes
+    /// [`BaseType`]: juniper::macros::reflect::BaseType
+    /// [`WrappedType`]: juniper::macros::reflect::WrappedType
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn impl_reflection_traits_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+        let name = &self.name;
+        let (impl_generics, ty_full, where_clause) = self.impl_generics(false);
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::juniper::macros::reflect::BaseType<#scalar>
+                for #ty_full
+                #where_clause
+            {
+                const NAME: ::juniper::macros::reflect::Type = #name;
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::juniper::macros::reflect::BaseSubTypes<#scalar>
+                for #ty_full
+                #where_clause
+            {
+                const NAMES: ::juniper::macros::reflect::Types =
+                    &[<Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME];
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::juniper::macros::reflect::WrappedType<#scalar>
+                for #ty_full
+                #where_clause
+            {
+                const VALUE: ::juniper::macros::reflect::WrappedValue = 1;
+            }
+        }
+    }
+}
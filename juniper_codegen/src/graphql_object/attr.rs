@@ -7,12 +7,13 @@ use quote::{ToTokens, quote};
 use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned};
 
 use crate::common::{
-    SpanContainer, diagnostic, field,
+    SpanContainer, diagnostic, field, name,
     parse::{self, TypeExt as _},
     path_eq_single, rename, scalar,
+    type_info::TypeInfo,
 };
 
-use super::{Attr, Definition, Query};
+use super::{Attr, ComplexPart, Definition, Query};
 
 /// [`diagnostic::Scope`] of errors for `#[graphql_object]` macro.
 const ERR: diagnostic::Scope = diagnostic::Scope::ObjectAttr;
@@ -64,6 +65,16 @@ where
         );
     }
 
+    let name = name::Template::parse(name, &ast.generics).map_err(|e| {
+        ERR.custom_error(
+            attr.name
+                .as_ref()
+                .map(SpanContainer::span_ident)
+                .unwrap_or_else(|| type_ident.span()),
+            e,
+        )
+    })?;
+
     let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
 
     diagnostic::abort_if_dirty();
@@ -95,13 +106,40 @@ where
     if !field::all_different(&fields) {
         ERR.emit_custom(type_span, "must have a different name for each field");
     }
+    attr.check_relay_nodes(&fields, ERR);
+    attr.check_type_info(ERR);
+    if async_only {
+        let node_span = attr.node.as_ref().map(SpanContainer::span_ident);
+        if let Some(span) = node_span.or_else(|| attr.nodes.iter().next().map(|n| n.span_ident())) {
+            ERR.emit_custom(
+                span,
+                "`node` and `nodes` arguments are not supported by subscriptions",
+            );
+        }
+    }
+    if let Some(complex) = &attr.complex {
+        if async_only {
+            ERR.emit_custom(
+                complex.span_ident(),
+                "`complex` argument is not supported by subscriptions",
+            );
+        }
+        attr.check_complex_impl(ERR);
+    }
 
     diagnostic::abort_if_dirty();
 
+    let self_ty = ast.self_ty.unparenthesized();
     let context = attr
         .context
         .as_deref()
         .cloned()
+        .or_else(|| {
+            // Context of a `complex` impl block is defined by its struct.
+            attr.complex.is_some().then(|| {
+                parse_quote! { <#self_ty as ::juniper::macros::helper::ComplexObjectStruct>::Context }
+            })
+        })
         .or_else(|| {
             fields.iter().find_map(|f| {
                 f.arguments.as_ref().and_then(|f| {
@@ -113,19 +151,36 @@ where
         })
         .unwrap_or_else(|| parse_quote! { () });
 
+    // Type info of a `complex` impl block is defined by its struct.
+    let type_info = attr.complex.is_some().then(|| {
+        let ty: syn::Type = parse_quote! {
+            <#self_ty as ::juniper::macros::helper::ComplexObjectStruct>::TypeInfo
+        };
+        TypeInfo::from(ty)
+    });
+
+    let nodes = attr.sorted_nodes();
     let generated_code = Definition::<Operation> {
         name,
-        ty: ast.self_ty.unparenthesized().clone(),
+        ty: self_ty.clone(),
         generics: ast.generics.clone(),
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
+        guard: attr.guard.map(SpanContainer::into_inner),
         context,
+        type_info: attr.type_info.map(SpanContainer::into_inner).or(type_info),
         scalar,
         fields,
+        flattened: vec![],
         interfaces: attr
             .interfaces
             .iter()
             .map(|ty| ty.as_ref().clone())
             .collect(),
+        is_node: attr.node.is_some(),
+        nodes,
+        complex: attr.complex.is_some().then_some(ComplexPart::Impl),
         _operation: PhantomData,
     };
 
@@ -158,7 +213,7 @@ fn parse_field(
         .map_err(diagnostic::emit_error)
         .ok()?;
 
-    if attr.ignore.is_some() {
+    if attr.ignore.is_some() || attr.check_no_flatten(ERR) {
         return None;
     }
 
@@ -222,6 +277,9 @@ fn parse_field(
         ty,
         description: attr.description.map(SpanContainer::into_inner),
         deprecated: attr.deprecated.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
+        guard: attr.guard.map(SpanContainer::into_inner),
         ident: method_ident.clone(),
         arguments: Some(arguments),
         has_receiver: method.sig.receiver().is_some(),
@@ -9,12 +9,12 @@ use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned as _};
 use crate::common::{
     SpanContainer,
     diagnostic::{self, ResultExt as _},
-    field,
+    field, name,
     parse::TypeExt as _,
     rename, scalar,
 };
 
-use super::{Attr, Definition, Query};
+use super::{Attr, ComplexPart, Definition, Query};
 
 /// [`diagnostic::Scope`] of errors for `#[derive(GraphQLObject)]` macro.
 const ERR: diagnostic::Scope = diagnostic::Scope::ObjectDerive;
@@ -55,6 +55,16 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
         );
     }
 
+    let name = name::Template::parse(name, &ast.generics).map_err(|e| {
+        ERR.custom_error(
+            attr.name
+                .as_ref()
+                .map(SpanContainer::span_ident)
+                .unwrap_or_else(|| struct_ident.span()),
+            e,
+        )
+    })?;
+
     let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
 
     diagnostic::abort_if_dirty();
@@ -65,13 +75,13 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
         .copied()
         .unwrap_or(rename::Policy::CamelCase);
 
-    let mut fields = vec![];
+    let (mut fields, mut flattened) = (vec![], vec![]);
     if let syn::Data::Struct(data) = &ast.data {
         if let syn::Fields::Named(fs) = &data.fields {
             fields = fs
                 .named
                 .iter()
-                .filter_map(|f| parse_field(f, &renaming))
+                .filter_map(|f| parse_field(f, &renaming, &mut flattened))
                 .collect();
         } else {
             ERR.emit_custom(struct_span, "only named fields are allowed");
@@ -80,40 +90,57 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
 
     diagnostic::abort_if_dirty();
 
-    if fields.is_empty() {
+    if fields.is_empty() && flattened.is_empty() && attr.complex.is_none() {
         ERR.emit_custom(struct_span, "must have at least one field");
     }
     if !field::all_different(&fields) {
         ERR.emit_custom(struct_span, "must have a different name for each field");
     }
+    attr.check_relay_nodes(&fields, ERR);
+    attr.check_type_info(ERR);
 
     diagnostic::abort_if_dirty();
 
+    let nodes = attr.sorted_nodes();
     Ok(Definition {
         name,
         ty,
         generics: ast.generics,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
+        guard: attr.guard.map(SpanContainer::into_inner),
         context: attr
             .context
             .map(SpanContainer::into_inner)
             .unwrap_or_else(|| parse_quote! { () }),
+        type_info: attr.type_info.map(SpanContainer::into_inner),
         scalar,
         fields,
+        flattened,
         interfaces: attr
             .interfaces
             .iter()
             .map(|ty| ty.as_ref().clone())
             .collect(),
+        is_node: attr.node.is_some(),
+        nodes,
+        complex: attr.complex.is_some().then_some(ComplexPart::Struct),
         _operation: PhantomData,
     })
 }
 
 /// Parses a [`field::Definition`] from the given Rust struct [`syn::Field`].
 ///
-/// Returns [`None`] if parsing fails, or the struct field is ignored.
+/// Returns [`None`] if parsing fails, or the struct field is ignored or
+/// flattened. Flattened struct fields are pushed into the provided `flattened`
+/// ones instead.
 #[must_use]
-fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::Definition> {
+fn parse_field(
+    field: &syn::Field,
+    renaming: &rename::Policy,
+    flattened: &mut Vec<(syn::Ident, syn::Type)>,
+) -> Option<field::Definition> {
     let attr = field::Attr::from_attrs("graphql", &field.attrs)
         .map_err(diagnostic::emit_error)
         .ok()?;
@@ -124,6 +151,11 @@ fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::D
 
     let field_ident = field.ident.as_ref().unwrap();
 
+    if attr.flatten.is_some() {
+        flattened.push((field_ident.clone(), field.ty.unparenthesized().clone()));
+        return None;
+    }
+
     let name = attr
         .name
         .as_ref()
@@ -147,6 +179,9 @@ fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::D
         ty,
         description: attr.description.map(SpanContainer::into_inner),
         deprecated: attr.deprecated.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
+        guard: attr.guard.map(SpanContainer::into_inner),
         ident: field_ident.clone(),
         arguments: None,
         has_receiver: false,
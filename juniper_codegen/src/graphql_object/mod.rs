@@ -18,12 +18,15 @@ use syn::{
 };
 
 use crate::common::{
-    AttrNames, Description, SpanContainer, field, filter_attrs, generate,
+    AttrNames, Description, SpanContainer, diagnostic, directive, field, filter_attrs, generate,
+    guard, name,
     parse::{
         GenericsExt as _, ParseBufferExt as _, TypeExt,
         attr::{OptionExt as _, err},
     },
     rename, scalar,
+    type_info::{self, TypeInfo},
+    visibility,
 };
 
 /// Available arguments behind `#[graphql]` (or `#[graphql_object]`) attribute
@@ -34,6 +37,8 @@ use crate::common::{
 pub(crate) struct Attr {
     /// Explicitly specified name of this [GraphQL object][1] type.
     ///
+    /// May contain `{T}` placeholders of the Rust type parameters.
+    ///
     /// If [`None`], then Rust type name is used by default.
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
@@ -48,6 +53,25 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     pub(crate) description: Option<SpanContainer<Description>>,
 
+    /// Explicitly specified [directives][2] applied to this [GraphQL
+    /// object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Vec<directive::Directive>,
+
+    /// Explicitly specified [`visibility::Predicate`] of this [GraphQL
+    /// object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) visible: Option<SpanContainer<visibility::Predicate>>,
+
+    /// Explicitly specified [`guard::Guard`] of all the fields of this
+    /// [GraphQL object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) guard: Option<SpanContainer<guard::Guard>>,
+
     /// Explicitly specified type of [`Context`] to use for resolving this
     /// [GraphQL object][1] type with.
     ///
@@ -57,6 +81,15 @@ pub(crate) struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
     pub(crate) context: Option<SpanContainer<syn::Type>>,
 
+    /// Explicitly specified type of [`TypeInfo`] to use for resolving this
+    /// [GraphQL object][1] type with.
+    ///
+    /// If [`None`], then unit type `()` is assumed as a type of [`TypeInfo`].
+    ///
+    /// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) type_info: Option<SpanContainer<TypeInfo>>,
+
     /// Explicitly specified type (or type parameter with its bounds) of
     /// [`ScalarValue`] to use for resolving this [GraphQL object][1] type with.
     ///
@@ -78,6 +111,27 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/October2021#sec-Interfaces
     pub(crate) interfaces: HashSet<SpanContainer<syn::Type>>,
 
+    /// Explicitly specified marker indicating that this [GraphQL object][1]
+    /// implements the Relay [`Node` interface][2].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://relay.dev/graphql/objectidentification.htm#sec-Node-Interface
+    pub(crate) node: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified Rust types of the Relay [`Node`s][2] fetched by the
+    /// generated `node` and `nodes` fields of this [GraphQL object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://relay.dev/graphql/objectidentification.htm#sec-Node-Interface
+    pub(crate) nodes: HashSet<SpanContainer<syn::Type>>,
+
+    /// Explicitly specified marker indicating that this [GraphQL object][1] is
+    /// split between a `#[derive(GraphQLObject)]` struct and a
+    /// `#[graphql_object]` impl block.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) complex: Option<SpanContainer<syn::Ident>>,
+
     /// Explicitly specified [`rename::Policy`] for all fields of this
     /// [GraphQL object][1] type.
     ///
@@ -116,6 +170,28 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(desc.span()), desc))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse()?);
+                }
+                "visible" => {
+                    input.parse::<token::Eq>()?;
+                    let predicate = input.parse::<visibility::Predicate>()?;
+                    out.visible
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(predicate.span()),
+                            predicate,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "guard" => {
+                    input.parse::<token::Eq>()?;
+                    let guard = input.parse::<guard::Guard>()?;
+                    out.guard
+                        .replace(SpanContainer::new(ident.span(), Some(guard.span()), guard))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ctx" | "context" | "Context" => {
                     input.parse::<token::Eq>()?;
                     let ctx = input.parse::<syn::Type>()?;
@@ -123,6 +199,13 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(ctx.span()), ctx))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "type_info" => {
+                    input.parse::<token::Eq>()?;
+                    let ti = input.parse::<TypeInfo>()?;
+                    out.type_info
+                        .replace(SpanContainer::new(ident.span(), Some(ti.span()), ti))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "scalar" | "Scalar" | "ScalarValue" => {
                     input.parse::<token::Eq>()?;
                     let scl = input.parse::<scalar::AttrValue>()?;
@@ -142,6 +225,26 @@ impl Parse for Attr {
                             .none_or_else(|_| err::dup_arg(iface_span))?;
                     }
                 }
+                "node" => out
+                    .node
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                "nodes" => {
+                    input.parse::<token::Eq>()?;
+                    for node in input.parse_maybe_wrapped_and_punctuated::<
+                        syn::Type, token::Bracket, token::Comma,
+                    >()? {
+                        let node_span = node.span();
+                        out
+                            .nodes
+                            .replace(SpanContainer::new(ident.span(), Some(node_span), node))
+                            .none_or_else(|_| err::dup_arg(node_span))?;
+                    }
+                }
+                "complex" => out
+                    .complex
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
                 "rename_all" => {
                     input.parse::<token::Eq>()?;
                     let val = input.parse::<syn::LitStr>()?;
@@ -173,9 +276,20 @@ impl Attr {
         Ok(Self {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            visible: try_merge_opt!(visible: self, another),
+            guard: try_merge_opt!(guard: self, another),
             context: try_merge_opt!(context: self, another),
+            type_info: try_merge_opt!(type_info: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             interfaces: try_merge_hashset!(interfaces: self, another => span_joined),
+            node: try_merge_opt!(node: self, another),
+            nodes: try_merge_hashset!(nodes: self, another => span_joined),
+            complex: try_merge_opt!(complex: self, another),
             rename_fields: try_merge_opt!(rename_fields: self, another),
             is_internal: self.is_internal || another.is_internal,
         })
@@ -194,6 +308,137 @@ impl Attr {
 
         Ok(attr)
     }
+
+    /// Emits errors about the Relay `node` and `nodes` arguments of this
+    /// [`Attr`] being incompatible with the provided `fields`, if any.
+    fn check_relay_nodes(&self, fields: &[field::Definition], scope: diagnostic::Scope) {
+        if let Some(node) = &self.node {
+            if !fields.iter().any(|f| f.name == "id") {
+                scope.emit_custom(
+                    node.span_ident(),
+                    "marked as `node` must have an `id: ID!` field",
+                );
+            }
+        }
+        if let Some(node) = self.nodes.iter().next() {
+            if let Some(f) = fields
+                .iter()
+                .find(|f| f.name == "node" || f.name == "nodes")
+            {
+                scope.emit_custom(
+                    node.span_ident(),
+                    format!(
+                        "with `nodes` argument cannot have its own `{}` field",
+                        f.name,
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Emits errors about the arguments of this [`Attr`] being incompatible
+    /// with its `type_info` argument, if any.
+    ///
+    /// Custom [`TypeInfo`] cannot be propagated to the [GraphQL interfaces][2],
+    /// as they're always resolved with the unit type `()` as [`TypeInfo`].
+    /// Also, the name and fields overridden by it at runtime would bypass the
+    /// compile-time checks of implementing the [GraphQL interfaces][2] (like
+    /// hiding a field required by an interface).
+    ///
+    /// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+    /// [2]: https://spec.graphql.org/October2021#sec-Interfaces
+    fn check_type_info(&self, scope: diagnostic::Scope) {
+        if self.type_info.is_none() {
+            return;
+        }
+        let spans = [
+            self.interfaces
+                .iter()
+                .next()
+                .map(|a| ("impl", a.span_ident())),
+            self.node.as_ref().map(|a| ("node", a.span_ident())),
+            self.nodes.iter().next().map(|a| ("nodes", a.span_ident())),
+        ];
+        for (arg, span) in spans.into_iter().flatten() {
+            scope.emit_custom(
+                span,
+                format!(
+                    "with `type_info` argument cannot have `{arg}` argument, as GraphQL \
+                     interfaces are always resolved with the unit type `()` as their type info, \
+                     and its runtime name and fields would bypass the compile-time checks of \
+                     implementing them",
+                ),
+            );
+        }
+    }
+
+    /// Emits errors about the arguments of this [`Attr`] placed on a
+    /// `#[graphql_object(complex)]` impl block, which may be specified on the
+    /// `#[derive(GraphQLObject)]` struct only, if any.
+    fn check_complex_impl(&self, scope: diagnostic::Scope) {
+        let spans = [
+            self.name.as_ref().map(|a| ("name", a.span_ident())),
+            self.description
+                .as_ref()
+                .map(|a| ("description", a.span_ident())),
+            self.context.as_ref().map(|a| ("context", a.span_ident())),
+            self.scalar.as_ref().map(|a| ("scalar", a.span_ident())),
+            self.visible.as_ref().map(|a| ("visible", a.span_ident())),
+            self.guard.as_ref().map(|a| ("guard", a.span_ident())),
+            self.interfaces
+                .iter()
+                .next()
+                .map(|a| ("impl", a.span_ident())),
+            self.node.as_ref().map(|a| ("node", a.span_ident())),
+            self.nodes.iter().next().map(|a| ("nodes", a.span_ident())),
+            self.type_info
+                .as_ref()
+                .map(|a| ("type_info", a.span_ident())),
+        ];
+        for (arg, span) in spans.into_iter().flatten() {
+            scope.emit_custom(
+                span,
+                format!(
+                    "with `complex` argument cannot have `{arg}` argument, specify it on the \
+                     `#[derive(GraphQLObject)]` struct instead",
+                ),
+            );
+        }
+        if !self.directives.is_empty() {
+            scope.emit_custom(
+                self.complex.as_ref().unwrap().span_ident(),
+                "with `complex` argument cannot have `directive` arguments, specify them on the \
+                 `#[derive(GraphQLObject)]` struct instead",
+            );
+        }
+    }
+
+    /// Returns Rust types of the Relay `nodes` of this [`Attr`], sorted to
+    /// guarantee the order of types registered in schema.
+    fn sorted_nodes(&self) -> Vec<syn::Type> {
+        let mut nodes: Vec<_> = self.nodes.iter().map(|ty| ty.as_ref().clone()).collect();
+        nodes.sort_unstable_by_key(|ty| quote!(#ty).to_string());
+        nodes
+    }
+}
+
+/// Part of a [GraphQL object][1] split between a `#[derive(GraphQLObject)]`
+/// struct and a `#[graphql_object]` impl block via `complex` argument.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ComplexPart {
+    /// `#[derive(GraphQLObject)]` struct, defining the [GraphQL object][1]
+    /// itself along with its struct fields.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    Struct,
+
+    /// `#[graphql_object]` impl block, defining additional fields of the
+    /// [GraphQL object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    Impl,
 }
 
 /// Definition of [GraphQL object][1] for code generation.
@@ -203,8 +448,10 @@ impl Attr {
 pub(crate) struct Definition<Operation: ?Sized> {
     /// Name of this [GraphQL object][1] in GraphQL schema.
     ///
+    /// May be templated with type parameters of the Rust type.
+    ///
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
-    pub(crate) name: String,
+    pub(crate) name: name::Template,
 
     /// Rust type that this [GraphQL object][1] is represented with.
     ///
@@ -224,6 +471,23 @@ pub(crate) struct Definition<Operation: ?Sized> {
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
     pub(crate) description: Option<Description>,
 
+    /// [Directives][2] applied to this [GraphQL object][1] in GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Vec<directive::Directive>,
+
+    /// [`visibility::Predicate`] of this [GraphQL object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) visible: Option<visibility::Predicate>,
+
+    /// [`guard::Guard`] of all the fields of this [GraphQL object][1], checked
+    /// before the own [`guard::Guard`] of a field.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) guard: Option<guard::Guard>,
+
     /// Rust type of [`Context`] to generate [`GraphQLType`] implementation with
     /// for this [GraphQL object][1].
     ///
@@ -232,6 +496,16 @@ pub(crate) struct Definition<Operation: ?Sized> {
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
     pub(crate) context: syn::Type,
 
+    /// Custom Rust type of [`TypeInfo`] to generate [`GraphQLType`]
+    /// implementation with for this [GraphQL object][1].
+    ///
+    /// If [`None`], then unit type `()` is used.
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) type_info: Option<TypeInfo>,
+
     /// [`ScalarValue`] parametrization to generate [`GraphQLType`]
     /// implementation with for this [GraphQL object][1].
     ///
@@ -246,12 +520,42 @@ pub(crate) struct Definition<Operation: ?Sized> {
     /// [2]: https://spec.graphql.org/October2021#sec-Language.Fields
     pub(crate) fields: Vec<field::Definition>,
 
+    /// Flattened [GraphQL objects][1] along with the Rust struct fields holding
+    /// them, whose [GraphQL fields][2] are inlined into this
+    /// [GraphQL object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) flattened: Vec<(syn::Ident, syn::Type)>,
+
     /// [GraphQL interfaces][2] implemented by this [GraphQL object][1].
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
     /// [2]: https://spec.graphql.org/October2021#sec-Interfaces
     pub(crate) interfaces: HashSet<syn::Type>,
 
+    /// Indicator whether this [GraphQL object][1] implements the Relay
+    /// [`Node` interface][2].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://relay.dev/graphql/objectidentification.htm#sec-Node-Interface
+    pub(crate) is_node: bool,
+
+    /// Rust types of the Relay [`Node`s][2] fetched by the generated `node` and
+    /// `nodes` fields of this [GraphQL object][1].
+    ///
+    /// Sorted to guarantee the order of types registered in schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://relay.dev/graphql/objectidentification.htm#sec-Node-Interface
+    pub(crate) nodes: Vec<syn::Type>,
+
+    /// [`ComplexPart`] of this [GraphQL object][1], if it's split between a
+    /// `#[derive(GraphQLObject)]` struct and a `#[graphql_object]` impl block.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) complex: Option<ComplexPart>,
+
     /// [GraphQL operation][1] this [`Definition`] should generate code for.
     ///
     /// Either [GraphQL query][2] or [GraphQL subscription][3].
@@ -289,6 +593,60 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
         if let Some(bound) = scalar.bounds() {
             generics.make_where_clause().predicates.push(bound);
         }
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(self.name.bounds(scalar));
+        if !self.nodes.is_empty() {
+            let context = &self.context;
+            let predicates = &mut generics.make_where_clause().predicates;
+            predicates.push(parse_quote! { #context: ::core::marker::Sync });
+            if scalar.is_generic() {
+                predicates
+                    .push(parse_quote! { #scalar: ::core::marker::Send + ::core::marker::Sync });
+            }
+            predicates.extend(self.nodes.iter().map(|node| -> syn::WherePredicate {
+                parse_quote! { #node: ::juniper::relay::Node<#scalar, Context = #context> }
+            }));
+        }
+
+        if !self.flattened.is_empty() {
+            let context = &self.context;
+            let type_info = type_info::ty_tokens(self.type_info.as_ref());
+            let predicates = &mut generics.make_where_clause().predicates;
+            for (_, ty) in &self.flattened {
+                predicates.push(parse_quote! {
+                    #ty: ::juniper::GraphQLType<#scalar, Context = #context>
+                        + ::juniper::macros::reflect::Fields<#scalar>
+                });
+                predicates.push(parse_quote! {
+                    #ty: ::juniper::macros::helper::FlattenedTypeInfo<#scalar, #type_info>
+                });
+                if for_async {
+                    predicates.push(parse_quote! { #ty: ::juniper::GraphQLValueAsync<#scalar> });
+                    predicates.push(parse_quote! {
+                        <#ty as ::juniper::GraphQLValue<#scalar>>::TypeInfo: ::core::marker::Sync
+                    });
+                }
+            }
+        }
+        if self.complex == Some(ComplexPart::Struct) {
+            let context = &self.context;
+            let type_info = type_info::ty_tokens(self.type_info.as_ref());
+            let predicates = &mut generics.make_where_clause().predicates;
+            predicates.push(parse_quote! {
+                Self: ::juniper::macros::helper::ComplexObject<
+                    #scalar,
+                    Context = #context,
+                    TypeInfo = #type_info,
+                >
+            });
+            if for_async {
+                predicates.push(
+                    parse_quote! { Self: ::juniper::macros::helper::AsyncComplexObject<#scalar> },
+                );
+            }
+        }
 
         if for_async {
             let self_ty = if self.generics.lifetimes().next().is_some() {
@@ -352,6 +710,7 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
         });
 
         let interface_tys = self.interfaces.iter();
+        let flattened_tys = self.flattened.iter().map(|(_, ty)| ty);
 
         quote! {
             #[automatically_derived]
@@ -359,6 +718,7 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
             {
                 fn mark() {
                     #( #fields_marks )*
+                    #( <#flattened_tys as ::juniper::marker::IsOutputType<#scalar>>::mark(); )*
                     #( #assert_args_deprecable )*
                     #( <#interface_tys as ::juniper::marker::IsOutputType<#scalar>>::mark(); )*
                 }
@@ -377,11 +737,34 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
     #[must_use]
     pub(crate) fn impl_reflection_traits_tokens(&self) -> TokenStream {
         let scalar = &self.scalar;
-        let name = &self.name;
+        let name = self.name.as_str();
         let (impl_generics, where_clause) = self.impl_generics(false);
         let ty = &self.ty;
         let fields = self.fields.iter().map(|f| &f.name);
-        let interfaces = self.interfaces.iter();
+        let fields = self
+            .flattened
+            .iter()
+            .map(|(_, ty)| quote! { <#ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES })
+            .chain((self.complex == Some(ComplexPart::Struct)).then(|| {
+                quote! { <Self as ::juniper::macros::helper::ComplexObject<#scalar>>::NAMES }
+            }))
+            .fold(quote! { &[#(#fields),*] }, |names, more| {
+                let err = format!(
+                    "GraphQL object `{name}` cannot have more than \
+                     `juniper::macros::reflect::MAX_CONCAT_NAMES` fields in total, including the \
+                     flattened and `complex` ones",
+                );
+                quote! {
+                    ::juniper::macros::reflect::names_from_buf(
+                        &::juniper::macros::reflect::concat_names(#names, #more, #err),
+                    )
+                }
+            });
+        let context = &self.context;
+        let node_interface: Option<syn::Type> = self
+            .is_node
+            .then(|| parse_quote! { ::juniper::relay::NodeValue<#context, #scalar> });
+        let interfaces = self.interfaces.iter().chain(&node_interface);
 
         quote! {
             #[automatically_derived]
@@ -423,7 +806,7 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
                 for #ty
                 #where_clause
             {
-                const NAMES: ::juniper::macros::reflect::Names = &[#(#fields),*];
+                const NAMES: ::juniper::macros::reflect::Names = #fields;
             }
         }
     }
@@ -436,12 +819,15 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
     #[must_use]
     pub(crate) fn impl_graphql_type_tokens(&self) -> TokenStream {
         let scalar = &self.scalar;
+        let info = type_info::child_tokens();
 
         let (impl_generics, where_clause) = self.impl_generics(false);
         let ty = &self.ty;
 
-        let name = &self.name;
+        let name = self.name.arcstr_tokens(scalar);
         let description = &self.description;
+        let directives = &self.directives;
+        let visible = &self.visible;
 
         let extract_stream_type = TypeId::of::<Operation>() != TypeId::of::<Query>();
         let fields_meta = self
@@ -455,39 +841,153 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
             let (a, b) = (quote!(#a).to_string(), quote!(#b).to_string());
             a.cmp(&b)
         });
-        let interfaces = (!interface_tys.is_empty()).then(|| {
+        let node_interface = self.is_node.then(|| {
+            let context = &self.context;
+            quote! { registry.get_type::<::juniper::relay::NodeValue<#context, #scalar>>(#info), }
+        });
+        let interfaces = (!interface_tys.is_empty() || self.is_node).then(|| {
             quote! {
                 .interfaces(&[
-                    #( registry.get_type::<#interface_tys>(info), )*
+                    #( registry.get_type::<#interface_tys>(#info), )*
+                    #node_interface
                 ])
             }
         });
 
+        let (node_fields_meta, node_fields) = self
+            .node_fetchers_tokens()
+            .map(|fetchers| {
+                let context = &self.context;
+                let meta = quote! {
+                    let [node_field, nodes_field] =
+                        ::juniper::relay::node_fields_meta::<#context, #scalar>(
+                            registry,
+                            &#fetchers,
+                        );
+                };
+                (meta, quote! { node_field, nodes_field, })
+            })
+            .unzip();
+
+        let extra_fields_meta = self
+            .flattened
+            .iter()
+            .map(|(_, ty)| {
+                let info = type_info::flattened_tokens(ty, scalar, self.type_info.as_ref());
+                quote! {
+                    ::juniper::macros::helper::merged_object_fields(
+                        &#name,
+                        <#ty as ::juniper::GraphQLType<#scalar>>::meta(#info, registry),
+                    )
+                    .unwrap_or_else(|e| {
+                        registry.add_error(e);
+                        ::std::vec::Vec::new()
+                    })
+                }
+            })
+            .chain((self.complex == Some(ComplexPart::Struct)).then(|| {
+                quote! {
+                    <Self as ::juniper::macros::helper::ComplexObject<#scalar>>::fields_meta(
+                        info, registry,
+                    )
+                }
+            }))
+            .collect::<Vec<_>>();
+        let extra_fields_meta = (!extra_fields_meta.is_empty()).then(|| {
+            quote! {
+                let fields: ::std::vec::Vec<_> = ::core::iter::IntoIterator::into_iter(fields)
+                    #( .chain(#extra_fields_meta) )*
+                    .collect();
+            }
+        });
+
+        let name_method = type_info::name_method_tokens(self.type_info.as_ref(), &name);
+        let fields_filter = type_info::fields_filter_tokens(self.type_info.as_ref());
+        let dyn_description = type_info::description_tokens(self.type_info.as_ref());
+
         quote! {
             #[automatically_derived]
             impl #impl_generics ::juniper::GraphQLType<#scalar> for #ty #where_clause
             {
-                fn name(_ : &Self::TypeInfo) -> ::core::option::Option<::juniper::ArcStr> {
-                    ::core::option::Option::Some(::juniper::arcstr::literal!(#name))
-                }
+                #name_method
 
                 fn meta(
                     info: &Self::TypeInfo,
                     registry: &mut ::juniper::Registry<#scalar>,
                 ) -> ::juniper::meta::MetaType<#scalar> {
+                    #node_fields_meta
                     let fields = [
                         #( #fields_meta, )*
+                        #node_fields
                     ];
-                    registry.build_object_type::<#ty>(info, &fields)
+                    #extra_fields_meta
+                    #fields_filter
+                    let meta = registry.build_object_type::<#ty>(info, &fields)
                         #description
-                        #interfaces
-                        .into_meta()
+                        #( #directives )*
+                        #visible
+                        #interfaces;
+                    #dyn_description
+                    meta.into_meta()
+                }
+            }
+        }
+    }
+
+    /// Returns generated code for the [`GraphQLValue::concrete_type_name`]
+    /// method of this [GraphQL object][1], respecting its custom
+    /// [`TypeInfo`], if any.
+    ///
+    /// [`GraphQLValue::concrete_type_name`]: juniper::GraphQLValue::concrete_type_name
+    /// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    pub(crate) fn method_concrete_type_name_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+        let name = self.name.string_tokens(scalar);
+
+        if self.type_info.is_some() {
+            quote! {
+                fn concrete_type_name(
+                    &self,
+                    _: &Self::Context,
+                    info: &Self::TypeInfo,
+                ) -> ::std::string::String {
+                    <Self as ::juniper::GraphQLType<#scalar>>::name(info)
+                        .map_or_else(|| #name, |name| name.to_string())
+                }
+            }
+        } else {
+            quote! {
+                fn concrete_type_name(
+                    &self,
+                    _: &Self::Context,
+                    _: &Self::TypeInfo,
+                ) -> ::std::string::String {
+                    #name
                 }
             }
         }
     }
 }
 
+impl<Operation: ?Sized> Definition<Operation> {
+    /// Returns generated code of an array of [`NodeFetcher`]s of the Relay
+    /// `nodes` of this [GraphQL object][1], if it has any.
+    ///
+    /// [`NodeFetcher`]: juniper::relay::NodeFetcher
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn node_fetchers_tokens(&self) -> Option<TokenStream> {
+        let (context, scalar, nodes) = (&self.context, &self.scalar, &self.nodes);
+        (!nodes.is_empty()).then(|| {
+            quote! {
+                [#( ::juniper::relay::NodeFetcher::<#context, #scalar>::of::<#nodes>(), )*]
+            }
+        })
+    }
+}
+
 /// [GraphQL query operation][2] of the [`Definition`] to generate code for.
 ///
 /// [2]: https://spec.graphql.org/October2021#sec-Query
@@ -495,7 +995,17 @@ struct Query;
 
 impl ToTokens for Definition<Query> {
     fn to_tokens(&self, into: &mut TokenStream) {
+        if self.complex == Some(ComplexPart::Impl) {
+            self.impl_complex_object_tokens().to_tokens(into);
+            self.impl_field_meta_tokens().to_tokens(into);
+            self.impl_field_tokens().to_tokens(into);
+            self.impl_async_field_tokens().to_tokens(into);
+            return;
+        }
+
         self.impl_graphql_object_tokens().to_tokens(into);
+        self.impl_complex_object_struct_tokens().to_tokens(into);
+        self.impl_interface_guards_tokens().to_tokens(into);
         self.impl_output_type_tokens().to_tokens(into);
         self.impl_graphql_type_tokens().to_tokens(into);
         self.impl_graphql_value_tokens().to_tokens(into);
@@ -522,6 +1032,7 @@ impl Definition<Query> {
         let ty = &self.ty;
 
         let interface_tys = self.interfaces.iter();
+        let flattened_tys = self.flattened.iter().map(|(_, ty)| ty);
 
         let generics = {
             let mut generics = self.generics.clone();
@@ -542,11 +1053,61 @@ impl Definition<Query> {
         //    quote! { ::juniper::sa::assert_type_ne_all!(#( #interface_tys ),*); }
         //});
 
+        let node_checks = self.is_node.then(|| {
+            let (mut const_ty, mut const_context) = (ty.clone(), self.context.clone());
+            generics.replace_type_with_defaults(&mut const_ty);
+            generics.replace_type_with_defaults(&mut const_context);
+            quote! {
+                ::juniper::assert_field!(
+                    ::juniper::relay::NodeValue<#const_context, #const_scalar>,
+                    #const_ty,
+                    #const_scalar,
+                    "id",
+                );
+            }
+        });
+
+        let nodes_checks = self.nodes.iter().map(|node| {
+            let mut const_node = node.clone();
+            generics.replace_type_with_defaults(&mut const_node);
+            quote! {
+                const _: () = {
+                    if !::juniper::macros::reflect::str_exists_in_arr(
+                        <::juniper::relay::NodeValue<(), #const_scalar>
+                            as ::juniper::macros::reflect::BaseType<#const_scalar>>::NAME,
+                        <#const_node as ::juniper::macros::reflect::Implements<#const_scalar>>
+                            ::NAMES,
+                    ) {
+                        const MSG: &str = ::juniper::const_concat!(
+                            "type `",
+                            <#const_node as ::juniper::macros::reflect::BaseType<#const_scalar>>
+                                ::NAME,
+                            "` listed in `nodes` must implement `Node` interface via ",
+                            "`#[graphql(node)]` attribute",
+                        );
+                        ::core::panic!("{}", MSG);
+                    }
+                };
+            }
+        });
+
+        // Fields of a complex object are checked in its `#[graphql_object(complex)]` impl block.
+        let unique_fields_check =
+            (!self.flattened.is_empty() && self.complex != Some(ComplexPart::Struct)).then(|| {
+                let mut const_ty = ty.clone();
+                generics.replace_type_with_defaults(&mut const_ty);
+                quote! { ::juniper::assert_unique_fields!(#const_ty, #const_scalar); }
+            });
+
         quote! {
             #[automatically_derived]
             impl #impl_generics ::juniper::marker::GraphQLObject<#scalar> for #ty #where_clause
             {
                 fn mark() {
+                    #unique_fields_check
+                    #node_checks
+                    #( #nodes_checks )*
+                    #( <#flattened_tys as ::juniper::marker::GraphQLObject<#scalar>>::mark(); )*
                     #( <#interface_tys as ::juniper::marker::GraphQLInterface<#scalar>>::mark(); )*
                     ::juniper::assert_implemented_for!(
                         #const_scalar, #ty, #(#const_interface_tys),*
@@ -556,6 +1117,177 @@ impl Definition<Query> {
         }
     }
 
+    /// Returns generated code implementing [`ComplexObjectStruct`] trait for
+    /// this [`ComplexPart::Struct`] of a [GraphQL object][1], if it's the one.
+    ///
+    /// [`ComplexObjectStruct`]: juniper::macros::helper::ComplexObjectStruct
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn impl_complex_object_struct_tokens(&self) -> Option<TokenStream> {
+        (self.complex == Some(ComplexPart::Struct)).then(|| {
+            let context = &self.context;
+            let type_info = type_info::ty_tokens(self.type_info.as_ref());
+            let (impl_generics, _, where_clause) = self.generics.split_for_impl();
+            let ty = &self.ty;
+
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::juniper::macros::helper::ComplexObjectStruct for #ty
+                    #where_clause
+                {
+                    type Context = #context;
+                    type TypeInfo = #type_info;
+                }
+            }
+        })
+    }
+
+    /// Returns generated code implementing [`InterfaceGuards`] trait for this
+    /// [GraphQL object][1], checking the [`Guard`]s of all the [GraphQL
+    /// interfaces][2] it implements.
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [`InterfaceGuards`]: juniper::macros::helper::InterfaceGuards
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Interfaces
+    #[must_use]
+    fn impl_interface_guards_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let interface_tys = self.interfaces.iter();
+        let (field, context) = if self.interfaces.is_empty() {
+            (quote! { _ }, quote! { _ })
+        } else {
+            (quote! { field }, quote! { context })
+        };
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::juniper::macros::helper::InterfaceGuards<#scalar> for #ty
+                #where_clause
+            {
+                fn check_guards(
+                    #field: &::core::primitive::str,
+                    #context: &Self::Context,
+                ) -> ::juniper::FieldResult<(), #scalar> {
+                    #( <#interface_tys as ::juniper::macros::helper::InterfaceGuards<#scalar>>
+                        ::check_guards(field, context)?; )*
+                    ::core::result::Result::Ok(())
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`ComplexObject`] and
+    /// [`AsyncComplexObject`] traits for this [`ComplexPart::Impl`] of a
+    /// [GraphQL object][1].
+    ///
+    /// [`AsyncComplexObject`]: juniper::macros::helper::AsyncComplexObject
+    /// [`ComplexObject`]: juniper::macros::helper::ComplexObject
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn impl_complex_object_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+        let context = &self.context;
+        let type_info = type_info::ty_tokens(self.type_info.as_ref());
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let (impl_generics_async, where_clause_async) = self.impl_generics(true);
+        let ty = &self.ty;
+        let ty_name = ty.to_token_stream().to_string();
+
+        let names = self.fields.iter().map(|f| &f.name);
+        let fields_meta = self.fields.iter().map(|f| f.method_meta_tokens(None));
+
+        let fields_resolvers = self.fields.iter().map(|f| {
+            let name = &f.name;
+            quote! {
+                #name => {
+                    ::juniper::macros::reflect::Field::<
+                        #scalar,
+                        { ::juniper::macros::reflect::fnv1a128(#name) }
+                    >::call(self, info, args, executor)
+                }
+            }
+        });
+        let fields_async_resolvers = self.fields.iter().map(|f| {
+            let name = &f.name;
+            quote! {
+                #name => {
+                    ::juniper::macros::reflect::AsyncField::<
+                        #scalar,
+                        { ::juniper::macros::reflect::fnv1a128(#name) }
+                    >::call(self, info, args, executor)
+                }
+            }
+        });
+
+        let no_field_err =
+            field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
+
+        let const_scalar = self.scalar.default_ty();
+        let mut const_ty = ty.clone();
+        self.generics.replace_type_with_defaults(&mut const_ty);
+
+        quote! {
+            ::juniper::assert_unique_fields!(#const_ty, #const_scalar);
+
+            #[allow(deprecated)]
+            #[automatically_derived]
+            impl #impl_generics ::juniper::macros::helper::ComplexObject<#scalar> for #ty
+                #where_clause
+            {
+                type Context = #context;
+                type TypeInfo = #type_info;
+
+                const NAMES: ::juniper::macros::reflect::Names = &[#(#names),*];
+
+                fn fields_meta(
+                    info: &Self::TypeInfo,
+                    registry: &mut ::juniper::Registry<#scalar>,
+                ) -> ::std::vec::Vec<::juniper::meta::Field<#scalar>> {
+                    ::std::vec![#( #fields_meta ),*]
+                }
+
+                fn resolve_complex_field(
+                    &self,
+                    info: &Self::TypeInfo,
+                    field: &::core::primitive::str,
+                    args: &::juniper::Arguments<'_, #scalar>,
+                    executor: &::juniper::Executor<'_, '_, Self::Context, #scalar>,
+                ) -> ::juniper::ExecutionResult<#scalar> {
+                    match field {
+                        #( #fields_resolvers )*
+                        _ => #no_field_err,
+                    }
+                }
+            }
+
+            #[allow(deprecated, non_snake_case)]
+            #[automatically_derived]
+            impl #impl_generics_async ::juniper::macros::helper::AsyncComplexObject<#scalar>
+                for #ty
+                #where_clause_async
+            {
+                fn resolve_complex_field_async<'b>(
+                    &'b self,
+                    info: &'b Self::TypeInfo,
+                    field: &'b ::core::primitive::str,
+                    args: &'b ::juniper::Arguments<'_, #scalar>,
+                    executor: &'b ::juniper::Executor<'_, '_, Self::Context, #scalar>,
+                ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
+                    match field {
+                        #( #fields_async_resolvers )*
+                        _ => ::std::boxed::Box::pin(async move { #no_field_err }),
+                    }
+                }
+            }
+        }
+    }
+
     /// Returns generated code implementing [`FieldMeta`] traits for each field
     /// of this [GraphQL object][1].
     ///
@@ -566,6 +1298,7 @@ impl Definition<Query> {
         let impl_ty = &self.ty;
         let scalar = &self.scalar;
         let context = &self.context;
+        let type_info = type_info::ty_tokens(self.type_info.as_ref());
         let (impl_generics, where_clause) = self.impl_generics(false);
 
         self.fields
@@ -599,7 +1332,7 @@ impl Definition<Query> {
                         { ::juniper::macros::reflect::fnv1a128(#name) }
                     > for #impl_ty #where_clause {
                         type Context = #context;
-                        type TypeInfo = ();
+                        type TypeInfo = #type_info;
                         const TYPE: ::juniper::macros::reflect::Type =
                             <#ty as ::juniper::macros::reflect::BaseType<#scalar>>::NAME;
                         const SUB_TYPES: ::juniper::macros::reflect::Types =
@@ -659,9 +1392,16 @@ impl Definition<Query> {
                         quote! { &self.#ident }
                     };
 
+                    let guards =
+                        guard::checks_tokens(self.guard.iter().chain(&field.guard), scalar, false);
                     let resolving_code = generate::sync_resolving_code();
 
                     quote! {
+                        <Self as ::juniper::macros::helper::InterfaceGuards<#scalar>>::check_guards(
+                            #name,
+                            executor.context(),
+                        )?;
+                        #guards
                         let res: #res_ty = #res;
                         #resolving_code
                     }
@@ -726,6 +1466,8 @@ impl Definition<Query> {
                     res = quote! { ::juniper::futures::future::ready(#res) };
                 }
 
+                let guards =
+                    guard::checks_tokens(self.guard.iter().chain(&field.guard), scalar, true);
                 let resolving_code = generate::async_resolving_code(Some(&res_ty));
 
                 quote! {
@@ -743,6 +1485,12 @@ impl Definition<Query> {
                             args: &'b ::juniper::Arguments<'_, #scalar>,
                             executor: &'b ::juniper::Executor<'_, '_, Self::Context, #scalar>,
                         ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
+                            if let ::core::result::Result::Err(e) = <Self as
+                                ::juniper::macros::helper::InterfaceGuards<#scalar>
+                            >::check_guards(#name, executor.context()) {
+                                return ::std::boxed::Box::pin(::juniper::futures::future::err(e));
+                            }
+                            #guards
                             let fut = #res;
                             #resolving_code
                         }
@@ -761,12 +1509,13 @@ impl Definition<Query> {
     fn impl_graphql_value_tokens(&self) -> TokenStream {
         let scalar = &self.scalar;
         let context = &self.context;
+        let type_info = type_info::ty_tokens(self.type_info.as_ref());
 
         let (impl_generics, where_clause) = self.impl_generics(false);
         let ty = &self.ty;
         let ty_name = ty.to_token_stream().to_string();
 
-        let name = &self.name;
+        let concrete_type_name = self.method_concrete_type_name_tokens();
 
         let fields_resolvers = self.fields.iter().map(|f| {
             let name = &f.name;
@@ -780,8 +1529,48 @@ impl Definition<Query> {
             }
         });
 
-        let no_field_err =
-            field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
+        let guards = guard::checks_tokens(&self.guard, scalar, false);
+
+        let flattened_resolvers = self.flattened.iter().map(|(ident, ty)| {
+            let info = type_info::flattened_tokens(ty, scalar, self.type_info.as_ref());
+            quote! {
+                _ if <#ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES
+                    .contains(&field) =>
+                {
+                    <Self as ::juniper::macros::helper::InterfaceGuards<#scalar>>::check_guards(
+                        field,
+                        executor.context(),
+                    )?;
+                    #guards
+                    <#ty as ::juniper::GraphQLValue<#scalar>>::resolve_field(
+                        &self.#ident, #info, field, args, executor,
+                    )
+                }
+            }
+        });
+
+        let node_fields_resolvers = (!self.nodes.is_empty()).then(|| {
+            quote! {
+                "node" | "nodes" => {
+                    ::core::result::Result::Err(::juniper::FieldError::from(::std::format!(
+                        "Field `{}` on type `{}` can be resolved asynchronously only",
+                        field,
+                        <Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
+                    )))
+                }
+            }
+        });
+
+        let no_field_err = if self.complex == Some(ComplexPart::Struct) {
+            quote! {{
+                #guards
+                <Self as ::juniper::macros::helper::ComplexObject<#scalar>>::resolve_complex_field(
+                    self, info, field, args, executor,
+                )
+            }}
+        } else {
+            field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name)
+        };
 
         quote! {
             #[allow(deprecated)]
@@ -789,7 +1578,7 @@ impl Definition<Query> {
             impl #impl_generics ::juniper::GraphQLValue<#scalar> for #ty #where_clause
             {
                 type Context = #context;
-                type TypeInfo = ();
+                type TypeInfo = #type_info;
 
                 fn type_name(
                     &self,
@@ -807,17 +1596,13 @@ impl Definition<Query> {
                 ) -> ::juniper::ExecutionResult<#scalar> {
                     match field {
                         #( #fields_resolvers )*
+                        #( #flattened_resolvers )*
+                        #node_fields_resolvers
                         _ => #no_field_err,
                     }
                 }
 
-                fn concrete_type_name(
-                    &self,
-                    _: &Self::Context,
-                    _: &Self::TypeInfo,
-                ) -> ::std::string::String {
-                    #name.into()
-                }
+                #concrete_type_name
             }
         }
     }
@@ -847,8 +1632,51 @@ impl Definition<Query> {
             }
         });
 
-        let no_field_err =
-            field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
+        let guards = guard::checks_tokens(&self.guard, scalar, true);
+
+        let flattened_resolvers = self.flattened.iter().map(|(ident, ty)| {
+            let info = type_info::flattened_tokens(ty, scalar, self.type_info.as_ref());
+            quote! {
+                _ if <#ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES
+                    .contains(&field) =>
+                {
+                    if let ::core::result::Result::Err(e) = <Self as
+                        ::juniper::macros::helper::InterfaceGuards<#scalar>
+                    >::check_guards(field, executor.context()) {
+                        return ::std::boxed::Box::pin(::juniper::futures::future::err(e));
+                    }
+                    #guards
+                    <#ty as ::juniper::GraphQLValueAsync<#scalar>>::resolve_field_async(
+                        &self.#ident, #info, field, args, executor,
+                    )
+                }
+            }
+        });
+
+        let node_fields_resolvers = self.node_fetchers_tokens().map(|fetchers| {
+            quote! {
+                "node" | "nodes" => {
+                    #guards
+                    ::juniper::relay::resolve_node_field_async(
+                        field,
+                        args,
+                        executor,
+                        ::std::vec::Vec::from(#fetchers),
+                    )
+                }
+            }
+        });
+
+        let no_field_err = if self.complex == Some(ComplexPart::Struct) {
+            quote! {{
+                #guards
+                <Self as ::juniper::macros::helper::AsyncComplexObject<#scalar>>
+                    ::resolve_complex_field_async(self, info, field, args, executor)
+            }}
+        } else {
+            let err = field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
+            quote! { ::std::boxed::Box::pin(async move { #err }) }
+        };
 
         quote! {
             #[allow(deprecated, non_snake_case)]
@@ -864,7 +1692,9 @@ impl Definition<Query> {
                 ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
                     match field {
                         #( #fields_resolvers )*
-                        _ => ::std::boxed::Box::pin(async move { #no_field_err }),
+                        #( #flattened_resolvers )*
+                        #node_fields_resolvers
+                        _ => #no_field_err,
                     }
                 }
             }
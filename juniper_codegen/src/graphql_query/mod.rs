@@ -0,0 +1,1258 @@
+//! Code generation for `#[derive(GraphQLQuery)]` macro, generating a typed
+//! client of a [GraphQL operation][0] validated against a [GraphQL schema][1]
+//! described in [SDL][2].
+//!
+//! [0]: https://spec.graphql.org/October2021#sec-Executable-Definitions
+//! [1]: https://spec.graphql.org/October2021#sec-Schema
+//! [2]: https://spec.graphql.org/October2021#sec-Type-System
+
+use std::collections::{BTreeSet, HashMap};
+
+use graphql_parser::{
+    Pos,
+    query::{self, Definition, Directive, OperationDefinition, Selection, SelectionSet},
+    schema::{self as sdl, TypeDefinition},
+};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::{
+    common::{
+        SpanContainer, diagnostic, filter_attrs,
+        parse::{
+            ParseBufferExt as _,
+            attr::{OptionExt as _, err},
+        },
+    },
+    graphql_schema::{
+        Generator, ident, is_builtin_scalar, read_file, to_pascal_case, to_snake_case,
+    },
+};
+
+/// [`diagnostic::Scope`] of errors for `#[derive(GraphQLQuery)]` macro.
+const ERR: diagnostic::Scope = diagnostic::Scope::QueryDerive;
+
+/// Executable GraphQL document parsed from a file.
+type Document<'a> = query::Document<'a, &'a str>;
+
+/// Type reference in a GraphQL document.
+type Type<'a> = query::Type<'a, &'a str>;
+
+/// Value in a GraphQL document.
+type Value<'a> = query::Value<'a, &'a str>;
+
+/// Variable definition of a GraphQL operation.
+type VariableDef<'a> = query::VariableDefinition<'a, &'a str>;
+
+/// Field definition in an SDL document.
+type FieldDef<'a> = sdl::Field<'a, &'a str>;
+
+/// Expands `#[derive(GraphQLQuery)]` macro into generated code.
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let ast = syn::parse2::<syn::DeriveInput>(input)?;
+    if !matches!(ast.data, syn::Data::Struct(_)) {
+        return Err(ERR.custom_error(ast.span(), "can only be derived for structs"));
+    }
+    if !ast.generics.params.is_empty() {
+        return Err(ERR.custom_error(ast.generics.span(), "generics are not supported"));
+    }
+
+    let attr = Attr::from_attrs("graphql", &ast.attrs)?;
+    let schema = attr.schema.map(SpanContainer::into_inner).ok_or_else(|| {
+        ERR.custom_error(
+            ast.ident.span(),
+            "expects `schema = \"path/to/schema.graphql\"` attribute argument",
+        )
+    })?;
+    let document = attr
+        .document
+        .map(SpanContainer::into_inner)
+        .ok_or_else(|| {
+            ERR.custom_error(
+                ast.ident.span(),
+                "expects `document = \"path/to/operation.graphql\"` attribute argument",
+            )
+        })?;
+    let scalar = attr
+        .scalar
+        .map(SpanContainer::into_inner)
+        .unwrap_or_else(|| parse_quote! { ::juniper::DefaultScalarValue });
+
+    let (schema_path, sdl_src) = read_file(&schema, &ERR)?;
+    let sdl_doc = sdl::parse_schema::<&str>(&sdl_src).map_err(|e| {
+        ERR.custom_error(
+            schema.span(),
+            format!("file `{}` cannot be parsed: {e}", schema_path.display()),
+        )
+    })?;
+    let (doc_path, src) = read_file(&document, &ERR)?;
+    let doc = query::parse_query::<&str>(&src).map_err(|e| {
+        ERR.custom_error(
+            document.span(),
+            format!("file `{}` cannot be parsed: {e}", doc_path.display()),
+        )
+    })?;
+
+    let schema = Generator::new(&schema, None, Some(scalar.clone()), &sdl_doc)?;
+    let operation = attr
+        .operation
+        .map(SpanContainer::into_inner)
+        .unwrap_or_else(|| ast.ident.to_string());
+    let generated = Codegen::new(&schema, &document, &doc)?.generate(&operation)?;
+
+    let (ty, vis) = (&ast.ident, &ast.vis);
+    let module = ident(&to_snake_case(&ty.to_string()));
+    let doc_comment = format!("Types of the `{operation}` GraphQL operation.");
+    let (types, op_name) = (generated.types, generated.name);
+    Ok(quote! {
+        #[doc = #doc_comment]
+        #vis mod #module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #types
+        }
+
+        #[automatically_derived]
+        impl ::juniper::client::GraphQLQuery<#scalar> for #ty {
+            type Variables = #module::Variables;
+            type ResponseData = #module::ResponseData;
+
+            const OPERATION_NAME: &'static str = #op_name;
+            const DOCUMENT: &'static str = #src;
+        }
+
+        #schema_path
+        #doc_path
+    })
+}
+
+/// Available arguments behind `#[graphql]` attribute when generating code for
+/// a [GraphQL operation][0].
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Executable-Definitions
+#[derive(Debug, Default)]
+struct Attr {
+    /// Path to the SDL file of the GraphQL schema, relative to the
+    /// `CARGO_MANIFEST_DIR`.
+    schema: Option<SpanContainer<syn::LitStr>>,
+
+    /// Path to the file of the executable GraphQL document, relative to the
+    /// `CARGO_MANIFEST_DIR`.
+    document: Option<SpanContainer<syn::LitStr>>,
+
+    /// Explicitly specified name of the GraphQL operation in the document.
+    ///
+    /// If [`None`], then the operation named as the Rust struct is used, or
+    /// the single one in the document.
+    operation: Option<SpanContainer<String>>,
+
+    /// Explicitly specified type of [`ScalarValue`] to use for executing the
+    /// GraphQL operation.
+    ///
+    /// If [`None`], then [`DefaultScalarValue`] is used.
+    ///
+    /// [`DefaultScalarValue`]: juniper::DefaultScalarValue
+    /// [`ScalarValue`]: juniper::ScalarValue
+    scalar: Option<SpanContainer<syn::Type>>,
+}
+
+impl Parse for Attr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse_any_ident()?;
+            match ident.to_string().as_str() {
+                "schema" => {
+                    input.parse::<token::Eq>()?;
+                    let path = input.parse::<syn::LitStr>()?;
+                    out.schema
+                        .replace(SpanContainer::new(ident.span(), Some(path.span()), path))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "document" | "doc" => {
+                    input.parse::<token::Eq>()?;
+                    let path = input.parse::<syn::LitStr>()?;
+                    out.document
+                        .replace(SpanContainer::new(ident.span(), Some(path.span()), path))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "operation" => {
+                    input.parse::<token::Eq>()?;
+                    let name = input.parse::<syn::LitStr>()?;
+                    out.operation
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(name.span()),
+                            name.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "scalar" | "Scalar" | "ScalarValue" => {
+                    input.parse::<token::Eq>()?;
+                    let scl = input.parse::<syn::Type>()?;
+                    out.scalar
+                        .replace(SpanContainer::new(ident.span(), Some(scl.span()), scl))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            input.try_parse::<token::Comma>()?;
+        }
+        Ok(out)
+    }
+}
+
+impl Attr {
+    /// Tries to merge two [`Attr`]s into a single one, reporting about
+    /// duplicates, if any.
+    fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        Ok(Self {
+            schema: try_merge_opt!(schema: self, another),
+            document: try_merge_opt!(document: self, another),
+            operation: try_merge_opt!(operation: self, another),
+            scalar: try_merge_opt!(scalar: self, another),
+        })
+    }
+
+    /// Parses an [`Attr`] from the provided multiple [`syn::Attribute`]s with
+    /// the specified `name`, placed on a struct definition.
+    fn from_attrs(name: &str, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        filter_attrs(name, attrs)
+            .map(|attr| attr.parse_args())
+            .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))
+    }
+}
+
+/// Generated code of a GraphQL operation.
+struct Generated<'a> {
+    /// Name of the GraphQL operation.
+    name: &'a str,
+
+    /// Generated `Variables` and `ResponseData` types of the GraphQL
+    /// operation, along with the GraphQL enums and input objects they use.
+    types: TokenStream,
+}
+
+/// Generator of Rust code for a GraphQL operation of an executable
+/// [`Document`], validating it against the GraphQL schema.
+struct Codegen<'a> {
+    /// Generator of Rust code for the GraphQL schema definitions.
+    schema: &'a Generator<'a>,
+
+    /// [`Span`] to report errors of the executable [`Document`] with.
+    span: Span,
+
+    /// Path to the executable [`Document`] file, as specified in the macro.
+    file: String,
+
+    /// Executable [`Document`] containing the GraphQL operation.
+    doc: &'a Document<'a>,
+
+    /// Fragment definitions of the executable [`Document`] by their names.
+    fragments: HashMap<&'a str, &'a query::FragmentDefinition<'a, &'a str>>,
+
+    /// Names of the GraphQL enums and input objects used by the GraphQL
+    /// operation, which Rust definitions should be generated.
+    used: BTreeSet<&'a str>,
+
+    /// Variable definitions of the GraphQL operation by their names.
+    variables: HashMap<&'a str, &'a VariableDef<'a>>,
+
+    /// Names of the variables used by the GraphQL operation.
+    used_variables: BTreeSet<&'a str>,
+
+    /// Generated structs of the response data.
+    structs: Vec<TokenStream>,
+}
+
+/// GraphQL field collected from a selection set, merged by its response key.
+struct CollectedField<'a> {
+    /// Response key of this field (alias or name).
+    key: &'a str,
+
+    /// Name of this field.
+    name: &'a str,
+
+    /// Definition of this field in the GraphQL schema.
+    ///
+    /// [`None`] for the `__typename` meta field.
+    def: Option<&'a FieldDef<'a>>,
+
+    /// Indicator whether this field may be absent in the response, because
+    /// all its occurrences are conditional (under a fragment of another type,
+    /// or under `@skip`/`@include` directives).
+    optional: bool,
+
+    /// Selection sets of all the occurrences of this field, along with the
+    /// conditionality of each occurrence.
+    selections: Vec<(&'a SelectionSet<'a, &'a str>, bool)>,
+
+    /// Position of the first occurrence of this field.
+    pos: Pos,
+}
+
+impl<'a> Codegen<'a> {
+    /// Collects the fragment definitions of the provided executable
+    /// [`Document`].
+    fn new(
+        schema: &'a Generator<'a>,
+        path: &syn::LitStr,
+        doc: &'a Document<'a>,
+    ) -> syn::Result<Self> {
+        let mut this = Self {
+            schema,
+            span: path.span(),
+            file: path.value(),
+            doc,
+            fragments: HashMap::new(),
+            used: BTreeSet::new(),
+            variables: HashMap::new(),
+            used_variables: BTreeSet::new(),
+            structs: vec![],
+        };
+        for def in &doc.definitions {
+            if let Definition::Fragment(f) = def {
+                if this.fragments.insert(f.name, f).is_some() {
+                    return Err(this.error(
+                        f.position,
+                        format!("fragment `{}` is defined more than once", f.name),
+                    ));
+                }
+            }
+        }
+        Ok(this)
+    }
+
+    /// Generates Rust code for the GraphQL operation of the provided `name`.
+    ///
+    /// If the [`Document`] contains no such operation, but contains the single
+    /// one, then it's used instead.
+    fn generate(mut self, name: &str) -> syn::Result<Generated<'a>> {
+        let mut operations = self
+            .doc
+            .definitions
+            .iter()
+            .filter_map(|def| match def {
+                Definition::Operation(op) => Some(Operation::new(op)),
+                Definition::Fragment(_) => None,
+            })
+            .collect::<Vec<_>>();
+        let op = match operations.iter().position(|op| op.name == Some(name)) {
+            Some(idx) => operations.swap_remove(idx),
+            None if operations.len() == 1 => operations.swap_remove(0),
+            None => {
+                return Err(ERR.custom_error(
+                    self.span,
+                    format!(
+                        "operation `{name}` is not found in `{}`, specify it via \
+                         `#[graphql(operation = \"...\")]` attribute argument",
+                        self.file,
+                    ),
+                ));
+            }
+        };
+
+        let Operation {
+            root,
+            name,
+            variables: vars,
+            selection_set,
+            pos,
+        } = op;
+        let name = name.ok_or_else(|| self.error(pos, "operation must be named"))?;
+        let root = match root {
+            Some(idx) => self.schema.roots[idx]
+                .map(|(root, _)| root)
+                .ok_or_else(|| {
+                    let kind = ["query", "mutation"][idx];
+                    self.error(pos, format!("schema doesn't define `{kind}` root type"))
+                })?,
+            None => return Err(self.error(pos, "subscriptions are not supported")),
+        };
+
+        let variables = self.variables_tokens(vars)?;
+        self.selection_tokens(
+            format_ident!("ResponseData"),
+            root,
+            &[(selection_set, false)],
+        )?;
+        if let Some(var) = vars.iter().find(|v| !self.used_variables.contains(v.name)) {
+            return Err(self.error(
+                var.position,
+                format!("variable `${}` is never used", var.name),
+            ));
+        }
+
+        let definitions = self
+            .used
+            .iter()
+            .map(|name| match self.schema.types[name] {
+                TypeDefinition::Enum(e) => self.schema.enum_tokens(e),
+                TypeDefinition::InputObject(i) => self.schema.input_object_tokens(i),
+                _ => unreachable!("only enums and input objects are collected"),
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+        let structs = &self.structs;
+
+        Ok(Generated {
+            name,
+            types: quote! {
+                #variables
+                #( #structs )*
+                #( #definitions )*
+            },
+        })
+    }
+
+    /// Returns generated code of the `Variables` struct of the GraphQL
+    /// operation with the provided variable definitions.
+    fn variables_tokens(&mut self, vars: &'a [VariableDef<'a>]) -> syn::Result<TokenStream> {
+        let scalar = self.scalar();
+
+        for var in vars {
+            if self.variables.insert(var.name, var).is_some() {
+                return Err(self.error(
+                    var.position,
+                    format!("variable `${}` is defined more than once", var.name),
+                ));
+            }
+        }
+
+        let mut fields = vec![];
+        let mut values = vec![];
+        for var in vars {
+            let name = base_type_name(&var.var_type);
+            match self.schema.types.get(name) {
+                Some(TypeDefinition::Scalar(_) | TypeDefinition::Enum(_)) => {}
+                Some(TypeDefinition::InputObject(_)) => {}
+                None if is_builtin_scalar(name) => {}
+                Some(_) => {
+                    return Err(self.error(
+                        var.position,
+                        format!("variable `${}` must be of an input type", var.name),
+                    ));
+                }
+                None => {
+                    return Err(self.error(var.position, format!("type `{name}` is unknown")));
+                }
+            }
+            self.use_input_type(name);
+            if let Some(default) = &var.default_value {
+                self.check_value(default, &var.var_type, false)
+                    .map_err(|e| {
+                        self.error(
+                            var.position,
+                            format!("default value of variable `${}` is invalid: {e}", var.name),
+                        )
+                    })?;
+            }
+
+            let field = ident(&to_snake_case(var.name));
+            let key = var.name;
+            // Variables with default values may be omitted, so are `Option`al.
+            let omittable = var.default_value.is_some() || !is_non_null(&var.var_type);
+            let ty = if omittable {
+                let ty = self.schema.non_null_ty(&var.var_type, var.position)?;
+                quote! { ::core::option::Option<#ty> }
+            } else {
+                self.schema.ty(&var.var_type, var.position)?
+            };
+            fields.push(quote! { pub #field: #ty });
+            values.push(if omittable {
+                quote! {
+                    (#key, self.#field.as_ref().map(::juniper::ToInputValue::to_input_value))
+                }
+            } else {
+                quote! {
+                    (#key, ::core::option::Option::Some(
+                        ::juniper::ToInputValue::to_input_value(&self.#field),
+                    ))
+                }
+            });
+        }
+
+        let body = if fields.is_empty() {
+            quote! { ; }
+        } else {
+            quote! { { #( #fields ),* } }
+        };
+        Ok(quote! {
+            /// Variables of the GraphQL operation.
+            ///
+            /// [`None`] values of `Option`al fields are omitted.
+            #[derive(::core::clone::Clone, ::core::fmt::Debug)]
+            pub struct Variables #body
+
+            #[automatically_derived]
+            impl ::juniper::ToInputValue<#scalar> for Variables {
+                fn to_input_value(&self) -> ::juniper::InputValue<#scalar> {
+                    ::juniper::macros::helper::client::variables([#( #values ),*])
+                }
+            }
+        })
+    }
+
+    /// Marks the GraphQL input type of the provided `name`, and all the input
+    /// types it refers to, as used.
+    fn use_input_type(&mut self, name: &str) {
+        let Some((name, def)) = self.schema.types.get_key_value(name) else {
+            return;
+        };
+        match def {
+            TypeDefinition::Enum(_) => {
+                self.used.insert(name);
+            }
+            TypeDefinition::InputObject(obj) if self.used.insert(name) => {
+                for f in &obj.fields {
+                    self.use_input_type(base_type_name(&f.value_type));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Generates the struct of the provided `ty` name, representing the
+    /// response data of the provided selection sets on the GraphQL type of
+    /// the provided `parent` name.
+    fn selection_tokens(
+        &mut self,
+        ty: syn::Ident,
+        parent: &'a str,
+        sets: &[(&'a SelectionSet<'a, &'a str>, bool)],
+    ) -> syn::Result<()> {
+        let scalar = self.scalar();
+
+        let mut collected = vec![];
+        for (set, conditional) in sets {
+            self.collect_fields(parent, set, *conditional, &mut vec![], &mut collected)?;
+        }
+
+        let mut idents = HashMap::new();
+        let mut fields = vec![];
+        let mut decoders = vec![];
+        for f in collected {
+            let field = ident(&to_snake_case(if f.name == "__typename" {
+                f.key.trim_start_matches('_')
+            } else {
+                f.key
+            }));
+            if let Some(other) = idents.insert(field.to_string(), f.key) {
+                return Err(self.error(
+                    f.pos,
+                    format!(
+                        "response keys `{other}` and `{}` clash as `{field}` Rust field",
+                        f.key,
+                    ),
+                ));
+            }
+
+            let (rust_ty, doc) = match f.def {
+                None => {
+                    if let Some((set, _)) = f.selections.iter().find(|(s, _)| !s.items.is_empty()) {
+                        return Err(self.error(set.span.0, "`__typename` must have no selection"));
+                    }
+                    let ty = quote! { ::std::string::String };
+                    let ty = if f.optional {
+                        quote! { ::core::option::Option<#ty> }
+                    } else {
+                        ty
+                    };
+                    (ty, None)
+                }
+                Some(def) => {
+                    let base = self.field_base_ty(&ty, &f, def)?;
+                    let doc = def.description.as_ref().map(|d| quote! { #[doc = #d] });
+                    // Absent fields are represented as `null`s.
+                    let ty = match &def.field_type {
+                        Type::NonNullType(inner) if f.optional => wrap_ty(inner, &base),
+                        ty => wrap_ty(ty, &base),
+                    };
+                    (ty, doc)
+                }
+            };
+
+            let key = f.key;
+            fields.push(quote! {
+                #doc
+                pub #field: #rust_ty
+            });
+            decoders.push(quote! {
+                #field: ::juniper::macros::helper::client::field(&fields, #key)?
+            });
+        }
+
+        self.structs.push(quote! {
+            #[derive(::core::clone::Clone, ::core::fmt::Debug, ::core::cmp::PartialEq)]
+            pub struct #ty {
+                #( #fields ),*
+            }
+
+            #[automatically_derived]
+            impl ::juniper::FromInputValue<#scalar> for #ty {
+                type Error = ::juniper::FieldError<#scalar>;
+
+                fn from_input_value(
+                    v: &::juniper::InputValue<#scalar>,
+                ) -> ::core::result::Result<Self, Self::Error> {
+                    let fields = ::juniper::macros::helper::client::object(v, #parent)?;
+                    ::core::result::Result::Ok(Self {
+                        #( #decoders ),*
+                    })
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// Returns the Rust type of the provided collected field, ignoring its
+    /// nullability and list wrappers, generating the nested struct for its
+    /// selection sets, if the field is of a composite type.
+    fn field_base_ty(
+        &mut self,
+        parent_ty: &syn::Ident,
+        f: &CollectedField<'a>,
+        def: &'a FieldDef<'a>,
+    ) -> syn::Result<TokenStream> {
+        let name = base_type_name(&def.field_type);
+        let is_composite = matches!(
+            self.schema.types.get(name),
+            Some(
+                TypeDefinition::Object(_) | TypeDefinition::Interface(_) | TypeDefinition::Union(_),
+            ),
+        );
+        let has_selection = f.selections.iter().any(|(s, _)| !s.items.is_empty());
+
+        if !is_composite {
+            if has_selection {
+                return Err(self.error(
+                    f.pos,
+                    format!("field `{}` of `{name}` type must have no selection", f.name),
+                ));
+            }
+            if let Some(TypeDefinition::Enum(_)) = self.schema.types.get(name) {
+                self.used
+                    .insert(self.schema.types.get_key_value(name).unwrap().0);
+            }
+            return self.schema.non_null_ty(&Type::NamedType(name), f.pos);
+        }
+        if !has_selection {
+            return Err(self.error(
+                f.pos,
+                format!("field `{}` of `{name}` type must have a selection", f.name),
+            ));
+        }
+
+        let ty = format_ident!("{parent_ty}{}", to_pascal_case(&to_snake_case(f.key)));
+        // If the field itself is absent, then its inner fields are not
+        // conditional on their own.
+        let sets = f
+            .selections
+            .iter()
+            .map(|(s, conditional)| (*s, *conditional && !f.optional))
+            .collect::<Vec<_>>();
+        let name = self.schema.types.get_key_value(name).unwrap().0;
+        self.selection_tokens(ty.clone(), name, &sets)?;
+        Ok(quote! { #ty })
+    }
+
+    /// Collects the fields of the provided selection set on the GraphQL type
+    /// of the provided `parent` name into the provided `out`put, flattening
+    /// the fragments and merging the fields by their response keys.
+    ///
+    /// `spreads` are the names of the fragments being spread at the moment,
+    /// for detecting cycles.
+    fn collect_fields(
+        &mut self,
+        parent: &'a str,
+        set: &'a SelectionSet<'a, &'a str>,
+        conditional: bool,
+        spreads: &mut Vec<&'a str>,
+        out: &mut Vec<CollectedField<'a>>,
+    ) -> syn::Result<()> {
+        for item in &set.items {
+            match item {
+                Selection::Field(field) => {
+                    self.check_directives(&field.directives)?;
+                    let conditional = conditional || is_conditional(&field.directives);
+                    let def = self.field_def(parent, field)?;
+                    let key = field.alias.unwrap_or(field.name);
+                    let selection = (&field.selection_set, conditional);
+                    if let Some(f) = out.iter_mut().find(|f| f.key == key) {
+                        let same_type = match (f.def, def) {
+                            (Some(a), Some(b)) => a.field_type == b.field_type,
+                            (a, b) => a.is_none() && b.is_none(),
+                        };
+                        if f.name != field.name || !same_type {
+                            return Err(self.error(
+                                field.position,
+                                format!(
+                                    "fields `{}` and `{}` conflict under `{key}` response key",
+                                    f.name, field.name,
+                                ),
+                            ));
+                        }
+                        f.optional &= conditional;
+                        f.selections.push(selection);
+                    } else {
+                        out.push(CollectedField {
+                            key,
+                            name: field.name,
+                            def,
+                            optional: conditional,
+                            selections: vec![selection],
+                            pos: field.position,
+                        });
+                    }
+                }
+                Selection::InlineFragment(frag) => {
+                    self.check_directives(&frag.directives)?;
+                    let (on, conditional) = match &frag.type_condition {
+                        Some(query::TypeCondition::On(on)) => {
+                            let on = self.fragment_type(on, frag.position)?;
+                            (on, conditional || !self.always_applies(parent, on))
+                        }
+                        None => (parent, conditional),
+                    };
+                    let conditional = conditional || is_conditional(&frag.directives);
+                    self.collect_fields(on, &frag.selection_set, conditional, spreads, out)?;
+                }
+                Selection::FragmentSpread(spread) => {
+                    self.check_directives(&spread.directives)?;
+                    let name = spread.fragment_name;
+                    let frag = self.fragments.get(name).copied().ok_or_else(|| {
+                        self.error(spread.position, format!("fragment `{name}` is unknown"))
+                    })?;
+                    if spreads.contains(&name) {
+                        return Err(self
+                            .error(spread.position, format!("fragment `{name}` spreads itself")));
+                    }
+                    let query::TypeCondition::On(on) = &frag.type_condition;
+                    let on = self.fragment_type(on, frag.position)?;
+                    let conditional = conditional
+                        || !self.always_applies(parent, on)
+                        || is_conditional(&spread.directives);
+                    spreads.push(name);
+                    self.collect_fields(on, &frag.selection_set, conditional, spreads, out)?;
+                    spreads.pop();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Looks up the definition of the provided selected `field` on the GraphQL
+    /// type of the provided `parent` name, validating its arguments.
+    ///
+    /// Returns [`None`] for the `__typename` meta field.
+    fn field_def(
+        &mut self,
+        parent: &str,
+        field: &'a query::Field<'a, &'a str>,
+    ) -> syn::Result<Option<&'a FieldDef<'a>>> {
+        if field.name == "__typename" {
+            return Ok(None);
+        }
+        let defs = match self.schema.types.get(parent) {
+            Some(TypeDefinition::Object(o)) => o.fields.as_slice(),
+            Some(TypeDefinition::Interface(i)) => i.fields.as_slice(),
+            _ => &[],
+        };
+        let def = defs.iter().find(|f| f.name == field.name).ok_or_else(|| {
+            self.error(
+                field.position,
+                format!("field `{}` is not defined on `{parent}` type", field.name),
+            )
+        })?;
+
+        for (arg, value) in &field.arguments {
+            let Some(arg_def) = def.arguments.iter().find(|a| a.name == *arg) else {
+                return Err(self.error(
+                    field.position,
+                    format!(
+                        "argument `{arg}` is not defined on `{parent}.{}` field",
+                        def.name
+                    ),
+                ));
+            };
+            self.check_value(value, &arg_def.value_type, arg_def.default_value.is_some())
+                .map_err(|e| {
+                    self.error(
+                        field.position,
+                        format!(
+                            "argument `{arg}` of `{parent}.{}` field is invalid: {e}",
+                            def.name,
+                        ),
+                    )
+                })?;
+        }
+        for arg in &def.arguments {
+            let is_required = is_non_null(&arg.value_type) && arg.default_value.is_none();
+            if is_required && !field.arguments.iter().any(|(a, _)| *a == arg.name) {
+                return Err(self.error(
+                    field.position,
+                    format!(
+                        "argument `{}` of `{parent}.{}` field is required",
+                        arg.name, def.name,
+                    ),
+                ));
+            }
+        }
+        Ok(Some(def))
+    }
+
+    /// Validates the arguments of the provided `@skip`/`@include` directives,
+    /// rejecting any other directives.
+    fn check_directives(&mut self, directives: &'a [Directive<'a, &'a str>]) -> syn::Result<()> {
+        let boolean = Type::NonNullType(Box::new(Type::NamedType("Boolean")));
+        for d in directives {
+            if !matches!(d.name, "skip" | "include") {
+                return Err(self.error(d.position, format!("directive `@{}` is unknown", d.name)));
+            }
+            if let Some((arg, _)) = d.arguments.iter().find(|(arg, _)| *arg != "if") {
+                return Err(self.error(
+                    d.position,
+                    format!("argument `{arg}` is not defined on `@{}` directive", d.name),
+                ));
+            }
+            let Some((_, value)) = d.arguments.iter().find(|(arg, _)| *arg == "if") else {
+                return Err(self.error(
+                    d.position,
+                    format!("argument `if` of `@{}` directive is required", d.name),
+                ));
+            };
+            self.check_value(value, &boolean, false).map_err(|e| {
+                self.error(
+                    d.position,
+                    format!("argument `if` of `@{}` directive is invalid: {e}", d.name),
+                )
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Checks whether the provided `value` is of the provided input [`Type`],
+    /// marking the variables it refers to as used.
+    ///
+    /// `has_default` indicates whether the location of the `value` has a
+    /// default value, so a `null`able variable is allowed to be used there,
+    /// even if the location is non-`null`able.
+    ///
+    /// Returns the reason of the `value` being invalid, if any.
+    fn check_value(
+        &mut self,
+        value: &'a Value<'a>,
+        ty: &Type<'a>,
+        has_default: bool,
+    ) -> Result<(), String> {
+        match (value, ty) {
+            (Value::Variable(name), _) => {
+                let var = self
+                    .variables
+                    .get(name)
+                    .ok_or_else(|| format!("variable `${name}` is not defined"))?;
+                self.used_variables.insert(name);
+                // See: https://spec.graphql.org/October2021#IsVariableUsageAllowed()
+                let has_non_null_default = var
+                    .default_value
+                    .as_ref()
+                    .is_some_and(|v| !matches!(v, Value::Null));
+                let location_ty = match ty {
+                    Type::NonNullType(inner)
+                        if !is_non_null(&var.var_type) && (has_default || has_non_null_default) =>
+                    {
+                        inner
+                    }
+                    ty => ty,
+                };
+                if !are_types_compatible(&var.var_type, location_ty) {
+                    return Err(format!(
+                        "variable `${name}` of `{}` type is used where `{ty}` is expected",
+                        var.var_type,
+                    ));
+                }
+                Ok(())
+            }
+            (Value::Null, Type::NonNullType(_)) => Err(format!("expected `{ty}`, found `null`")),
+            (Value::Null, _) => Ok(()),
+            (value, Type::NonNullType(inner)) => self.check_value(value, inner, false),
+            (Value::List(items), Type::ListType(inner)) => items
+                .iter()
+                .try_for_each(|v| self.check_value(v, inner, false)),
+            // Single values are coerced into lists.
+            (value, Type::ListType(inner)) => self.check_value(value, inner, false),
+            (value, Type::NamedType(name)) => self.check_named_value(value, name),
+        }
+    }
+
+    /// Checks whether the provided non-`null` `value` is of the named GraphQL
+    /// input type of the provided `name`, marking the variables it refers to
+    /// as used.
+    ///
+    /// Returns the reason of the `value` being invalid, if any.
+    fn check_named_value(&mut self, value: &'a Value<'a>, name: &str) -> Result<(), String> {
+        let is_valid = match (self.schema.types.get(name), value) {
+            (None, _) if is_builtin_scalar(name) => match (name, value) {
+                ("Int", Value::Int(n)) => n.as_i64().is_some_and(|n| i32::try_from(n).is_ok()),
+                ("Float", Value::Int(_) | Value::Float(_))
+                | ("String", Value::String(_))
+                | ("Boolean", Value::Boolean(_))
+                | ("ID", Value::String(_) | Value::Int(_)) => true,
+                _ => false,
+            },
+            // Custom scalars may accept any value.
+            (Some(TypeDefinition::Scalar(_)), _) => true,
+            (Some(TypeDefinition::Enum(e)), Value::Enum(v)) => {
+                e.values.iter().any(|ev| ev.name == *v)
+            }
+            (Some(TypeDefinition::InputObject(obj)), Value::Object(fields)) => {
+                if let Some(unknown) = fields
+                    .keys()
+                    .find(|k| !obj.fields.iter().any(|f| f.name == **k))
+                {
+                    return Err(format!(
+                        "field `{unknown}` is not defined on `{name}` input object"
+                    ));
+                }
+                if obj.directives.iter().any(|d| d.name == "oneOf") {
+                    let is_present = |v: &Value<'a>| match v {
+                        Value::Null => false,
+                        Value::Variable(var) => self
+                            .variables
+                            .get(var)
+                            .is_none_or(|def| is_non_null(&def.var_type)),
+                        _ => true,
+                    };
+                    if fields.len() != 1 || !fields.values().all(is_present) {
+                        return Err(format!(
+                            "exactly one non-`null` field of `{name}` input object must be specified",
+                        ));
+                    }
+                }
+                for f in &obj.fields {
+                    match fields.get(f.name) {
+                        Some(v) => self.check_value(v, &f.value_type, f.default_value.is_some())?,
+                        None if is_non_null(&f.value_type) && f.default_value.is_none() => {
+                            return Err(format!(
+                                "field `{}` of `{name}` input object is required",
+                                f.name,
+                            ));
+                        }
+                        None => {}
+                    }
+                }
+                true
+            }
+            _ => false,
+        };
+        if is_valid {
+            Ok(())
+        } else {
+            Err(format!("expected `{name}`, found `{value}`"))
+        }
+    }
+
+    /// Returns the name of the composite GraphQL type, specified as a type
+    /// condition of a fragment.
+    fn fragment_type(&self, on: &str, pos: Pos) -> syn::Result<&'a str> {
+        match self.schema.types.get_key_value(on) {
+            Some((
+                name,
+                TypeDefinition::Object(_) | TypeDefinition::Interface(_) | TypeDefinition::Union(_),
+            )) => Ok(name),
+            Some(_) => Err(self.error(
+                pos,
+                format!("fragment on `{on}` must be of a composite type"),
+            )),
+            None => Err(self.error(pos, format!("type `{on}` is unknown"))),
+        }
+    }
+
+    /// Indicates whether a fragment on the GraphQL type `on` always applies
+    /// to the selection set on the GraphQL type `parent`.
+    fn always_applies(&self, parent: &str, on: &str) -> bool {
+        parent == on
+            || matches!(
+                self.schema.types.get(parent),
+                Some(TypeDefinition::Object(o)) if o.implements_interfaces.contains(&on),
+            )
+    }
+
+    /// Returns Rust type of [`ScalarValue`] of the generated code.
+    ///
+    /// [`ScalarValue`]: juniper::ScalarValue
+    fn scalar(&self) -> &'a syn::Type {
+        self.schema.scalar()
+    }
+
+    /// Creates a [`syn::Error`] related to the provided position in the
+    /// executable document file.
+    fn error(&self, pos: Pos, msg: impl AsRef<str>) -> syn::Error {
+        ERR.custom_error(
+            self.span,
+            format!("at `{}:{pos}`: {}", self.file, msg.as_ref()),
+        )
+    }
+}
+
+/// GraphQL operation of an executable [`Document`].
+struct Operation<'a> {
+    /// Index of the root operation type (in [`Generator::roots`]).
+    ///
+    /// [`None`] for subscriptions.
+    root: Option<usize>,
+
+    /// Name of this operation, if any.
+    name: Option<&'a str>,
+
+    /// Variable definitions of this operation.
+    variables: &'a [query::VariableDefinition<'a, &'a str>],
+
+    /// Selection set of this operation.
+    selection_set: &'a SelectionSet<'a, &'a str>,
+
+    /// Position of this operation.
+    pos: Pos,
+}
+
+impl<'a> Operation<'a> {
+    /// Destructures the provided [`OperationDefinition`].
+    fn new(op: &'a OperationDefinition<'a, &'a str>) -> Self {
+        let (root, name, variables, selection_set, pos) = match op {
+            OperationDefinition::SelectionSet(s) => (Some(0), None, &[][..], s, s.span.0),
+            OperationDefinition::Query(q) => (
+                Some(0),
+                q.name,
+                &q.variable_definitions[..],
+                &q.selection_set,
+                q.position,
+            ),
+            OperationDefinition::Mutation(m) => (
+                Some(1),
+                m.name,
+                &m.variable_definitions[..],
+                &m.selection_set,
+                m.position,
+            ),
+            OperationDefinition::Subscription(s) => (
+                None,
+                s.name,
+                &s.variable_definitions[..],
+                &s.selection_set,
+                s.position,
+            ),
+        };
+        Self {
+            root,
+            name,
+            variables,
+            selection_set,
+            pos,
+        }
+    }
+}
+
+/// Indicates whether the provided directives make a selection conditional.
+fn is_conditional<'a>(directives: &[Directive<'a, &'a str>]) -> bool {
+    directives
+        .iter()
+        .any(|d| matches!(d.name, "skip" | "include"))
+}
+
+/// Indicates whether the provided [`Type`] is non-`null`able.
+fn is_non_null<'a>(ty: &Type<'a>) -> bool {
+    matches!(ty, Type::NonNullType(_))
+}
+
+/// Indicates whether a variable of the provided `var` [`Type`] may be used
+/// where the provided `location` [`Type`] is expected.
+///
+/// See: https://spec.graphql.org/October2021#AreTypesCompatible()
+fn are_types_compatible<'a>(var: &Type<'a>, location: &Type<'a>) -> bool {
+    match (var, location) {
+        (Type::NonNullType(var), Type::NonNullType(location)) => {
+            are_types_compatible(var, location)
+        }
+        (_, Type::NonNullType(_)) => false,
+        (Type::NonNullType(var), location) => are_types_compatible(var, location),
+        (Type::ListType(var), Type::ListType(location)) => are_types_compatible(var, location),
+        (Type::NamedType(var), Type::NamedType(location)) => var == location,
+        _ => false,
+    }
+}
+
+/// Returns the name of the named type, wrapped by the provided [`Type`].
+fn base_type_name<'a>(ty: &Type<'a>) -> &'a str {
+    match ty {
+        Type::NamedType(name) => name,
+        Type::ListType(inner) | Type::NonNullType(inner) => base_type_name(inner),
+    }
+}
+
+/// Wraps the provided `base` Rust type according to the nullability and list
+/// wrappers of the provided [`Type`].
+fn wrap_ty<'a>(ty: &Type<'a>, base: &TokenStream) -> TokenStream {
+    let non_null = |ty: &Type<'a>| match ty {
+        Type::ListType(inner) => {
+            let inner = wrap_ty(inner, base);
+            quote! { ::std::vec::Vec<#inner> }
+        }
+        _ => base.clone(),
+    };
+    match ty {
+        Type::NonNullType(inner) => non_null(inner),
+        ty => {
+            let ty = non_null(ty);
+            quote! { ::core::option::Option<#ty> }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use graphql_parser::{query::parse_query, schema::parse_schema};
+    use quote::quote;
+
+    use crate::graphql_schema::Generator;
+
+    use super::Codegen;
+
+    const SDL: &str = "
+        enum Episode { NEW_HOPE EMPIRE }
+        interface Character { id: ID! name: String! }
+        type Human implements Character { id: ID! name: String! homePlanet: String }
+        type Droid implements Character { id: ID! name: String! }
+        union SearchResult = Human | Droid
+        type Query {
+            hero(episode: Episode): Character
+            human(id: ID!): Human
+            search(text: String!): [SearchResult!]!
+        }
+    ";
+
+    fn generate(doc: &str) -> Result<(), String> {
+        let path = syn::parse2::<syn::LitStr>(quote! { "query.graphql" }).unwrap();
+        let sdl = parse_schema::<&str>(SDL).unwrap();
+        let schema = Generator::new(&path, None, None, &sdl).unwrap();
+        let doc = parse_query::<&str>(doc).unwrap();
+        Codegen::new(&schema, &path, &doc)
+            .and_then(|c| c.generate("Op"))
+            .map(drop)
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn generates_valid_operations() {
+        for doc in [
+            "query Op($ep: Episode) { hero(episode: $ep) { __typename id ...F } }
+             fragment F on Character { name ... on Human { homePlanet } }",
+            "query Op { search(text: \"R2\") { ... on Droid { id } ... on Human { id name } } }",
+            "query Other { __typename } query Op { h: human(id: 1) { name @skip(if: true) } }",
+            "query Op($id: ID = 1, $skip: Boolean!) { human(id: $id) { id @skip(if: $skip) } }",
+        ] {
+            assert_eq!(generate(doc), Ok(()), "document: {doc}");
+        }
+    }
+
+    #[test]
+    fn errors_on_invalid_operations() {
+        for (doc, expected) in [
+            (
+                "query Op { hero { nickname } }",
+                "GraphQL operation at `query.graphql:1:19`: field `nickname` is not defined on \
+                 `Character` type",
+            ),
+            (
+                "query Op { human { id } }",
+                "GraphQL operation at `query.graphql:1:12`: argument `id` of `Query.human` field \
+                 is required",
+            ),
+            (
+                "query Op { hero(ep: EMPIRE) { id } }",
+                "GraphQL operation at `query.graphql:1:12`: argument `ep` is not defined on \
+                 `Query.hero` field",
+            ),
+            (
+                "query Op { hero }",
+                "GraphQL operation at `query.graphql:1:12`: field `hero` of `Character` type \
+                 must have a selection",
+            ),
+            (
+                "query Op { hero { id { value } } }",
+                "GraphQL operation at `query.graphql:1:19`: field `id` of `ID` type must have no \
+                 selection",
+            ),
+            (
+                "query Op { hero { ...F } }",
+                "GraphQL operation at `query.graphql:1:22`: fragment `F` is unknown",
+            ),
+            (
+                "query Op($v: Character) { __typename }",
+                "GraphQL operation at `query.graphql:1:10`: variable `$v` must be of an input \
+                 type",
+            ),
+            (
+                "query Op { human(id: true) { id } }",
+                "GraphQL operation at `query.graphql:1:12`: argument `id` of `Query.human` field \
+                 is invalid: expected `ID`, found `true`",
+            ),
+            (
+                "query Op { hero(episode: JEDI) { id } }",
+                "GraphQL operation at `query.graphql:1:12`: argument `episode` of `Query.hero` \
+                 field is invalid: expected `Episode`, found `JEDI`",
+            ),
+            (
+                "query Op { human(id: $id) { id } }",
+                "GraphQL operation at `query.graphql:1:12`: argument `id` of `Query.human` field \
+                 is invalid: variable `$id` is not defined",
+            ),
+            (
+                "query Op($id: String) { human(id: $id) { id } }",
+                "GraphQL operation at `query.graphql:1:25`: argument `id` of `Query.human` field \
+                 is invalid: variable `$id` of `String` type is used where `ID!` is expected",
+            ),
+            (
+                "query Op($ep: Episode = JEDI) { hero(episode: $ep) { id } }",
+                "GraphQL operation at `query.graphql:1:10`: default value of variable `$ep` is \
+                 invalid: expected `Episode`, found `JEDI`",
+            ),
+            (
+                "query Op($ep: Episode) { __typename }",
+                "GraphQL operation at `query.graphql:1:10`: variable `$ep` is never used",
+            ),
+            (
+                "query Op($s: Boolean) { __typename @skip(if: $s) }",
+                "GraphQL operation at `query.graphql:1:36`: argument `if` of `@skip` directive \
+                 is invalid: variable `$s` of `Boolean` type is used where `Boolean!` is expected",
+            ),
+            (
+                "query Op { __typename @defer }",
+                "GraphQL operation at `query.graphql:1:23`: directive `@defer` is unknown",
+            ),
+            (
+                "{ __typename }",
+                "GraphQL operation at `query.graphql:1:1`: operation must be named",
+            ),
+            (
+                "query A { __typename } query B { __typename }",
+                "GraphQL operation operation `Op` is not found in `query.graphql`, specify it via \
+                 `#[graphql(operation = \"...\")]` attribute argument",
+            ),
+            (
+                "mutation Op { __typename }",
+                "GraphQL operation at `query.graphql:1:1`: schema doesn't define `mutation` root \
+                 type",
+            ),
+        ] {
+            assert_eq!(generate(doc), Err(expected.into()), "document: {doc}");
+        }
+    }
+}
@@ -58,8 +58,10 @@ fn expand_on_type_alias(
             .map(SpanContainer::into_inner)
             .unwrap_or_else(|| ast.ident.to_string()),
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
         specified_by_url: attr.specified_by_url.map(SpanContainer::into_inner),
         scalar,
+        type_info: attr.type_info.map(SpanContainer::into_inner),
     };
 
     Ok(quote! {
@@ -89,8 +91,10 @@ fn expand_on_derive_input(
             .map(SpanContainer::into_inner)
             .unwrap_or_else(|| ast.ident.to_string()),
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
         specified_by_url: attr.specified_by_url.map(SpanContainer::into_inner),
         scalar,
+        type_info: attr.type_info.map(SpanContainer::into_inner),
     };
 
     Ok(quote! {
@@ -30,8 +30,10 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
             .map(SpanContainer::into_inner)
             .unwrap_or_else(|| ast.ident.to_string()),
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
         specified_by_url: attr.specified_by_url.map(SpanContainer::into_inner),
         scalar,
+        type_info: attr.type_info.map(SpanContainer::into_inner),
     }
     .to_token_stream())
 }
@@ -15,12 +15,13 @@ use syn::{
 use url::Url;
 
 use crate::common::{
-    AttrNames, Description, SpanContainer, filter_attrs,
+    AttrNames, Description, SpanContainer, directive, filter_attrs,
     parse::{
         ParseBufferExt as _,
         attr::{OptionExt as _, err},
     },
     scalar,
+    type_info::{self, TypeInfo},
 };
 
 pub mod attr;
@@ -42,6 +43,13 @@ struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
     description: Option<SpanContainer<Description>>,
 
+    /// Explicitly specified [directives][2] applied to this [GraphQL
+    /// scalar][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
     /// Spec [`Url`] of this [GraphQL scalar][1] to put into GraphQL schema.
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
@@ -61,6 +69,15 @@ struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
     scalar: Option<SpanContainer<scalar::AttrValue>>,
 
+    /// Explicitly specified type of [`TypeInfo`] to use for resolving this
+    /// [GraphQL scalar][1] type with.
+    ///
+    /// If [`None`], then unit type `()` is assumed as a type of [`TypeInfo`].
+    ///
+    /// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    type_info: Option<SpanContainer<TypeInfo>>,
+
     /// Explicitly specified function to be used as
     /// [`ToInputValue::to_input_value`] implementation.
     ///
@@ -115,6 +132,10 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(desc.span()), desc))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse()?);
+                }
                 "specified_by_url" => {
                     input.parse::<token::Eq>()?;
                     let lit = input.parse::<syn::LitStr>()?;
@@ -132,6 +153,13 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(scl.span()), scl))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "type_info" => {
+                    input.parse::<token::Eq>()?;
+                    let ti = input.parse::<TypeInfo>()?;
+                    out.type_info
+                        .replace(SpanContainer::new(ident.span(), Some(ti.span()), ti))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "to_output_with" => {
                     input.parse::<token::Eq>()?;
                     let scl = input.parse::<syn::ExprPath>()?;
@@ -229,8 +257,14 @@ impl Attr {
         Ok(Self {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
             specified_by_url: try_merge_opt!(specified_by_url: self, another),
             scalar: try_merge_opt!(scalar: self, another),
+            type_info: try_merge_opt!(type_info: self, another),
             to_output: try_merge_opt!(to_output: self, another),
             from_input: try_merge_opt!(from_input: self, another),
             parse_token: try_merge_opt!(parse_token: self, another),
@@ -299,6 +333,12 @@ struct Definition {
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
     description: Option<Description>,
 
+    /// [Directives][2] applied to this [GraphQL scalar][1] in GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
     /// Spec [`Url`] of this [GraphQL scalar][1] to put into GraphQL schema.
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
@@ -311,6 +351,16 @@ struct Definition {
     /// [`ScalarValue`]: juniper::ScalarValue
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
     scalar: scalar::Type,
+
+    /// Custom Rust type of [`TypeInfo`] to generate [`GraphQLType`]
+    /// implementation with for this [GraphQL scalar][1].
+    ///
+    /// If [`None`], then unit type `()` is used.
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    type_info: Option<TypeInfo>,
 }
 
 impl ToTokens for Definition {
@@ -363,6 +413,7 @@ impl Definition {
 
         let name = &self.name;
         let description = &self.description;
+        let directives = &self.directives;
         let specified_by_url = self.specified_by_url.as_ref().map(|url| {
             let url_lit = url.as_str();
             quote! {
@@ -373,23 +424,29 @@ impl Definition {
         let (ty, generics) = self.impl_self_and_generics(false);
         let (impl_gens, _, where_clause) = generics.split_for_impl();
 
+        let name_method = type_info::name_method_tokens(
+            self.type_info.as_ref(),
+            &quote! { ::juniper::arcstr::literal!(#name) },
+        );
+        let dyn_description = type_info::description_tokens(self.type_info.as_ref());
+
         quote! {
             #[automatically_derived]
             impl #impl_gens ::juniper::GraphQLType<#scalar> for #ty
                 #where_clause
             {
-                fn name(_: &Self::TypeInfo) -> ::core::option::Option<::juniper::ArcStr> {
-                    ::core::option::Option::Some(::juniper::arcstr::literal!(#name))
-                }
+                #name_method
 
                 fn meta(
                     info: &Self::TypeInfo,
                     registry: &mut ::juniper::Registry<#scalar>,
                 ) -> ::juniper::meta::MetaType<#scalar> {
-                    registry.build_scalar_type::<Self>(info)
+                    let meta = registry.build_scalar_type::<Self>(info)
                         #description
-                        #specified_by_url
-                        .into_meta()
+                        #( #directives )*
+                        #specified_by_url;
+                    #dyn_description
+                    meta.into_meta()
                 }
             }
         }
@@ -402,6 +459,7 @@ impl Definition {
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
     fn impl_value_tokens(&self) -> TokenStream {
         let scalar = &self.scalar;
+        let type_info = type_info::ty_tokens(self.type_info.as_ref());
 
         let (ty, mut generics) = self.impl_self_and_generics(false);
 
@@ -430,7 +488,7 @@ impl Definition {
                 quote! {
                     ::juniper::GraphQLValue::<#scalar>::resolve(
                         &self.#field,
-                        info,
+                        &(),
                         selection,
                         executor,
                     )
@@ -444,7 +502,7 @@ impl Definition {
             #[automatically_derived]
             impl #impl_gens ::juniper::GraphQLValue<#scalar> for #ty #where_clause {
                 type Context = ();
-                type TypeInfo = ();
+                type TypeInfo = #type_info;
 
                 fn type_name(
                     &self,
@@ -455,7 +513,7 @@ impl Definition {
 
                 fn resolve(
                     &self,
-                    info: &(),
+                    _: &Self::TypeInfo,
                     selection: ::core::option::Option<&[::juniper::Selection<'_, #scalar>]>,
                     executor: &::juniper::Executor<'_, '_, Self::Context, #scalar>,
                 ) -> ::juniper::ExecutionResult<#scalar> {
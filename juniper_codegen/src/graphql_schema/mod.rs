@@ -0,0 +1,1099 @@
+//! Code generation for `graphql_schema_from_file!` macro, generating Rust
+//! definitions of a [GraphQL schema][0] described in [SDL][1].
+//!
+//! [0]: https://spec.graphql.org/October2021#sec-Schema
+//! [1]: https://spec.graphql.org/October2021#sec-Type-System
+
+use std::{collections::HashMap, env, fs, path::PathBuf};
+
+use graphql_parser::{
+    Pos,
+    schema::{
+        self as sdl, Definition, Directive, EnumType, Field, InputObjectType, InputValue,
+        InterfaceType, ObjectType, TypeDefinition, UnionType,
+    },
+};
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote, token,
+};
+
+use crate::common::{
+    diagnostic,
+    parse::{
+        ParseBufferExt as _,
+        attr::{OptionExt as _, err},
+    },
+    rename,
+};
+
+/// [`diagnostic::Scope`] of errors for `graphql_schema_from_file!` macro.
+const ERR: diagnostic::Scope = diagnostic::Scope::SchemaFromFile;
+
+/// SDL document parsed from a file.
+type Document<'a> = sdl::Document<'a, &'a str>;
+
+/// Type reference in an SDL document.
+type Type<'a> = sdl::Type<'a, &'a str>;
+
+/// Input value in an SDL document.
+type Value<'a> = sdl::Value<'a, &'a str>;
+
+/// Expands `graphql_schema_from_file!` macro into generated code.
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let attr = syn::parse2::<Attr>(input)?;
+
+    let (path, src) = read_file(&attr.path, &ERR)?;
+    let doc = sdl::parse_schema::<&str>(&src).map_err(|e| {
+        ERR.custom_error(
+            attr.path.span(),
+            format!("file `{}` cannot be parsed: {e}", path.display()),
+        )
+    })?;
+
+    let generated = Generator::new(&attr.path, attr.context, attr.scalar, &doc)?.generate(&doc)?;
+
+    Ok(quote! {
+        #generated
+
+        #path
+    })
+}
+
+/// Reads the file of the provided `path`, relative to the `CARGO_MANIFEST_DIR`.
+///
+/// Returns the read file contents along with the generated code making the
+/// compiler to track changes of the file.
+pub(crate) fn read_file(
+    path: &syn::LitStr,
+    scope: &diagnostic::Scope,
+) -> syn::Result<(TrackedPath, String)> {
+    let full = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap_or_default()).join(path.value());
+    let src = fs::read_to_string(&full).map_err(|e| {
+        scope.custom_error(
+            path.span(),
+            format!("file `{}` cannot be read: {e}", full.display()),
+        )
+    })?;
+    Ok((TrackedPath(full), src))
+}
+
+/// Path to a file, whose changes should be tracked by the compiler.
+pub(crate) struct TrackedPath(PathBuf);
+
+impl TrackedPath {
+    /// Returns a [`Display`]able representation of this [`TrackedPath`].
+    ///
+    /// [`Display`]: std::fmt::Display
+    pub(crate) fn display(&self) -> std::path::Display<'_> {
+        self.0.display()
+    }
+}
+
+impl ToTokens for TrackedPath {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        let path = self.0.to_string_lossy();
+        quote! {
+            const _: &str = ::core::include_str!(#path);
+        }
+        .to_tokens(into);
+    }
+}
+
+/// Arguments of `graphql_schema_from_file!` macro.
+struct Attr {
+    /// Path to the SDL file, relative to the `CARGO_MANIFEST_DIR`.
+    path: syn::LitStr,
+
+    /// Explicitly specified type of [`Context`] to use for resolving the
+    /// generated GraphQL types.
+    ///
+    /// If [`None`], then unit type `()` is assumed as a type of [`Context`].
+    ///
+    /// [`Context`]: juniper::Context
+    context: Option<syn::Type>,
+
+    /// Explicitly specified type of [`ScalarValue`] to use for resolving the
+    /// generated GraphQL types.
+    ///
+    /// If [`None`], then [`DefaultScalarValue`] is used.
+    ///
+    /// [`DefaultScalarValue`]: juniper::DefaultScalarValue
+    /// [`ScalarValue`]: juniper::ScalarValue
+    scalar: Option<syn::Type>,
+}
+
+impl Parse for Attr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let path = input.parse::<syn::LitStr>()?;
+        let (mut context, mut scalar) = (None, None);
+        while input.try_parse::<token::Comma>()?.is_some() && !input.is_empty() {
+            let ident = input.parse_any_ident()?;
+            input.parse::<token::Eq>()?;
+            match ident.to_string().as_str() {
+                "context" | "ctx" | "Context" => context
+                    .replace(input.parse::<syn::Type>()?)
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                "scalar" | "Scalar" | "ScalarValue" => scalar
+                    .replace(input.parse::<syn::Type>()?)
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+        }
+        if !input.is_empty() {
+            return Err(input.error("expected `,`"));
+        }
+        Ok(Self {
+            path,
+            context,
+            scalar,
+        })
+    }
+}
+
+/// Generator of Rust code from an SDL [`Document`].
+pub(crate) struct Generator<'a> {
+    /// [`Span`] to report errors of the SDL [`Document`] with.
+    span: Span,
+
+    /// Path to the SDL file, as specified in the macro.
+    file: String,
+
+    /// Rust type of [`Context`] of the generated GraphQL types.
+    ///
+    /// [`Context`]: juniper::Context
+    context: syn::Type,
+
+    /// Rust type of [`ScalarValue`] of the generated GraphQL types.
+    ///
+    /// [`ScalarValue`]: juniper::ScalarValue
+    scalar: syn::Type,
+
+    /// Type definitions of the SDL [`Document`] by their names.
+    pub(crate) types: HashMap<&'a str, &'a TypeDefinition<'a, &'a str>>,
+
+    /// Names of GraphQL objects and interfaces implementing a GraphQL
+    /// interface, by the interface name.
+    implementers: HashMap<&'a str, Vec<&'a str>>,
+
+    /// Names of the root operation types: `query`, `mutation` and
+    /// `subscription` respectively.
+    pub(crate) roots: [Option<(&'a str, Pos)>; 3],
+}
+
+impl<'a> Generator<'a> {
+    /// Collects the definitions of the provided SDL [`Document`], checking
+    /// that only the supported ones are used.
+    ///
+    /// If `context` or `scalar` is [`None`], then `()` is used as a [`Context`]
+    /// and [`DefaultScalarValue`] as a [`ScalarValue`] of the generated GraphQL
+    /// types respectively.
+    ///
+    /// [`Context`]: juniper::Context
+    /// [`DefaultScalarValue`]: juniper::DefaultScalarValue
+    /// [`ScalarValue`]: juniper::ScalarValue
+    pub(crate) fn new(
+        path: &syn::LitStr,
+        context: Option<syn::Type>,
+        scalar: Option<syn::Type>,
+        doc: &'a Document<'a>,
+    ) -> syn::Result<Self> {
+        let mut this = Self {
+            span: path.span(),
+            file: path.value(),
+            context: context.unwrap_or_else(|| parse_quote! { () }),
+            scalar: scalar.unwrap_or_else(|| parse_quote! { ::juniper::DefaultScalarValue }),
+            types: HashMap::new(),
+            implementers: HashMap::new(),
+            roots: [None, None, None],
+        };
+
+        let mut schema_def = None;
+        for def in &doc.definitions {
+            match def {
+                Definition::SchemaDefinition(s) => {
+                    if schema_def.replace(s).is_some() {
+                        return Err(this.error(s.position, "schema is defined more than once"));
+                    }
+                }
+                Definition::TypeDefinition(t) => {
+                    let (name, pos) = type_name_and_pos(t);
+                    if this.types.insert(name, t).is_some() {
+                        return Err(
+                            this.error(pos, format!("type `{name}` is defined more than once"))
+                        );
+                    }
+                    let implements = match t {
+                        TypeDefinition::Object(o) => o.implements_interfaces.as_slice(),
+                        TypeDefinition::Interface(i) => i.implements_interfaces.as_slice(),
+                        _ => &[],
+                    };
+                    for iface in implements {
+                        this.implementers.entry(iface).or_default().push(name);
+                    }
+                }
+                Definition::TypeExtension(ext) => {
+                    let pos = match ext {
+                        sdl::TypeExtension::Scalar(e) => e.position,
+                        sdl::TypeExtension::Object(e) => e.position,
+                        sdl::TypeExtension::Interface(e) => e.position,
+                        sdl::TypeExtension::Union(e) => e.position,
+                        sdl::TypeExtension::Enum(e) => e.position,
+                        sdl::TypeExtension::InputObject(e) => e.position,
+                    };
+                    return Err(this.error(pos, "type extensions are not supported"));
+                }
+                // Definitions of custom directives are not registered in the
+                // schema, only their applications are reproduced.
+                Definition::DirectiveDefinition(_) => {}
+            }
+        }
+
+        this.roots = if let Some(s) = schema_def {
+            [s.query, s.mutation, s.subscription].map(|n| n.map(|n| (n, s.position)))
+        } else {
+            ["Query", "Mutation", "Subscription"]
+                .map(|n| this.types.get(n).map(|t| type_name_and_pos(t)))
+        };
+        for (name, pos) in this.roots.iter().flatten() {
+            match this.types.get(name) {
+                Some(TypeDefinition::Object(_)) => {}
+                Some(_) => {
+                    return Err(this.error(*pos, format!("root type `{name}` must be an object")));
+                }
+                None => return Err(this.error(*pos, format!("root type `{name}` is unknown"))),
+            }
+        }
+        Ok(this)
+    }
+
+    /// Generates Rust code for all the type definitions of the provided SDL
+    /// [`Document`], and the `Schema` type alias, if the `query` root type is
+    /// defined.
+    fn generate(&self, doc: &'a Document<'a>) -> syn::Result<TokenStream> {
+        if let Some((_, pos)) = self.roots[2] {
+            return Err(self.error(pos, "subscriptions are not supported"));
+        }
+
+        let mut out = TokenStream::new();
+        for def in &doc.definitions {
+            if let Definition::TypeDefinition(t) = def {
+                out.extend(match t {
+                    TypeDefinition::Scalar(s) => self.scalar_tokens(s.name),
+                    TypeDefinition::Object(o) => self.object_tokens(o)?,
+                    TypeDefinition::Interface(i) => self.interface_tokens(i)?,
+                    TypeDefinition::Union(u) => self.union_tokens(u)?,
+                    TypeDefinition::Enum(e) => self.enum_tokens(e)?,
+                    TypeDefinition::InputObject(i) => self.input_object_tokens(i)?,
+                });
+            }
+        }
+
+        if let Some((query, _)) = self.roots[0] {
+            let (ctx, scalar) = (&self.context, &self.scalar);
+            let query = ident(query);
+            let mutation = self.roots[1].map_or_else(
+                || quote! { ::juniper::EmptyMutation<#ctx> },
+                |(name, _)| ident(name).into_token_stream(),
+            );
+            out.extend(quote! {
+                /// [`RootNode`] of the GraphQL schema.
+                ///
+                /// [`RootNode`]: ::juniper::RootNode
+                pub type Schema = ::juniper::RootNode<
+                    #query, #mutation, ::juniper::EmptySubscription<#ctx>, #scalar,
+                >;
+            });
+        }
+
+        Ok(out)
+    }
+
+    /// Returns generated code asserting that the Rust type, named the same as
+    /// the provided custom GraphQL scalar, is a GraphQL scalar of this name.
+    fn scalar_tokens(&self, name: &str) -> TokenStream {
+        if is_builtin_scalar(name) {
+            return TokenStream::new();
+        }
+
+        let (ty, scalar) = (ident(name), &self.scalar);
+        let msg = format!("`{name}` Rust type must be a GraphQL scalar named `{name}`");
+        quote! {
+            const _: () = ::core::assert!(
+                ::juniper::macros::reflect::str_eq(
+                    <#ty as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
+                    #name,
+                ),
+                #msg,
+            );
+        }
+    }
+
+    /// Returns generated code of a GraphQL object: a trait of its field
+    /// resolvers, and a `#[graphql_object]` implementation for the Rust type
+    /// of the same name, delegating to this trait.
+    fn object_tokens(&self, obj: &'a ObjectType<'a, &'a str>) -> syn::Result<TokenStream> {
+        let (ctx, scalar) = (&self.context, &self.scalar);
+        let ty = ident(obj.name);
+        let trait_ident = format_ident!("{}Fields", obj.name);
+        let trait_doc = format!(
+            "Field resolvers of the `{}` GraphQL object, as described in `{}` file.",
+            obj.name, self.file,
+        );
+
+        let interfaces = obj
+            .implements_interfaces
+            .iter()
+            .map(|name| self.interface_value_ident(name, obj.position))
+            .collect::<syn::Result<Vec<_>>>()?;
+        let mut attrs = vec![
+            name_arg(obj.name),
+            quote! { context = #ctx },
+            quote! { scalar = #scalar },
+        ];
+        if !interfaces.is_empty() {
+            attrs.push(quote! { impl = [#( #interfaces ),*] });
+        }
+        attrs.extend(description_arg(&obj.description));
+        attrs.extend(self.directive_args(&obj.directives)?);
+
+        let fields = obj
+            .fields
+            .iter()
+            .map(|f| self.field(f))
+            .collect::<syn::Result<Vec<_>>>()?;
+        let trait_methods = fields.iter().map(|f| {
+            let (method, ret, doc) = (&f.method, &f.ty, &f.doc);
+            let args = f.args.iter().map(|a| {
+                let (ident, ty) = (&a.ident, &a.ty);
+                quote! { #ident: #ty }
+            });
+            quote! {
+                #doc
+                fn #method(
+                    &self,
+                    context: &#ctx,
+                    #( #args ),*
+                ) -> ::juniper::FieldResult<#ret, #scalar>;
+            }
+        });
+        let resolvers = fields.iter().map(|f| {
+            let (method, ret, attrs) = (&f.method, &f.ty, &f.attrs);
+            let args = f.args.iter().map(Argument::tokens);
+            let arg_idents = f.args.iter().map(|a| &a.ident);
+            quote! {
+                #[graphql(#( #attrs ),*)]
+                fn #method(
+                    &self,
+                    #[graphql(context)] __context: &#ctx,
+                    #( #args ),*
+                ) -> ::juniper::FieldResult<#ret, #scalar> {
+                    <Self as #trait_ident>::#method(self, __context, #( #arg_idents ),*)
+                }
+            }
+        });
+
+        Ok(quote! {
+            #[doc = #trait_doc]
+            pub trait #trait_ident {
+                #( #trait_methods )*
+            }
+
+            #[::juniper::graphql_object]
+            #[graphql(#( #attrs ),*)]
+            impl #ty {
+                #( #resolvers )*
+            }
+        })
+    }
+
+    /// Returns generated code of a `#[graphql_interface]` trait of a GraphQL
+    /// interface.
+    fn interface_tokens(&self, iface: &'a InterfaceType<'a, &'a str>) -> syn::Result<TokenStream> {
+        let (ctx, scalar) = (&self.context, &self.scalar);
+        let ty = ident(iface.name);
+
+        let interfaces = iface
+            .implements_interfaces
+            .iter()
+            .map(|name| self.interface_value_ident(name, iface.position))
+            .collect::<syn::Result<Vec<_>>>()?;
+        let implementers = self
+            .implementers
+            .get(iface.name)
+            .into_iter()
+            .flatten()
+            .map(|name| match self.types.get(name) {
+                Some(TypeDefinition::Interface(_)) => format_ident!("{name}Value"),
+                _ => ident(name),
+            })
+            .collect::<Vec<_>>();
+        let mut attrs = vec![
+            name_arg(iface.name),
+            quote! { context = #ctx },
+            quote! { scalar = #scalar },
+        ];
+        if !interfaces.is_empty() {
+            attrs.push(quote! { impl = [#( #interfaces ),*] });
+        }
+        if !implementers.is_empty() {
+            attrs.push(quote! { for = [#( #implementers ),*] });
+        }
+        attrs.extend(description_arg(&iface.description));
+        attrs.extend(self.directive_args(&iface.directives)?);
+
+        let methods = iface
+            .fields
+            .iter()
+            .map(|f| {
+                let f = self.field(f)?;
+                let (method, ret, attrs) = (&f.method, &f.ty, &f.attrs);
+                let args = f.args.iter().map(Argument::tokens);
+                Ok(quote! {
+                    #[graphql(#( #attrs ),*)]
+                    fn #method(
+                        &self,
+                        #[graphql(context)] __context: &#ctx,
+                        #( #args ),*
+                    ) -> #ret;
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            #[::juniper::graphql_interface]
+            #[graphql(#( #attrs ),*)]
+            pub trait #ty {
+                #( #methods )*
+            }
+        })
+    }
+
+    /// Returns generated code of a `#[derive(GraphQLUnion)]` enum of a GraphQL
+    /// union.
+    fn union_tokens(&self, union: &'a UnionType<'a, &'a str>) -> syn::Result<TokenStream> {
+        let (ctx, scalar) = (&self.context, &self.scalar);
+        let ty = ident(union.name);
+
+        let mut attrs = vec![
+            name_arg(union.name),
+            quote! { context = #ctx },
+            quote! { scalar = #scalar },
+        ];
+        attrs.extend(description_arg(&union.description));
+        attrs.extend(self.directive_args(&union.directives)?);
+
+        let variants = union
+            .types
+            .iter()
+            .map(|name| match self.types.get(name) {
+                Some(TypeDefinition::Object(_)) => {
+                    let var = ident(name);
+                    Ok(quote! { #var(#var) })
+                }
+                Some(_) => Err(self.error(
+                    union.position,
+                    format!("union member `{name}` must be an object"),
+                )),
+                None => {
+                    Err(self.error(union.position, format!("union member `{name}` is unknown")))
+                }
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            #[derive(::juniper::GraphQLUnion)]
+            #[graphql(#( #attrs ),*)]
+            pub enum #ty {
+                #( #variants ),*
+            }
+        })
+    }
+
+    /// Returns generated code of a `#[derive(GraphQLEnum)]` enum of a GraphQL
+    /// enum.
+    pub(crate) fn enum_tokens(&self, enm: &'a EnumType<'a, &'a str>) -> syn::Result<TokenStream> {
+        let (ty, scalar) = (ident(enm.name), &self.scalar);
+
+        let mut attrs = vec![name_arg(enm.name), quote! { scalar = #scalar }];
+        attrs.extend(description_arg(&enm.description));
+        attrs.extend(self.directive_args(&enm.directives)?);
+
+        let variants = enm
+            .values
+            .iter()
+            .map(|v| {
+                let var = ident(&to_pascal_case(v.name));
+                let mut attrs = vec![name_arg(v.name)];
+                attrs.extend(description_arg(&v.description));
+                attrs.extend(self.deprecated_arg(&v.directives));
+                attrs.extend(self.directive_args(&v.directives)?);
+                Ok(quote! {
+                    #[graphql(#( #attrs ),*)]
+                    #var
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            #[derive(
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::fmt::Debug,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+                ::core::cmp::PartialEq,
+                ::juniper::GraphQLEnum,
+            )]
+            #[graphql(#( #attrs ),*)]
+            pub enum #ty {
+                #( #variants ),*
+            }
+        })
+    }
+
+    /// Returns generated code of a `#[derive(GraphQLInputObject)]` struct (or
+    /// enum, if [`@oneOf`]) of a GraphQL input object.
+    ///
+    /// [`@oneOf`]: https://spec.graphql.org/September2025#sec--oneOf
+    pub(crate) fn input_object_tokens(
+        &self,
+        obj: &'a InputObjectType<'a, &'a str>,
+    ) -> syn::Result<TokenStream> {
+        let (ty, scalar) = (ident(obj.name), &self.scalar);
+        let is_one_of = obj.directives.iter().any(|d| d.name == "oneOf");
+
+        let mut attrs = vec![name_arg(obj.name), quote! { scalar = #scalar }];
+        attrs.extend(description_arg(&obj.description));
+        attrs.extend(self.directive_args(&obj.directives)?);
+
+        let fields = obj
+            .fields
+            .iter()
+            .map(|f| {
+                let mut attrs = vec![name_arg(f.name)];
+                attrs.extend(description_arg(&f.description));
+                attrs.extend(self.deprecated_arg(&f.directives));
+                attrs.extend(self.directive_args(&f.directives)?);
+                if is_one_of {
+                    let var = ident(&to_pascal_case(f.name));
+                    if let Type::NonNullType(_) = &f.value_type {
+                        return Err(self.error(
+                            f.position,
+                            format!("`@oneOf` input object field `{}` must be nullable", f.name),
+                        ));
+                    }
+                    let ty = self.non_null_ty(&f.value_type, f.position)?;
+                    return Ok(quote! {
+                        #[graphql(#( #attrs ),*)]
+                        #var(#ty)
+                    });
+                }
+                let field = ident(&to_snake_case(f.name));
+                let ty = self.ty(&f.value_type, f.position)?;
+                if let Some(val) = &f.default_value {
+                    let val = self.value_expr(val, &f.value_type, f.position)?;
+                    attrs.push(quote! { default = #val });
+                }
+                Ok(quote! {
+                    #[graphql(#( #attrs ),*)]
+                    pub #field: #ty
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let body = if is_one_of {
+            quote! { pub enum #ty { #( #fields ),* } }
+        } else {
+            quote! { pub struct #ty { #( #fields ),* } }
+        };
+        Ok(quote! {
+            #[derive(::core::clone::Clone, ::core::fmt::Debug, ::juniper::GraphQLInputObject)]
+            #[graphql(#( #attrs ),*)]
+            #body
+        })
+    }
+
+    /// Prepares the provided GraphQL [`Field`] for code generation.
+    fn field(&self, field: &'a Field<'a, &'a str>) -> syn::Result<FieldTokens> {
+        let mut attrs = vec![name_arg(field.name)];
+        attrs.extend(description_arg(&field.description));
+        attrs.extend(self.deprecated_arg(&field.directives));
+        attrs.extend(self.directive_args(&field.directives)?);
+
+        Ok(FieldTokens {
+            method: ident(&to_snake_case(field.name)),
+            ty: self.ty(&field.field_type, field.position)?,
+            doc: field.description.as_ref().map(|d| quote! { #[doc = #d] }),
+            attrs,
+            args: field
+                .arguments
+                .iter()
+                .map(|a| self.argument(a))
+                .collect::<syn::Result<_>>()?,
+        })
+    }
+
+    /// Prepares the provided GraphQL field argument for code generation.
+    fn argument(&self, arg: &'a InputValue<'a, &'a str>) -> syn::Result<Argument> {
+        let mut attrs = vec![name_arg(arg.name)];
+        attrs.extend(description_arg(&arg.description));
+        attrs.extend(self.deprecated_arg(&arg.directives));
+        attrs.extend(self.directive_args(&arg.directives)?);
+        if let Some(val) = &arg.default_value {
+            let val = self.value_expr(val, &arg.value_type, arg.position)?;
+            attrs.push(quote! { default = #val });
+        }
+
+        // Avoid clashing with the `context` argument of the generated trait.
+        let mut name = to_snake_case(arg.name);
+        if name == "context" {
+            name.push('_');
+        }
+
+        Ok(Argument {
+            ident: ident(&name),
+            ty: self.ty(&arg.value_type, arg.position)?,
+            attrs,
+        })
+    }
+
+    /// Returns Rust type representing the provided GraphQL [`Type`].
+    pub(crate) fn ty(&self, ty: &Type<'a>, pos: Pos) -> syn::Result<TokenStream> {
+        Ok(match ty {
+            Type::NonNullType(inner) => self.non_null_ty(inner, pos)?,
+            ty => {
+                let ty = self.non_null_ty(ty, pos)?;
+                quote! { ::core::option::Option<#ty> }
+            }
+        })
+    }
+
+    /// Returns Rust type representing the provided GraphQL [`Type`], ignoring
+    /// its nullability.
+    pub(crate) fn non_null_ty(&self, ty: &Type<'a>, pos: Pos) -> syn::Result<TokenStream> {
+        Ok(match ty {
+            Type::NonNullType(inner) => self.non_null_ty(inner, pos)?,
+            Type::ListType(inner) => {
+                let ty = self.ty(inner, pos)?;
+                quote! { ::std::vec::Vec<#ty> }
+            }
+            Type::NamedType(name) => match *name {
+                "Int" => quote! { i32 },
+                "Float" => quote! { f64 },
+                "String" => quote! { ::std::string::String },
+                "Boolean" => quote! { bool },
+                "ID" => quote! { ::juniper::ID },
+                name => match self.types.get(name) {
+                    Some(TypeDefinition::Interface(_)) => {
+                        self.interface_value_ident(name, pos)?.into_token_stream()
+                    }
+                    Some(_) => ident(name).into_token_stream(),
+                    None => return Err(self.error(pos, format!("type `{name}` is unknown"))),
+                },
+            },
+        })
+    }
+
+    /// Returns Rust expression of the provided GraphQL type `ty`, representing
+    /// the provided GraphQL input value `val`.
+    fn value_expr(&self, val: &Value<'a>, ty: &Type<'a>, pos: Pos) -> syn::Result<TokenStream> {
+        let Type::NonNullType(ty) = ty else {
+            return Ok(if let Value::Null = val {
+                quote! { ::core::option::Option::None }
+            } else {
+                let expr = self.value_expr(val, &Type::NonNullType(Box::new(ty.clone())), pos)?;
+                quote! { ::core::option::Option::Some(#expr) }
+            });
+        };
+        Ok(match (&**ty, val) {
+            (_, Value::Null) => {
+                return Err(self.error(pos, "`null` is not allowed for a non-`null`able type"));
+            }
+            (_, Value::Variable(_)) => {
+                return Err(self.error(pos, "variables are not allowed in default values"));
+            }
+            (Type::ListType(inner), Value::List(items)) => {
+                let items = items
+                    .iter()
+                    .map(|v| self.value_expr(v, inner, pos))
+                    .collect::<syn::Result<Vec<_>>>()?;
+                quote! { ::std::vec![#( #items ),*] }
+            }
+            // Single value is coerced into a list, as required by the spec.
+            (Type::ListType(inner), val) => {
+                let item = self.value_expr(val, inner, pos)?;
+                quote! { ::std::vec![#item] }
+            }
+            (Type::NamedType("Int"), Value::Int(n)) => {
+                let n = n
+                    .as_i64()
+                    .and_then(|n| i32::try_from(n).ok())
+                    .ok_or_else(|| {
+                        self.error(pos, "`Int` default value doesn't fit into 32-bit integer")
+                    })?;
+                Literal::i32_suffixed(n).into_token_stream()
+            }
+            (Type::NamedType("Float"), Value::Int(n)) => {
+                let n = n.as_i64().unwrap_or_default() as f64;
+                Literal::f64_suffixed(n).into_token_stream()
+            }
+            (Type::NamedType("Float"), Value::Float(f)) => {
+                Literal::f64_suffixed(*f).into_token_stream()
+            }
+            (Type::NamedType("String"), Value::String(s)) => {
+                quote! { ::std::string::String::from(#s) }
+            }
+            (Type::NamedType("ID"), Value::String(s)) => quote! { ::juniper::ID::new(#s) },
+            (Type::NamedType("ID"), Value::Int(n)) => {
+                let s = n.as_i64().unwrap_or_default().to_string();
+                quote! { ::juniper::ID::new(#s) }
+            }
+            (Type::NamedType("Boolean"), Value::Boolean(b)) => quote! { #b },
+            (Type::NamedType(name), Value::Enum(v)) => match self.types.get(name) {
+                Some(TypeDefinition::Enum(e)) if e.values.iter().any(|ev| ev.name == *v) => {
+                    let (ty, var) = (ident(name), ident(&to_pascal_case(v)));
+                    quote! { #ty::#var }
+                }
+                _ => return Err(self.error(pos, format!("`{v}` is not a value of `{name}` enum"))),
+            },
+            (Type::NamedType(name), Value::Object(fields)) => {
+                let Some(TypeDefinition::InputObject(obj)) = self.types.get(name) else {
+                    return Err(self.error(pos, format!("`{name}` is not an input object")));
+                };
+                if let Some(unknown) = fields
+                    .keys()
+                    .find(|k| !obj.fields.iter().any(|f| f.name == **k))
+                {
+                    return Err(self.error(
+                        pos,
+                        format!("`{name}` input object has no `{unknown}` field"),
+                    ));
+                }
+                let ty = ident(name);
+                if obj.directives.iter().any(|d| d.name == "oneOf") {
+                    let mut fields = fields.iter().filter(|(_, v)| !matches!(v, Value::Null));
+                    let (Some((field, val)), None) = (fields.next(), fields.next()) else {
+                        return Err(self.error(
+                            pos,
+                            format!("exactly one non-`null` field of `{name}` must be specified"),
+                        ));
+                    };
+                    let def = obj.fields.iter().find(|f| f.name == *field).unwrap();
+                    let var = ident(&to_pascal_case(field));
+                    let expr = self.value_expr(
+                        val,
+                        &Type::NonNullType(Box::new(def.value_type.clone())),
+                        pos,
+                    )?;
+                    quote! { #ty::#var(#expr) }
+                } else {
+                    let fields = obj
+                        .fields
+                        .iter()
+                        .map(|f| {
+                            let field = ident(&to_snake_case(f.name));
+                            let expr = match (fields.get(&f.name), &f.default_value, &f.value_type)
+                            {
+                                (Some(v), ..) | (None, Some(v), _) => {
+                                    self.value_expr(v, &f.value_type, pos)?
+                                }
+                                (None, None, Type::NonNullType(_)) => {
+                                    return Err(self.error(
+                                        pos,
+                                        format!("`{}` field of `{name}` must be specified", f.name),
+                                    ));
+                                }
+                                (None, None, _) => quote! { ::core::option::Option::None },
+                            };
+                            Ok(quote! { #field: #expr })
+                        })
+                        .collect::<syn::Result<Vec<_>>>()?;
+                    quote! { #ty { #( #fields ),* } }
+                }
+            }
+            (Type::NamedType(name), _) => {
+                return Err(self.error(
+                    pos,
+                    format!("default value is not supported for `{name}` type"),
+                ));
+            }
+            (Type::NonNullType(_), _) => unreachable!("nested non-`null` type is not parsed"),
+        })
+    }
+
+    /// Returns name of the `enum` generated by `#[graphql_interface]` macro
+    /// for the provided GraphQL interface.
+    fn interface_value_ident(&self, name: &str, pos: Pos) -> syn::Result<syn::Ident> {
+        match self.types.get(name) {
+            Some(TypeDefinition::Interface(_)) => Ok(format_ident!("{name}Value")),
+            Some(_) => Err(self.error(pos, format!("`{name}` is not an interface"))),
+            None => Err(self.error(pos, format!("interface `{name}` is unknown"))),
+        }
+    }
+
+    /// Returns `deprecated` attribute's argument, if the [`@deprecated`]
+    /// directive is present in the provided ones.
+    ///
+    /// [`@deprecated`]: https://spec.graphql.org/October2021#sec--deprecated
+    fn deprecated_arg(&self, directives: &[Directive<'a, &'a str>]) -> Option<TokenStream> {
+        let dir = directives.iter().find(|d| d.name == "deprecated")?;
+        Some(match dir.arguments.iter().find(|(n, _)| *n == "reason") {
+            Some((_, Value::String(reason))) => quote! { deprecated = #reason },
+            _ => quote! { deprecated },
+        })
+    }
+
+    /// Returns `directive` attribute's arguments reproducing the provided
+    /// applied directives, omitting the built-in ones.
+    fn directive_args(
+        &self,
+        directives: &[Directive<'a, &'a str>],
+    ) -> syn::Result<Vec<TokenStream>> {
+        directives
+            .iter()
+            .filter(|d| !matches!(d.name, "deprecated" | "oneOf"))
+            .map(|d| {
+                let name = ident(d.name);
+                if d.arguments.is_empty() {
+                    return Ok(quote! { directive = #name });
+                }
+                let args = d
+                    .arguments
+                    .iter()
+                    .map(|(n, v)| {
+                        let n = ident(n);
+                        let v = self.directive_value(v, d.position)?;
+                        Ok(quote! { #n = #v })
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+                Ok(quote! { directive = #name(#( #args ),*) })
+            })
+            .collect()
+    }
+
+    /// Returns the provided argument value of an applied directive, as
+    /// accepted by `directive` attribute's argument.
+    fn directive_value(&self, val: &Value<'a>, pos: Pos) -> syn::Result<TokenStream> {
+        Ok(match val {
+            Value::Int(n) => {
+                Literal::i64_unsuffixed(n.as_i64().unwrap_or_default()).into_token_stream()
+            }
+            Value::Float(f) => Literal::f64_unsuffixed(*f).into_token_stream(),
+            Value::String(s) => quote! { #s },
+            Value::Boolean(b) => quote! { #b },
+            Value::Null => quote! { null },
+            Value::Enum(v) => ident(v).into_token_stream(),
+            Value::List(items) => {
+                let items = items
+                    .iter()
+                    .map(|v| self.directive_value(v, pos))
+                    .collect::<syn::Result<Vec<_>>>()?;
+                quote! { [#( #items ),*] }
+            }
+            Value::Variable(_) | Value::Object(_) => {
+                return Err(self.error(
+                    pos,
+                    "only scalar, enum, `null` and list values are supported in applied directives",
+                ));
+            }
+        })
+    }
+
+    /// Returns Rust type of [`ScalarValue`] of the generated GraphQL types.
+    ///
+    /// [`ScalarValue`]: juniper::ScalarValue
+    pub(crate) fn scalar(&self) -> &syn::Type {
+        &self.scalar
+    }
+
+    /// Creates a [`syn::Error`] related to the provided position in the SDL
+    /// file.
+    pub(crate) fn error(&self, pos: Pos, msg: impl AsRef<str>) -> syn::Error {
+        ERR.custom_error(
+            self.span,
+            format!("at `{}:{pos}`: {}", self.file, msg.as_ref()),
+        )
+    }
+}
+
+/// GraphQL field prepared for code generation.
+struct FieldTokens {
+    /// Rust method name of this field.
+    method: syn::Ident,
+
+    /// Rust type of this field.
+    ty: TokenStream,
+
+    /// Rust doc comment of this field's method.
+    doc: Option<TokenStream>,
+
+    /// `#[graphql]` attribute's arguments of this field.
+    attrs: Vec<TokenStream>,
+
+    /// Arguments of this field.
+    args: Vec<Argument>,
+}
+
+/// GraphQL field argument prepared for code generation.
+struct Argument {
+    /// Rust name of this argument.
+    ident: syn::Ident,
+
+    /// Rust type of this argument.
+    ty: TokenStream,
+
+    /// `#[graphql]` attribute's arguments of this argument.
+    attrs: Vec<TokenStream>,
+}
+
+impl Argument {
+    /// Returns generated code of this [`Argument`] in a method signature.
+    fn tokens(&self) -> TokenStream {
+        let (ident, ty, attrs) = (&self.ident, &self.ty, &self.attrs);
+        quote! {
+            #[graphql(#( #attrs ),*)]
+            #ident: #ty
+        }
+    }
+}
+
+/// Returns name and position of the provided [`TypeDefinition`].
+fn type_name_and_pos<'a>(def: &TypeDefinition<'a, &'a str>) -> (&'a str, Pos) {
+    match def {
+        TypeDefinition::Scalar(t) => (t.name, t.position),
+        TypeDefinition::Object(t) => (t.name, t.position),
+        TypeDefinition::Interface(t) => (t.name, t.position),
+        TypeDefinition::Union(t) => (t.name, t.position),
+        TypeDefinition::Enum(t) => (t.name, t.position),
+        TypeDefinition::InputObject(t) => (t.name, t.position),
+    }
+}
+
+/// Indicates whether the provided type name is a built-in GraphQL scalar.
+pub(crate) fn is_builtin_scalar(name: &str) -> bool {
+    matches!(name, "Int" | "Float" | "String" | "Boolean" | "ID")
+}
+
+/// Returns `name` attribute's argument with the provided GraphQL name.
+pub(crate) fn name_arg(name: &str) -> TokenStream {
+    quote! { name = #name }
+}
+
+/// Returns `description` attribute's argument with the provided GraphQL
+/// description, if any.
+fn description_arg(description: &Option<String>) -> Option<TokenStream> {
+    description.as_ref().map(|d| quote! { description = #d })
+}
+
+/// Creates a Rust identifier from the provided GraphQL name, escaping Rust
+/// keywords.
+pub(crate) fn ident(name: &str) -> syn::Ident {
+    syn::parse_str::<syn::Ident>(name)
+        .or_else(|_| syn::parse_str::<syn::Ident>(&format!("r#{name}")))
+        .unwrap_or_else(|_| format_ident!("_{name}"))
+}
+
+/// Converts the provided GraphQL name into `snake_case`.
+pub(crate) fn to_snake_case(name: &str) -> String {
+    rename::Policy::SnakeCase.apply(name)
+}
+
+/// Converts the provided GraphQL name into `PascalCase`.
+pub(crate) fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .flat_map(|part| {
+            let is_upper = !part.chars().any(char::is_lowercase);
+            part.chars().enumerate().flat_map(move |(i, c)| {
+                if i == 0 {
+                    c.to_uppercase().collect::<Vec<_>>()
+                } else if is_upper {
+                    c.to_lowercase().collect()
+                } else {
+                    vec![c]
+                }
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod to_pascal_case_tests {
+    use super::to_pascal_case;
+
+    #[test]
+    fn converts() {
+        for (input, expected) in [
+            ("NEW_HOPE", "NewHope"),
+            ("JEDI", "Jedi"),
+            ("newHope", "NewHope"),
+            ("_private", "Private"),
+            ("A", "A"),
+        ] {
+            assert_eq!(to_pascal_case(input), expected, "input: {input}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod generator_tests {
+    use graphql_parser::schema::parse_schema;
+    use quote::quote;
+
+    use super::{Attr, Generator};
+
+    fn generate(sdl: &str) -> Result<(), String> {
+        let attr = syn::parse2::<Attr>(quote! { "schema.graphql" }).unwrap();
+        let doc = parse_schema::<&str>(sdl).unwrap();
+        Generator::new(&attr.path, attr.context, attr.scalar, &doc)
+            .and_then(|g| g.generate(&doc))
+            .map(drop)
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn generates_supported_definitions() {
+        assert_eq!(
+            generate(
+                "enum Episode { NEW_HOPE }
+                 input Filter { episode: Episode = NEW_HOPE, ids: [ID!] = 1 }
+                 type Query { heroes(filter: Filter = {}): [String] }",
+            ),
+            Ok(()),
+        );
+    }
+
+    #[test]
+    fn errors_on_unsupported_definitions() {
+        for (sdl, expected) in [
+            (
+                "type Query { hero: Hero }",
+                "GraphQL schema at `schema.graphql:1:14`: type `Hero` is unknown",
+            ),
+            (
+                "type Query { id: ID } extend type Query { name: String }",
+                "GraphQL schema at `schema.graphql:1:30`: type extensions are not supported",
+            ),
+            (
+                "type Query { id: ID } type Subscription { id: ID }",
+                "GraphQL schema at `schema.graphql:1:23`: subscriptions are not supported",
+            ),
+            (
+                "enum Episode { JEDI } type Query { hero(episode: Episode = EMPIRE): ID }",
+                "GraphQL schema at `schema.graphql:1:41`: `EMPIRE` is not a value of `Episode` \
+                 enum",
+            ),
+            (
+                "input Filter { id: ID! } type Query { hero(filter: Filter = {}): ID }",
+                "GraphQL schema at `schema.graphql:1:44`: `id` field of `Filter` must be specified",
+            ),
+        ] {
+            assert_eq!(generate(sdl), Err(expected.into()), "SDL: {sdl}");
+        }
+    }
+}
@@ -8,7 +8,10 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::parse_quote;
 
-use crate::{common::field, graphql_object::Definition};
+use crate::{
+    common::{field, type_info},
+    graphql_object::Definition,
+};
 
 /// [GraphQL subscription operation][2] of the [`Definition`] to generate code
 /// for.
@@ -40,14 +43,15 @@ impl Definition<Subscription> {
         let (impl_generics, where_clause) = self.impl_generics(false);
         let ty = &self.ty;
 
-        let name = &self.name;
+        let type_info = type_info::ty_tokens(self.type_info.as_ref());
+        let concrete_type_name = self.method_concrete_type_name_tokens();
 
         quote! {
             #[automatically_derived]
             impl #impl_generics ::juniper::GraphQLValue<#scalar> for #ty #where_clause
             {
                 type Context = #context;
-                type TypeInfo = ();
+                type TypeInfo = #type_info;
 
                 fn type_name(
                     &self,
@@ -68,13 +72,7 @@ impl Definition<Subscription> {
                     ))
                 }
 
-                fn concrete_type_name(
-                    &self,
-                    _: &Self::Context,
-                    _: &Self::TypeInfo,
-                ) -> ::std::string::String {
-                    #name.into()
-                }
+                #concrete_type_name
             }
         }
     }
@@ -105,7 +103,7 @@ impl Definition<Subscription> {
         let fields_resolvers = self
             .fields
             .iter()
-            .map(|f| f.method_resolve_field_into_stream_tokens(scalar));
+            .map(|f| f.method_resolve_field_into_stream_tokens(self.guard.as_ref(), scalar));
         let no_field_err =
             field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
 
@@ -91,7 +91,10 @@ fn expand_on_trait(
         ty: parse_quote! { #trait_ident },
         is_trait_object: true,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
         context,
+        type_info: attr.type_info.map(SpanContainer::into_inner),
         scalar: scalar::Type::parse(attr.scalar.as_deref(), &ast.generics),
         generics: ast.generics.clone(),
         variants,
@@ -83,10 +83,13 @@ fn expand_enum(ast: syn::DeriveInput) -> syn::Result<Definition> {
         ty: parse_quote! { #enum_ident },
         is_trait_object: false,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
         context: attr
             .context
             .map(SpanContainer::into_inner)
             .unwrap_or_else(|| parse_quote! { () }),
+        type_info: attr.type_info.map(SpanContainer::into_inner),
         scalar: scalar::Type::parse(attr.scalar.as_deref(), &ast.generics),
         generics: ast.generics,
         variants,
@@ -216,10 +219,13 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition> {
         ty: parse_quote! { #struct_ident },
         is_trait_object: false,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        visible: attr.visible.map(SpanContainer::into_inner),
         context: attr
             .context
             .map(SpanContainer::into_inner)
             .unwrap_or_else(|| parse_quote! { () }),
+        type_info: attr.type_info.map(SpanContainer::into_inner),
         scalar: scalar::Type::parse(attr.scalar.as_deref(), &ast.generics),
         generics: ast.generics,
         variants,
@@ -18,12 +18,14 @@ use syn::{
 };
 
 use crate::common::{
-    AttrNames, Description, SpanContainer, filter_attrs, generate,
+    AttrNames, Description, SpanContainer, directive, filter_attrs, generate,
     parse::{
         ParseBufferExt as _,
         attr::{OptionExt as _, err},
     },
     scalar,
+    type_info::{self, TypeInfo},
+    visibility,
 };
 
 /// Helper alias for the type of [`Attr::external_resolvers`] field.
@@ -51,6 +53,18 @@ struct Attr {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<SpanContainer<Description>>,
 
+    /// Explicitly specified [directives][2] applied to this [GraphQL union][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Unions
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
+    /// Explicitly specified [`visibility::Predicate`] of this [GraphQL
+    /// union][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Unions
+    visible: Option<SpanContainer<visibility::Predicate>>,
+
     /// Explicitly specified type of [`Context`] to use for resolving this
     /// [GraphQL union][1] type with.
     ///
@@ -60,6 +74,15 @@ struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Unions
     context: Option<SpanContainer<syn::Type>>,
 
+    /// Explicitly specified type of [`TypeInfo`] to use for resolving this
+    /// [GraphQL union][1] type with.
+    ///
+    /// If [`None`], then unit type `()` is assumed as a type of [`TypeInfo`].
+    ///
+    /// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+    /// [1]: https://spec.graphql.org/October2021#sec-Unions
+    type_info: Option<SpanContainer<TypeInfo>>,
+
     /// Explicitly specified type of [`ScalarValue`] to use for resolving this
     /// [GraphQL union][1] type with.
     ///
@@ -114,6 +137,21 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(desc.span()), desc))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse()?);
+                }
+                "visible" => {
+                    input.parse::<token::Eq>()?;
+                    let predicate = input.parse::<visibility::Predicate>()?;
+                    out.visible
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(predicate.span()),
+                            predicate,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ctx" | "context" | "Context" => {
                     input.parse::<token::Eq>()?;
                     let ctx = input.parse::<syn::Type>()?;
@@ -121,6 +159,13 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(ctx.span()), ctx))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "type_info" => {
+                    input.parse::<token::Eq>()?;
+                    let ti = input.parse::<TypeInfo>()?;
+                    out.type_info
+                        .replace(SpanContainer::new(ident.span(), Some(ti.span()), ti))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "scalar" | "Scalar" | "ScalarValue" => {
                     input.parse::<token::Eq>()?;
                     let scl = input.parse::<scalar::AttrValue>()?;
@@ -158,7 +203,14 @@ impl Attr {
         Ok(Self {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            visible: try_merge_opt!(visible: self, another),
             context: try_merge_opt!(context: self, another),
+            type_info: try_merge_opt!(type_info: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             external_resolvers: try_merge_hashmap!(
                 external_resolvers: self, another => span_joined
@@ -279,6 +331,17 @@ struct Definition {
     /// [1]: https://spec.graphql.org/October2021#sec-Unions
     description: Option<Description>,
 
+    /// [Directives][2] applied to this [GraphQL union][1] in GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Unions
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    directives: Vec<directive::Directive>,
+
+    /// [`visibility::Predicate`] of this [GraphQL union][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Unions
+    visible: Option<visibility::Predicate>,
+
     /// Rust type of [`Context`] to generate [`GraphQLType`] implementation with
     /// for this [GraphQL union][1].
     ///
@@ -287,6 +350,16 @@ struct Definition {
     /// [1]: https://spec.graphql.org/October2021#sec-Unions
     context: syn::Type,
 
+    /// Custom Rust type of [`TypeInfo`] to generate [`GraphQLType`]
+    /// implementation with for this [GraphQL union][1].
+    ///
+    /// If [`None`], then unit type `()` is used.
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
+    /// [1]: https://spec.graphql.org/October2021#sec-Unions
+    type_info: Option<TypeInfo>,
+
     /// Rust type of [`ScalarValue`] to generate [`GraphQLType`] implementation
     /// with for this [GraphQL union][1].
     ///
@@ -466,27 +539,37 @@ impl Definition {
 
         let name = &self.name;
         let description = &self.description;
+        let directives = &self.directives;
+        let visible = &self.visible;
 
         let variant_tys = self.variants.iter().map(|var| &var.ty);
+        let info = type_info::child_tokens();
+
+        let name_method = type_info::name_method_tokens(
+            self.type_info.as_ref(),
+            &quote! { ::juniper::arcstr::literal!(#name) },
+        );
+        let dyn_description = type_info::description_tokens(self.type_info.as_ref());
 
         quote! {
             #[automatically_derived]
             impl #impl_generics ::juniper::GraphQLType<#scalar> for #ty_full #where_clause
             {
-                fn name(_ : &Self::TypeInfo) -> ::core::option::Option<::juniper::ArcStr> {
-                    ::core::option::Option::Some(::juniper::arcstr::literal!(#name))
-                }
+                #name_method
 
                 fn meta(
                     info: &Self::TypeInfo,
                     registry: &mut ::juniper::Registry<#scalar>,
                 ) -> ::juniper::meta::MetaType<#scalar> {
                     let types = [
-                        #( registry.get_type::<#variant_tys>(info), )*
+                        #( registry.get_type::<#variant_tys>(#info), )*
                     ];
-                    registry.build_union_type::<#ty_full>(info, &types)
+                    let meta = registry.build_union_type::<#ty_full>(info, &types)
                         #description
-                        .into_meta()
+                        #( #directives )*
+                        #visible;
+                    #dyn_description
+                    meta.into_meta()
                 }
             }
         }
@@ -501,6 +584,7 @@ impl Definition {
     fn impl_graphql_value_tokens(&self) -> TokenStream {
         let scalar = &self.scalar;
         let context = &self.context;
+        let type_info = type_info::ty_tokens(self.type_info.as_ref());
 
         let (impl_generics, ty_full, where_clause) = self.impl_generics(false);
 
@@ -521,7 +605,7 @@ impl Definition {
             impl #impl_generics ::juniper::GraphQLValue<#scalar> for #ty_full #where_clause
             {
                 type Context = #context;
-                type TypeInfo = ();
+                type TypeInfo = #type_info;
 
                 fn type_name(
                     &self,
@@ -690,10 +774,11 @@ impl VariantDefinition {
     fn method_concrete_type_name_tokens(&self, scalar: &scalar::Type) -> TokenStream {
         let ty = &self.ty;
         let check = &self.resolver_check;
+        let info = type_info::child_tokens();
 
         quote! {
             if #check {
-                return <#ty as ::juniper::GraphQLType<#scalar>>::name(info)
+                return <#ty as ::juniper::GraphQLType<#scalar>>::name(#info)
                     .unwrap()
                     .to_string();
             }
@@ -711,9 +796,10 @@ impl VariantDefinition {
         let ty_name = ty.to_token_stream().to_string();
         let expr = &self.resolver_code;
         let resolving_code = generate::sync_resolving_code();
+        let info = type_info::child_tokens();
 
         quote! {
-            if type_name == <#ty as ::juniper::GraphQLType<#scalar>>::name(info)
+            if type_name == <#ty as ::juniper::GraphQLType<#scalar>>::name(#info)
                 .ok_or_else(|| ::juniper::macros::helper::err_unnamed_type(#ty_name))?
             {
                 let res = { #expr };
@@ -734,9 +820,10 @@ impl VariantDefinition {
         let ty_name = ty.to_token_stream().to_string();
         let expr = &self.resolver_code;
         let resolving_code = generate::async_resolving_code(None);
+        let info = type_info::child_tokens();
 
         quote! {
-            match <#ty as ::juniper::GraphQLType<#scalar>>::name(info) {
+            match <#ty as ::juniper::GraphQLType<#scalar>>::name(#info) {
                 ::core::option::Option::Some(name) => {
                     if type_name == name {
                         let fut = ::juniper::futures::future::ready({ #expr });
@@ -106,8 +106,13 @@ mod common;
 mod graphql_enum;
 mod graphql_input_object;
 mod graphql_interface;
+mod graphql_merged_object;
 mod graphql_object;
+#[cfg(feature = "schema-language")]
+mod graphql_query;
 mod graphql_scalar;
+#[cfg(feature = "schema-language")]
+mod graphql_schema;
 mod graphql_subscription;
 mod graphql_union;
 mod scalar_value;
@@ -274,8 +279,75 @@ use self::common::diagnostic::{self, ResultExt as _};
 /// }
 /// ```
 ///
+/// # Flattening fields
+///
+/// To inline the fields of a nested [GraphQL input object][0] into the parent one (similarly to
+/// `#[serde(flatten)]`), use the `flatten` attribute's argument on the Rust struct field holding
+/// it. The inlined fields are placed after the own ones, and must not clash with them (checked at
+/// compile time). Not supported by [`@oneOf`] input objects.
+///
+/// Note, that an input object with flattened fields cannot have more than 256 fields in total
+/// (including the flattened ones), as they're checked at compile time in a fixed-size buffer.
+/// Exceeding this limit fails compilation.
+///
+/// ```rust
+/// # use juniper::GraphQLInputObject;
+/// #
+/// #[derive(GraphQLInputObject)]
+/// struct Pagination {
+///     #[graphql(default = 10)]
+///     first: i32,
+///     after: Option<String>,
+/// }
+///
+/// #[derive(GraphQLInputObject)]
+/// struct UsersFilter {
+///     name: String,
+///     #[graphql(flatten)] // exposed as `{ name, first, after }`
+///     pagination: Pagination,
+/// }
+/// ```
+///
+/// # Validating fields
+///
+/// To declaratively validate the values of [GraphQL input object fields][1], use the `validate`
+/// attribute's argument with the following rules:
+/// - `min = <expr>` and `max = <expr>` (inclusive bounds of a numeric value);
+/// - `min_length = <int>` and `max_length = <int>` (length of a string in `char`s, or of a list);
+/// - `regex = "<pattern>"` (requires `regex` Juniper feature, which makes invalid patterns fail
+///   the build).
+///
+/// Validation runs during [`FromInputValue`] conversion, and its failure is reported as a
+/// [`FieldError`] having the path to the offending value (like `$filter.users[3].name`, starting
+/// with the argument name) in its `inputPath` extension. `null`able fields are validated only when present. Not supported by
+/// [`@oneOf`] input objects.
+///
+/// ```rust
+/// # use juniper::GraphQLInputObject;
+/// #
+/// #[derive(GraphQLInputObject)]
+/// struct NewUser {
+///     #[graphql(validate(min_length = 1, max_length = 64))]
+///     name: String,
+///     #[graphql(validate(min = 0, max = 150))]
+///     age: Option<i32>,
+/// }
+/// ```
+///
+/// # Runtime type information
+///
+/// By default, the generated code uses unit type `()` as the [`TypeInfo`] of
+/// the [GraphQL input object][0]. A custom one, implementing
+/// [`DynamicTypeInfo`], may be specified with a `type_info` attribute's
+/// argument, allowing to compute the name and description of the [GraphQL input
+/// object][0] at runtime.
+///
 /// [`@oneOf`]: https://spec.graphql.org/September2025#sec--oneOf
+/// [`DynamicTypeInfo`]: juniper::DynamicTypeInfo
+/// [`FieldError`]: juniper::FieldError
+/// [`FromInputValue`]: juniper::FromInputValue
 /// [`ScalarValue`]: juniper::ScalarValue
+/// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
 /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
 /// [1]: https://spec.graphql.org/October2021#InputFieldsDefinition
 /// [2]: https://spec.graphql.org/October2021#sec-Scalars
@@ -409,7 +481,16 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Runtime type information
+///
+/// By default, the generated code uses unit type `()` as the [`TypeInfo`] of
+/// the [GraphQL enum][0]. A custom one, implementing [`DynamicTypeInfo`], may
+/// be specified with a `type_info` attribute's argument, allowing to compute
+/// the name and description of the [GraphQL enum][0] at runtime.
+///
+/// [`DynamicTypeInfo`]: juniper::DynamicTypeInfo
 /// [`ScalarValue`]: juniper::ScalarValue
+/// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
 /// [0]: https://spec.graphql.org/October2021#sec-Enums
 /// [1]: https://spec.graphql.org/October2021#sec-Enum-Value
 #[proc_macro_derive(GraphQLEnum, attributes(graphql))]
@@ -756,6 +837,15 @@ pub fn derive_enum(input: TokenStream) -> TokenStream {
 /// `#[graphql(where(<bounds>))]` attribute, like the following:
 /// `#[graphql(where(S: Trait, Self: fmt::Debug + fmt::Display))]`.
 ///
+/// # Runtime type information
+///
+/// By default, the generated code uses unit type `()` as the [`TypeInfo`] of the [GraphQL
+/// scalar][0]. A custom one, implementing [`DynamicTypeInfo`], may be specified with a `type_info`
+/// attribute's argument, allowing to compute the name and description of the [GraphQL scalar][0] at
+/// runtime.
+///
+/// [`DynamicTypeInfo`]: juniper::DynamicTypeInfo
+/// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
 /// [0]: https://spec.graphql.org/October2021#sec-Scalars
 /// [1]: https://rust-unofficial.github.io/patterns/patterns/behavioural/newtype.html
 /// [`ScalarValue`]: juniper::ScalarValue
@@ -1368,6 +1458,17 @@ pub fn derive_scalar_value(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Field guards
+///
+/// Resolving of a [GraphQL interface][1] field may be authorized by specifying
+/// a `guard` attribute's argument (on a trait method or on the whole trait)
+/// with an expression implementing [`juniper::Guard`] for the context, the same
+/// way as for `#[graphql_object]` macro. Such guards are checked by the field
+/// resolvers of the [GraphQL interface][1] implementers (defined via
+/// `#[derive(GraphQLObject)]` or `#[graphql_object]` macros), before their own
+/// guards, so selections on the implementers (like `... on Human { id }`) are
+/// authorized by them too.
+///
 /// # Using `Executor`
 ///
 /// If an [`Executor`] is required in a trait method to resolve a [GraphQL interface][1] field,
@@ -1554,6 +1655,144 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Templated name
+///
+/// Generic [GraphQL object][1] may template its name with the type parameters,
+/// so every instantiation of it becomes a distinct GraphQL type. A `{T}`
+/// placeholder in the `name` attribute's argument is substituted with the
+/// GraphQL name of the `T` type parameter in runtime (so the nested
+/// `Connection<Connection<User>>` becomes `UserConnectionConnection` type),
+/// which must have unit type `()` as its `TypeInfo`, and must not be a list or
+/// `null`able type (so a schema using `Connection<Vec<Post>>` fails to build,
+/// rather than colliding with `Connection<Post>`).
+///
+/// Note, that the names used by compile-time reflection (like the checks of
+/// GraphQL interface implementers' field types) cannot be computed in a
+/// `const` context, so they contain the raw template (like `{T}Connection`)
+/// for every instantiation. This way, such checks don't distinguish
+/// `Connection<User>` from `Connection<Post>`, while GraphQL schema does.
+///
+/// ```
+/// # use std::marker::PhantomData;
+/// #
+/// # use juniper::GraphQLObject;
+/// #
+/// #[derive(GraphQLObject)]
+/// // `Connection<User>` becomes `UserConnection` type in GraphQL schema,
+/// // while `Connection<Post>` becomes `PostConnection` type.
+/// #[graphql(name = "{T}Connection")]
+/// struct Connection<T> {
+///     total_count: i32,
+///     #[graphql(ignore)]
+///     _node: PhantomData<T>,
+/// }
+/// ```
+///
+/// # Relay `Node`
+///
+/// `node` attribute's argument makes a [GraphQL object][1] implement the
+/// [Relay `Node` interface][relay-node], which requires the object to have an
+/// `id: ID!` field returning its [`relay::GlobalId`].
+///
+/// `nodes` attribute's argument adds `node(id: ID!): Node` and
+/// `nodes(ids: [ID!]!): [Node]!` fields to a query root, fetching the listed
+/// types via their [`relay::Node`] implementations. Every listed type must
+/// have the `node` attribute's argument itself. A failed fetch of a node in
+/// the `nodes` field nulls only its own entry, reporting a field error. These
+/// fields can be resolved asynchronously only, so the synchronous execution
+/// returns a field error for them.
+///
+/// ```
+/// # use juniper::{FieldResult, GraphQLObject, ID, relay};
+/// #
+/// #[derive(GraphQLObject)]
+/// #[graphql(node)]
+/// struct User {
+///     id: ID, // encoded `relay::GlobalId`
+///     name: String,
+/// }
+///
+/// impl relay::Node for User {
+///     async fn fetch_node(local_id: &str, _: &()) -> FieldResult<Option<Self>> {
+///         Ok(Some(Self {
+///             id: relay::GlobalId::new("User", local_id).into(),
+///             name: "John".into(),
+///         }))
+///     }
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(nodes = [User])]
+/// struct Query {
+///     api_version: String,
+/// }
+/// ```
+///
+/// [`relay::GlobalId`]: juniper::relay::GlobalId
+/// [`relay::Node`]: juniper::relay::Node
+/// [relay-node]: https://relay.dev/graphql/objectidentification.htm#sec-Node-Interface
+///
+/// # Flattening fields
+///
+/// To inline the fields of a nested [GraphQL object][1] into the parent one
+/// (similarly to `#[serde(flatten)]`), use the `flatten` attribute's argument
+/// on the struct field holding it. The nested object should use the same
+/// `context` and `scalar` as the parent one, and its fields are placed after
+/// the own ones of the parent, and must not clash with them (checked at
+/// compile time). Flattened fields cannot be used to implement fields of
+/// GraphQL interfaces.
+///
+/// Note, that an object with flattened or [complex](#complex-fields) fields
+/// cannot have more than 256 fields in total (including the flattened and
+/// complex ones), as they're checked at compile time in a fixed-size buffer.
+/// Exceeding this limit fails compilation.
+///
+/// ```
+/// # use juniper::GraphQLObject;
+/// #
+/// #[derive(GraphQLObject)]
+/// struct Audit {
+///     created_by: String,
+///     revision: i32,
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// struct Post {
+///     title: String,
+///     #[graphql(flatten)] // exposed as `{ title, createdBy, revision }`
+///     audit: Audit,
+/// }
+/// ```
+///
+/// # Complex fields
+///
+/// `complex` attribute's argument allows to add fields resolved by methods to
+/// a derived [GraphQL object][1], without rewriting all its struct fields as
+/// methods. The additional fields are defined in a separate impl block marked
+/// with `#[graphql_object(complex)]`, which accepts the same field attributes
+/// as a regular `#[graphql_object]` impl block, and uses the `context` of the
+/// struct. Its fields must be named differently from the struct ones, which is
+/// checked at compile time (so the [limit](#flattening-fields) of the total
+/// number of fields applies).
+///
+/// ```
+/// # use juniper::{graphql_object, GraphQLObject};
+/// #
+/// #[derive(GraphQLObject)]
+/// #[graphql(complex)]
+/// struct User {
+///     first_name: String,
+///     last_name: String,
+/// }
+///
+/// #[graphql_object(complex)]
+/// impl User {
+///     fn full_name(&self) -> String {
+///         format!("{} {}", self.first_name, self.last_name)
+///     }
+/// }
+/// ```
+///
 /// # Renaming policy
 ///
 /// By default, all [GraphQL object][1] fields are renamed via `camelCase`
@@ -1597,6 +1836,109 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Applied directives
+///
+/// Arbitrary [directives][2] may be applied to a [GraphQL object][1] or its
+/// fields by specifying a `directive` attribute's argument (possibly multiple
+/// times). Directive arguments accept Rust literals, `null`, lists (`[...]`)
+/// and bare identifiers (treated as GraphQL enum values).
+///
+/// Applied directives are printed in SDL and may be inspected at runtime via
+/// `MetaType::directives()`, but are not exposed via introspection. The same
+/// argument is supported by all other GraphQL type definition macros.
+///
+/// ```
+/// # use juniper::GraphQLObject;
+/// #
+/// #[derive(GraphQLObject)]
+/// #[graphql(directive = key(fields = "id"), directive = shareable)]
+/// struct Human {
+///     id: String,
+///     #[graphql(directive = auth(requires = ADMIN, scopes = ["read"]))]
+///     email: String,
+/// }
+/// ```
+///
+/// # Per-request visibility
+///
+/// A [GraphQL object][1] or its fields may be hidden from some requests by
+/// specifying a `visible` attribute's argument with a path to a function
+/// accepting a reference to the context and returning a `bool`. Hidden fields
+/// and types are omitted from introspection and rejected by validation, as if
+/// they didn't exist. The same argument is supported by `#[graphql_object]`,
+/// `#[graphql_interface]` and `#[graphql_union]` macros.
+///
+/// Predicates are evaluated only for schemas built with
+/// `RootNode::enable_visibility()`, otherwise every request (as well as
+/// `juniper::validate()`, having no request context) fails with a
+/// `GraphQLError::VisibilityError`.
+///
+/// ```
+/// # use juniper::GraphQLObject;
+/// #
+/// struct Context {
+///     is_internal: bool,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// fn is_internal(ctx: &Context) -> bool {
+///     ctx.is_internal
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(context = Context)]
+/// struct Build {
+///     version: String,
+///     #[graphql(visible = is_internal)]
+///     host: String,
+/// }
+/// ```
+///
+/// # Field guards
+///
+/// Resolving of a [GraphQL object][1] field may be authorized by specifying a
+/// `guard` attribute's argument with an expression implementing
+/// [`juniper::Guard`] for the context (like a function accepting a reference
+/// to the context and returning a `FieldResult<()>`). A denying guard fails the
+/// field with its error, without calling the resolver. Guards may be combined
+/// via [`juniper::GuardExt`] methods.
+///
+/// Specified on the whole [GraphQL object][1], a guard is checked for each of
+/// its fields before the field's own one. Guards of the implemented
+/// `#[graphql_interface]`s are checked before both. The same argument is
+/// supported by `#[graphql_object]`, `#[graphql_interface]` and
+/// `#[graphql_subscription]` macros.
+///
+/// ```
+/// # use juniper::{FieldResult, GraphQLObject, ScalarValue};
+/// #
+/// struct Session {
+///     is_admin: bool,
+/// }
+///
+/// impl juniper::Context for Session {}
+///
+/// fn admin<S: ScalarValue>(session: &Session) -> FieldResult<(), S> {
+///     if session.is_admin {
+///         Ok(())
+///     } else {
+///         Err("Forbidden".into())
+///     }
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(context = Session)]
+/// struct Employee {
+///     name: String,
+///     #[graphql(guard = admin)]
+///     salary: i32,
+/// }
+/// ```
+///
+/// > __NOTE:__ Unless a concrete [`ScalarValue`] is specified via `scalar`
+/// >           attribute's argument, guards should be generic over it.
+///
 /// # Custom `ScalarValue`
 ///
 /// By default, `#[derive(GraphQLObject)]` macro generates code, which is
@@ -1623,8 +1965,58 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Runtime type information
+///
+/// By default, the generated code uses unit type `()` as the [`TypeInfo`] of
+/// the [GraphQL object][1]. A custom one, implementing
+/// [`juniper::DynamicTypeInfo`], may be specified with a `type_info`
+/// attribute's argument, allowing to compute the name, description and the
+/// set of fields of the [GraphQL object][1] at runtime, from the value passed
+/// to the [`RootNode::new_with_info()`].
+///
+/// The same `type_info` value is passed through to the fields and arguments
+/// whose [`TypeInfo`] is the same type, while the ones with unit type `()` as
+/// their [`TypeInfo`] are resolved with it, so such [GraphQL objects][1] may
+/// be nested into each other. Any other [`TypeInfo`] of a field or an argument
+/// is rejected at compile time. With the `complex` argument, the fields of the
+/// `#[graphql_object(complex)]` impl block are resolved with the same
+/// `type_info` value too. The `type_info` argument cannot be combined with
+/// `impl`, `node` and `nodes` ones, as [GraphQL interfaces][3] are always
+/// resolved with unit type `()` as their [`TypeInfo`], and the name and fields
+/// computed at runtime would bypass the compile-time checks of implementing
+/// them (like hiding a field required by an interface).
+///
+/// ```
+/// # use juniper::{ArcStr, DynamicTypeInfo, GraphQLObject};
+/// #
+/// struct Tenant {
+///     name: ArcStr,
+/// }
+///
+/// impl DynamicTypeInfo for Tenant {
+///     fn name(&self) -> Option<ArcStr> {
+///         Some(self.name.clone())
+///     }
+///
+///     fn has_field(&self, name: &str) -> bool {
+///         name != "internalId"
+///     }
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(type_info = Tenant)]
+/// struct Settings {
+///     theme: String,
+///     internal_id: i32,
+/// }
+/// ```
+///
+/// [`RootNode::new_with_info()`]: juniper::RootNode::new_with_info
 /// [`ScalarValue`]: juniper::ScalarValue
+/// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
 /// [1]: https://spec.graphql.org/October2021#sec-Objects
+/// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+/// [3]: https://spec.graphql.org/October2021#sec-Interfaces
 #[proc_macro_derive(GraphQLObject, attributes(graphql))]
 pub fn derive_object(body: TokenStream) -> TokenStream {
     diagnostic::entry_point(|| {
@@ -1634,6 +2026,76 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
     })
 }
 
+/// `#[derive(GraphQLMergedObject)]` macro for deriving a [GraphQL object][1]
+/// implementation merging the fields of multiple other [GraphQL objects][1].
+///
+/// This allows to split a large root type (like a query or a mutation one)
+/// into several independent [GraphQL objects][1], owned by different modules
+/// or teams, and to compose them back into a single one.
+///
+/// Each field of the struct should hold a [GraphQL object][1] implemented via
+/// `#[graphql_object]` or `#[derive(GraphQLObject)]` macro, sharing the same
+/// context type. Resolving a field of the merged [GraphQL object][1] is
+/// delegated to the one declaring it. The interfaces implemented by the merged
+/// [GraphQL objects][1] are not inherited.
+///
+/// ```
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, GraphQLMergedObject, RootNode, graphql_object,
+/// };
+///
+/// struct UsersQuery;
+///
+/// #[graphql_object]
+/// impl UsersQuery {
+///     fn user_count() -> i32 {
+///         42
+///     }
+/// }
+///
+/// struct PostsQuery;
+///
+/// #[graphql_object]
+/// impl PostsQuery {
+///     fn post_count() -> i32 {
+///         7
+///     }
+/// }
+///
+/// #[derive(GraphQLMergedObject)]
+/// #[graphql(name = "Query")]
+/// struct Query(UsersQuery, PostsQuery);
+///
+/// // Exposes both `userCount` and `postCount` fields on the `Query` type.
+/// let schema = RootNode::new(
+///     Query(UsersQuery, PostsQuery),
+///     EmptyMutation::<()>::new(),
+///     EmptySubscription::<()>::new(),
+/// );
+/// ```
+///
+/// # Field name conflicts
+///
+/// Merged [GraphQL objects][1] must not declare fields with the same name.
+/// Otherwise, building a schema with such a merged [GraphQL object][1] panics
+/// in the `SchemaType::new()` method.
+///
+/// # Attributes
+///
+/// The `name`, `description`, `context`, `scalar`, `directive` and `visible`
+/// attribute's arguments are supported, having the same meaning as for
+/// `#[derive(GraphQLObject)]` macro.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+#[proc_macro_derive(GraphQLMergedObject, attributes(graphql))]
+pub fn derive_merged_object(body: TokenStream) -> TokenStream {
+    diagnostic::entry_point(|| {
+        self::graphql_merged_object::derive::expand(body.into())
+            .unwrap_or_abort()
+            .into()
+    })
+}
+
 /// `#[graphql_object]` macro for generating a [GraphQL object][1]
 /// implementation for structs with computable field resolvers (declared via
 /// a regular Rust `impl` block).
@@ -1785,6 +2247,123 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Validating arguments
+///
+/// Values of field arguments may be validated declaratively with a `validate`
+/// attribute's argument, supporting the same rules as the one of the
+/// [`GraphQLInputObject`] fields. A validation failure is returned as a
+/// [`FieldError`] of the field, pointing to the offending argument via its
+/// `inputPath` extension.
+///
+/// ```
+/// # use juniper::graphql_object;
+/// #
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn search(
+///         #[graphql(validate(min_length = 3))] text: String,
+///         #[graphql(default = 10, validate(min = 1, max = 100))] first: i32,
+///     ) -> Vec<String> {
+///         vec![text; first as usize]
+///     }
+/// }
+/// ```
+///
+/// # Templated name
+///
+/// Generic [GraphQL object][1] may template its name with the type parameters,
+/// so every instantiation of it becomes a distinct GraphQL type. A `{T}`
+/// placeholder in the `name` attribute's argument is substituted with the
+/// GraphQL name of the `T` type parameter in runtime (so the nested
+/// `Connection<Connection<User>>` becomes `UserConnectionConnection` type),
+/// which must have unit type `()` as its `TypeInfo`, and must not be a list or
+/// `null`able type (so a schema using `Connection<Vec<Post>>` fails to build,
+/// rather than colliding with `Connection<Post>`).
+///
+/// Note, that the names used by compile-time reflection (like the checks of
+/// GraphQL interface implementers' field types) cannot be computed in a
+/// `const` context, so they contain the raw template (like `{T}Connection`)
+/// for every instantiation. This way, such checks don't distinguish
+/// `Connection<User>` from `Connection<Post>`, while GraphQL schema does.
+///
+/// ```
+/// # use std::marker::PhantomData;
+/// #
+/// # use juniper::graphql_object;
+/// #
+/// struct Connection<T>(PhantomData<T>);
+///
+/// // `Connection<User>` becomes `UserConnection` type in GraphQL schema,
+/// // while `Connection<Post>` becomes `PostConnection` type.
+/// #[graphql_object(name = "{T}Connection")]
+/// impl<T> Connection<T> {
+///     fn total_count(&self) -> i32 {
+///         0
+///     }
+/// }
+/// ```
+///
+/// # Relay `Node`
+///
+/// `node` attribute's argument makes a [GraphQL object][1] implement the
+/// [Relay `Node` interface][relay-node], which requires the object to have an
+/// `id: ID!` field returning its [`relay::GlobalId`].
+///
+/// `nodes` attribute's argument adds `node(id: ID!): Node` and
+/// `nodes(ids: [ID!]!): [Node]!` fields to a query root, fetching the listed
+/// types via their [`relay::Node`] implementations. Every listed type must
+/// have the `node` attribute's argument itself. A failed fetch of a node in
+/// the `nodes` field nulls only its own entry, reporting a field error. These
+/// fields can be resolved asynchronously only, so the synchronous execution
+/// returns a field error for them.
+///
+/// ```
+/// # use juniper::{graphql_object, FieldResult, ID, relay};
+/// #
+/// struct User {
+///     id: i32,
+/// }
+///
+/// #[graphql_object(node)]
+/// impl User {
+///     fn id(&self) -> ID {
+///         relay::GlobalId::new("User", self.id.to_string()).into()
+///     }
+/// }
+///
+/// impl relay::Node for User {
+///     async fn fetch_node(local_id: &str, _: &()) -> FieldResult<Option<Self>> {
+///         Ok(Some(Self { id: local_id.parse()? }))
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object(nodes = [User])]
+/// impl Query {
+///     fn api_version() -> &'static str {
+///         "0.1"
+///     }
+/// }
+/// ```
+///
+/// [`relay::GlobalId`]: juniper::relay::GlobalId
+/// [`relay::Node`]: juniper::relay::Node
+/// [relay-node]: https://relay.dev/graphql/objectidentification.htm#sec-Node-Interface
+///
+/// # Complex fields
+///
+/// `complex` attribute's argument makes an impl block define additional fields
+/// of a `#[derive(GraphQLObject)]` struct marked with `#[graphql(complex)]`
+/// instead of a standalone [GraphQL object][1]. Such impl block cannot specify
+/// arguments describing the object itself (like `name`, `description`,
+/// `context`, `scalar`, `impl` or `node`), as they're specified on the struct. See [`GraphQLObject`] derive macro docs
+/// for details.
+///
+/// [`GraphQLObject`]: derive@crate::GraphQLObject
+///
 /// # Renaming policy
 ///
 /// By default, all [GraphQL object][1] fields and their arguments are renamed
@@ -1880,6 +2459,60 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Field guards
+///
+/// Resolving of a [GraphQL object][1] field may be authorized by specifying a
+/// `guard` attribute's argument (on a method or on the whole `impl` block)
+/// with an expression implementing [`juniper::Guard`] for the [`Context`]. A
+/// type-level guard is checked before the field's own one, and guards of the
+/// implemented `#[graphql_interface]`s are checked before both. Guards may be
+/// combined via [`juniper::GuardExt`] methods.
+///
+/// ```
+/// # use juniper::{graphql_object, FieldResult, Guard, GuardExt as _, ScalarValue};
+/// #
+/// struct Session {
+///     role: Option<&'static str>,
+///     banned: bool,
+/// }
+/// impl juniper::Context for Session {}
+///
+/// struct Role(&'static str);
+///
+/// impl<S: ScalarValue> Guard<Session, S> for Role {
+///     fn check(&self, session: &Session) -> FieldResult<(), S> {
+///         if session.role == Some(self.0) {
+///             Ok(())
+///         } else {
+///             Err(format!("`{}` role is required", self.0).into())
+///         }
+///     }
+/// }
+///
+/// fn not_banned<S: ScalarValue>(session: &Session) -> FieldResult<(), S> {
+///     if session.banned {
+///         Err("Banned".into())
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// #[graphql(context = Session, guard = not_banned)]
+/// impl Query {
+///     fn news() -> &'static str {
+///         "Nothing happened"
+///     }
+///
+///     #[graphql(guard = Role("admin").or(Role("auditor")))]
+///     fn audit_log() -> Vec<String> {
+///         vec![]
+///     }
+/// }
+/// ```
+///
 /// # Using `Executor`
 ///
 /// If an [`Executor`] is required in a method to resolve a [GraphQL object][1]
@@ -1953,11 +2586,46 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Runtime type information
+///
+/// Similarly to `#[derive(GraphQLObject)]`, a custom [`TypeInfo`] (implementing
+/// [`juniper::DynamicTypeInfo`]) may be specified with a `type_info`
+/// attribute's argument, allowing to compute the name, description and the set
+/// of fields of the [GraphQL object][1] at runtime. It cannot be combined with
+/// `impl`, `node` and `nodes` arguments either. The same argument is supported
+/// by `#[graphql_subscription]` macro.
+///
+/// ```
+/// # use juniper::{ArcStr, DynamicTypeInfo, graphql_object};
+/// #
+/// struct Api {
+///     version: ArcStr,
+/// }
+///
+/// impl DynamicTypeInfo for Api {
+///     fn description(&self) -> Option<ArcStr> {
+///         Some(format!("API of version {}.", self.version).into())
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object(type_info = Api)]
+/// impl Query {
+///     fn ping() -> &'static str {
+///         "pong"
+///     }
+/// }
+/// ```
+///
 /// [`Context`]: juniper::Context
 /// [`Executor`]: juniper::Executor
+/// [`FieldError`]: juniper::FieldError
+/// [`GraphQLInputObject`]: derive@crate::GraphQLInputObject
 /// [`GraphQLType`]: juniper::GraphQLType
 /// [`GraphQLValue`]: juniper::GraphQLValue
 /// [`ScalarValue`]: juniper::ScalarValue
+/// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
 /// [0]: https://spec.graphql.org/October2021
 /// [1]: https://spec.graphql.org/October2021#sec-Objects
 #[proc_macro_attribute]
@@ -2023,6 +2691,193 @@ pub fn graphql_subscription(attr: TokenStream, body: TokenStream) -> TokenStream
     })
 }
 
+/// `graphql_schema_from_file!` macro for generating Rust definitions of a
+/// [GraphQL schema][0] described in an [SDL][1] file, for designing the schema
+/// first.
+///
+/// The file path is resolved relatively to the `CARGO_MANIFEST_DIR` of the
+/// crate invoking the macro. Optionally, `context` and `scalar` arguments may
+/// be specified to use a custom [`Context`] and [`ScalarValue`] types for all
+/// the generated definitions (by default, `()` and [`DefaultScalarValue`] are
+/// used).
+///
+/// For the following schema:
+/// ```graphql
+/// schema {
+///   query: Query
+/// }
+///
+/// "Episode of the saga."
+/// enum Episode {
+///   NEW_HOPE
+///   EMPIRE
+/// }
+///
+/// interface Character {
+///   id: ID!
+/// }
+///
+/// type Human implements Character {
+///   id: ID!
+///   homePlanet: String @deprecated(reason: "Use `planet` instead.")
+/// }
+///
+/// type Query {
+///   hero(episode: Episode = NEW_HOPE): Character
+/// }
+/// ```
+///
+/// the macro generates:
+/// - `#[derive(GraphQLEnum)]` Rust enums for GraphQL enums (with
+///   `PascalCase`d variants);
+/// - `#[derive(GraphQLInputObject)]` Rust structs for GraphQL input objects
+///   (with `snake_case`d public fields), or Rust enums for [`@oneOf`] ones;
+/// - `#[derive(GraphQLUnion)]` Rust enums for GraphQL unions;
+/// - `#[graphql_interface]` Rust traits for GraphQL interfaces (so the
+///   `CharacterValue` enum is used for referring to the `Character` interface);
+/// - `HumanFields` Rust trait with a resolver method for each field of the
+///   `Human` GraphQL object, and `#[graphql_object]` implementation for the
+///   `Human` Rust type, delegating to this trait;
+/// - `Schema` type alias of the [`RootNode`], if the `query` root is defined;
+/// - compile-time assertions that the Rust types named as custom GraphQL
+///   scalars are the GraphQL scalars of the same names.
+///
+/// So, only the Rust types of GraphQL objects and custom scalars, and the
+/// resolvers traits implementations should be provided manually. Every
+/// mismatch between the SDL and the Rust implementation results in a
+/// compilation error.
+///
+/// ```rust,ignore
+/// use juniper::{FieldResult, ID};
+///
+/// juniper::graphql_schema_from_file!("schema.graphql", context = Database);
+///
+/// pub struct Human {
+///     id: ID,
+/// }
+///
+/// impl HumanFields for Human {
+///     fn id(&self, _: &Database) -> FieldResult<ID> {
+///         Ok(self.id.clone())
+///     }
+///
+///     fn home_planet(&self, db: &Database) -> FieldResult<Option<String>> {
+///         Ok(db.planet_of(&self.id))
+///     }
+/// }
+///
+/// pub struct Query;
+///
+/// impl QueryFields for Query {
+///     fn hero(
+///         &self,
+///         db: &Database,
+///         episode: Option<Episode>,
+///     ) -> FieldResult<Option<CharacterValue>> {
+///         Ok(db.hero(episode).map(Into::into))
+///     }
+/// }
+/// ```
+///
+/// Type extensions, subscriptions and default values of custom scalars are not
+/// supported. Definitions of custom directives are ignored, while their
+/// applications are reproduced via `directive` attribute's argument.
+///
+/// [`@oneOf`]: https://spec.graphql.org/September2025#sec--oneOf
+/// [`Context`]: juniper::Context
+/// [`DefaultScalarValue`]: juniper::DefaultScalarValue
+/// [`RootNode`]: juniper::RootNode
+/// [`ScalarValue`]: juniper::ScalarValue
+/// [0]: https://spec.graphql.org/October2021#sec-Schema
+/// [1]: https://spec.graphql.org/October2021#sec-Type-System
+#[cfg(feature = "schema-language")]
+#[proc_macro]
+pub fn graphql_schema_from_file(input: TokenStream) -> TokenStream {
+    diagnostic::entry_point(|| {
+        self::graphql_schema::expand(input.into())
+            .unwrap_or_abort()
+            .into()
+    })
+}
+
+/// `#[derive(GraphQLQuery)]` macro for generating a typed client of a
+/// [GraphQL operation][0], validated against a [GraphQL schema][1] described
+/// in an [SDL][2] file.
+///
+/// The `schema` and `document` file paths are resolved relatively to the
+/// `CARGO_MANIFEST_DIR` of the crate invoking the macro. The operation named
+/// as the Rust struct is used (or the single one in the document), unless the
+/// `operation` name is specified explicitly. Optionally, `scalar` argument may
+/// be specified to use a custom [`ScalarValue`] type (by default,
+/// [`DefaultScalarValue`] is used).
+///
+/// For the following operation:
+/// ```graphql
+/// query HeroQuery($episode: Episode) {
+///   hero(episode: $episode) {
+///     __typename
+///     name
+///     ... on Human {
+///       homePlanet
+///     }
+///   }
+/// }
+/// ```
+///
+/// the macro generates a `hero_query` module (named as the Rust struct in
+/// `snake_case`) containing:
+/// - `Variables` struct with a public field for each variable of the
+///   operation (`Option`al, if the variable is `null`able or has a default
+///   value, so [`None`] omits it);
+/// - `ResponseData` struct with a public field for each response key of the
+///   operation, and a nested `ResponseData{Path}` struct (`ResponseDataHero`
+///   here) for each selection of a composite type;
+/// - `#[derive(GraphQLEnum)]` Rust enums and `#[derive(GraphQLInputObject)]`
+///   Rust structs for the GraphQL enums and input objects used by the
+///   operation.
+///
+/// Fragments are flattened, so the fields selected under a fragment of
+/// another type (or under `@skip`/`@include` directives) are `Option`al. The
+/// `__typename` field is represented as a `typename` Rust field. Custom
+/// scalars are referred by their names, so the Rust types named as them
+/// should be in scope of the Rust struct.
+///
+/// ```rust,ignore
+/// use juniper::{GraphQLQuery, client};
+///
+/// #[derive(GraphQLQuery)]
+/// #[graphql(schema = "schema.graphql", document = "operations.graphql")]
+/// struct HeroQuery;
+///
+/// let variables = hero_query::Variables {
+///     episode: Some(hero_query::Episode::Empire),
+/// };
+/// let response = client::execute_sync(HeroQuery, &variables, &schema, &ctx)?;
+/// let hero: Option<hero_query::ResponseDataHero> = response.data?.hero;
+/// ```
+///
+/// Every unknown type, field, argument, variable or directive, missing
+/// required argument, argument value of a wrong type, variable used where its
+/// type is not allowed, unused variable or invalid selection results in a
+/// compilation error. Only `@skip` and `@include` directives are supported.
+/// Values of custom scalars are not checked. Subscriptions and anonymous
+/// operations are not supported.
+///
+/// [`DefaultScalarValue`]: juniper::DefaultScalarValue
+/// [`ScalarValue`]: juniper::ScalarValue
+/// [0]: https://spec.graphql.org/October2021#sec-Executable-Definitions
+/// [1]: https://spec.graphql.org/October2021#sec-Schema
+/// [2]: https://spec.graphql.org/October2021#sec-Type-System
+#[cfg(feature = "schema-language")]
+#[proc_macro_derive(GraphQLQuery, attributes(graphql))]
+pub fn derive_query(input: TokenStream) -> TokenStream {
+    diagnostic::entry_point(|| {
+        self::graphql_query::expand(input.into())
+            .unwrap_or_abort()
+            .into()
+    })
+}
+
 /// `#[derive(GraphQLUnion)]` macro for deriving a [GraphQL union][1] implementation for enums and
 /// structs.
 ///
@@ -2319,8 +3174,17 @@ pub fn graphql_subscription(attr: TokenStream, body: TokenStream) -> TokenStream
 /// }
 /// ```
 ///
+/// # Runtime type information
+///
+/// By default, the generated code uses unit type `()` as the [`TypeInfo`] of
+/// the [GraphQL union][1]. A custom one, implementing [`DynamicTypeInfo`], may
+/// be specified with a `type_info` attribute's argument, allowing to compute
+/// the name and description of the [GraphQL union][1] at runtime.
+///
 /// [`Context`]: juniper::Context
+/// [`DynamicTypeInfo`]: juniper::DynamicTypeInfo
 /// [`ScalarValue`]: juniper::ScalarValue
+/// [`TypeInfo`]: juniper::GraphQLValue::TypeInfo
 /// [1]: https://spec.graphql.org/October2021#sec-Unions
 /// [4]: https://doc.rust-lang.org/stable/std/primitive.unit.html
 #[proc_macro_derive(GraphQLUnion, attributes(graphql))]
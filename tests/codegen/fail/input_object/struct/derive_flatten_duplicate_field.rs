@@ -0,0 +1,15 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+struct Pagination {
+    first: i32,
+}
+
+#[derive(GraphQLInputObject)]
+struct Object {
+    first: i32,
+    #[graphql(flatten)]
+    pagination: Pagination,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+struct Object {
+    #[graphql(validate(regex = "^[a-z+$"))]
+    login: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+struct Object {
+    id: String,
+    #[graphql(ignore, validate(min_length = 1))]
+    name: String,
+}
+
+fn main() {}
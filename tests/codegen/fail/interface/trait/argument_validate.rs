@@ -0,0 +1,8 @@
+use juniper::graphql_interface;
+
+#[graphql_interface]
+trait Character {
+    fn id(&self, #[graphql(validate(min = 1))] num: i32) -> &str;
+}
+
+fn main() {}
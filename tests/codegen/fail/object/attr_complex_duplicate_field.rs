@@ -0,0 +1,17 @@
+use juniper::{graphql_object, GraphQLObject};
+
+#[derive(GraphQLObject)]
+#[graphql(complex)]
+struct Human {
+    id: i32,
+    name: String,
+}
+
+#[graphql_object(complex)]
+impl Human {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+fn main() {}
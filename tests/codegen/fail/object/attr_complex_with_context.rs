@@ -0,0 +1,20 @@
+use juniper::{graphql_object, GraphQLObject};
+
+struct Database;
+
+impl juniper::Context for Database {}
+
+#[derive(GraphQLObject)]
+#[graphql(complex, context = Database)]
+struct Human {
+    id: i32,
+}
+
+#[graphql_object(complex, context = Database)]
+impl Human {
+    fn name() -> &'static str {
+        "Luke"
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use juniper::{graphql_object, FieldResult, GraphQLObject};
+
+fn deny(_: &()) -> FieldResult<()> {
+    Err("Forbidden".into())
+}
+
+#[derive(GraphQLObject)]
+#[graphql(complex)]
+struct Human {
+    id: i32,
+}
+
+#[graphql_object(complex, guard = deny)]
+impl Human {
+    fn name() -> &'static str {
+        "Luke"
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use juniper::{graphql_object, GraphQLObject};
+
+#[derive(GraphQLObject)]
+#[graphql(complex)]
+struct Human {
+    id: i32,
+}
+
+#[graphql_object(complex, name = "Person")]
+impl Human {
+    fn name() -> &'static str {
+        "Luke"
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use juniper::{graphql_object, DynamicTypeInfo, GraphQLObject};
+
+struct Info;
+
+impl DynamicTypeInfo for Info {}
+
+#[derive(GraphQLObject)]
+#[graphql(complex, type_info = Info)]
+struct Human {
+    id: i32,
+}
+
+#[graphql_object(complex, type_info = Info)]
+impl Human {
+    fn name() -> &'static str {
+        "Luke"
+    }
+}
+
+fn main() {}
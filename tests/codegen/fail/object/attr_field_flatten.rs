@@ -0,0 +1,13 @@
+use juniper::graphql_object;
+
+struct Obj;
+
+#[graphql_object]
+impl Obj {
+    #[graphql(flatten)]
+    fn id(&self) -> &str {
+        "funA"
+    }
+}
+
+fn main() {}
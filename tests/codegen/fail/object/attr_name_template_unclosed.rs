@@ -0,0 +1,14 @@
+use std::marker::PhantomData;
+
+use juniper::graphql_object;
+
+struct Connection<T>(PhantomData<T>);
+
+#[graphql_object(name = "{TConnection")]
+impl<T> Connection<T> {
+    fn total_count(&self) -> i32 {
+        0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use juniper::graphql_object;
+
+struct Human;
+
+#[graphql_object(node)]
+impl Human {
+    fn name() -> &'static str {
+        "Luke"
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use juniper::{graphql_object, GraphQLObject, ID};
+
+#[derive(GraphQLObject)]
+#[graphql(node)]
+struct Human {
+    id: ID,
+}
+
+struct QueryRoot;
+
+#[graphql_object(nodes = [Human])]
+impl QueryRoot {
+    fn node() -> Option<Human> {
+        None
+    }
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use juniper::{graphql_object, relay::Node, FieldResult, GraphQLObject, ID};
+
+#[derive(GraphQLObject)]
+struct Human {
+    id: ID,
+}
+
+impl Node for Human {
+    async fn fetch_node(_: &str, _: &()) -> FieldResult<Option<Self>> {
+        Ok(None)
+    }
+}
+
+struct QueryRoot;
+
+#[graphql_object(nodes = [Human])]
+impl QueryRoot {
+    fn humans_count() -> i32 {
+        0
+    }
+}
+
+fn main() {}
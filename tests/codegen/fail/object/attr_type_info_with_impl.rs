@@ -0,0 +1,20 @@
+use juniper::{graphql_object, ArcStr, DynamicTypeInfo};
+
+struct Info;
+
+impl DynamicTypeInfo for Info {
+    fn name(&self) -> Option<ArcStr> {
+        Some("Droid".into())
+    }
+}
+
+struct Human;
+
+#[graphql_object(type_info = Info, impl = CharacterValue)]
+impl Human {
+    fn id(&self) -> &str {
+        "human-32"
+    }
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use juniper::GraphQLObject;
+
+#[derive(GraphQLObject)]
+struct Audit {
+    id: String,
+}
+
+#[derive(GraphQLObject)]
+struct ObjA {
+    id: String,
+    #[graphql(flatten)]
+    audit: Audit,
+}
+
+fn main() {}
@@ -0,0 +1,277 @@
+use juniper::GraphQLObject;
+
+#[derive(GraphQLObject)]
+struct Left {
+    l0: i32,
+    l1: i32,
+    l2: i32,
+    l3: i32,
+    l4: i32,
+    l5: i32,
+    l6: i32,
+    l7: i32,
+    l8: i32,
+    l9: i32,
+    l10: i32,
+    l11: i32,
+    l12: i32,
+    l13: i32,
+    l14: i32,
+    l15: i32,
+    l16: i32,
+    l17: i32,
+    l18: i32,
+    l19: i32,
+    l20: i32,
+    l21: i32,
+    l22: i32,
+    l23: i32,
+    l24: i32,
+    l25: i32,
+    l26: i32,
+    l27: i32,
+    l28: i32,
+    l29: i32,
+    l30: i32,
+    l31: i32,
+    l32: i32,
+    l33: i32,
+    l34: i32,
+    l35: i32,
+    l36: i32,
+    l37: i32,
+    l38: i32,
+    l39: i32,
+    l40: i32,
+    l41: i32,
+    l42: i32,
+    l43: i32,
+    l44: i32,
+    l45: i32,
+    l46: i32,
+    l47: i32,
+    l48: i32,
+    l49: i32,
+    l50: i32,
+    l51: i32,
+    l52: i32,
+    l53: i32,
+    l54: i32,
+    l55: i32,
+    l56: i32,
+    l57: i32,
+    l58: i32,
+    l59: i32,
+    l60: i32,
+    l61: i32,
+    l62: i32,
+    l63: i32,
+    l64: i32,
+    l65: i32,
+    l66: i32,
+    l67: i32,
+    l68: i32,
+    l69: i32,
+    l70: i32,
+    l71: i32,
+    l72: i32,
+    l73: i32,
+    l74: i32,
+    l75: i32,
+    l76: i32,
+    l77: i32,
+    l78: i32,
+    l79: i32,
+    l80: i32,
+    l81: i32,
+    l82: i32,
+    l83: i32,
+    l84: i32,
+    l85: i32,
+    l86: i32,
+    l87: i32,
+    l88: i32,
+    l89: i32,
+    l90: i32,
+    l91: i32,
+    l92: i32,
+    l93: i32,
+    l94: i32,
+    l95: i32,
+    l96: i32,
+    l97: i32,
+    l98: i32,
+    l99: i32,
+    l100: i32,
+    l101: i32,
+    l102: i32,
+    l103: i32,
+    l104: i32,
+    l105: i32,
+    l106: i32,
+    l107: i32,
+    l108: i32,
+    l109: i32,
+    l110: i32,
+    l111: i32,
+    l112: i32,
+    l113: i32,
+    l114: i32,
+    l115: i32,
+    l116: i32,
+    l117: i32,
+    l118: i32,
+    l119: i32,
+    l120: i32,
+    l121: i32,
+    l122: i32,
+    l123: i32,
+    l124: i32,
+    l125: i32,
+    l126: i32,
+    l127: i32,
+    l128: i32,
+}
+
+#[derive(GraphQLObject)]
+struct Right {
+    r0: i32,
+    r1: i32,
+    r2: i32,
+    r3: i32,
+    r4: i32,
+    r5: i32,
+    r6: i32,
+    r7: i32,
+    r8: i32,
+    r9: i32,
+    r10: i32,
+    r11: i32,
+    r12: i32,
+    r13: i32,
+    r14: i32,
+    r15: i32,
+    r16: i32,
+    r17: i32,
+    r18: i32,
+    r19: i32,
+    r20: i32,
+    r21: i32,
+    r22: i32,
+    r23: i32,
+    r24: i32,
+    r25: i32,
+    r26: i32,
+    r27: i32,
+    r28: i32,
+    r29: i32,
+    r30: i32,
+    r31: i32,
+    r32: i32,
+    r33: i32,
+    r34: i32,
+    r35: i32,
+    r36: i32,
+    r37: i32,
+    r38: i32,
+    r39: i32,
+    r40: i32,
+    r41: i32,
+    r42: i32,
+    r43: i32,
+    r44: i32,
+    r45: i32,
+    r46: i32,
+    r47: i32,
+    r48: i32,
+    r49: i32,
+    r50: i32,
+    r51: i32,
+    r52: i32,
+    r53: i32,
+    r54: i32,
+    r55: i32,
+    r56: i32,
+    r57: i32,
+    r58: i32,
+    r59: i32,
+    r60: i32,
+    r61: i32,
+    r62: i32,
+    r63: i32,
+    r64: i32,
+    r65: i32,
+    r66: i32,
+    r67: i32,
+    r68: i32,
+    r69: i32,
+    r70: i32,
+    r71: i32,
+    r72: i32,
+    r73: i32,
+    r74: i32,
+    r75: i32,
+    r76: i32,
+    r77: i32,
+    r78: i32,
+    r79: i32,
+    r80: i32,
+    r81: i32,
+    r82: i32,
+    r83: i32,
+    r84: i32,
+    r85: i32,
+    r86: i32,
+    r87: i32,
+    r88: i32,
+    r89: i32,
+    r90: i32,
+    r91: i32,
+    r92: i32,
+    r93: i32,
+    r94: i32,
+    r95: i32,
+    r96: i32,
+    r97: i32,
+    r98: i32,
+    r99: i32,
+    r100: i32,
+    r101: i32,
+    r102: i32,
+    r103: i32,
+    r104: i32,
+    r105: i32,
+    r106: i32,
+    r107: i32,
+    r108: i32,
+    r109: i32,
+    r110: i32,
+    r111: i32,
+    r112: i32,
+    r113: i32,
+    r114: i32,
+    r115: i32,
+    r116: i32,
+    r117: i32,
+    r118: i32,
+    r119: i32,
+    r120: i32,
+    r121: i32,
+    r122: i32,
+    r123: i32,
+    r124: i32,
+    r125: i32,
+    r126: i32,
+    r127: i32,
+}
+
+#[derive(GraphQLObject)]
+struct Both {
+    id: i32,
+    #[graphql(flatten)]
+    left: Left,
+    #[graphql(flatten)]
+    right: Right,
+}
+
+fn main() {}
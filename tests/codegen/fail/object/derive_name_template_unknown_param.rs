@@ -0,0 +1,13 @@
+use std::marker::PhantomData;
+
+use juniper::GraphQLObject;
+
+#[derive(GraphQLObject)]
+#[graphql(name = "{U}Connection")]
+struct Connection<T> {
+    total_count: i32,
+    #[graphql(ignore)]
+    _node: PhantomData<T>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use juniper::GraphQLObject;
+
+#[derive(GraphQLObject)]
+#[graphql(node)]
+struct Human {
+    id: String,
+}
+
+fn main() {}
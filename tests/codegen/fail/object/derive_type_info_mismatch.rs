@@ -0,0 +1,23 @@
+use juniper::{DynamicTypeInfo, GraphQLObject};
+
+struct Info;
+
+impl DynamicTypeInfo for Info {}
+
+struct OtherInfo;
+
+impl DynamicTypeInfo for OtherInfo {}
+
+#[derive(GraphQLObject)]
+#[graphql(type_info = OtherInfo)]
+struct Settings {
+    theme: String,
+}
+
+#[derive(GraphQLObject)]
+#[graphql(type_info = Info)]
+struct User {
+    settings: Settings,
+}
+
+fn main() {}
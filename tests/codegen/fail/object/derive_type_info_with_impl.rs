@@ -0,0 +1,17 @@
+use juniper::{DynamicTypeInfo, GraphQLObject};
+
+struct Info;
+
+impl DynamicTypeInfo for Info {
+    fn has_field(&self, name: &str) -> bool {
+        name != "id"
+    }
+}
+
+#[derive(GraphQLObject)]
+#[graphql(type_info = Info, impl = CharacterValue)]
+struct Human {
+    id: String,
+}
+
+fn main() {}
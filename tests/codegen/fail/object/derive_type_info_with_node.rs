@@ -0,0 +1,13 @@
+use juniper::{DynamicTypeInfo, GraphQLObject, ID};
+
+struct Info;
+
+impl DynamicTypeInfo for Info {}
+
+#[derive(GraphQLObject)]
+#[graphql(type_info = Info, node)]
+struct Human {
+    id: ID,
+}
+
+fn main() {}
@@ -0,0 +1,139 @@
+//! Tests for SDL of schemas having applied custom directives.
+
+use std::collections::BTreeSet;
+
+use graphql_parser::schema::{Definition, Directive, TypeDefinition};
+use juniper::{
+    EmptyMutation, EmptySubscription, GraphQLEnum, GraphQLInputObject, GraphQLObject, RootNode,
+    graphql_object,
+};
+
+#[derive(GraphQLEnum)]
+enum Role {
+    Admin,
+    Guest,
+}
+
+#[derive(GraphQLInputObject)]
+struct Filter {
+    #[graphql(directive = constraint(maxLength = 10))]
+    name: Option<String>,
+}
+
+#[derive(GraphQLObject)]
+#[graphql(directive = tag(name = "public"), directive = tag(name = "v2"))]
+struct User {
+    #[graphql(directive = auth(requires = ADMIN, scopes = ["read"]))]
+    email: String,
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    #[graphql(directive = cost(weight = 2.5))]
+    fn users(#[graphql(directive = sensitive)] filter: Option<Filter>) -> Vec<User> {
+        _ = filter;
+        vec![]
+    }
+
+    fn role() -> Role {
+        Role::Guest
+    }
+}
+
+fn schema() -> RootNode<Query, EmptyMutation, EmptySubscription> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+/// Collects names of all the [`Directive`]s applied in the provided [`Definition`]s.
+fn applied_names<'a>(definitions: &[Definition<'a, String>]) -> BTreeSet<String> {
+    let names =
+        |ds: &[Directive<'a, String>]| ds.iter().map(|d| d.name.clone()).collect::<Vec<_>>();
+
+    let mut applied = BTreeSet::new();
+    for def in definitions {
+        let Definition::TypeDefinition(ty) = def else {
+            continue;
+        };
+        match ty {
+            TypeDefinition::Object(o) => {
+                applied.extend(names(&o.directives));
+                for f in &o.fields {
+                    applied.extend(names(&f.directives));
+                    for a in &f.arguments {
+                        applied.extend(names(&a.directives));
+                    }
+                }
+            }
+            TypeDefinition::InputObject(i) => {
+                applied.extend(names(&i.directives));
+                for f in &i.fields {
+                    applied.extend(names(&f.directives));
+                }
+            }
+            TypeDefinition::Enum(e) => {
+                applied.extend(names(&e.directives));
+                for v in &e.values {
+                    applied.extend(names(&v.directives));
+                }
+            }
+            TypeDefinition::Interface(i) => applied.extend(names(&i.directives)),
+            TypeDefinition::Scalar(s) => applied.extend(names(&s.directives)),
+            TypeDefinition::Union(u) => applied.extend(names(&u.directives)),
+        }
+    }
+    applied
+}
+
+#[test]
+fn round_trips_sdl_through_parser() {
+    let sdl = schema().as_sdl();
+
+    let doc = graphql_parser::parse_schema::<String>(&sdl)
+        .unwrap_or_else(|e| panic!("failed to parse SDL: {e}\n{sdl}"));
+
+    assert_eq!(doc.to_string(), sdl);
+}
+
+#[test]
+fn defines_every_applied_directive() {
+    let doc = graphql_parser::parse_schema::<String>(&schema().as_sdl())
+        .unwrap()
+        .into_static();
+
+    let defined = doc
+        .definitions
+        .iter()
+        .filter_map(|d| match d {
+            Definition::DirectiveDefinition(d) => Some(d.name.clone()),
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>();
+
+    assert_eq!(
+        defined,
+        ["auth", "constraint", "cost", "sensitive", "tag"]
+            .map(ToString::to_string)
+            .into(),
+    );
+    assert_eq!(applied_names(&doc.definitions), defined);
+}
+
+#[test]
+fn infers_directive_definitions() {
+    let sdl = schema().as_sdl();
+
+    for definition in [
+        "directive @auth(requires: Role, scopes: [String]) on FIELD_DEFINITION",
+        "directive @constraint(maxLength: Int) on INPUT_FIELD_DEFINITION",
+        "directive @cost(weight: Float) on FIELD_DEFINITION",
+        "directive @sensitive on ARGUMENT_DEFINITION",
+        "directive @tag(name: String) repeatable on OBJECT",
+    ] {
+        assert!(
+            sdl.contains(definition),
+            "missing `{definition}` in SDL:\n{sdl}",
+        );
+    }
+}
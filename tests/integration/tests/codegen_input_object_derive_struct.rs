@@ -3,8 +3,8 @@
 pub mod common;
 
 use juniper::{
-    GraphQLInputObject, RuleError, execute, graphql_object, graphql_value, graphql_vars,
-    parser::SourcePosition,
+    GraphQLInputObject, RuleError, RuleErrorExtensions, execute, graphql_object, graphql_value,
+    graphql_vars, parser::SourcePosition,
 };
 
 use self::common::util::schema;
@@ -218,6 +218,11 @@ mod default_value {
                 "Variable \"$x\" of required type \"Float!\" was not provided.",
                 &[SourcePosition::new(8, 0, 8)],
             )
+            .with_extensions(
+                RuleErrorExtensions::new("CoerceVariableValues")
+                    .with_input_path("$x")
+                    .with_expected_type("Float!"),
+            )
             .into()),
         );
     }
@@ -889,3 +894,283 @@ mod renamed_all_fields {
         );
     }
 }
+
+mod flatten {
+    use juniper::{FromInputValue as _, InputValue, ToInputValue as _, graphql_input_value};
+
+    use super::*;
+
+    #[derive(GraphQLInputObject, Debug, PartialEq)]
+    struct Pagination {
+        #[graphql(default = 10)]
+        first: i32,
+        after: prelude::Option<prelude::String>,
+    }
+
+    #[derive(GraphQLInputObject, Debug, PartialEq)]
+    struct UsersFilter {
+        name: prelude::String,
+        #[graphql(flatten)]
+        pagination: Pagination,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn users(filter: UsersFilter) -> prelude::String {
+            prelude::format!(
+                "{} {} {:?}",
+                filter.name,
+                filter.pagination.first,
+                filter.pagination.after,
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            explicit: users(filter: { name: "John", first: 5, after: "abc" })
+            implicit: users(filter: { name: "Jane" })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "explicit": "John 5 Some(\"abc\")",
+                    "implicit": "Jane 10 None",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_on_unknown_nested_field() {
+        // language=GraphQL
+        const DOC: &str = r#"{ users(filter: { name: "John", pagination: {} }) }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Err(RuleError::new(
+                "Invalid value for argument \"filter\", reason: \
+                 Field \"pagination\" does not exist on type \"UsersFilter\"",
+                &[SourcePosition::new(16, 0, 16)],
+            )
+            .into()),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_input_fields() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            __type(name: "UsersFilter") {
+                inputFields {
+                    name
+                    defaultValue
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"inputFields": [
+                    {"name": "name", "defaultValue": null},
+                    {"name": "first", "defaultValue": "10"},
+                    {"name": "after", "defaultValue": null},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn roundtrips_input_value() {
+        let filter = UsersFilter {
+            name: "John".into(),
+            pagination: Pagination {
+                first: 5,
+                after: None,
+            },
+        };
+
+        let value: InputValue = filter.to_input_value();
+
+        assert_eq!(
+            value,
+            graphql_input_value!({"name": "John", "first": 5, "after": null}),
+        );
+        assert_eq!(UsersFilter::from_input_value(&value), Ok(filter));
+    }
+}
+
+mod validate {
+    use juniper::{ExecutionError, FieldError};
+
+    use super::*;
+
+    #[derive(GraphQLInputObject)]
+    struct UserInput {
+        #[graphql(validate(min_length = 1, max_length = 5))]
+        name: prelude::String,
+        #[graphql(validate(min = 0, max = 150))]
+        age: prelude::Option<i32>,
+        #[graphql(validate(regex = "^[a-z]+$"))]
+        login: prelude::String,
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct UsersFilter {
+        user: UserInput,
+        #[graphql(default)]
+        friends: prelude::Vec<UserInput>,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn user(input: UserInput) -> prelude::String {
+            prelude::format!("{} {:?} {}", input.name, input.age, input.login)
+        }
+
+        fn users(filter: UsersFilter) -> prelude::String {
+            prelude::format!("{} {}", filter.user.name, filter.friends.len())
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_valid_input() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            full: user(input: { name: "John", age: 42, login: "john" })
+            partial: user(input: { name: "J", login: "j" })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "full": "John Some(42) john",
+                    "partial": "J None j",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_on_invalid_field() {
+        for (input, path, reason) in [
+            (
+                r#"{ name: "", login: "j" }"#,
+                "$input.name",
+                "length must be at least 1",
+            ),
+            (
+                r#"{ name: "Johnny", login: "j" }"#,
+                "$input.name",
+                "length must be at most 5",
+            ),
+            (
+                r#"{ name: "John", age: -1, login: "j" }"#,
+                "$input.age",
+                "must be greater than or equal to 0",
+            ),
+            (
+                r#"{ name: "John", age: 151, login: "j" }"#,
+                "$input.age",
+                "must be less than or equal to 150",
+            ),
+            (
+                r#"{ name: "John", login: "J0hn" }"#,
+                "$input.login",
+                "must match the \"^[a-z]+$\" pattern",
+            ),
+        ] {
+            let doc = prelude::format!("{{ user(input: {input}) }}");
+
+            let schema = schema(QueryRoot);
+
+            assert_eq!(
+                execute(&doc, None, &schema, &graphql_vars! {}, &()).await,
+                Ok((
+                    graphql_value!(null),
+                    vec![ExecutionError::new(
+                        SourcePosition::new(2, 0, 2),
+                        &["user"],
+                        FieldError::new(
+                            prelude::format!("Invalid value at \"{path}\": {reason}"),
+                            graphql_value!({"inputPath": path}),
+                        ),
+                    )],
+                )),
+                "input: {input}",
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn errs_on_invalid_nested_field() {
+        // language=GraphQL
+        const DOC: &str = r#"{ users(filter: { user: { name: "", login: "j" } }) }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!(null),
+                vec![ExecutionError::new(
+                    SourcePosition::new(2, 0, 2),
+                    &["users"],
+                    FieldError::new(
+                        "Invalid value at \"$filter.user.name\": length must be at least 1",
+                        graphql_value!({"inputPath": "$filter.user.name"}),
+                    ),
+                )],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_on_invalid_list_element() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            users(filter: {
+                user: { name: "John", login: "j" },
+                friends: [{ name: "Jane", login: "j" }, { name: "", login: "j" }],
+            })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!(null),
+                vec![ExecutionError::new(
+                    SourcePosition::new(14, 1, 12),
+                    &["users"],
+                    FieldError::new(
+                        "Invalid value at \"$filter.friends[1].name\": length must be at least 1",
+                        graphql_value!({"inputPath": "$filter.friends[1].name"}),
+                    ),
+                )],
+            )),
+        );
+    }
+}
@@ -2919,6 +2919,204 @@ mod executor {
     }
 }
 
+mod guarded_field {
+    use juniper::{ExecutionError, execute_sync, parser::SourcePosition};
+
+    use super::*;
+
+    struct Session {
+        logged_in: bool,
+        admin: bool,
+    }
+
+    impl juniper::Context for Session {}
+
+    fn logged_in<S>(session: &Session) -> FieldResult<(), S> {
+        if session.logged_in {
+            Ok(())
+        } else {
+            Err("Unauthorized".into())
+        }
+    }
+
+    fn admin_only<S>(session: &Session) -> FieldResult<(), S> {
+        if session.admin {
+            Ok(())
+        } else {
+            Err("Forbidden".into())
+        }
+    }
+
+    #[graphql_interface(for = [Human, Droid], context = Session, guard = logged_in)]
+    trait Character {
+        fn id(&self) -> &str;
+
+        #[graphql(guard = admin_only)]
+        fn secret(&self) -> prelude::Option<&str>;
+    }
+
+    struct Human {
+        id: prelude::String,
+    }
+
+    #[graphql_object(impl = CharacterValue, context = Session)]
+    impl Human {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn secret(&self) -> prelude::Option<&str> {
+            Some("42")
+        }
+
+        fn home_planet(&self) -> &str {
+            "earth"
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(impl = CharacterValue, context = Session)]
+    struct Droid {
+        id: prelude::String,
+        secret: prelude::Option<prelude::String>,
+    }
+
+    #[derive(Clone, Copy)]
+    enum QueryRoot {
+        Human,
+        Droid,
+    }
+
+    #[graphql_object(context = Session)]
+    impl QueryRoot {
+        fn character(&self) -> CharacterValue {
+            match self {
+                Self::Human => Human {
+                    id: "human-32".into(),
+                }
+                .into(),
+                Self::Droid => Droid {
+                    id: "droid-99".into(),
+                    secret: Some("43".into()),
+                }
+                .into(),
+            }
+        }
+    }
+
+    const ADMIN: Session = Session {
+        logged_in: true,
+        admin: true,
+    };
+    const USER: Session = Session {
+        logged_in: true,
+        admin: false,
+    };
+    const GUEST: Session = Session {
+        logged_in: false,
+        admin: false,
+    };
+
+    #[tokio::test]
+    async fn checks_interface_field_guard() {
+        // language=GraphQL
+        const DOC: &str = "{ character { id secret } }";
+
+        for (root, id, secret) in [
+            (QueryRoot::Human, "human-32", "42"),
+            (QueryRoot::Droid, "droid-99", "43"),
+        ] {
+            let schema = schema(root);
+
+            assert_eq!(
+                execute(DOC, None, &schema, &graphql_vars! {}, &ADMIN).await,
+                Ok((
+                    graphql_value!({"character": {"id": id, "secret": secret}}),
+                    vec![],
+                )),
+            );
+            assert_eq!(
+                execute(DOC, None, &schema, &graphql_vars! {}, &USER).await,
+                Ok((
+                    graphql_value!({"character": {"id": id, "secret": null}}),
+                    vec![ExecutionError::new(
+                        SourcePosition::new(17, 0, 17),
+                        &["character", "secret"],
+                        FieldError::from("Forbidden"),
+                    )],
+                )),
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn checks_interface_field_guard_on_implementer_fragment() {
+        // language=GraphQL
+        const DOC: &str = "{ character { ... on Human { secret } ... on Droid { secret } } }";
+
+        for (root, pos) in [(QueryRoot::Human, 29), (QueryRoot::Droid, 53)] {
+            let schema = schema(root);
+
+            assert_eq!(
+                execute(DOC, None, &schema, &graphql_vars! {}, &USER).await,
+                Ok((
+                    graphql_value!({"character": {"secret": null}}),
+                    vec![ExecutionError::new(
+                        SourcePosition::new(pos, 0, pos),
+                        &["character", "secret"],
+                        FieldError::from("Forbidden"),
+                    )],
+                )),
+            );
+            assert_eq!(
+                execute_sync(DOC, None, &schema, &graphql_vars! {}, &USER),
+                Ok((
+                    graphql_value!({"character": {"secret": null}}),
+                    vec![ExecutionError::new(
+                        SourcePosition::new(pos, 0, pos),
+                        &["character", "secret"],
+                        FieldError::from("Forbidden"),
+                    )],
+                )),
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn checks_interface_guard() {
+        // language=GraphQL
+        const DOC: &str = "{ character { id ... on Human { id homePlanet } } }";
+
+        let schema = schema(QueryRoot::Human);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &USER).await,
+            Ok((
+                graphql_value!({"character": {"id": "human-32", "homePlanet": "earth"}}),
+                vec![],
+            )),
+        );
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &GUEST).await,
+            Ok((
+                graphql_value!(null),
+                vec![
+                    ExecutionError::new(
+                        SourcePosition::new(14, 0, 14),
+                        &["character", "id"],
+                        FieldError::from("Unauthorized"),
+                    ),
+                    ExecutionError::new(
+                        SourcePosition::new(32, 0, 32),
+                        &["character", "id"],
+                        FieldError::from("Unauthorized"),
+                    ),
+                ],
+            )),
+        );
+    }
+}
+
 mod ignored_method {
     use super::*;
 
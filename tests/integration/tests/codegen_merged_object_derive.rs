@@ -0,0 +1,247 @@
+//! Tests for `#[derive(GraphQLMergedObject)]` macro.
+
+pub mod common;
+
+use juniper::{
+    DefaultScalarValue, GraphQLMergedObject, GraphQLObject, execute, execute_sync, graphql_object,
+    graphql_value, graphql_vars,
+};
+
+use self::common::util::{schema, schema_with_scalar};
+
+// Override `std::prelude` items to check whether macros expand hygienically.
+use self::common::hygiene::*;
+
+mod trivial {
+    use super::*;
+
+    struct UsersQuery;
+
+    #[graphql_object]
+    impl UsersQuery {
+        fn user_count() -> i32 {
+            42
+        }
+
+        fn user(id: i32) -> prelude::String {
+            format!("user-{id}")
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    struct PostsQuery {
+        post_count: i32,
+    }
+
+    #[derive(GraphQLMergedObject)]
+    struct Query(UsersQuery, PostsQuery);
+
+    fn query() -> Query {
+        Query(UsersQuery, PostsQuery { post_count: 7 })
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            userCount
+            user(id: 3)
+            postCount
+        }"#;
+
+        let schema = schema(query());
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"userCount": 42, "user": "user-3", "postCount": 7}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_sync() {
+        const DOC: &str = r#"{
+            userCount
+            postCount
+        }"#;
+
+        let schema = schema(query());
+
+        assert_eq!(
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &()),
+            Ok((graphql_value!({"userCount": 42, "postCount": 7}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_merged_fields() {
+        const DOC: &str = r#"{
+            __type(name: "Query") {
+                kind
+                fields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(query());
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {
+                    "kind": "OBJECT",
+                    "fields": [
+                        {"name": "userCount"},
+                        {"name": "user"},
+                        {"name": "postCount"},
+                    ],
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_register_merged_types() {
+        for name in ["UsersQuery", "PostsQuery"] {
+            let doc = format!(
+                r#"{{
+                    __type(name: "{name}") {{
+                        kind
+                    }}
+                }}"#,
+            );
+
+            let schema = schema(query());
+
+            assert_eq!(
+                execute(&doc, None, &schema, &graphql_vars! {}, &()).await,
+                Ok((graphql_value!({"__type": null}), vec![])),
+            );
+        }
+    }
+}
+
+mod named_fields {
+    use super::*;
+
+    struct UsersQuery;
+
+    #[graphql_object(context = Context)]
+    impl UsersQuery {
+        fn viewer(context: &Context) -> &str {
+            &context.viewer
+        }
+    }
+
+    struct PostsQuery;
+
+    #[graphql_object(context = Context)]
+    impl PostsQuery {
+        async fn posts_of(context: &Context) -> prelude::String {
+            format!("posts of {}", context.viewer)
+        }
+    }
+
+    struct Context {
+        viewer: prelude::String,
+    }
+
+    impl juniper::Context for Context {}
+
+    /// Root of the whole API.
+    #[derive(GraphQLMergedObject)]
+    #[graphql(name = "Root", context = Context, scalar = DefaultScalarValue)]
+    struct Query {
+        users: UsersQuery,
+        posts: PostsQuery,
+    }
+
+    #[tokio::test]
+    async fn resolves_with_context() {
+        const DOC: &str = r#"{
+            viewer
+            postsOf
+        }"#;
+
+        let schema = schema_with_scalar::<DefaultScalarValue, _, _>(Query {
+            users: UsersQuery,
+            posts: PostsQuery,
+        });
+        let ctx = Context {
+            viewer: "alice".into(),
+        };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            Ok((
+                graphql_value!({"viewer": "alice", "postsOf": "posts of alice"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_custom_name_and_description() {
+        const DOC: &str = r#"{
+            __schema {
+                queryType {
+                    name
+                    description
+                }
+            }
+        }"#;
+
+        let schema = schema_with_scalar::<DefaultScalarValue, _, _>(Query {
+            users: UsersQuery,
+            posts: PostsQuery,
+        });
+        let ctx = Context {
+            viewer: "alice".into(),
+        };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            Ok((
+                graphql_value!({"__schema": {"queryType": {
+                    "name": "Root",
+                    "description": "Root of the whole API.",
+                }}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod conflicting_fields {
+    use super::*;
+
+    struct UsersQuery;
+
+    #[graphql_object]
+    impl UsersQuery {
+        fn count() -> i32 {
+            42
+        }
+    }
+
+    struct PostsQuery;
+
+    #[graphql_object]
+    impl PostsQuery {
+        fn count() -> i32 {
+            7
+        }
+    }
+
+    #[derive(GraphQLMergedObject)]
+    struct Query(UsersQuery, PostsQuery);
+
+    #[test]
+    #[should_panic(expected = "Field `count` is defined multiple times on type `Query`")]
+    fn panics_on_schema_build() {
+        _ = schema(Query(UsersQuery, PostsQuery));
+    }
+}
@@ -534,6 +534,363 @@ mod generic {
     }
 }
 
+mod templated_name {
+    use std::marker::PhantomData;
+
+    use super::*;
+
+    struct Edge<N, C> {
+        cursor: &'static str,
+        _node: PhantomData<(N, C)>,
+    }
+
+    #[graphql_object(name = "{N}By{C}Edge")]
+    impl<N, C> Edge<N, C> {
+        fn cursor(&self) -> &str {
+            self.cursor
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn by_id(&self) -> Edge<bool, i32> {
+            Edge {
+                cursor: "1",
+                _node: PhantomData,
+            }
+        }
+
+        fn by_name(&self) -> Edge<bool, prelude::String> {
+            Edge {
+                cursor: "a",
+                _node: PhantomData,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            byId { cursor __typename }
+            byName { cursor __typename }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "byId": {"cursor": "1", "__typename": "BooleanByIntEdge"},
+                    "byName": {"cursor": "a", "__typename": "BooleanByStringEdge"},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_type_name_per_instantiation() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            __schema {
+                queryType {
+                    fields { type { ofType { name } } }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__schema": {"queryType": {"fields": [
+                    {"type": {"ofType": {"name": "BooleanByIntEdge"}}},
+                    {"type": {"ofType": {"name": "BooleanByStringEdge"}}},
+                ]}}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod relay_node {
+    use juniper::{
+        ExecutionError, ID,
+        parser::SourcePosition,
+        relay::{GlobalId, Node},
+    };
+
+    use super::*;
+
+    struct Database {
+        humans: &'static [&'static str],
+    }
+
+    impl juniper::Context for Database {}
+
+    struct Human {
+        id: usize,
+        name: &'static str,
+    }
+
+    #[graphql_object(context = Database, node)]
+    impl Human {
+        fn id(&self) -> ID {
+            GlobalId::new("Human", self.id.to_string()).into()
+        }
+
+        fn name(&self) -> &str {
+            self.name
+        }
+    }
+
+    impl Node for Human {
+        async fn fetch_node(local_id: &str, db: &Database) -> FieldResult<prelude::Option<Self>> {
+            let id: usize = local_id.parse()?;
+            Ok(db.humans.get(id).map(|&name| Self { id, name }))
+        }
+    }
+
+    struct Droid;
+
+    #[graphql_object(context = Database, node)]
+    impl Droid {
+        fn id(&self) -> ID {
+            GlobalId::new("Droid", "r2d2").into()
+        }
+    }
+
+    impl Node for Droid {
+        async fn fetch_node(local_id: &str, _: &Database) -> FieldResult<prelude::Option<Self>> {
+            Ok((local_id == "r2d2").then_some(Droid))
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Database, nodes = [Human, Droid])]
+    impl QueryRoot {
+        fn humans_count(context: &Database) -> i32 {
+            context.humans.len().try_into().unwrap()
+        }
+    }
+
+    fn database() -> Database {
+        Database {
+            humans: &["Luke", "Leia"],
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_node() {
+        let id = GlobalId::new("Human", "1").to_id();
+        // language=GraphQL
+        let doc = prelude::format!(
+            r#"{{
+                node(id: "{id}") {{
+                    id
+                    __typename
+                    ... on Human {{ name }}
+                }}
+            }}"#,
+        );
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(&doc, None, &schema, &graphql_vars! {}, &database()).await,
+            Ok((
+                graphql_value!({"node": {
+                    "id": "SHVtYW46MQ==",
+                    "__typename": "Human",
+                    "name": "Leia",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_nodes() {
+        // language=GraphQL
+        const DOC: &str = r#"query($ids: [ID!]!) {
+            nodes(ids: $ids) {
+                __typename
+                ... on Human { name }
+            }
+        }"#;
+
+        let ids: prelude::Vec<_> = [
+            GlobalId::new("Droid", "r2d2").to_id(),
+            GlobalId::new("Human", "0").to_id(),
+            GlobalId::new("Human", "2").to_id(),
+            GlobalId::new("Starship", "1").to_id(),
+            ID::new("invalid"),
+        ]
+        .into_iter()
+        .map(|id| juniper::InputValue::scalar(id.to_string()))
+        .collect();
+        let vars = graphql_vars! {"ids": juniper::InputValue::list(ids)};
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &vars, &database()).await,
+            Ok((
+                graphql_value!({"nodes": [
+                    {"__typename": "Droid"},
+                    {"__typename": "Human", "name": "Luke"},
+                    null,
+                    null,
+                    null,
+                ]}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn nulls_only_failed_nodes() {
+        // language=GraphQL
+        const DOC: &str = r#"query($ids: [ID!]!) {
+            nodes(ids: $ids) {
+                __typename
+                ... on Human { name }
+            }
+        }"#;
+
+        let ids: prelude::Vec<_> = [
+            GlobalId::new("Human", "0").to_id(),
+            GlobalId::new("Human", "broken").to_id(),
+            GlobalId::new("Droid", "r2d2").to_id(),
+        ]
+        .into_iter()
+        .map(|id| juniper::InputValue::scalar(id.to_string()))
+        .collect();
+        let vars = graphql_vars! {"ids": juniper::InputValue::list(ids)};
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &vars, &database()).await,
+            Ok((
+                graphql_value!({"nodes": [
+                    {"__typename": "Human", "name": "Luke"},
+                    null,
+                    {"__typename": "Droid"},
+                ]}),
+                vec![ExecutionError::new(
+                    SourcePosition::new(34, 1, 12),
+                    &["nodes"],
+                    FieldError::from("invalid digit found in string"),
+                )],
+            )),
+        );
+    }
+
+    #[test]
+    fn errors_on_sync_execution() {
+        // language=GraphQL
+        const DOC: &str = r#"{ node(id: "SHVtYW46MQ==") { id } }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            juniper::execute_sync(DOC, None, &schema, &graphql_vars! {}, &database()),
+            Ok((
+                graphql_value!({"node": null}),
+                vec![ExecutionError::new(
+                    SourcePosition::new(2, 0, 2),
+                    &["node"],
+                    FieldError::from(
+                        "Field `node` on type `QueryRoot` can be resolved asynchronously only",
+                    ),
+                )],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn implements_node_interface() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            __type(name: "Node") {
+                kind
+                fields { name }
+                possibleTypes { name }
+            }
+            human: __type(name: "Human") {
+                interfaces { name }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &database()).await,
+            Ok((
+                graphql_value!({
+                    "__type": {
+                        "kind": "INTERFACE",
+                        "fields": [{"name": "id"}],
+                        "possibleTypes": [{"name": "Droid"}, {"name": "Human"}],
+                    },
+                    "human": {"interfaces": [{"name": "Node"}]},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_node_fields() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            __type(name: "QueryRoot") {
+                fields {
+                    name
+                    args { name type { kind ofType { kind } } }
+                    type { kind name ofType { kind } }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &database()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{
+                    "name": "humansCount",
+                    "args": [],
+                    "type": {"kind": "NON_NULL", "name": null, "ofType": {"kind": "SCALAR"}},
+                }, {
+                    "name": "node",
+                    "args": [{
+                        "name": "id",
+                        "type": {"kind": "NON_NULL", "ofType": {"kind": "SCALAR"}},
+                    }],
+                    "type": {"kind": "INTERFACE", "name": "Node", "ofType": null},
+                }, {
+                    "name": "nodes",
+                    "args": [{
+                        "name": "ids",
+                        "type": {"kind": "NON_NULL", "ofType": {"kind": "LIST"}},
+                    }],
+                    "type": {"kind": "NON_NULL", "name": null, "ofType": {"kind": "LIST"}},
+                }]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod generic_async {
     use super::*;
 
@@ -1221,6 +1578,311 @@ mod default_argument {
     }
 }
 
+mod validated_argument {
+    use juniper::{ExecutionError, parser::SourcePosition};
+
+    use super::*;
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn greet(
+            #[graphql(validate(min_length = 1, max_length = 10))] name: prelude::String,
+            #[graphql(validate(min = 1, max = 3))] times: prelude::Option<i32>,
+        ) -> prelude::String {
+            name.repeat(times.unwrap_or(1).try_into().unwrap())
+        }
+
+        async fn login(
+            #[graphql(validate(regex = "^[a-z]+$"))] login: prelude::String,
+        ) -> prelude::String {
+            login
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_valid_arguments() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            greet(name: "hi", times: 2)
+            login(login: "john")
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"greet": "hihi", "login": "john"}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_on_invalid_arguments() {
+        let schema = schema(QueryRoot);
+
+        for (doc, vars, field, path, reason) in [
+            (
+                r#"{ greet(name: "") }"#,
+                graphql_vars! {},
+                "greet",
+                "$name",
+                "length must be at least 1",
+            ),
+            (
+                r#"{ greet(name: "hi", times: 4) }"#,
+                graphql_vars! {},
+                "greet",
+                "$times",
+                "must be less than or equal to 3",
+            ),
+            (
+                "query q($times: Int) { greet(name: \"hi\", times: $times) }",
+                graphql_vars! {"times": 0},
+                "greet",
+                "$times",
+                "must be greater than or equal to 1",
+            ),
+            (
+                r#"{ login(login: "John") }"#,
+                graphql_vars! {},
+                "login",
+                "$login",
+                "must match the \"^[a-z]+$\" pattern",
+            ),
+        ] {
+            let pos = if doc.starts_with('q') {
+                SourcePosition::new(23, 0, 23)
+            } else {
+                SourcePosition::new(2, 0, 2)
+            };
+
+            assert_eq!(
+                execute(doc, None, &schema, &vars, &()).await,
+                Ok((
+                    graphql_value!(null),
+                    vec![ExecutionError::new(
+                        pos,
+                        &[field],
+                        FieldError::new(
+                            format!("Invalid value at \"{path}\": {reason}"),
+                            graphql_value!({"inputPath": path}),
+                        ),
+                    )],
+                )),
+                "doc: {doc}",
+            );
+        }
+    }
+}
+
+mod guarded_field {
+    use juniper::{ExecutionError, Guard, GuardExt as _, execute_sync, parser::SourcePosition};
+
+    use super::*;
+
+    struct Session {
+        role: prelude::Option<&'static str>,
+        banned: bool,
+    }
+
+    impl juniper::Context for Session {}
+
+    struct Role(&'static str);
+
+    impl<S: ScalarValue> Guard<Session, S> for Role {
+        fn check(&self, session: &Session) -> FieldResult<(), S> {
+            if session.role == Some(self.0) {
+                Ok(())
+            } else {
+                Err(format!("`{}` role is required", self.0).into())
+            }
+        }
+    }
+
+    fn not_banned<S>(session: &Session) -> FieldResult<(), S> {
+        if session.banned {
+            Err("Banned".into())
+        } else {
+            Ok(())
+        }
+    }
+
+    struct Human;
+
+    #[graphql_object(context = Session)]
+    impl Human {
+        fn name() -> &'static str {
+            "Luke"
+        }
+
+        #[graphql(guard = Role("admin"))]
+        fn secret() -> prelude::Option<&'static str> {
+            Some("42")
+        }
+
+        #[graphql(guard = Role("admin").or(Role("moderator")))]
+        async fn notes() -> prelude::Option<&'static str> {
+            Some("notes")
+        }
+
+        #[graphql(guard = Role("admin").and(not_banned))]
+        fn audit() -> prelude::Option<&'static str> {
+            Some("audit")
+        }
+    }
+
+    struct Vault;
+
+    #[graphql_object(context = Session, guard = not_banned)]
+    impl Vault {
+        fn code() -> prelude::Option<i32> {
+            Some(1)
+        }
+
+        #[graphql(guard = |s: &Session| s.role.map(drop).ok_or_else(|| "Anonymous".into()))]
+        fn owner() -> prelude::Option<i32> {
+            Some(2)
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Session)]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+
+        fn vault() -> Vault {
+            Vault
+        }
+    }
+
+    fn denied(path: &[&str], column: usize, msg: &str) -> ExecutionError<DefaultScalarValue> {
+        ExecutionError::new(
+            SourcePosition::new(column, 0, column),
+            path,
+            FieldError::from(msg),
+        )
+    }
+
+    #[tokio::test]
+    async fn allows_access() {
+        // language=GraphQL
+        const DOC: &str = "{ human { name secret notes audit } vault { code owner } }";
+
+        let schema = schema(QueryRoot);
+        let session = Session {
+            role: Some("admin"),
+            banned: false,
+        };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &session).await,
+            Ok((
+                graphql_value!({
+                    "human": {"name": "Luke", "secret": "42", "notes": "notes", "audit": "audit"},
+                    "vault": {"code": 1, "owner": 2},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn denies_field_access() {
+        // language=GraphQL
+        const DOC: &str = "{ human { name secret notes audit } }";
+
+        let schema = schema(QueryRoot);
+        let session = Session {
+            role: Some("moderator"),
+            banned: false,
+        };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &session).await,
+            Ok((
+                graphql_value!({
+                    "human": {"name": "Luke", "secret": null, "notes": "notes", "audit": null},
+                }),
+                vec![
+                    denied(&["human", "secret"], 15, "`admin` role is required"),
+                    denied(&["human", "audit"], 28, "`admin` role is required"),
+                ],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn checks_combined_guards_in_order() {
+        // language=GraphQL
+        const DOC: &str = "{ human { notes audit } }";
+
+        let schema = schema(QueryRoot);
+        let session = Session {
+            role: None,
+            banned: true,
+        };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &session).await,
+            Ok((
+                graphql_value!({"human": {"notes": null, "audit": null}}),
+                vec![
+                    denied(&["human", "notes"], 10, "`moderator` role is required"),
+                    denied(&["human", "audit"], 16, "`admin` role is required"),
+                ],
+            )),
+        );
+    }
+
+    #[test]
+    fn checks_type_guard_before_field_guard() {
+        // language=GraphQL
+        const DOC: &str = "{ vault { code owner } }";
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute_sync(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {},
+                &Session {
+                    role: None,
+                    banned: false,
+                },
+            ),
+            Ok((
+                graphql_value!({"vault": {"code": 1, "owner": null}}),
+                vec![denied(&["vault", "owner"], 15, "Anonymous")],
+            )),
+        );
+        assert_eq!(
+            execute_sync(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {},
+                &Session {
+                    role: Some("admin"),
+                    banned: true,
+                },
+            ),
+            Ok((
+                graphql_value!({"vault": {"code": null, "owner": null}}),
+                vec![
+                    denied(&["vault", "code"], 10, "Banned"),
+                    denied(&["vault", "owner"], 15, "Banned"),
+                ],
+            )),
+        );
+    }
+}
+
 mod description_from_doc_comment {
     use super::*;
 
@@ -328,6 +328,484 @@ mod generic_lifetime {
     }
 }
 
+mod templated_name {
+    use std::marker::PhantomData;
+
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    struct User {
+        name: &'static str,
+    }
+
+    #[derive(GraphQLObject)]
+    struct Post {
+        title: &'static str,
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(name = "{T}Connection")]
+    struct Connection<T> {
+        total_count: i32,
+        #[graphql(ignore)]
+        _node: PhantomData<T>,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn users() -> Connection<User> {
+            Connection {
+                total_count: 1,
+                _node: PhantomData,
+            }
+        }
+
+        fn posts() -> Connection<Post> {
+            Connection {
+                total_count: 0,
+                _node: PhantomData,
+            }
+        }
+
+        fn user_pages() -> Connection<Connection<User>> {
+            Connection {
+                total_count: 2,
+                _node: PhantomData,
+            }
+        }
+
+        fn post_pages() -> Connection<Connection<Post>> {
+            Connection {
+                total_count: 3,
+                _node: PhantomData,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            users { totalCount }
+            posts { totalCount }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "users": {"totalCount": 1},
+                    "posts": {"totalCount": 0},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_type_name_per_instantiation() {
+        const DOC: &str = r#"{
+            users { __typename }
+            posts { __typename }
+            userConnection: __type(name: "UserConnection") { name }
+            postConnection: __type(name: "PostConnection") { name }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "users": {"__typename": "UserConnection"},
+                    "posts": {"__typename": "PostConnection"},
+                    "userConnection": {"name": "UserConnection"},
+                    "postConnection": {"name": "PostConnection"},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_type_name_per_nested_instantiation() {
+        const DOC: &str = r#"{
+            userPages { __typename totalCount }
+            postPages { __typename totalCount }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "userPages": {"__typename": "UserConnectionConnection", "totalCount": 2},
+                    "postPages": {"__typename": "PostConnectionConnection", "totalCount": 3},
+                }),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod relay_node {
+    use juniper::{
+        FieldResult, ID,
+        relay::{GlobalId, Node},
+    };
+
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    #[graphql(node)]
+    struct Human {
+        id: ID,
+        name: prelude::String,
+    }
+
+    impl Node for Human {
+        async fn fetch_node(local_id: &str, _: &()) -> FieldResult<prelude::Option<Self>> {
+            Ok((local_id == "luke").then(|| Self {
+                id: GlobalId::new("Human", local_id).into(),
+                name: "Luke".into(),
+            }))
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(nodes = [Human])]
+    struct QueryRoot {
+        version: i32,
+    }
+
+    #[tokio::test]
+    async fn resolves_node() {
+        let id = GlobalId::new("Human", "luke").to_id();
+        // language=GraphQL
+        let doc = prelude::format!(
+            r#"{{
+                version
+                node(id: "{id}") {{ id ... on Human {{ name }} }}
+            }}"#,
+        );
+
+        let schema = schema(QueryRoot { version: 1 });
+
+        assert_eq!(
+            execute(&doc, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "version": 1,
+                    "node": {"id": "SHVtYW46bHVrZQ==", "name": "Luke"},
+                }),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod complex {
+    use juniper::{execute_sync, graphql_interface};
+
+    use super::*;
+
+    struct Database {
+        greeting: prelude::String,
+    }
+
+    impl juniper::Context for Database {}
+
+    #[graphql_interface(for = Human, context = Database)]
+    #[expect(dead_code, reason = "GraphQL schema testing")]
+    trait Named {
+        fn full_name(&self) -> prelude::String;
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(complex, context = Database, impl = NamedValue)]
+    struct Human {
+        id: i32,
+        first_name: prelude::String,
+        last_name: prelude::String,
+    }
+
+    #[graphql_object(complex)]
+    impl Human {
+        /// Full name of this human.
+        fn full_name(&self) -> prelude::String {
+            prelude::format!("{} {}", self.first_name, self.last_name)
+        }
+
+        fn greeting(
+            &self,
+            context: &Database,
+            #[graphql(default = 1)] times: i32,
+        ) -> prelude::String {
+            (0..times)
+                .map(|_| prelude::format!("{} {}", context.greeting, self.first_name))
+                .collect::<prelude::Vec<_>>()
+                .join(", ")
+        }
+
+        async fn friends_count(&self) -> i32 {
+            self.id * 2
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(complex)]
+    struct Counter {
+        #[graphql(ignore)]
+        value: i32,
+    }
+
+    #[graphql_object(complex)]
+    impl Counter {
+        fn doubled(&self) -> i32 {
+            self.value * 2
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Database)]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human {
+                id: 21,
+                first_name: "Luke".into(),
+                last_name: "Skywalker".into(),
+            }
+        }
+
+        fn named() -> NamedValue {
+            Self::human().into()
+        }
+
+        fn counter() -> Counter {
+            Counter { value: 5 }
+        }
+    }
+
+    fn database() -> Database {
+        Database {
+            greeting: "Hello,".into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_struct_and_impl_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+                firstName
+                fullName
+                greeting(times: 2)
+                friendsCount
+            }
+            counter {
+                doubled
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &database()).await,
+            Ok((
+                graphql_value!({
+                    "human": {
+                        "id": 21,
+                        "firstName": "Luke",
+                        "fullName": "Luke Skywalker",
+                        "greeting": "Hello, Luke, Hello, Luke",
+                        "friendsCount": 42,
+                    },
+                    "counter": {"doubled": 10},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_impl_fields_synchronously() {
+        const DOC: &str = r#"{
+            human {
+                lastName
+                greeting
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &database()),
+            Ok((
+                graphql_value!({
+                    "human": {"lastName": "Skywalker", "greeting": "Hello, Luke"},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_interface_field_from_impl() {
+        const DOC: &str = r#"{
+            named {
+                fullName
+                ... on Human {
+                    id
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &database()).await,
+            Ok((
+                graphql_value!({"named": {"fullName": "Luke Skywalker", "id": 21}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_all_fields_in_single_object() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                    description
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &database()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id", "description": null},
+                    {"name": "firstName", "description": null},
+                    {"name": "lastName", "description": null},
+                    {"name": "fullName", "description": "Full name of this human."},
+                    {"name": "greeting", "description": null},
+                    {"name": "friendsCount", "description": null},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod flatten {
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    struct Audit {
+        created_by: prelude::String,
+        /// Revision of the record.
+        revision: i32,
+    }
+
+    #[derive(GraphQLObject)]
+    struct Post<'a> {
+        title: &'a str,
+        #[graphql(flatten)]
+        audit: Audit,
+    }
+
+    struct QueryRoot(prelude::String);
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn post(&self) -> Post<'_> {
+            Post {
+                title: self.0.as_str(),
+                audit: Audit {
+                    created_by: "admin".into(),
+                    revision: 3,
+                },
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_flattened_fields() {
+        const DOC: &str = r#"{
+            post {
+                title
+                createdBy
+                revision
+            }
+        }"#;
+
+        let schema = schema(QueryRoot("Hello".into()));
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"post": {
+                    "title": "Hello",
+                    "createdBy": "admin",
+                    "revision": 3,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_flattened_fields_synchronously() {
+        const DOC: &str = r#"{
+            post {
+                revision
+            }
+        }"#;
+
+        let schema = schema(QueryRoot("Hello".into()));
+
+        assert_eq!(
+            juniper::execute_sync(DOC, None, &schema, &graphql_vars! {}, &()),
+            Ok((graphql_value!({"post": {"revision": 3}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_flattened_fields() {
+        const DOC: &str = r#"{
+            __type(name: "Post") {
+                fields {
+                    name
+                    description
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot("Hello".into()));
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "title", "description": null},
+                    {"name": "createdBy", "description": null},
+                    {"name": "revision", "description": "Revision of the record."},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod nested_generic_lifetime_async {
     use super::*;
 
@@ -985,3 +1463,84 @@ mod explicit_custom_context {
         );
     }
 }
+
+mod guarded_field {
+    use juniper::{ExecutionError, FieldError, FieldResult, parser::SourcePosition};
+
+    use super::*;
+
+    struct Session {
+        admin: bool,
+    }
+
+    impl juniper::Context for Session {}
+
+    fn admin_only<S>(session: &Session) -> FieldResult<(), S> {
+        if session.admin {
+            Ok(())
+        } else {
+            Err("Forbidden".into())
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(context = Session)]
+    struct Human {
+        id: &'static str,
+        #[graphql(guard = admin_only)]
+        salary: prelude::Option<i32>,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Session)]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human {
+                id: "human-32",
+                salary: Some(100),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn checks_guard() {
+        // language=GraphQL
+        const DOC: &str = "{ human { id salary } }";
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {},
+                &Session { admin: true }
+            )
+            .await,
+            Ok((
+                graphql_value!({"human": {"id": "human-32", "salary": 100}}),
+                vec![],
+            )),
+        );
+        assert_eq!(
+            execute(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {},
+                &Session { admin: false }
+            )
+            .await,
+            Ok((
+                graphql_value!({"human": {"id": "human-32", "salary": null}}),
+                vec![ExecutionError::new(
+                    SourcePosition::new(13, 0, 13),
+                    &["human", "salary"],
+                    FieldError::from("Forbidden"),
+                )],
+            )),
+        );
+    }
+}
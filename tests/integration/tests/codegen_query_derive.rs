@@ -0,0 +1,302 @@
+//! Tests for `#[derive(GraphQLQuery)]` macro.
+
+pub mod common;
+
+use juniper::{
+    EmptySubscription, FieldError, FieldResult, GraphQLQuery, GraphQLScalar, ID,
+    client::{self, Response},
+    graphql_schema_from_file, graphql_value,
+};
+
+// Override `std::prelude` items to check whether macros expand hygienically.
+use self::common::hygiene::*;
+
+graphql_schema_from_file!("tests/fixtures/schema_from_file.graphql");
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema = "tests/fixtures/schema_from_file.graphql",
+    document = "tests/fixtures/operations.graphql"
+)]
+struct HeroQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema = "tests/fixtures/schema_from_file.graphql",
+    document = "tests/fixtures/operations.graphql"
+)]
+struct Appearances;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema = "tests/fixtures/schema_from_file.graphql",
+    document = "tests/fixtures/operations.graphql",
+    operation = "Search"
+)]
+struct SearchQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema = "tests/fixtures/schema_from_file.graphql",
+    document = "tests/fixtures/operations.graphql"
+)]
+struct Review;
+
+/// Link to a web page.
+#[derive(Clone, Debug, GraphQLScalar, PartialEq)]
+#[graphql(transparent)]
+pub struct Url(prelude::String);
+
+pub struct Human;
+
+impl HumanFields for Human {
+    fn id(&self, _: &()) -> FieldResult<ID> {
+        Ok(ID::new("1000"))
+    }
+
+    fn name(&self, _: &()) -> FieldResult<prelude::String> {
+        Ok("Luke Skywalker".into())
+    }
+
+    fn friends(
+        &self,
+        _: &(),
+        first: prelude::Option<i32>,
+    ) -> FieldResult<prelude::Vec<CharacterValue>> {
+        Ok((0..first.unwrap_or_default())
+            .map(|_| Droid.into())
+            .collect())
+    }
+
+    fn home_planet(&self, _: &()) -> FieldResult<prelude::Option<prelude::String>> {
+        Ok(Some("Tatooine".into()))
+    }
+
+    fn wiki(&self, _: &()) -> FieldResult<prelude::Option<Url>> {
+        Ok(Some(Url("https://wiki/Luke".into())))
+    }
+
+    fn appears_in(&self, _: &()) -> FieldResult<prelude::Vec<Episode>> {
+        Ok(prelude::vec![Episode::NewHope, Episode::Empire])
+    }
+}
+
+pub struct Droid;
+
+impl DroidFields for Droid {
+    fn id(&self, _: &()) -> FieldResult<ID> {
+        Ok(ID::new("2001"))
+    }
+
+    fn name(&self, _: &()) -> FieldResult<prelude::String> {
+        Ok("R2-D2".into())
+    }
+
+    fn friends(
+        &self,
+        _: &(),
+        _: prelude::Option<i32>,
+    ) -> FieldResult<prelude::Vec<CharacterValue>> {
+        Ok(prelude::vec![Human.into()])
+    }
+
+    fn primary_function(&self, _: &()) -> FieldResult<prelude::String> {
+        Ok("Astromech".into())
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn hero(
+        &self,
+        _: &(),
+        episode: prelude::Option<Episode>,
+    ) -> FieldResult<prelude::Option<CharacterValue>> {
+        Ok(Some(match episode {
+            Some(Episode::Empire) => Human.into(),
+            _ => Droid.into(),
+        }))
+    }
+
+    fn character(&self, _: &(), _: CharacterBy) -> FieldResult<prelude::Option<CharacterValue>> {
+        Ok(None)
+    }
+
+    fn search(&self, _: &(), text: prelude::String) -> FieldResult<prelude::Vec<SearchResult>> {
+        Ok(
+            prelude::vec![SearchResult::Human(Human), SearchResult::Droid(Droid)]
+                .into_iter()
+                .filter(|r| match r {
+                    SearchResult::Human(_) => "Luke Skywalker".contains(&text),
+                    SearchResult::Droid(_) => "R2-D2".contains(&text),
+                })
+                .collect(),
+        )
+    }
+}
+
+pub struct Mutation;
+
+impl MutationFields for Mutation {
+    fn review(&self, _: &(), input: prelude::Option<ReviewInput>) -> FieldResult<i32> {
+        let input = input.ok_or("no review")?;
+        if input.stars < 0 {
+            return Err("negative stars".into());
+        }
+        Ok(input.stars * input.episodes.map_or(0, |e| e.len().try_into().unwrap()))
+    }
+}
+
+fn schema() -> Schema {
+    Schema::new(Query, Mutation, EmptySubscription::new())
+}
+
+#[tokio::test]
+async fn decodes_interfaces_and_fragments() {
+    let variables = hero_query::Variables {
+        episode: Some(hero_query::Episode::Empire),
+    };
+
+    assert_eq!(
+        client::execute(HeroQuery, &variables, &schema(), &()).await,
+        Ok(Response {
+            data: Ok(hero_query::ResponseData {
+                hero: Some(hero_query::ResponseDataHero {
+                    typename: "Human".into(),
+                    id: ID::new("1000"),
+                    name: "Luke Skywalker".into(),
+                    friends: prelude::vec![hero_query::ResponseDataHeroFriends {
+                        name: "R2-D2".into(),
+                    }],
+                    home_planet: Some("Tatooine".into()),
+                    wiki: Some(Url("https://wiki/Luke".into())),
+                }),
+            }),
+            errors: vec![],
+        }),
+    );
+}
+
+#[tokio::test]
+async fn omits_absent_variables() {
+    let variables = hero_query::Variables { episode: None };
+
+    let response = client::execute(HeroQuery, &variables, &schema(), &())
+        .await
+        .unwrap();
+    let hero = response.data.unwrap().hero.unwrap();
+
+    assert_eq!(hero.typename, "Droid");
+    assert_eq!(hero.name, "R2-D2");
+    assert_eq!(hero.home_planet, None);
+    assert_eq!(hero.wiki, None);
+}
+
+#[test]
+fn decodes_enums() {
+    assert_eq!(
+        client::execute_sync(Appearances, &appearances::Variables, &schema(), &()),
+        Ok(Response {
+            data: Ok(appearances::ResponseData {
+                hero: Some(appearances::ResponseDataHero {
+                    appears_in: Some(prelude::vec![
+                        appearances::Episode::NewHope,
+                        appearances::Episode::Empire,
+                    ]),
+                }),
+            }),
+            errors: vec![],
+        }),
+    );
+}
+
+#[test]
+fn decodes_unions_and_conditional_fields() {
+    let variables = search_query::Variables {
+        text: "2".into(),
+        with_id: None,
+    };
+
+    assert_eq!(
+        client::execute_sync(SearchQuery, &variables, &schema(), &()),
+        Ok(Response {
+            data: Ok(search_query::ResponseData {
+                results: prelude::vec![search_query::ResponseDataResults {
+                    name: Some("R2-D2".into()),
+                    primary_function: Some("Astromech".into()),
+                    id: None,
+                }],
+            }),
+            errors: vec![],
+        }),
+    );
+
+    let variables = search_query::Variables {
+        text: "Luke".into(),
+        with_id: Some(true),
+    };
+
+    assert_eq!(
+        client::execute_sync(SearchQuery, &variables, &schema(), &())
+            .unwrap()
+            .data
+            .unwrap()
+            .results,
+        prelude::vec![search_query::ResponseDataResults {
+            name: Some("Luke Skywalker".into()),
+            primary_function: None,
+            id: Some(ID::new("1000")),
+        }],
+    );
+}
+
+#[test]
+fn encodes_input_objects_with_defaults() {
+    let variables = review::Variables {
+        input: Some(review::ReviewInput {
+            stars: 4,
+            commentary: None,
+            episodes: Some(prelude::vec![
+                review::Episode::NewHope,
+                review::Episode::Empire
+            ]),
+        }),
+    };
+
+    assert_eq!(
+        client::execute_sync(Review, &variables, &schema(), &()),
+        Ok(Response {
+            data: Ok(review::ResponseData { review: 8 }),
+            errors: vec![],
+        }),
+    );
+    assert_eq!(
+        client::execute_sync(Review, &review::Variables { input: None }, &schema(), &())
+            .unwrap()
+            .data,
+        Ok(review::ResponseData { review: 5 }),
+    );
+}
+
+#[test]
+fn reports_undecodable_data() {
+    let variables = review::Variables {
+        input: Some(review::ReviewInput {
+            stars: -1,
+            commentary: None,
+            episodes: None,
+        }),
+    };
+
+    let response = client::execute_sync(Review, &variables, &schema(), &()).unwrap();
+
+    assert_eq!(
+        response.data,
+        Err(FieldError::new(
+            "Expected `Mutation` object, found: null",
+            graphql_value!(null),
+        )),
+    );
+    assert_eq!(response.errors.len(), 1);
+}
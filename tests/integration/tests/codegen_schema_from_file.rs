@@ -0,0 +1,271 @@
+//! Tests for `graphql_schema_from_file!` macro.
+
+pub mod common;
+
+use juniper::{
+    EmptySubscription, FieldResult, GraphQLScalar, ID, execute, graphql_schema_from_file,
+    graphql_value, graphql_vars,
+};
+
+// Override `std::prelude` items to check whether macros expand hygienically.
+use self::common::hygiene::*;
+
+pub struct Database;
+
+impl juniper::Context for Database {}
+
+graphql_schema_from_file!(
+    "tests/fixtures/schema_from_file.graphql",
+    context = Database
+);
+
+/// Link to a web page.
+#[derive(GraphQLScalar)]
+#[graphql(transparent)]
+pub struct Url(prelude::String);
+
+pub struct Human {
+    id: &'static str,
+    name: &'static str,
+}
+
+impl HumanFields for Human {
+    fn id(&self, _: &Database) -> FieldResult<ID> {
+        Ok(ID::new(self.id))
+    }
+
+    fn name(&self, _: &Database) -> FieldResult<prelude::String> {
+        Ok(self.name.into())
+    }
+
+    fn friends(
+        &self,
+        _: &Database,
+        first: prelude::Option<i32>,
+    ) -> FieldResult<prelude::Vec<CharacterValue>> {
+        Ok(droids()
+            .into_iter()
+            .take(first.unwrap_or_default().try_into()?)
+            .map(Into::into)
+            .collect())
+    }
+
+    fn home_planet(&self, _: &Database) -> FieldResult<prelude::Option<prelude::String>> {
+        Ok(Some("Tatooine".into()))
+    }
+
+    fn wiki(&self, _: &Database) -> FieldResult<prelude::Option<Url>> {
+        Ok(Some(Url(prelude::format!("https://wiki/{}", self.name))))
+    }
+
+    fn appears_in(&self, _: &Database) -> FieldResult<prelude::Vec<Episode>> {
+        Ok(prelude::vec![Episode::NewHope, Episode::Empire])
+    }
+}
+
+pub struct Droid {
+    id: &'static str,
+    name: &'static str,
+}
+
+impl DroidFields for Droid {
+    fn id(&self, _: &Database) -> FieldResult<ID> {
+        Ok(ID::new(self.id))
+    }
+
+    fn name(&self, _: &Database) -> FieldResult<prelude::String> {
+        Ok(self.name.into())
+    }
+
+    fn friends(
+        &self,
+        _: &Database,
+        _: prelude::Option<i32>,
+    ) -> FieldResult<prelude::Vec<CharacterValue>> {
+        Ok(humans().into_iter().map(Into::into).collect())
+    }
+
+    fn primary_function(&self, _: &Database) -> FieldResult<prelude::String> {
+        Ok("Protocol".into())
+    }
+}
+
+fn humans() -> prelude::Vec<Human> {
+    prelude::vec![Human {
+        id: "1000",
+        name: "Luke Skywalker",
+    }]
+}
+
+fn droids() -> prelude::Vec<Droid> {
+    prelude::vec![
+        Droid {
+            id: "2000",
+            name: "C-3PO",
+        },
+        Droid {
+            id: "2001",
+            name: "R2-D2",
+        },
+    ]
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn hero(
+        &self,
+        _: &Database,
+        episode: prelude::Option<Episode>,
+    ) -> FieldResult<prelude::Option<CharacterValue>> {
+        Ok(match episode {
+            Some(Episode::Empire) => humans().pop().map(Into::into),
+            _ => droids().pop().map(Into::into),
+        })
+    }
+
+    fn character(
+        &self,
+        _: &Database,
+        by: CharacterBy,
+    ) -> FieldResult<prelude::Option<CharacterValue>> {
+        let humans = humans().into_iter().map(|h| (h.id, h.name, h.into()));
+        let droids = droids().into_iter().map(|d| (d.id, d.name, d.into()));
+        Ok(humans
+            .chain(droids)
+            .find(|(id, name, _)| match &by {
+                CharacterBy::Id(by_id) => by_id.to_string() == *id,
+                CharacterBy::Name(by_name) => by_name == name,
+            })
+            .map(|(.., c)| c))
+    }
+
+    fn search(
+        &self,
+        _: &Database,
+        text: prelude::String,
+    ) -> FieldResult<prelude::Vec<SearchResult>> {
+        let humans = humans().into_iter().map(SearchResult::Human);
+        let droids = droids().into_iter().map(SearchResult::Droid);
+        Ok(humans
+            .chain(droids)
+            .filter(|r| match r {
+                SearchResult::Human(h) => h.name.contains(&text),
+                SearchResult::Droid(d) => d.name.contains(&text),
+            })
+            .collect())
+    }
+}
+
+pub struct Mutation;
+
+impl MutationFields for Mutation {
+    fn review(&self, _: &Database, input: prelude::Option<ReviewInput>) -> FieldResult<i32> {
+        let input = input.ok_or("no review")?;
+        assert_eq!(input.commentary.as_deref(), Some("No comments."));
+        assert_eq!(input.episodes, Some(prelude::vec![Episode::NewHope]));
+        Ok(input.stars)
+    }
+}
+
+fn schema() -> Schema {
+    Schema::new(Query, Mutation, EmptySubscription::new())
+}
+
+#[tokio::test]
+async fn resolves_interfaces_with_argument_defaults() {
+    // language=GraphQL
+    const DOC: &str = r#"{
+        hero {
+            name
+            friends { name }
+            ... on Droid { primaryFunction }
+        }
+        empire: hero(episode: EMPIRE) {
+            id
+            friends(first: 1) { id }
+            ... on Human { homePlanet wiki }
+        }
+    }"#;
+
+    assert_eq!(
+        execute(DOC, None, &schema(), &graphql_vars! {}, &Database).await,
+        Ok((
+            graphql_value!({
+                "hero": {
+                    "name": "R2-D2",
+                    "friends": [{"name": "Luke Skywalker"}],
+                    "primaryFunction": "Protocol",
+                },
+                "empire": {
+                    "id": "1000",
+                    "friends": [{"id": "2000"}],
+                    "homePlanet": "Tatooine",
+                    "wiki": "https://wiki/Luke Skywalker",
+                },
+            }),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn resolves_unions_and_one_of_input_objects() {
+    // language=GraphQL
+    const DOC: &str = r#"{
+        character(by: {name: "C-3PO"}) { id }
+        search(text: "2") {
+            __typename
+            ... on Droid { id }
+        }
+    }"#;
+
+    assert_eq!(
+        execute(DOC, None, &schema(), &graphql_vars! {}, &Database).await,
+        Ok((
+            graphql_value!({
+                "character": {"id": "2000"},
+                "search": [{"__typename": "Droid", "id": "2001"}],
+            }),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn resolves_input_object_defaults() {
+    // language=GraphQL
+    const DOC: &str = r#"mutation {
+        default: review
+        explicit: review(input: {stars: 3})
+    }"#;
+
+    assert_eq!(
+        execute(DOC, None, &schema(), &graphql_vars! {}, &Database).await,
+        Ok((graphql_value!({"default": 5, "explicit": 3}), vec![])),
+    );
+}
+
+#[test]
+fn reproduces_sdl() {
+    let sdl = schema().as_sdl();
+
+    for expected in [
+        "\"Link to a web page.\"\nscalar Url",
+        "\"Episode of the saga.\"\nenum Episode {",
+        "JEDI @deprecated(reason: \"Not filmed yet.\")",
+        "\"Character of the saga.\"\ninterface Character implements Node {",
+        "friends(first: Int = 10): [Character!]!",
+        "type Human implements Character & Node @key(fields: \"id\") {",
+        "homePlanet: String @deprecated(reason: \"Use `planet` instead.\")",
+        "\"Primary function of the droid.\"\n  primaryFunction: String!",
+        "union SearchResult = Human | Droid",
+        "commentary: String = \"No comments.\"",
+        "episodes: [Episode!] = [",
+        "input CharacterBy @oneOf {",
+        "hero(episode: Episode = ",
+        "review(input: ReviewInput = {",
+    ] {
+        assert!(sdl.contains(expected), "`{expected}` is missing in:\n{sdl}");
+    }
+}
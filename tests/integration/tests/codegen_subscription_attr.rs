@@ -1697,6 +1697,99 @@ mod inferred_custom_context_from_field {
     }
 }
 
+mod guarded_field {
+    use juniper::{ExecutionError, parser::SourcePosition};
+
+    use super::*;
+
+    struct Session {
+        user: prelude::Option<&'static str>,
+    }
+
+    impl juniper::Context for Session {}
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Session)]
+    impl QueryRoot {
+        fn empty() -> bool {
+            true
+        }
+    }
+
+    fn authenticated<S>(session: &Session) -> FieldResult<(), S> {
+        session
+            .user
+            .map(drop)
+            .ok_or_else(|| "Unauthenticated".into())
+    }
+
+    struct Human;
+
+    #[graphql_subscription(context = Session, guard = authenticated)]
+    impl Human {
+        async fn id(context: &Session) -> Stream<'static, prelude::String> {
+            let id = context.user.unwrap_or_default().into();
+            prelude::Box::pin(stream::once(future::ready(id)))
+        }
+
+        #[graphql(guard = |s: &Session| {
+            (s.user == Some("admin")).then_some(()).ok_or_else(|| "Forbidden".into())
+        })]
+        async fn secret() -> Stream<'static, i32> {
+            prelude::Box::pin(stream::once(future::ready(42)))
+        }
+    }
+
+    #[tokio::test]
+    async fn allows_access() {
+        const DOC: &str = r#"subscription {
+            secret
+        }"#;
+
+        let schema = schema(QueryRoot, Human);
+        let session = Session {
+            user: Some("admin"),
+        };
+
+        assert_eq!(
+            resolve_into_stream(DOC, None, &schema, &graphql_vars! {}, &session)
+                .then(extract_next)
+                .await,
+            Ok((graphql_value!({"secret": 42}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn denies_access() {
+        let schema = schema(QueryRoot, Human);
+
+        for (field, user, err) in [
+            ("id", None, "Unauthenticated"),
+            ("secret", None, "Unauthenticated"),
+            ("secret", Some("john"), "Forbidden"),
+        ] {
+            let doc = prelude::format!("subscription {{ {field} }}");
+            let session = Session { user };
+
+            assert_eq!(
+                resolve_into_stream(&doc, None, &schema, &graphql_vars! {}, &session)
+                    .then(extract_next)
+                    .await,
+                Ok((
+                    graphql_value!(null),
+                    vec![ExecutionError::new(
+                        SourcePosition::new(15, 0, 15),
+                        &[field],
+                        FieldError::from(err),
+                    )],
+                )),
+                "field: {field}, user: {user:?}",
+            );
+        }
+    }
+}
+
 mod executor {
     use super::*;
 
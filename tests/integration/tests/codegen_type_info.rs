@@ -0,0 +1,500 @@
+//! Tests for `type_info` argument of `#[graphql]` attribute of GraphQL type macros.
+
+pub mod common;
+
+use std::pin::Pin;
+
+use futures::{future, stream};
+use juniper::{
+    ArcStr, DefaultScalarValue, DynamicTypeInfo, EmptyMutation, EmptySubscription, GraphQLEnum,
+    GraphQLInputObject, GraphQLObject, GraphQLScalar, GraphQLType, GraphQLUnion, Registry,
+    RootNode, execute, graphql_object, graphql_subscription, graphql_value, graphql_vars,
+};
+
+// Override `std::prelude` items to check whether macros expand hygienically.
+use self::common::hygiene::*;
+
+struct Info {
+    name: prelude::Option<&'static str>,
+    description: prelude::Option<&'static str>,
+    hidden: &'static [&'static str],
+}
+
+impl Info {
+    const DEFAULT: Self = Self {
+        name: None,
+        description: None,
+        hidden: &[],
+    };
+}
+
+impl DynamicTypeInfo for Info {
+    fn name(&self) -> prelude::Option<ArcStr> {
+        self.name.map(Into::into)
+    }
+
+    fn description(&self) -> prelude::Option<ArcStr> {
+        self.description.map(Into::into)
+    }
+
+    fn has_field(&self, name: &str) -> bool {
+        !self.hidden.contains(&name)
+    }
+}
+
+/// Returns the name and description of the GraphQL type `T`, registered with the provided `info`.
+fn name_and_description<T>(info: &Info) -> (prelude::String, prelude::Option<prelude::String>)
+where
+    T: GraphQLType<DefaultScalarValue, TypeInfo = Info> + ?Sized,
+{
+    let mut registry = Registry::new(prelude::Default::default());
+    let meta = T::meta(info, &mut registry);
+    (
+        meta.name().unwrap().to_string(),
+        meta.description().map(prelude::ToString::to_string),
+    )
+}
+
+mod object {
+    use super::*;
+
+    /// Settings of a user.
+    #[derive(GraphQLObject)]
+    #[graphql(type_info = Info)]
+    struct Settings {
+        theme: prelude::String,
+        secret: prelude::String,
+    }
+
+    fn schema(info: Info) -> RootNode<Settings, EmptyMutation, EmptySubscription> {
+        RootNode::new_with_info(
+            Settings {
+                theme: "dark".into(),
+                secret: "42".into(),
+            },
+            EmptyMutation::new(),
+            EmptySubscription::new(),
+            info,
+            (),
+            (),
+        )
+    }
+
+    #[tokio::test]
+    async fn uses_static_definitions_by_default() {
+        const DOC: &str = r#"{
+            __type(name: "Settings") {
+                description
+                fields { name }
+            }
+            theme
+            secret
+            __typename
+        }"#;
+
+        let schema = schema(Info::DEFAULT);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "__type": {
+                        "description": "Settings of a user.",
+                        "fields": [{"name": "theme"}, {"name": "secret"}],
+                    },
+                    "theme": "dark",
+                    "secret": "42",
+                    "__typename": "Settings",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_dynamic_definitions() {
+        const DOC: &str = r#"{
+            __type(name: "PublicSettings") {
+                description
+                fields { name }
+            }
+            theme
+            __typename
+        }"#;
+
+        let schema = schema(Info {
+            name: Some("PublicSettings"),
+            description: Some("Public settings of a user."),
+            hidden: &["secret"],
+        });
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "__type": {
+                        "description": "Public settings of a user.",
+                        "fields": [{"name": "theme"}],
+                    },
+                    "theme": "dark",
+                    "__typename": "PublicSettings",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn hides_filtered_fields() {
+        const DOC: &str = r#"{
+            secret
+        }"#;
+
+        let schema = schema(Info {
+            hidden: &["secret"],
+            ..Info::DEFAULT
+        });
+
+        assert!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &())
+                .await
+                .is_err(),
+        );
+    }
+}
+
+mod object_attr {
+    use super::*;
+
+    struct Query;
+
+    #[graphql_object(type_info = Info)]
+    impl Query {
+        fn greeting(name: prelude::String) -> prelude::String {
+            prelude::format!("Hello, {name}!")
+        }
+
+        fn internal() -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn uses_dynamic_definitions() {
+        const DOC: &str = r#"{
+            __schema {
+                queryType {
+                    name
+                    description
+                    fields { name }
+                }
+            }
+            greeting(name: "World")
+            __typename
+        }"#;
+
+        let schema: RootNode<_, _, _> = RootNode::new_with_info(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+            Info {
+                name: Some("Root"),
+                description: Some("Root of all the queries."),
+                hidden: &["internal"],
+            },
+            (),
+            (),
+        );
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "__schema": {
+                        "queryType": {
+                            "name": "Root",
+                            "description": "Root of all the queries.",
+                            "fields": [{"name": "greeting"}],
+                        },
+                    },
+                    "greeting": "Hello, World!",
+                    "__typename": "Root",
+                }),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod nested {
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    #[graphql(type_info = Info)]
+    struct Settings {
+        theme: prelude::String,
+        secret: prelude::String,
+        #[graphql(flatten)]
+        audit: Audit,
+    }
+
+    #[derive(GraphQLObject)]
+    struct Audit {
+        revision: i32,
+    }
+
+    #[derive(GraphQLObject)]
+    struct Avatar {
+        url: prelude::String,
+    }
+
+    #[derive(GraphQLInputObject)]
+    #[graphql(type_info = Info)]
+    struct Filter {
+        name: prelude::String,
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(complex, type_info = Info)]
+    struct User {
+        name: prelude::String,
+        secret: prelude::String,
+        avatar: Avatar,
+    }
+
+    #[graphql_object(complex)]
+    impl User {
+        fn settings() -> Settings {
+            Settings {
+                theme: "dark".into(),
+                secret: "42".into(),
+                audit: Audit { revision: 1 },
+            }
+        }
+
+        async fn friend(&self, filter: Filter) -> User {
+            User {
+                name: filter.name,
+                secret: self.secret.clone(),
+                avatar: Avatar {
+                    url: "https://avatar".into(),
+                },
+            }
+        }
+    }
+
+    fn schema() -> RootNode<User, EmptyMutation, EmptySubscription> {
+        RootNode::new_with_info(
+            User {
+                name: "John".into(),
+                secret: "42".into(),
+                avatar: Avatar {
+                    url: "https://avatar".into(),
+                },
+            },
+            EmptyMutation::new(),
+            EmptySubscription::new(),
+            Info {
+                hidden: &["secret"],
+                ..Info::DEFAULT
+            },
+            (),
+            (),
+        )
+    }
+
+    #[tokio::test]
+    async fn passes_type_info_to_fields_and_arguments() {
+        const DOC: &str = r#"{
+            userType: __type(name: "User") { fields { name } }
+            settingsType: __type(name: "Settings") { fields { name } }
+            filterType: __type(name: "Filter") { inputFields { name } }
+            avatar { url }
+            settings { theme revision }
+            friend(filter: {name: "Jane"}) { name avatar { url } }
+        }"#;
+
+        assert_eq!(
+            execute(DOC, None, &schema(), &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "userType": {"fields": [
+                        {"name": "name"},
+                        {"name": "avatar"},
+                        {"name": "settings"},
+                        {"name": "friend"},
+                    ]},
+                    "settingsType": {"fields": [{"name": "theme"}, {"name": "revision"}]},
+                    "filterType": {"inputFields": [{"name": "name"}]},
+                    "avatar": {"url": "https://avatar"},
+                    "settings": {"theme": "dark", "revision": 1},
+                    "friend": {"name": "Jane", "avatar": {"url": "https://avatar"}},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn hides_filtered_nested_fields() {
+        for doc in [
+            "{ settings { secret } }",
+            r#"{ friend(filter: {name: "Jane"}) { secret } }"#,
+        ] {
+            assert!(
+                execute(doc, None, &schema(), &graphql_vars! {}, &())
+                    .await
+                    .is_err(),
+                "document: {doc}",
+            );
+        }
+    }
+}
+
+mod subscription {
+    use super::*;
+
+    type Stream<'a, I> = Pin<prelude::Box<dyn futures::Stream<Item = I> + prelude::Send + 'a>>;
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn empty() -> bool {
+            true
+        }
+    }
+
+    struct Subscription;
+
+    #[graphql_subscription(type_info = Info)]
+    impl Subscription {
+        async fn ticks() -> Stream<'static, i32> {
+            prelude::Box::pin(stream::once(future::ready(1)))
+        }
+    }
+
+    #[tokio::test]
+    async fn uses_dynamic_definitions() {
+        const DOC: &str = r#"{
+            __schema {
+                subscriptionType {
+                    name
+                    description
+                }
+            }
+        }"#;
+
+        let schema: RootNode<_, _, _> = RootNode::new_with_info(
+            Query,
+            EmptyMutation::<()>::new(),
+            Subscription,
+            (),
+            (),
+            Info {
+                name: Some("Updates"),
+                description: Some("Live updates."),
+                ..Info::DEFAULT
+            },
+        );
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "__schema": {
+                        "subscriptionType": {
+                            "name": "Updates",
+                            "description": "Live updates.",
+                        },
+                    },
+                }),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod leaf_types {
+    use super::*;
+
+    /// Color of a theme.
+    #[derive(GraphQLEnum)]
+    #[graphql(type_info = Info)]
+    enum Color {
+        Red,
+        Green,
+    }
+
+    /// Filter of users.
+    #[derive(GraphQLInputObject)]
+    #[graphql(type_info = Info)]
+    struct Filter {
+        name: prelude::String,
+    }
+
+    /// Email address.
+    #[derive(GraphQLScalar)]
+    #[graphql(transparent, type_info = Info)]
+    struct Email(prelude::String);
+
+    #[derive(GraphQLObject)]
+    struct Human {
+        name: prelude::String,
+    }
+
+    #[derive(GraphQLObject)]
+    struct Droid {
+        model: prelude::String,
+    }
+
+    /// Any character.
+    #[derive(GraphQLUnion)]
+    #[graphql(type_info = Info)]
+    #[expect(dead_code, reason = "GraphQL schema testing")]
+    enum Character {
+        Human(Human),
+        Droid(Droid),
+    }
+
+    #[test]
+    fn uses_static_definitions_by_default() {
+        let info = Info::DEFAULT;
+
+        assert_eq!(
+            name_and_description::<Color>(&info),
+            ("Color".into(), Some("Color of a theme.".into())),
+        );
+        assert_eq!(
+            name_and_description::<Filter>(&info),
+            ("Filter".into(), Some("Filter of users.".into())),
+        );
+        assert_eq!(
+            name_and_description::<Email>(&info),
+            ("Email".into(), Some("Email address.".into())),
+        );
+        assert_eq!(
+            name_and_description::<Character>(&info),
+            ("Character".into(), Some("Any character.".into())),
+        );
+    }
+
+    #[test]
+    fn uses_dynamic_definitions() {
+        let info = Info {
+            name: Some("Dynamic"),
+            description: Some("Computed at runtime."),
+            ..Info::DEFAULT
+        };
+
+        for (name, description) in [
+            name_and_description::<Color>(&info),
+            name_and_description::<Filter>(&info),
+            name_and_description::<Email>(&info),
+            name_and_description::<Character>(&info),
+        ] {
+            assert_eq!(name, "Dynamic");
+            assert_eq!(description.as_deref(), Some("Computed at runtime."));
+        }
+    }
+}